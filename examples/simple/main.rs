@@ -21,7 +21,6 @@ pub struct App {
     memory: Memory,
     // Not relevant code to this crate, here to show performance at this point in time.
     fh: FrameHistory,
-    is_open: bool,
 }
 
 impl Default for App {
@@ -41,7 +40,6 @@ impl Default for App {
             mem_editor,
             memory: Default::default(),
             fh: Default::default(),
-            is_open: true,
         }
     }
 }
@@ -50,11 +48,10 @@ impl eframe::App for App {
     fn update(&mut self, ctx: &Context, frame: &mut Frame) {
         create_frame_history(ctx, frame, &mut self.fh);
 
-        // This will automatically check for `mem_editor.options.is_open`, so no need to do that here.
+        // The window can be shown and hidden with `mem_editor.set_open`, so no need to check for that here.
         // The write function is optional, if you don't set it the UI will be in read-only mode.
         self.mem_editor.window_ui(
             ctx,
-            &mut self.is_open,
             &mut self.memory,
             |mem, address| mem.read_value(address).into(),
             |mem, address, val| mem.write_value(address, val),
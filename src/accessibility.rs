@@ -0,0 +1,51 @@
+//! Descriptions of the values for screen readers, which are exposed through egui's widget info (and AccessKit).
+use egui::{Response, WidgetInfo, WidgetType};
+
+use crate::Address;
+use crate::translations::Translator;
+
+/// The state of a value which is read out along with it.
+#[derive(Copy, Clone, Debug, Default)]
+pub(crate) struct CellState {
+    pub is_cursor: bool,
+    pub is_selected: bool,
+}
+
+/// Describe the value at `address` to screen readers, like `address 0xC012, value 3F, character '?', selected`.
+pub(crate) fn describe_cell(
+    response: &Response,
+    tr: &Translator,
+    address: Address,
+    value: Option<u8>,
+    state: CellState,
+) {
+    response.widget_info(|| WidgetInfo::labeled(WidgetType::Label, true, cell_description(tr, address, value, state)));
+}
+
+fn cell_description(tr: &Translator, address: Address, value: Option<u8>, state: CellState) -> String {
+    let address = format!("{:#X}", address);
+    let mut description = match value {
+        Some(value) if value.is_ascii_graphic() || value == b' ' => tr.format(
+            "address {address}, value {value}, character '{character}'",
+            &[
+                ("address", &address),
+                ("value", &format!("{:02X}", value)),
+                ("character", &(value as char)),
+            ],
+        ),
+        Some(value) => tr.format(
+            "address {address}, value {value}",
+            &[("address", &address), ("value", &format!("{:02X}", value))],
+        ),
+        None => tr.format("address {address}, unreadable", &[("address", &address)]),
+    };
+
+    if state.is_cursor {
+        description.push_str(&tr.text(", cursor"));
+    }
+    if state.is_selected {
+        description.push_str(&tr.text(", selected"));
+    }
+
+    description
+}
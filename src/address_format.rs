@@ -0,0 +1,121 @@
+//! How addresses are displayed in the address column, the goto box, and exports, see [`AddressFormat`].
+use std::ops::Range;
+
+use crate::{Address, MemoryEditor};
+
+/// The notation in which addresses are displayed and entered.
+///
+/// ```
+/// # use egui_memory_editor::address_format::AddressFormat;
+/// let banked = AddressFormat::Banked { bank_size: 0x4000 };
+/// assert_eq!(banked.format(0x14005, 0x20000), "05:0005");
+/// assert_eq!(banked.parse("5:5"), Some(0x14005));
+///
+/// assert_eq!(AddressFormat::Segmented.format(0x12345, 0x100000), "1234:0005");
+/// assert_eq!(AddressFormat::Segmented.parse("1000:0345"), Some(0x10345));
+/// ```
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum AddressFormat {
+    /// A single hexadecimal number, like `0x1234`.
+    #[default]
+    Linear,
+    /// The bank and the offset within the bank, like `BANK:OFFSET` for banked cartridge memory.
+    Banked { bank_size: usize },
+    /// The segment and offset of x86 real mode, where segments are 16 byte paragraphs, like `SEG:OFF`.
+    Segmented,
+}
+
+impl AddressFormat {
+    /// The size of a real mode paragraph, the distance between consecutive segments.
+    const PARAGRAPH_SIZE: Address = 16;
+
+    pub fn iter() -> impl Iterator<Item = AddressFormat> {
+        [
+            AddressFormat::Linear,
+            AddressFormat::Banked { bank_size: 0x4000 },
+            AddressFormat::Segmented,
+        ]
+        .into_iter()
+    }
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            AddressFormat::Linear => "Linear",
+            AddressFormat::Banked { .. } => "BANK:OFFSET",
+            AddressFormat::Segmented => "SEG:OFF",
+        }
+    }
+
+    /// Format `address`, padded so that every address below `end` has the same width.
+    pub fn format(&self, address: Address, end: Address) -> String {
+        match *self {
+            AddressFormat::Linear => {
+                let characters = end.next_power_of_two().ilog2() as usize / 4;
+                format!("0x{:01$X}", address, characters)
+            }
+            AddressFormat::Banked { bank_size } => {
+                let bank_size = bank_size.max(1);
+                let bank_characters = hex_digits(end.saturating_sub(1) / bank_size).max(2);
+                let offset_characters = hex_digits(bank_size - 1);
+                format!(
+                    "{:02$X}:{:03$X}",
+                    address / bank_size,
+                    address % bank_size,
+                    bank_characters,
+                    offset_characters
+                )
+            }
+            AddressFormat::Segmented => {
+                let segment_characters = hex_digits(end.saturating_sub(1) / Self::PARAGRAPH_SIZE).max(4);
+                format!(
+                    "{:02$X}:{:04X}",
+                    address / Self::PARAGRAPH_SIZE,
+                    address % Self::PARAGRAPH_SIZE,
+                    segment_characters
+                )
+            }
+        }
+    }
+
+    /// Parse an address written in this notation, a plain hexadecimal address (optionally prefixed with `0x`) is
+    /// accepted in every notation.
+    pub fn parse(&self, text: &str) -> Option<Address> {
+        let parse_hex = |text: &str| {
+            let text = text.trim();
+            let text = text
+                .strip_prefix("0x")
+                .or_else(|| text.strip_prefix("0X"))
+                .unwrap_or(text);
+            Address::from_str_radix(text, 16).ok()
+        };
+
+        let Some((high, low)) = text.split_once(':') else {
+            return parse_hex(text);
+        };
+        let unit = match *self {
+            AddressFormat::Linear => return None,
+            AddressFormat::Banked { bank_size } => bank_size,
+            AddressFormat::Segmented => Self::PARAGRAPH_SIZE,
+        };
+
+        parse_hex(high)?.checked_mul(unit)?.checked_add(parse_hex(low)?)
+    }
+}
+
+impl MemoryEditor {
+    /// Format byte lane addresses as the word addresses they're displayed as, padded to the width of the addresses in
+    /// `address_space`.
+    pub(crate) fn address_formatter(&self, address_space: &Range<Address>) -> impl Fn(Address) -> String + use<> {
+        let address_format = self.options.address_format;
+        let word_size = self.options.word_size;
+        let end = word_size.word_address(address_space.end);
+
+        move |address| address_format.format(word_size.word_address(address), end)
+    }
+}
+
+/// The amount of hexadecimal digits needed to display `value`.
+fn hex_digits(value: usize) -> usize {
+    ((usize::BITS - value.leading_zeros()) as usize).div_ceil(4).max(1)
+}
@@ -0,0 +1,78 @@
+//! Addresses written in free text, such as annotations and bookmark notes, drawn as links which jump to them.
+use egui::{Color32, RichText, Ui};
+
+use crate::Address;
+
+/// A part of a text, either plain text or an address written like `0xC230`.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+enum Segment<'a> {
+    Text(&'a str),
+    Address(&'a str, Address),
+}
+
+/// Split `text` into plain text and the hexadecimal addresses in it, which start with `0x` and aren't part of a
+/// longer word.
+fn segments(text: &str) -> Vec<Segment<'_>> {
+    let mut segments = Vec::new();
+    let mut text_start = 0;
+    let mut search_start = 0;
+
+    while let Some(found) = text[search_start..].find("0x") {
+        let start = search_start + found;
+        let digits = &text[start + 2..];
+        let digit_count = digits.chars().take_while(char::is_ascii_hexdigit).count();
+        let end = start + 2 + digit_count;
+        let is_word_start = !text[..start].ends_with(|c: char| c.is_alphanumeric() || c == '_');
+        let is_word_end = !text[end..].starts_with(|c: char| c.is_alphanumeric() || c == '_');
+
+        match Address::from_str_radix(&digits[..digit_count], 16) {
+            Ok(address) if digit_count > 0 && is_word_start && is_word_end => {
+                if text_start < start {
+                    segments.push(Segment::Text(&text[text_start..start]));
+                }
+                segments.push(Segment::Address(&text[start..end], address));
+                text_start = end;
+            }
+            _ => {}
+        }
+        search_start = end.max(start + 2);
+    }
+
+    if text_start < text.len() {
+        segments.push(Segment::Text(&text[text_start..]));
+    }
+
+    segments
+}
+
+/// Draw `text` in `colour`, with the addresses in it drawn as links. Returns the address of the clicked link, if any.
+pub(crate) fn draw_linked_text(ui: &mut Ui, text: &str, colour: Option<Color32>) -> Option<Address> {
+    let mut clicked = None;
+
+    ui.vertical(|ui| {
+        for line in text.lines() {
+            ui.horizontal_wrapped(|ui| {
+                ui.spacing_mut().item_spacing.x = 0.0;
+
+                for segment in segments(line) {
+                    match segment {
+                        Segment::Text(text) => {
+                            let mut text = RichText::new(text);
+                            if let Some(colour) = colour {
+                                text = text.color(colour);
+                            }
+                            ui.label(text);
+                        }
+                        Segment::Address(text, address) => {
+                            if ui.link(text).clicked() {
+                                clicked = Some(address);
+                            }
+                        }
+                    }
+                }
+            });
+        }
+    });
+
+    clicked
+}
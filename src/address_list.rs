@@ -0,0 +1,241 @@
+//! Importing named addresses from existing symbol artefacts, such as a CSV export or a linker map, see
+//! [`AddressListFormat`].
+#[cfg(feature = "bookmarks")]
+use egui::Ui;
+
+use crate::address_format::AddressFormat;
+#[cfg(feature = "bookmarks")]
+use crate::bookmarks::Bookmark;
+#[cfg(feature = "bookmarks")]
+use crate::notices::Notice;
+use crate::translations::Translator;
+use crate::{Address, MemoryEditor};
+
+/// The format of a list of named addresses.
+///
+/// Addresses are read in the given [`AddressFormat`], so `BANK:OFFSET` addresses can be imported when the banked
+/// notation is used.
+///
+/// ```
+/// # use egui_memory_editor::address_format::AddressFormat;
+/// # use egui_memory_editor::address_list::AddressListFormat;
+/// let csv = "address,name\n0xC000,wPlayerX\nC001,\"wPlayerY\"\n";
+/// assert_eq!(
+///     AddressListFormat::Csv.parse(csv, AddressFormat::Linear),
+///     Ok(vec![(0xC000, "wPlayerX".to_string()), (0xC001, "wPlayerY".to_string())])
+/// );
+///
+/// let map = "
+///  .text          0x08000000      0x1a4 main.o
+///                 0x08000000                _start
+///                 0x08000040                main
+/// 080000c0 T reset_handler
+/// ";
+/// assert_eq!(
+///     AddressListFormat::LinkerMap.parse(map, AddressFormat::Linear),
+///     Ok(vec![
+///         (0x08000000, "_start".to_string()),
+///         (0x08000040, "main".to_string()),
+///         (0x080000C0, "reset_handler".to_string()),
+///     ])
+/// );
+/// ```
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum AddressListFormat {
+    /// One `address,name` pair per line, optionally preceded by a header line. Further columns are ignored.
+    #[default]
+    Csv,
+    /// A linker map or symbol file with one `address name` pair per line, like the symbol lines of a GNU ld map,
+    /// `nm` output, or `BANK:ADDRESS name` symbol files. Lines in any other form, such as section headers, are
+    /// skipped.
+    LinkerMap,
+}
+
+impl AddressListFormat {
+    pub fn iter() -> impl Iterator<Item = AddressListFormat> {
+        [AddressListFormat::Csv, AddressListFormat::LinkerMap].into_iter()
+    }
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            AddressListFormat::Csv => "CSV",
+            AddressListFormat::LinkerMap => "Linker map",
+        }
+    }
+
+    /// Read the named addresses in `text`, in the order they're listed.
+    ///
+    /// Returns why the list couldn't be read if a CSV line is malformed, or if no named address was found.
+    pub fn parse(&self, text: &str, address_format: AddressFormat) -> Result<Vec<(Address, String)>, String> {
+        self.parse_translated(text, address_format, &Translator::default())
+    }
+
+    /// Read the named addresses in `text` like [`Self::parse`], translating why the list couldn't be read.
+    fn parse_translated(
+        &self,
+        text: &str,
+        address_format: AddressFormat,
+        tr: &Translator,
+    ) -> Result<Vec<(Address, String)>, String> {
+        let entries = match self {
+            AddressListFormat::Csv => parse_csv(text, address_format, tr)?,
+            AddressListFormat::LinkerMap => text
+                .lines()
+                .filter_map(|line| parse_linker_map_line(line, address_format))
+                .collect(),
+        };
+
+        if entries.is_empty() {
+            return Err(tr.text("No named addresses found").into_owned());
+        }
+        Ok(entries)
+    }
+}
+
+/// Read `address,name` lines, skipping empty lines, `#` comments, and a header on the first line.
+fn parse_csv(text: &str, address_format: AddressFormat, tr: &Translator) -> Result<Vec<(Address, String)>, String> {
+    let mut entries = Vec::new();
+
+    for (index, line) in text.lines().enumerate() {
+        let line = line.trim();
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+        let mut columns = line.split(',').map(|column| column.trim().trim_matches('"'));
+        let (address, name) = (columns.next().unwrap_or_default(), columns.next().unwrap_or_default());
+
+        match address_format.parse(address) {
+            Some(address) if !name.is_empty() => entries.push((address, name.to_string())),
+            Some(_) => return Err(tr.format("Line {line} has no name", &[("line", &(index + 1))])),
+            None if entries.is_empty() && index == 0 => continue,
+            None => {
+                return Err(tr.format(
+                    "Line {line}: `{address}` is not an address",
+                    &[("line", &(index + 1)), ("address", &address)],
+                ));
+            }
+        }
+    }
+
+    Ok(entries)
+}
+
+/// Read a line consisting of an address and a name, optionally separated by a single letter symbol type as in `nm`
+/// output. Returns `None` for any other line.
+fn parse_linker_map_line(line: &str, address_format: AddressFormat) -> Option<(Address, String)> {
+    let line = line.split(';').next().unwrap_or_default();
+    let tokens = line.split_whitespace().collect::<Vec<_>>();
+    let (address, name) = match tokens[..] {
+        [address, name] => (address, name),
+        [address, kind, name] if kind.len() == 1 && kind.chars().all(|c| c.is_ascii_alphabetic()) => (address, name),
+        _ => return None,
+    };
+
+    let is_identifier = name.starts_with(|c: char| c.is_ascii_alphabetic() || "_.$@".contains(c))
+        && name.chars().all(|c| c.is_ascii_alphanumeric() || "_.$@".contains(c))
+        && name != ".";
+    if !is_identifier {
+        return None;
+    }
+
+    let address = address.strip_prefix('$').unwrap_or(address);
+    Some((address_format.parse(address)?, name.to_string()))
+}
+
+impl MemoryEditor {
+    /// Register every named address in `text` as a symbol, replacing the existing symbols at the same addresses.
+    ///
+    /// Returns the amount of symbols read, or why the list couldn't be read, in which case nothing is registered.
+    ///
+    /// ```
+    /// # use egui_memory_editor::MemoryEditor;
+    /// # use egui_memory_editor::address_format::AddressFormat;
+    /// # use egui_memory_editor::address_list::AddressListFormat;
+    /// let mut memory_editor = MemoryEditor::new().with_address_range("ROM", 0..0x80000);
+    /// memory_editor.options.address_format = AddressFormat::Banked { bank_size: 0x4000 };
+    /// let map = "01:0000 ItemTable ; bank 1\n01:0100 EnemyTable\n";
+    ///
+    /// assert_eq!(memory_editor.import_symbol_list(map, AddressListFormat::LinkerMap), Ok(2));
+    /// assert_eq!(memory_editor.symbols()[&0x4100], "EnemyTable");
+    /// ```
+    pub fn import_symbol_list(&mut self, text: &str, format: AddressListFormat) -> Result<usize, String> {
+        let entries = format.parse_translated(text, self.options.address_format, &self.translator())?;
+        let count = entries.len();
+
+        self.symbols.extend(entries);
+        Ok(count)
+    }
+
+    /// Add a bookmark for every named address in `text`, after the existing bookmarks.
+    ///
+    /// Returns the amount of bookmarks added, or why the list couldn't be read, in which case nothing is added.
+    #[cfg(feature = "bookmarks")]
+    pub fn import_bookmark_list(&mut self, text: &str, format: AddressListFormat) -> Result<usize, String> {
+        let entries = format.parse_translated(text, self.options.address_format, &self.translator())?;
+        let count = entries.len();
+
+        self.bookmarks
+            .extend(entries.into_iter().map(|(address, name)| Bookmark::new(name, address)));
+        Ok(count)
+    }
+
+    /// Draw the controls for importing a pasted address list as symbols or bookmarks.
+    #[cfg(feature = "bookmarks")]
+    pub(crate) fn draw_address_list_import(&mut self, ui: &mut Ui) {
+        let tr = self.translator();
+
+        ui.collapsing(tr.text("Address list"), |ui| {
+            let format = &mut self.frame_data.address_list_format;
+            egui::ComboBox::from_id_salt("AddressListFormat")
+                .selected_text(tr.text(format.name()))
+                .show_ui(ui, |ui| {
+                    for option in AddressListFormat::iter() {
+                        ui.selectable_value(format, option, tr.text(option.name()));
+                    }
+                })
+                .response
+                .on_hover_text(
+                    tr.text("CSV lines of `address,name`, or the `address name` lines of a linker map or symbol file"),
+                );
+
+            ui.add(
+                egui::TextEdit::multiline(&mut self.frame_data.address_list_text)
+                    .hint_text(tr.text("Paste an address list"))
+                    .desired_rows(3),
+            );
+            ui.horizontal(|ui| {
+                let format = self.frame_data.address_list_format;
+                let mut result = None;
+                if ui
+                    .button(tr.text("Import as symbols"))
+                    .on_hover_text(tr.text("Show the names as section rows and use them in address expressions"))
+                    .clicked()
+                {
+                    let text = std::mem::take(&mut self.frame_data.address_list_text);
+                    result = Some(self.import_symbol_list(&text, format));
+                }
+                if ui.button(tr.text("Import as bookmarks")).clicked() {
+                    let text = std::mem::take(&mut self.frame_data.address_list_text);
+                    result = Some(self.import_bookmark_list(&text, format));
+                }
+
+                if let Some(Err(error)) = &result {
+                    self.frame_data.notify(Notice::ImportFailed {
+                        what: "an address list".to_string(),
+                        error: error.clone(),
+                    });
+                }
+                if result.is_some() {
+                    self.frame_data.address_list_import_result = result;
+                }
+            });
+
+            match &self.frame_data.address_list_import_result {
+                Some(Ok(count)) => ui.label(tr.format("Imported {count} address(es)", &[("count", count)])),
+                Some(Err(error)) => ui.colored_label(ui.visuals().error_fg_color, error),
+                None => ui.weak(tr.text("Addresses are read in the selected address notation")),
+            };
+        });
+    }
+}
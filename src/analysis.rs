@@ -0,0 +1,476 @@
+//! Heuristics which guess what kind of data a selection contains, as a starting point when reverse engineering.
+//!
+//! ```
+//! # use egui_memory_editor::analysis::{self, Finding};
+//! # use egui_memory_editor::option_data::Endianness;
+//! # use egui_memory_editor::pointer_scan::PointerWidth;
+//! // A table of little endian pointers into the first 16 KiB.
+//! let table = [0x0150u16, 0x0210, 0x0388, 0x0400, 0x04A0, 0x0512]
+//!     .iter()
+//!     .flat_map(|pointer| pointer.to_le_bytes())
+//!     .collect::<Vec<u8>>();
+//! let result = analysis::analyze(&table, &[0..0x4000]);
+//! assert!(result.findings.contains(&Finding::PointerTable {
+//!     width: PointerWidth::Bits16,
+//!     endianness: Endianness::Little,
+//!     pointers: 6,
+//! }));
+//!
+//! let text = b"Press START to begin\0Continue\0Options\0";
+//! assert!(matches!(analysis::analyze(text, &[0..0x4000]).findings[0], Finding::Text { .. }));
+//! ```
+use std::ops::Range;
+
+use egui::{Color32, Ui};
+
+use crate::instrumentation::{AccessReason, with_access_reason};
+use crate::option_data::Endianness;
+use crate::pointer_scan::PointerWidth;
+use crate::translations::Translator;
+use crate::{Address, MemoryEditor};
+
+/// The most bytes analyzed at once, to keep the analysis of large selections responsive.
+const MAX_ANALYZED_BYTES: usize = 0x10000;
+/// The largest record size considered when looking for repeating records.
+const MAX_RECORD_SIZE: usize = 0x100;
+/// The fraction of bytes which have to match the byte one record further for the data to be considered records.
+const MIN_RECORD_SIMILARITY: f32 = 0.5;
+/// The fraction of values which have to point into the address ranges for the data to be considered a pointer table.
+const MIN_POINTER_FRACTION: f32 = 0.75;
+/// The background of every other record while a detected stride is overlaid on the selection.
+const STRIDE_OVERLAY_COLOUR: Color32 = Color32::from_rgba_premultiplied(40, 60, 110, 60);
+
+/// A single guess about the contents of the analyzed data.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum Finding {
+    /// Every byte has the same value, like padding or cleared memory.
+    Uniform { value: u8 },
+    /// ASCII text, possibly a list of NUL terminated strings, with the fraction of printable characters.
+    Text { printable: f32 },
+    /// A table of pointers into the address ranges, with the amount of pointers.
+    PointerTable {
+        width: PointerWidth,
+        endianness: Endianness,
+        pointers: usize,
+    },
+    /// Records of a fixed size, with the fraction of bytes equal to the byte one record further.
+    Records { size: usize, similarity: f32 },
+    /// Close to random data, like compressed or encrypted data, with the entropy in bits per byte.
+    HighEntropy { entropy: f32 },
+}
+
+impl Finding {
+    /// The translated description of the finding.
+    pub(crate) fn message(&self, tr: &Translator) -> String {
+        match self {
+            Finding::Uniform { value } => tr.format(
+                "Every byte is {value}, likely padding or cleared memory",
+                &[("value", &format!("{:#04X}", value))],
+            ),
+            Finding::Text { printable } => tr.format(
+                "ASCII text, {printable}% printable",
+                &[("printable", &format!("{:.0}", printable * 100.0))],
+            ),
+            Finding::PointerTable {
+                width,
+                endianness,
+                pointers,
+            } => tr.format(
+                "A table of {count} {endianness} endian {width}-byte pointers",
+                &[
+                    ("count", pointers),
+                    ("endianness", &format!("{:?}", endianness)),
+                    ("width", &width.bytes()),
+                ],
+            ),
+            Finding::Records { size, similarity } => tr.format(
+                "Records of {size} ({size_hex}) bytes, {similarity}% of bytes repeat every record",
+                &[
+                    ("size", size),
+                    ("size_hex", &format!("{:#X}", size)),
+                    ("similarity", &format!("{:.0}", similarity * 100.0)),
+                ],
+            ),
+            Finding::HighEntropy { entropy } => tr.format(
+                "Close to random at {entropy} bits per byte, likely compressed or encrypted",
+                &[("entropy", &format!("{:.2}", entropy))],
+            ),
+        }
+    }
+}
+
+impl std::fmt::Display for Finding {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.message(&Translator::default()))
+    }
+}
+
+/// The result of analyzing a run of bytes, see [`analyze`].
+#[derive(Clone, Debug, PartialEq)]
+pub struct Analysis {
+    /// The amount of analyzed bytes.
+    pub len: usize,
+    /// The Shannon entropy in bits per byte, from `0.0` for uniform data to `8.0` for random data.
+    pub entropy: f32,
+    /// The guesses about the contents, from most to least specific.
+    pub findings: Vec<Finding>,
+}
+
+/// A stride detected in the selection, overlaid on the selected addresses until it's dismissed.
+#[derive(Clone, Debug)]
+pub(crate) struct DetectedStride {
+    /// The addresses the stride was detected in, from the start of the first selected run to the end of the last.
+    pub range: Range<Address>,
+    pub stride: usize,
+    pub similarity: f32,
+}
+
+/// Guess what kind of data `bytes` contains, where pointers are expected to point into one of `pointer_targets`.
+pub fn analyze(bytes: &[u8], pointer_targets: &[Range<Address>]) -> Analysis {
+    let mut findings = Vec::new();
+    let entropy = entropy(bytes);
+
+    if let Some(&value) = bytes.first()
+        && bytes.iter().all(|byte| *byte == value)
+    {
+        findings.push(Finding::Uniform { value });
+        return Analysis {
+            len: bytes.len(),
+            entropy,
+            findings,
+        };
+    }
+
+    let text = text_finding(bytes);
+    // Text happens to look like a table of pointers into a small address range, so it's never both.
+    let pointers = text
+        .is_none()
+        .then(|| pointer_table_finding(bytes, pointer_targets))
+        .flatten();
+    findings.extend(text);
+    findings.extend(pointers);
+
+    if let Some((size, similarity)) = dominant_stride(bytes, MAX_RECORD_SIZE) {
+        findings.push(Finding::Records { size, similarity });
+    }
+
+    // Short runs can't reach the full 8 bits per byte, so the threshold depends on the length.
+    let max_entropy = (bytes.len().min(256) as f32).log2();
+    if bytes.len() >= 64 && entropy >= 0.9 * max_entropy {
+        findings.push(Finding::HighEntropy { entropy });
+    }
+
+    Analysis {
+        len: bytes.len(),
+        entropy,
+        findings,
+    }
+}
+
+/// The Shannon entropy of `bytes` in bits per byte.
+pub fn entropy(bytes: &[u8]) -> f32 {
+    let mut counts = [0usize; 256];
+    for byte in bytes {
+        counts[*byte as usize] += 1;
+    }
+
+    counts
+        .iter()
+        .filter(|count| **count > 0)
+        .map(|count| {
+            let probability = *count as f32 / bytes.len() as f32;
+            -probability * probability.log2()
+        })
+        .sum()
+}
+
+/// The most likely record size of at most `max_stride` bytes, found by comparing every byte with the byte a record
+/// further, along with the fraction of bytes which match.
+///
+/// Multiples of the record size match just as well, so the smallest stride close to the best match is returned.
+///
+/// ```
+/// # use egui_memory_editor::analysis::dominant_stride;
+/// // Records of a 4-byte id, a type, and a varying value.
+/// let records = (0..32u8).flat_map(|i| [0x10, 0x00, 0x00, i / 4, 0x03, i]).collect::<Vec<u8>>();
+/// assert_eq!(dominant_stride(&records, 0x40).map(|(stride, _)| stride), Some(6));
+/// ```
+pub fn dominant_stride(bytes: &[u8], max_stride: usize) -> Option<(usize, f32)> {
+    // At least three records are needed to tell that they repeat.
+    let max_stride = max_stride.min(bytes.len() / 3);
+    let similarities = (2..=max_stride)
+        .map(|stride| {
+            let matches = bytes.iter().zip(&bytes[stride..]).filter(|(a, b)| a == b).count();
+            (stride, matches as f32 / (bytes.len() - stride) as f32)
+        })
+        .collect::<Vec<_>>();
+
+    let best = similarities
+        .iter()
+        .map(|(_, similarity)| *similarity)
+        .fold(0.0, f32::max);
+    let mean = similarities.iter().map(|(_, similarity)| similarity).sum::<f32>() / similarities.len().max(1) as f32;
+    // Data which matches itself at every stride, like mostly zeroes, has no meaningful record size.
+    if best < MIN_RECORD_SIMILARITY || best - mean < 0.2 {
+        return None;
+    }
+
+    similarities
+        .into_iter()
+        .find(|(_, similarity)| *similarity >= 0.9 * best)
+}
+
+/// Detect ASCII text, where NUL bytes are allowed as string terminators.
+fn text_finding(bytes: &[u8]) -> Option<Finding> {
+    let printable = bytes
+        .iter()
+        .filter(|byte| byte.is_ascii_graphic() || matches!(byte, b' ' | b'\t' | b'\n' | b'\r'))
+        .count();
+    let zeroes = bytes.iter().filter(|byte| **byte == 0).count();
+    let len = bytes.len() as f32;
+
+    (printable >= 4 && printable as f32 >= 0.6 * len && (printable + zeroes) as f32 >= 0.95 * len).then(|| {
+        Finding::Text {
+            printable: printable as f32 / len,
+        }
+    })
+}
+
+/// Detect a table of pointers, trying wider pointers first.
+///
+/// Besides pointing into the `targets`, the pointers have to be mostly distinct and close together, as random data
+/// would otherwise look like a table of small pointers.
+fn pointer_table_finding(bytes: &[u8], targets: &[Range<Address>]) -> Option<Finding> {
+    let target_len = targets
+        .iter()
+        .map(|range| range.len())
+        .fold(0usize, usize::saturating_add);
+
+    [PointerWidth::Bits64, PointerWidth::Bits32, PointerWidth::Bits16]
+        .into_iter()
+        .flat_map(|width| Endianness::iter().map(move |endianness| (width, endianness)))
+        .find_map(|(width, endianness)| {
+            let values = bytes
+                .chunks_exact(width.bytes())
+                .map(|chunk| match endianness {
+                    Endianness::Big => chunk.iter().fold(0u64, |acc, &byte| (acc << 8) | byte as u64),
+                    Endianness::Little => chunk.iter().rev().fold(0u64, |acc, &byte| (acc << 8) | byte as u64),
+                })
+                .collect::<Vec<u64>>();
+            if values.len() < 4 {
+                return None;
+            }
+
+            let mut pointers = values
+                .iter()
+                .filter_map(|value| Address::try_from(*value).ok())
+                .filter(|address| *address != 0 && targets.iter().any(|range| range.contains(address)))
+                .collect::<Vec<Address>>();
+            if (pointers.len() as f32) < MIN_POINTER_FRACTION * values.len() as f32 {
+                return None;
+            }
+
+            pointers.sort_unstable();
+            let span = pointers.last()? - pointers.first()?;
+            let count = pointers.len();
+            pointers.dedup();
+            (pointers.len() * 2 >= count && span <= target_len / 4).then_some(Finding::PointerTable {
+                width,
+                endianness,
+                pointers: count,
+            })
+        })
+}
+
+impl MemoryEditor {
+    /// Analyze the selected values, see [`analyze`].
+    ///
+    /// Unreadable values are skipped, and only the first 64 KiB of large selections is analyzed.
+    pub fn analyze_selection<T: ?Sized>(
+        &self,
+        mem: &mut T,
+        read_fn: impl FnMut(&mut T, Address) -> Option<u8>,
+    ) -> Analysis {
+        let bytes = self.selected_bytes(mem, read_fn);
+        let targets = self
+            .ordered_address_ranges()
+            .into_iter()
+            .map(|(_, range)| range.clone())
+            .collect::<Vec<_>>();
+
+        analyze(&bytes, &targets)
+    }
+
+    /// The dominant repeating stride of the selected values along with how well it matches, see [`dominant_stride`].
+    ///
+    /// ```
+    /// # use egui_memory_editor::MemoryEditor;
+    /// # use egui_memory_editor::selection::Selection;
+    /// // An array of 12 byte structures, each with an index and a few constant fields.
+    /// let memory = (0..8u8)
+    ///     .flat_map(|i| [0xAB, 0xCD, i, 0x00, 0x10, 0x20, 0x30, 0x40, 0x01, 0x02, 0x03, i * 2])
+    ///     .collect::<Vec<u8>>();
+    /// let mut memory_editor = MemoryEditor::new().with_address_range("Memory", 0..0x60);
+    /// memory_editor.set_selection(Selection::from_range(0..0x60));
+    ///
+    /// let stride = memory_editor.detect_selection_stride(&mut &memory[..], |mem, addr| mem.get(addr).copied());
+    /// assert_eq!(stride.map(|(stride, _)| stride), Some(12));
+    /// ```
+    pub fn detect_selection_stride<T: ?Sized>(
+        &self,
+        mem: &mut T,
+        read_fn: impl FnMut(&mut T, Address) -> Option<u8>,
+    ) -> Option<(usize, f32)> {
+        dominant_stride(&self.selected_bytes(mem, read_fn), MAX_RECORD_SIZE)
+    }
+
+    /// The readable selected values, at most [`MAX_ANALYZED_BYTES`] of them.
+    fn selected_bytes<T: ?Sized>(
+        &self,
+        mem: &mut T,
+        mut read_fn: impl FnMut(&mut T, Address) -> Option<u8>,
+    ) -> Vec<u8> {
+        self.frame_data
+            .selection
+            .merged_ranges()
+            .into_iter()
+            .flatten()
+            .take(MAX_ANALYZED_BYTES)
+            .filter_map(|address| read_fn(mem, address))
+            .collect()
+    }
+
+    /// The background of `address` while a detected stride is overlaid, shading every other record.
+    pub(crate) fn stride_overlay_colour(&self, address: Address) -> Option<Color32> {
+        let detected = self.frame_data.detected_stride.as_ref()?;
+        let is_odd_record = ((address.checked_sub(detected.range.start)? / detected.stride) % 2) == 1;
+
+        (detected.range.contains(&address) && is_odd_record).then_some(STRIDE_OVERLAY_COLOUR)
+    }
+
+    /// Draw the button to analyze the selection, along with the findings of the last analysis.
+    pub(crate) fn draw_analysis_options<T: ?Sized>(
+        &mut self,
+        ui: &mut Ui,
+        mem: &mut T,
+        read: &mut impl FnMut(&mut T, Address) -> Option<u8>,
+    ) {
+        let tr = self.translator();
+        ui.collapsing(tr.text("Analysis"), |ui| {
+            ui.horizontal(|ui| {
+                if ui
+                    .add_enabled(
+                        !self.frame_data.selection.is_empty(),
+                        egui::Button::new(tr.text("🔬 Analyze selection")),
+                    )
+                    .on_hover_text(
+                        tr.text("Guess whether the selection contains text, pointers, records, or compressed data"),
+                    )
+                    .on_disabled_hover_text(tr.text("Select the values to analyze first"))
+                    .clicked()
+                {
+                    let read = with_access_reason(self.access_reason.clone(), AccessReason::Search, read);
+                    self.frame_data.analysis = Some(self.analyze_selection(mem, read));
+                }
+
+                if ui
+                    .add_enabled(
+                        !self.frame_data.selection.is_empty(),
+                        egui::Button::new(tr.text("📏 Detect stride")),
+                    )
+                    .on_hover_text(
+                        tr.text(
+                            "Find the size of the repeating structures in the selection, and shade every other one",
+                        ),
+                    )
+                    .on_disabled_hover_text(tr.text("Select the values to analyze first"))
+                    .clicked()
+                {
+                    let read = with_access_reason(self.access_reason.clone(), AccessReason::Search, &mut *read);
+                    let detected = self.detect_selection_stride(mem, read);
+                    self.frame_data.detected_stride = match (detected, self.selection_span()) {
+                        (Some((stride, similarity)), Some(range)) => Some(DetectedStride {
+                            range,
+                            stride,
+                            similarity,
+                        }),
+                        _ => None,
+                    };
+                    self.frame_data.stride_not_found = self.frame_data.detected_stride.is_none();
+                }
+
+                let has_results = self.frame_data.analysis.is_some()
+                    || self.frame_data.detected_stride.is_some()
+                    || self.frame_data.stride_not_found;
+                if has_results && ui.button(tr.text("🗑 Clear")).clicked() {
+                    self.frame_data.analysis = None;
+                    self.frame_data.detected_stride = None;
+                    self.frame_data.stride_not_found = false;
+                }
+            });
+
+            self.draw_detected_stride(ui);
+
+            let Some(analysis) = &self.frame_data.analysis else {
+                return;
+            };
+
+            ui.label(tr.format(
+                "{count} bytes, {entropy} bits of entropy per byte",
+                &[
+                    ("count", &analysis.len),
+                    ("entropy", &format!("{:.2}", analysis.entropy)),
+                ],
+            ));
+            if analysis.findings.is_empty() {
+                ui.weak(tr.text("No recognizable structure"));
+            }
+            for finding in &analysis.findings {
+                ui.label(format!("• {}", finding.message(&tr)));
+            }
+        });
+    }
+
+    /// Draw the detected stride, offering to align the columns or records to it.
+    fn draw_detected_stride(&mut self, ui: &mut Ui) {
+        let tr = self.translator();
+        let Some(detected) = &self.frame_data.detected_stride else {
+            if self.frame_data.stride_not_found {
+                ui.weak(tr.text("No repeating stride found in the selection"));
+            }
+            return;
+        };
+        let stride = detected.stride;
+
+        ui.horizontal(|ui| {
+            ui.label(tr.format(
+                "Repeats every {stride} ({stride_hex}) bytes, {similarity}% similar",
+                &[
+                    ("stride", &stride),
+                    ("stride_hex", &format!("{:#X}", stride)),
+                    ("similarity", &format!("{:.0}", detected.similarity * 100.0)),
+                ],
+            ));
+
+            if ui
+                .add_enabled(
+                    stride <= self.options.max_column_count,
+                    egui::Button::new(tr.text("Use as columns")),
+                )
+                .on_hover_text(tr.text("Show a single structure per row"))
+                .on_disabled_hover_text(tr.text("The stride is larger than the maximum column count"))
+                .clicked()
+            {
+                self.options.column_count = stride;
+            }
+
+            if ui
+                .button(tr.text("Use as records"))
+                .on_hover_text(tr.text("Enable record mode with the stride as the record size"))
+                .clicked()
+            {
+                self.options.record_size = Some(stride);
+            }
+        });
+    }
+}
@@ -0,0 +1,289 @@
+use std::ops::Range;
+
+use egui::{Align2, CursorIcon, Rect, Response, Sense, Ui, Vec2};
+
+use crate::highlights::SELECTION_GROUP;
+use crate::option_data::{NonPrintableStyle, SidebarEncoding, UnreadableStyle};
+use crate::translations::Translator;
+use crate::unmapped::paint_hatching;
+use crate::{Address, MemoryEditor};
+
+/// The largest width of the sidebar relative to the width needed to fit every character.
+const MAX_WIDTH_FACTOR: f32 = 4.0;
+/// The width of the area around the right edge which can be dragged to resize the sidebar.
+const RESIZE_HANDLE_WIDTH: f32 = 6.0;
+
+/// Draw the chevron which collapses or expands the sidebar, shown above it in the column header.
+/// Returns whether it was clicked.
+pub(crate) fn draw_ascii_sidebar_toggle(ui: &mut Ui, tr: &Translator, is_collapsed: bool) -> bool {
+    let (text, hover) = if is_collapsed {
+        ("◀", "Expand the ASCII sidebar")
+    } else {
+        ("▶", "Collapse the ASCII sidebar")
+    };
+
+    ui.small_button(text).on_hover_text(tr.text(hover)).clicked()
+}
+
+impl MemoryEditor {
+    /// The amount of values decoded into a single character of the sidebar.
+    pub(crate) fn bytes_per_character(&self) -> usize {
+        match self.options.sidebar_encoding {
+            SidebarEncoding::Ascii => self.options.word_size.bytes(),
+            SidebarEncoding::Utf16Le | SidebarEncoding::Utf16Be => 2,
+        }
+    }
+
+    /// The amount of characters displayed in a single row of the sidebar.
+    fn ascii_characters_per_row(&self) -> usize {
+        self.options.column_count.div_ceil(self.bytes_per_character())
+    }
+
+    /// Decode the character made up of the values in `cell`, see [`decode_cell`].
+    fn decode_character<T: ?Sized>(
+        &mut self,
+        mem: &mut T,
+        read_fn: &mut impl FnMut(&mut T, Address) -> Option<u8>,
+        cell: Range<Address>,
+    ) -> Option<Result<char, u32>> {
+        decode_cell(self.options.sidebar_encoding, cell, |address| {
+            self.frame_data.read_cache.read(mem, read_fn, address)
+        })
+    }
+
+    /// The width of the widest character in the ASCII text style.
+    pub(crate) fn ascii_glyph_width(&self, ui: &Ui) -> f32 {
+        let font_id = self.options.memory_editor_ascii_text_style.resolve(ui.style());
+        ui.fonts_mut(|fonts| fonts.glyph_width(&font_id, 'W'))
+    }
+
+    /// The width of a single character cell of the sidebar, at least as wide as the widest character.
+    fn ascii_cell_width(&self, ui: &Ui) -> f32 {
+        let glyph_width = self.ascii_glyph_width(ui);
+
+        match self.options.ascii_sidebar_width {
+            Some(width) => (width / self.ascii_characters_per_row() as f32).max(glyph_width),
+            None => glyph_width,
+        }
+    }
+
+    /// Draw a single row of the ASCII sidebar, whose right edge can be dragged to resize the sidebar.
+    pub(crate) fn draw_ascii_sidebar<T: ?Sized>(
+        &mut self,
+        ui: &mut Ui,
+        mem: &mut T,
+        read_fn: &mut impl FnMut(&mut T, Address) -> Option<u8>,
+        start_address: Address,
+        address_space: &Range<Address>,
+    ) {
+        let cell_width = self.ascii_cell_width(ui);
+        let row = start_address..start_address + self.options.column_count;
+
+        ui.horizontal(|ui| {
+            ui.add(egui::Separator::default().vertical().spacing(3.0));
+            ui.style_mut().spacing.item_spacing.x = 0.0;
+
+            if self.options.ascii_sidebar_collapsed {
+                return;
+            }
+
+            self.draw_ascii_cells(ui, mem, read_fn, row, address_space, cell_width);
+            if !self.options_locked {
+                self.handle_ascii_sidebar_resize(ui, start_address, cell_width);
+            }
+        });
+    }
+
+    /// Draw the characters of `addresses` directly after their values, when the ASCII is interleaved with the values.
+    pub(crate) fn draw_ascii_group<T: ?Sized>(
+        &mut self,
+        ui: &mut Ui,
+        mem: &mut T,
+        read_fn: &mut impl FnMut(&mut T, Address) -> Option<u8>,
+        addresses: Range<Address>,
+        address_space: &Range<Address>,
+    ) {
+        let cell_width = self.ascii_glyph_width(ui);
+
+        ui.horizontal(|ui| {
+            ui.style_mut().spacing.item_spacing.x = 0.0;
+            self.draw_ascii_cells(ui, mem, read_fn, addresses, address_space, cell_width);
+        });
+    }
+
+    /// Draw a character cell of `cell_width` for every character starting in `addresses`.
+    fn draw_ascii_cells<T: ?Sized>(
+        &mut self,
+        ui: &mut Ui,
+        mem: &mut T,
+        read_fn: &mut impl FnMut(&mut T, Address) -> Option<u8>,
+        addresses: Range<Address>,
+        address_space: &Range<Address>,
+        cell_width: f32,
+    ) {
+        let cell_size = Vec2::new(
+            cell_width,
+            ui.text_style_height(&self.options.memory_editor_ascii_text_style),
+        );
+        let font_id = self.options.memory_editor_ascii_text_style.resolve(ui.style());
+        let show_selection = !self.is_group_hidden(SELECTION_GROUP);
+        let bytes_per_character = self.bytes_per_character();
+
+        for memory_address in addresses {
+            if !(memory_address - address_space.start).is_multiple_of(bytes_per_character) {
+                continue;
+            }
+
+            // Keep the tail of a partial last row blank, like its values.
+            if !address_space.contains(&memory_address) {
+                ui.allocate_exact_size(cell_size, Sense::hover());
+                continue;
+            }
+
+            let cell = memory_address..memory_address + bytes_per_character;
+            let (rect, response) = ui.allocate_exact_size(cell_size, Sense::click_and_drag());
+            self.handle_ascii_selection(ui, &response, cell.clone());
+
+            // A character partially outside the address space can't be decoded.
+            let decoded = if cell.end <= address_space.end {
+                self.decode_character(mem, read_fn, cell.clone())
+            } else {
+                None
+            };
+            let options = &self.options;
+            let is_printable = matches!(decoded, Some(Ok(_)));
+            let mut cell_font_id = font_id.clone();
+            let text = match (decoded, options.unreadable_style, options.non_printable_style) {
+                (Some(Ok(character)), ..) => character.to_string(),
+                (None, UnreadableStyle::Blank | UnreadableStyle::Hatched, _) => " ".to_string(),
+                (Some(Err(value)), _, NonPrintableStyle::Hex) => {
+                    let digits = 2 * bytes_per_character;
+                    // Shrink the digits to fit in a single character cell.
+                    let glyph_width = ui.fonts_mut(|fonts| fonts.glyph_width(&font_id, '0'));
+                    cell_font_id.size *= (cell_width / (glyph_width * digits as f32)).min(1.0);
+                    format!("{:01$X}", value, digits)
+                }
+                (_, _, style) => style.character().unwrap_or('.').to_string(),
+            };
+
+            let text_colour = if options.highlight_non_printable && !is_printable {
+                options.non_printable_colour
+            } else if options.non_printable_style == NonPrintableStyle::Hex && !is_printable {
+                ui.visuals().weak_text_color()
+            } else {
+                options.ascii_text_colour.unwrap_or(ui.visuals().text_color())
+            };
+            let (text_colour, background) = if cell.clone().any(|address| self.frame_data.should_highlight(address)) {
+                (options.highlight_text_colour, ui.visuals().code_bg_color)
+            } else if show_selection && cell.clone().any(|address| self.frame_data.selection.contains(address)) {
+                (text_colour, ui.visuals().selection.bg_fill)
+            } else {
+                (text_colour, options.ascii_background_colour)
+            };
+
+            ui.painter().rect_filled(rect, 0.0, background);
+            ui.painter()
+                .text(rect.center(), Align2::CENTER_CENTER, text, cell_font_id, text_colour);
+
+            if decoded.is_none() && options.unreadable_style == UnreadableStyle::Hatched {
+                paint_hatching(ui, rect, options.zero_colour);
+            }
+        }
+    }
+
+    /// Select the values of the characters clicked or dragged across, like clicking or dragging across the values
+    /// themselves. A selection made here is copied as text by default.
+    fn handle_ascii_selection(&mut self, ui: &Ui, response: &Response, cell: Range<Address>) {
+        let frame_data = &mut self.frame_data;
+        let modifiers = ui.input(|i| i.modifiers);
+
+        if response.drag_started() {
+            frame_data.start_drag_selection(cell.start, modifiers.command);
+            frame_data.extend_drag_selection_characters(cell);
+            frame_data.selected_from_ascii = true;
+        } else if frame_data.drag_selecting && ui.rect_contains_pointer(response.rect) {
+            frame_data.extend_drag_selection_characters(cell);
+        } else if response.clicked() {
+            if modifiers.shift || modifiers.command {
+                frame_data.modify_selection(cell.start, modifiers);
+            } else {
+                frame_data.cursors.clear();
+                frame_data.selection.clear();
+                frame_data.selection_anchor = Some(cell.start);
+                frame_data.selected_edit_address = None;
+                frame_data.set_highlight_address(cell.start);
+            }
+            frame_data.selected_from_ascii = true;
+        }
+    }
+
+    /// Allow the right edge of a sidebar row to be dragged, together the rows act as a single resizable border.
+    fn handle_ascii_sidebar_resize(&mut self, ui: &mut Ui, start_address: Address, cell_width: f32) {
+        let tr = self.translator();
+        let row = ui.min_rect();
+        let edge = Rect::from_x_y_ranges(
+            row.right() - RESIZE_HANDLE_WIDTH / 2.0..=row.right() + RESIZE_HANDLE_WIDTH / 2.0,
+            row.y_range(),
+        );
+        let handle = ui
+            .interact(
+                edge,
+                ui.id().with(("ascii_sidebar_resize", start_address)),
+                Sense::click_and_drag(),
+            )
+            .on_hover_text(tr.text("Drag to resize the ASCII sidebar, double click to reset its width"));
+
+        if handle.hovered() || handle.dragged() {
+            ui.ctx().set_cursor_icon(CursorIcon::ResizeHorizontal);
+        }
+
+        if handle.dragged() {
+            let characters = self.ascii_characters_per_row() as f32;
+            let font_id = self.options.memory_editor_ascii_text_style.resolve(ui.style());
+            let minimum_width = ui.fonts_mut(|fonts| fonts.glyph_width(&font_id, 'W')) * characters;
+            let width = cell_width * characters + handle.drag_delta().x;
+            self.options.ascii_sidebar_width = Some(width.clamp(minimum_width, minimum_width * MAX_WIDTH_FACTOR));
+        }
+
+        if handle.double_clicked() {
+            self.options.ascii_sidebar_width = None;
+        }
+    }
+}
+
+/// Decode the character made up of the values in `cell` as displayed in the sidebar, which is `None` if any value is
+/// unreadable, or `Some(Err(value))` with the raw value if the character isn't printable.
+pub(crate) fn decode_cell(
+    encoding: SidebarEncoding,
+    cell: Range<Address>,
+    mut read: impl FnMut(Address) -> Option<u8>,
+) -> Option<Result<char, u32>> {
+    match encoding {
+        // Words are decoded as a whole, as their byte lanes are stored big-endian.
+        SidebarEncoding::Ascii => {
+            let value = cell
+                .clone()
+                .try_fold(0u32, |word, address| Some(word << 8 | read(address)? as u32))?;
+            Some(if (32..128).contains(&value) {
+                Ok(value as u8 as char)
+            } else {
+                Err(value)
+            })
+        }
+        SidebarEncoding::Utf16Le | SidebarEncoding::Utf16Be => {
+            let bytes = [read(cell.start)?, read(cell.start + 1)?];
+            let unit = if encoding == SidebarEncoding::Utf16Le {
+                u16::from_le_bytes(bytes)
+            } else {
+                u16::from_be_bytes(bytes)
+            };
+
+            // Surrogates are only meaningful as a pair, so they're displayed as non-printable.
+            Some(
+                char::from_u32(unit as u32)
+                    .filter(|character| !character.is_control())
+                    .ok_or(unit as u32),
+            )
+        }
+    }
+}
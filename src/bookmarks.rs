@@ -0,0 +1,267 @@
+//! Named addresses which can be jumped to from the UI, see [`Bookmark`].
+use std::collections::BTreeSet;
+use std::ops::Range;
+
+use egui::{Color32, Ui};
+
+#[cfg(feature = "serde")]
+use crate::notices::Notice;
+use crate::{Address, MemoryEditor};
+
+/// The colours given to highlight groups promoted from the selection, in turn.
+const PROMOTED_HIGHLIGHT_COLOURS: [Color32; 4] = [
+    Color32::from_rgb(0x4C, 0x72, 0xB0),
+    Color32::from_rgb(0xDD, 0x84, 0x52),
+    Color32::from_rgb(0x55, 0xA8, 0x68),
+    Color32::from_rgb(0x81, 0x72, 0xB3),
+];
+
+/// A named address or range of addresses, shown in the gutter and in the bookmark list in the UI.
+///
+/// Bookmarks can be sorted into categories, coloured, and annotated with notes, which makes a list of bookmarks a
+/// shareable map of the memory of e.g. a game, see [`MemoryEditor::bookmarks_to_json`].
+///
+/// ```
+/// # use egui::Color32;
+/// # use egui_memory_editor::bookmarks::Bookmark;
+/// assert_eq!(Bookmark::new("Lives", 0x7E0DBE).range(), 0x7E0DBE..0x7E0DBF);
+///
+/// let party = Bookmark::range_of("Party", 0x7E1000..0x7E1040)
+///     .with_category("Player")
+///     .with_colour(Color32::GOLD)
+///     .with_notes("Four entries of 0x10 bytes, the leader first");
+/// assert_eq!(party.range(), 0x7E1000..0x7E1040);
+/// assert_eq!(party.category.as_deref(), Some("Player"));
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Bookmark {
+    /// The name shown in the UI.
+    pub name: String,
+    /// The bookmarked address, the start of the range for range bookmarks.
+    pub address: Address,
+    /// The exclusive end of the bookmarked range, or `None` if only [`Self::address`] is bookmarked.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub end: Option<Address>,
+    /// The category the bookmark is listed under, if any.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub category: Option<String>,
+    /// The colour of the bookmark in the gutter, or `None` for the default colour.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub colour: Option<Color32>,
+    /// Free text notes, shown when hovering the bookmark.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub notes: String,
+}
+
+impl Bookmark {
+    pub fn new(name: impl Into<String>, address: Address) -> Self {
+        Bookmark {
+            name: name.into(),
+            address,
+            end: None,
+            category: None,
+            colour: None,
+            notes: String::new(),
+        }
+    }
+
+    /// A bookmark of a range of addresses, such as a table or a structure.
+    pub fn range_of(name: impl Into<String>, range: Range<Address>) -> Self {
+        Bookmark {
+            end: Some(range.end),
+            ..Bookmark::new(name, range.start)
+        }
+    }
+
+    #[must_use]
+    pub fn with_category(mut self, category: impl Into<String>) -> Self {
+        self.category = Some(category.into());
+        self
+    }
+
+    #[must_use]
+    pub fn with_colour(mut self, colour: Color32) -> Self {
+        self.colour = Some(colour);
+        self
+    }
+
+    #[must_use]
+    pub fn with_notes(mut self, notes: impl Into<String>) -> Self {
+        self.notes = notes.into();
+        self
+    }
+
+    /// The bookmarked addresses, a single address unless this is a range bookmark.
+    pub fn range(&self) -> Range<Address> {
+        self.address..self.end.unwrap_or(self.address + 1)
+    }
+
+    /// The bookmarked address, or the first and last address of a range bookmark, like `0x10–0x1F`.
+    pub(crate) fn location(&self, format_address: impl Fn(Address) -> String) -> String {
+        match self.end {
+            Some(end) => format!(
+                "{}–{}",
+                format_address(self.address),
+                format_address(end.saturating_sub(1))
+            ),
+            None => format_address(self.address),
+        }
+    }
+}
+
+impl MemoryEditor {
+    /// All bookmarks, in the order they were added.
+    pub fn bookmarks(&self) -> &[Bookmark] {
+        &self.bookmarks
+    }
+
+    /// Add a bookmark, which will be displayed in the gutter and the bookmark list.
+    pub fn add_bookmark(&mut self, bookmark: Bookmark) {
+        self.bookmarks.push(bookmark);
+    }
+
+    /// Remove the bookmark at the given index, see [`Self::bookmarks`].
+    pub fn remove_bookmark(&mut self, index: usize) -> Option<Bookmark> {
+        (index < self.bookmarks.len()).then(|| self.bookmarks.remove(index))
+    }
+
+    /// Replace all bookmarks, useful for restoring previously persisted bookmarks.
+    pub fn set_bookmarks(&mut self, bookmarks: Vec<Bookmark>) {
+        self.bookmarks = bookmarks;
+    }
+
+    /// All bookmarks as pretty printed JSON, to share them or persist them outside of the editor's state.
+    ///
+    /// ```
+    /// # use egui_memory_editor::MemoryEditor;
+    /// # use egui_memory_editor::bookmarks::Bookmark;
+    /// let mut memory_editor = MemoryEditor::new().with_address_range("WRAM", 0..0x2000);
+    /// memory_editor.add_bookmark(Bookmark::new("Lives", 0x0DBE).with_category("Player"));
+    ///
+    /// let json = memory_editor.bookmarks_to_json();
+    /// let mut other = MemoryEditor::new().with_address_range("WRAM", 0..0x2000);
+    /// assert_eq!(other.import_bookmarks_json(&json), Ok(1));
+    /// assert_eq!(other.bookmarks(), memory_editor.bookmarks());
+    /// ```
+    #[cfg(feature = "serde")]
+    pub fn bookmarks_to_json(&self) -> String {
+        serde_json::to_string_pretty(&self.bookmarks).unwrap_or_default()
+    }
+
+    /// Add the bookmarks in `json`, as written by [`Self::bookmarks_to_json`], after the existing bookmarks.
+    ///
+    /// Returns the amount of bookmarks added, or why the JSON couldn't be read, in which case nothing is added.
+    #[cfg(feature = "serde")]
+    pub fn import_bookmarks_json(&mut self, json: &str) -> Result<usize, String> {
+        let bookmarks: Vec<Bookmark> = serde_json::from_str(json).map_err(|error| error.to_string())?;
+        let count = bookmarks.len();
+
+        self.bookmarks.extend(bookmarks);
+        Ok(count)
+    }
+
+    /// Bookmark the selection as a single range, from the start of its first run to the end of its last run.
+    ///
+    /// Returns `false` if nothing is selected.
+    pub fn bookmark_selection(&mut self, name: impl Into<String>) -> bool {
+        let Some(span) = self.selection_span() else {
+            return false;
+        };
+
+        self.add_bookmark(Bookmark::range_of(name, span));
+        true
+    }
+
+    /// The categories of the bookmarks, sorted alphabetically.
+    pub(crate) fn bookmark_categories(&self) -> Vec<String> {
+        let categories = self.bookmarks.iter().filter_map(|bookmark| bookmark.category.clone());
+        categories.collect::<BTreeSet<_>>().into_iter().collect()
+    }
+
+    /// Draw the controls for copying, saving, and importing the bookmarks as JSON.
+    #[cfg(feature = "serde")]
+    pub(crate) fn draw_bookmark_json_options(&mut self, ui: &mut Ui) {
+        let tr = self.translator();
+
+        ui.collapsing(tr.text("JSON"), |ui| {
+            ui.horizontal(|ui| {
+                if ui
+                    .button(tr.text("📋 Copy"))
+                    .on_hover_text(tr.text("Copy the bookmarks as JSON, to share them"))
+                    .clicked()
+                {
+                    ui.ctx().copy_text(self.bookmarks_to_json());
+                }
+                #[cfg(feature = "export")]
+                if let Some(handler) = &self.export_handler
+                    && ui.button(tr.text("💾 Save")).clicked()
+                {
+                    handler("bookmarks.json", self.bookmarks_to_json().into_bytes());
+                }
+            });
+
+            ui.add(
+                egui::TextEdit::multiline(&mut self.frame_data.bookmark_json)
+                    .hint_text(tr.text("Paste bookmarks as JSON"))
+                    .desired_rows(3),
+            );
+            ui.horizontal(|ui| {
+                if ui.button(tr.text("Import")).clicked() {
+                    let json = std::mem::take(&mut self.frame_data.bookmark_json);
+                    let result = self.import_bookmarks_json(&json);
+                    if let Err(error) = &result {
+                        self.frame_data.notify(Notice::ImportFailed {
+                            what: "bookmarks".to_string(),
+                            error: error.clone(),
+                        });
+                    }
+                    self.frame_data.bookmark_import_result = Some(result);
+                }
+                match &self.frame_data.bookmark_import_result {
+                    Some(Ok(count)) => ui.label(tr.format("Imported {count} bookmark(s)", &[("count", count)])),
+                    Some(Err(error)) => ui.colored_label(ui.visuals().error_fg_color, error),
+                    None => ui.weak(tr.text("Added after the existing bookmarks")),
+                };
+            });
+        });
+    }
+
+    /// Draw the menu for keeping the selection as a bookmark or highlight group.
+    pub(crate) fn draw_keep_selection_menu(&mut self, ui: &mut Ui) {
+        let tr = self.translator();
+
+        ui.menu_button(tr.text("🔖 Keep"), |ui| {
+            ui.add(
+                egui::TextEdit::singleline(&mut self.frame_data.new_bookmark_name)
+                    .hint_text(tr.text("Name"))
+                    .desired_width(120.0),
+            );
+
+            let as_bookmark = ui.button(tr.text("As bookmark")).clicked();
+            let as_highlight = ui.button(tr.text("As highlight")).clicked();
+            if !as_bookmark && !as_highlight {
+                return;
+            }
+
+            let mut name = std::mem::take(&mut self.frame_data.new_bookmark_name);
+            if name.is_empty()
+                && let Some(span) = self.selection_span()
+            {
+                name = format!(
+                    "Selection @ {}",
+                    self.address_formatter(&self.selected_address_space())(span.start)
+                );
+            }
+            if as_bookmark {
+                self.bookmark_selection(name);
+            } else {
+                let colour = PROMOTED_HIGHLIGHT_COLOURS[self.highlight_groups.len() % PROMOTED_HIGHLIGHT_COLOURS.len()];
+                self.highlight_selection(name, colour);
+            }
+            ui.close();
+        })
+        .response
+        .on_hover_text(tr.text("Keep the selection as a named bookmark or highlight"));
+    }
+}
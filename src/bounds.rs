@@ -0,0 +1,40 @@
+//! Reads of values spanning multiple addresses, which never reach past the edges of the address space.
+//!
+//! The last row of an address range can be partial, so a word, a previewed value, or a character starting near its
+//! end may extend beyond it. The host's read function is never called for those addresses.
+use std::ops::Range;
+
+use crate::Address;
+
+/// Read the value at `address`, or `None` without reading if it's outside of `address_space`.
+pub(crate) fn read_bounded<T: ?Sized>(
+    mem: &mut T,
+    read_fn: &mut impl FnMut(&mut T, Address) -> Option<u8>,
+    address: Address,
+    address_space: &Range<Address>,
+) -> Option<u8> {
+    if address_space.contains(&address) {
+        read_fn(mem, address)
+    } else {
+        None
+    }
+}
+
+/// Read `len` values starting at `address`, where unreadable values and values outside of `address_space` are
+/// padded with `0`.
+pub(crate) fn read_padded<T: ?Sized>(
+    mem: &mut T,
+    read_fn: &mut impl FnMut(&mut T, Address) -> Option<u8>,
+    address: Address,
+    len: usize,
+    address_space: &Range<Address>,
+) -> Vec<u8> {
+    (0..len)
+        .map(|i| {
+            address
+                .checked_add(i)
+                .and_then(|address| read_bounded(mem, read_fn, address, address_space))
+                .unwrap_or(0)
+        })
+        .collect()
+}
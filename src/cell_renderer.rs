@@ -0,0 +1,55 @@
+//! Letting the host draw specific value cells itself, see [`MemoryEditor::with_cell_renderer`].
+use std::sync::Arc;
+
+use egui::{FontId, Response, Ui, Vec2};
+
+use crate::{Address, MemoryEditor};
+
+pub(crate) type CellRenderer = Arc<dyn Fn(&mut Ui, CellInfo) -> Option<Response> + Send + Sync>;
+
+/// The value cell about to be drawn, passed to the renderer of [`MemoryEditor::with_cell_renderer`].
+#[derive(Clone, Debug, PartialEq)]
+pub struct CellInfo {
+    pub address: Address,
+    /// The value at the address, or `None` if it couldn't be read.
+    pub value: Option<u8>,
+    /// The font of the built-in values.
+    pub font_id: FontId,
+    /// The size of a built-in value, a renderer which allocates the same size keeps the columns aligned.
+    pub size: Vec2,
+    pub is_selected: bool,
+    pub is_cursor: bool,
+    /// Whether the address is highlighted, such as after a search or jump.
+    pub is_highlighted: bool,
+}
+
+impl MemoryEditor {
+    /// Draw some value cells with a host provided renderer, such as icons for opcode bytes or a small bar for
+    /// counters.
+    ///
+    /// The renderer is called for every visible value which isn't being edited. Returning `None` draws the built-in
+    /// value instead, so a renderer only has to handle the cells it cares about. The returned response keeps the
+    /// selection, context menu, and editing by clicking working like they do for the built-in values.
+    ///
+    /// ```
+    /// # use egui_memory_editor::MemoryEditor;
+    /// let memory_editor = MemoryEditor::new()
+    ///     .with_address_range("ROM", 0..0x8000)
+    ///     .with_cell_renderer(|ui, cell| {
+    ///         // Draw the NOP opcode of the SM83 as a dot.
+    ///         (cell.value == Some(0x00) && cell.address < 0x4000).then(|| {
+    ///             let (rect, response) = ui.allocate_exact_size(cell.size, egui::Sense::hover());
+    ///             ui.painter().circle_filled(rect.center(), 2.0, ui.visuals().weak_text_color());
+    ///             response
+    ///         })
+    ///     });
+    /// ```
+    #[must_use]
+    pub fn with_cell_renderer(
+        mut self,
+        renderer: impl Fn(&mut Ui, CellInfo) -> Option<Response> + Send + Sync + 'static,
+    ) -> Self {
+        self.cell_renderer = Some(Arc::new(renderer));
+        self
+    }
+}
@@ -0,0 +1,113 @@
+//! Plotting the selected values as a chart in the data preview, requires the `plot` feature.
+use std::ops::Range;
+
+use egui::Ui;
+use egui_plot::{Bar, BarChart, Line, Plot, PlotPoints};
+
+use crate::utilities::slice_to_f64;
+use crate::{Address, MemoryEditor};
+
+/// The most values plotted at once, to keep large selections responsive.
+const MAX_PLOTTED_VALUES: usize = 4096;
+
+/// How the selected values are plotted.
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum ChartStyle {
+    /// Values are connected by a line, suited to sample data and curves.
+    #[default]
+    Line,
+    /// Every value is a bar, suited to height maps and lookup tables.
+    Bars,
+}
+
+impl ChartStyle {
+    pub fn iter() -> impl Iterator<Item = ChartStyle> {
+        [ChartStyle::Line, ChartStyle::Bars].into_iter()
+    }
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            ChartStyle::Line => "Line",
+            ChartStyle::Bars => "Bars",
+        }
+    }
+}
+
+impl MemoryEditor {
+    /// The selected values as `[address, value]` points, decoded with the data preview format.
+    ///
+    /// Every selected run is split into values of the format size, a trailing partial value and any value containing an
+    /// unreadable byte is skipped.
+    fn chart_points<T: ?Sized>(
+        &self,
+        mem: &mut T,
+        read_fn: &mut impl FnMut(&mut T, Address) -> Option<u8>,
+        address_space: &Range<Address>,
+    ) -> Vec<[f64; 2]> {
+        let data_preview = self.options.data_preview;
+        let value_size = data_preview.selected_data_format.bytes_to_read();
+
+        self.frame_data
+            .selection
+            .merged_ranges()
+            .into_iter()
+            .flat_map(|run| {
+                let run = run.start.max(address_space.start)..run.end.min(address_space.end);
+                run.clone()
+                    .step_by(value_size)
+                    .take_while(move |address| address + value_size <= run.end)
+            })
+            .take(MAX_PLOTTED_VALUES)
+            .filter_map(|address| {
+                let bytes = (address..address + value_size)
+                    .map(|address| read_fn(mem, address))
+                    .collect::<Option<Vec<u8>>>()?;
+                Some([address as f64, slice_to_f64(data_preview, &bytes)])
+            })
+            .collect()
+    }
+
+    /// Draw the selected values as a chart, using the format and endianness of the data preview.
+    pub(crate) fn draw_selection_chart<T: ?Sized>(
+        &mut self,
+        ui: &mut Ui,
+        mem: &mut T,
+        read_fn: &mut impl FnMut(&mut T, Address) -> Option<u8>,
+        address_space: &Range<Address>,
+    ) {
+        let tr = self.translator();
+        ui.horizontal(|ui| {
+            ui.label(tr.text("Chart:"));
+            for style in ChartStyle::iter() {
+                ui.selectable_value(&mut self.options.chart_style, style, style.name());
+            }
+        });
+
+        if self.frame_data.selection.is_empty() {
+            ui.weak(tr.text("Select values to plot them"));
+            return;
+        }
+
+        let points = self.chart_points(mem, read_fn, address_space);
+        let format = self.options.data_preview.selected_data_format;
+        let value_size = format.bytes_to_read() as f64;
+        let style = self.options.chart_style;
+
+        Plot::new("memory_editor_selection_chart")
+            .height(160.0)
+            .allow_scroll(false)
+            .x_axis_formatter(|mark, _| format!("{:#X}", mark.value as Address))
+            .label_formatter(|_, point| format!("{:#X}: {}", point.x as Address, point.y))
+            .show(ui, |plot_ui| match style {
+                ChartStyle::Line => plot_ui.line(Line::new(format!("{format:?}"), PlotPoints::new(points))),
+                ChartStyle::Bars => {
+                    let bars = points
+                        .into_iter()
+                        .map(|[address, value]| Bar::new(address + value_size / 2.0, value).width(value_size))
+                        .collect();
+                    plot_ui.bar_chart(BarChart::new(format!("{format:?}"), bars));
+                }
+            });
+    }
+}
@@ -0,0 +1,77 @@
+//! A checksum of every row, displayed in a column after the values, see [`RowChecksum`].
+use std::ops::Range;
+
+use egui::{RichText, Ui};
+
+use crate::{Address, MemoryEditor};
+
+/// How the checksum of a row is calculated.
+///
+/// ```
+/// # use egui_memory_editor::checksum::RowChecksum;
+/// assert_eq!(RowChecksum::Sum.calculate(&[0xF0, 0x20, 0x01]), 0x11);
+/// assert_eq!(RowChecksum::Xor.calculate(&[0xF0, 0x20, 0x01]), 0xD1);
+/// ```
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum RowChecksum {
+    /// The sum of the values, modulo `0x100`.
+    Sum,
+    /// The values combined with exclusive or.
+    Xor,
+}
+
+impl RowChecksum {
+    pub fn iter() -> impl Iterator<Item = RowChecksum> {
+        [RowChecksum::Sum, RowChecksum::Xor].into_iter()
+    }
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            RowChecksum::Sum => "Sum",
+            RowChecksum::Xor => "XOR",
+        }
+    }
+
+    /// The checksum of the given values.
+    pub fn calculate(&self, values: &[u8]) -> u8 {
+        match self {
+            RowChecksum::Sum => values.iter().fold(0u8, |sum, value| sum.wrapping_add(*value)),
+            RowChecksum::Xor => values.iter().fold(0u8, |xor, value| xor ^ value),
+        }
+    }
+}
+
+impl MemoryEditor {
+    /// Draw the checksum of the row, if enabled. Rows containing an unreadable value have no checksum.
+    pub(crate) fn draw_row_checksum<T: ?Sized>(
+        &self,
+        ui: &mut Ui,
+        mem: &mut T,
+        read_fn: &mut impl FnMut(&mut T, Address) -> Option<u8>,
+        row: Range<Address>,
+        address_space: &Range<Address>,
+    ) {
+        let Some(checksum) = self.options.row_checksum else {
+            return;
+        };
+        let tr = self.translator();
+        let values = (row.start..row.end.min(address_space.end))
+            .map(|address| read_fn(mem, address))
+            .collect::<Option<Vec<u8>>>();
+        let text = match values {
+            Some(values) => format!("{:02X}", checksum.calculate(&values)),
+            None => self.options.none_display_value.clone(),
+        };
+
+        ui.horizontal(|ui| {
+            ui.add(egui::Separator::default().vertical().spacing(3.0));
+            ui.label(
+                RichText::new(text)
+                    .text_style(self.options.memory_editor_text_style.clone())
+                    .color(self.options.address_text_colour),
+            )
+            .on_hover_text(tr.format("{checksum} of the row's values", &[("checksum", &checksum.name())]));
+        });
+    }
+}
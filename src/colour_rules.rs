@@ -0,0 +1,295 @@
+//! Conditional formatting of values, see [`crate::option_data::MemoryEditorOptions::colour_rules`].
+//!
+//! ```
+//! # use egui_memory_editor::MemoryEditor;
+//! # use egui_memory_editor::colour_rules::{ColourRule, RuleCondition, RulePattern};
+//! let mut memory_editor = MemoryEditor::new().with_address_range("Memory", 0..0x100);
+//! memory_editor.options.colour_rules = vec![
+//!     ColourRule::new(RuleCondition::ValueEquals(0xFF), egui::Color32::DARK_RED),
+//!     ColourRule::new(RuleCondition::MatchesPattern(RulePattern::new("\"SEGA\"")), egui::Color32::DARK_GREEN),
+//! ];
+//! ```
+use std::ops::Range;
+
+use egui::{Color32, Ui};
+
+use crate::utilities::parse_search_pattern;
+use crate::{Address, MemoryEditor};
+
+/// When a [`ColourRule`] applies to a value.
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum RuleCondition {
+    /// The value is equal to the given value.
+    ValueEquals(u8),
+    /// The value lies within `min..=max`.
+    ValueInRange { min: u8, max: u8 },
+    /// The address lies within `start..end`.
+    AddressInRange { start: Address, end: Address },
+    /// The value is part of an occurrence of the pattern.
+    MatchesPattern(RulePattern),
+}
+
+/// A pattern written like the search box: hexadecimal bytes like `DE AD BE EF`, or text in quotes like `"Hello"`.
+///
+/// The pattern is parsed once when it's created, rather than every time the rules are evaluated.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(from = "String", into = "String"))]
+pub struct RulePattern {
+    text: String,
+    /// The bytes to match, or `None` if the text isn't a valid pattern.
+    bytes: Option<Vec<u8>>,
+}
+
+impl RulePattern {
+    /// Parse the pattern written in `text`, which is kept as written even if it isn't valid.
+    pub fn new(text: impl Into<String>) -> Self {
+        let text = text.into();
+        let bytes = parse_search_pattern(&text);
+        RulePattern { text, bytes }
+    }
+
+    /// The pattern as written.
+    pub fn text(&self) -> &str {
+        &self.text
+    }
+
+    /// The bytes to match, or `None` if the text isn't a valid pattern.
+    pub fn bytes(&self) -> Option<&[u8]> {
+        self.bytes.as_deref()
+    }
+}
+
+impl From<String> for RulePattern {
+    fn from(text: String) -> Self {
+        RulePattern::new(text)
+    }
+}
+
+impl From<RulePattern> for String {
+    fn from(pattern: RulePattern) -> Self {
+        pattern.text
+    }
+}
+
+impl RuleCondition {
+    /// The name of the kind of condition, as shown in the UI.
+    fn kind_name(&self) -> &'static str {
+        match self {
+            RuleCondition::ValueEquals(_) => "Value ==",
+            RuleCondition::ValueInRange { .. } => "Value in",
+            RuleCondition::AddressInRange { .. } => "Address in",
+            RuleCondition::MatchesPattern(_) => "Matches",
+        }
+    }
+
+    /// A default condition for every kind, used to switch kinds in the UI.
+    fn kinds() -> [RuleCondition; 4] {
+        [
+            RuleCondition::ValueEquals(0),
+            RuleCondition::ValueInRange { min: 0, max: 0xFF },
+            RuleCondition::AddressInRange { start: 0, end: 0 },
+            RuleCondition::MatchesPattern(RulePattern::default()),
+        ]
+    }
+}
+
+impl std::fmt::Display for RuleCondition {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            RuleCondition::ValueEquals(value) => write!(f, "Value == {:#04X}", value),
+            RuleCondition::ValueInRange { min, max } => write!(f, "Value in {:#04X}..={:#04X}", min, max),
+            RuleCondition::AddressInRange { start, end } => write!(f, "Address in {:#X}..{:#X}", start, end),
+            RuleCondition::MatchesPattern(pattern) => write!(f, "Matches {}", pattern.text()),
+        }
+    }
+}
+
+/// Colours the background of every value which satisfies the condition.
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ColourRule {
+    pub condition: RuleCondition,
+    pub colour: Color32,
+    /// Whether the rule is evaluated, allowing rules to be turned off without removing them.
+    pub enabled: bool,
+}
+
+impl ColourRule {
+    /// An enabled rule colouring every value satisfying the `condition` with the given `colour`.
+    pub fn new(condition: RuleCondition, colour: Color32) -> Self {
+        ColourRule {
+            condition,
+            colour,
+            enabled: true,
+        }
+    }
+}
+
+impl MemoryEditor {
+    /// The colour of every value in `row` according to the colour rules, the first enabled rule which applies to a
+    /// value determines its colour.
+    pub(crate) fn row_rule_colours<T: ?Sized>(
+        &mut self,
+        mem: &mut T,
+        read_fn: &mut impl FnMut(&mut T, Address) -> Option<u8>,
+        row: Range<Address>,
+        address_space: &Range<Address>,
+    ) -> Vec<Option<Color32>> {
+        let mut colours = vec![None; row.len()];
+        let rules: Vec<(&RuleCondition, Color32)> = self
+            .options
+            .colour_rules
+            .iter()
+            .filter(|rule| rule.enabled)
+            .map(|rule| (&rule.condition, rule.colour))
+            .collect();
+
+        if rules.is_empty() {
+            return colours;
+        }
+
+        // Patterns can start before, or end after, the row.
+        let longest_pattern = rules
+            .iter()
+            .filter_map(|(condition, _)| match condition {
+                RuleCondition::MatchesPattern(pattern) => pattern.bytes().map(<[u8]>::len),
+                _ => None,
+            })
+            .max()
+            .unwrap_or(1);
+        let context = row.start.saturating_sub(longest_pattern - 1).max(address_space.start)
+            ..(row.end + longest_pattern - 1).min(address_space.end);
+        let values: Vec<Option<u8>> = context
+            .clone()
+            .map(|address| self.frame_data.read_cache.read(mem, read_fn, address))
+            .collect();
+        let value_at = |address: Address| values[address - context.start];
+
+        for (condition, colour) in rules.iter().rev() {
+            let matches: Vec<bool> = match condition {
+                RuleCondition::MatchesPattern(pattern) => {
+                    let Some(pattern) = pattern.bytes() else {
+                        continue;
+                    };
+                    let mut covered = vec![false; row.len()];
+
+                    for start in context.start..(context.end + 1).saturating_sub(pattern.len()) {
+                        let is_match = pattern
+                            .iter()
+                            .enumerate()
+                            .all(|(offset, &byte)| value_at(start + offset) == Some(byte));
+
+                        if is_match {
+                            for address in (start..start + pattern.len()).filter(|address| row.contains(address)) {
+                                covered[address - row.start] = true;
+                            }
+                        }
+                    }
+
+                    covered
+                }
+                RuleCondition::ValueEquals(expected) => row
+                    .clone()
+                    .map(|address| value_at(address) == Some(*expected))
+                    .collect(),
+                RuleCondition::ValueInRange { min, max } => row
+                    .clone()
+                    .map(|address| value_at(address).is_some_and(|value| (*min..=*max).contains(&value)))
+                    .collect(),
+                RuleCondition::AddressInRange { start, end } => {
+                    row.clone().map(|address| (*start..*end).contains(&address)).collect()
+                }
+            };
+
+            // Rules are applied in reverse, such that earlier rules take precedence.
+            for (colour_slot, _) in colours.iter_mut().zip(matches).filter(|(_, matches)| *matches) {
+                *colour_slot = Some(*colour);
+            }
+        }
+
+        colours
+    }
+
+    /// Draw the list of colour rules, where rules can be added, edited and removed.
+    pub(crate) fn draw_colour_rule_options(&mut self, ui: &mut Ui) {
+        let tr = self.translator();
+        ui.collapsing(tr.text("Colour rules"), |ui| {
+            let rules = &mut self.options.colour_rules;
+            let mut to_remove = None;
+
+            for (index, rule) in rules.iter_mut().enumerate() {
+                ui.horizontal(|ui| {
+                    ui.push_id(index, |ui| {
+                        ui.checkbox(&mut rule.enabled, "");
+                        ui.color_edit_button_srgba(&mut rule.colour);
+
+                        egui::ComboBox::from_id_salt("kind")
+                            .selected_text(rule.condition.kind_name())
+                            .show_ui(ui, |ui| {
+                                for kind in RuleCondition::kinds() {
+                                    let is_selected = kind.kind_name() == rule.condition.kind_name();
+                                    if ui.selectable_label(is_selected, kind.kind_name()).clicked() && !is_selected {
+                                        rule.condition = kind;
+                                    }
+                                }
+                            });
+
+                        let hex = |value| egui::DragValue::new(value).hexadecimal(2, false, true).prefix("0x");
+                        match &mut rule.condition {
+                            RuleCondition::ValueEquals(value) => {
+                                ui.add(hex(value));
+                            }
+                            RuleCondition::ValueInRange { min, max } => {
+                                ui.add(hex(min));
+                                ui.label("..=");
+                                ui.add(hex(max));
+                            }
+                            RuleCondition::AddressInRange { start, end } => {
+                                ui.add(egui::DragValue::new(start).hexadecimal(1, false, true).prefix("0x"));
+                                ui.label("..");
+                                ui.add(egui::DragValue::new(end).hexadecimal(1, false, true).prefix("0x"));
+                            }
+                            RuleCondition::MatchesPattern(pattern) => {
+                                let mut text = pattern.text().to_string();
+                                let response = ui
+                                    .add(
+                                        egui::TextEdit::singleline(&mut text)
+                                            .hint_text("DE AD BE EF")
+                                            .desired_width(120.0),
+                                    )
+                                    .on_hover_text(tr.text(
+                                        "Hexadecimal bytes like `DE AD BE EF`, or text in quotes like `\"Hello\"`",
+                                    ));
+
+                                if response.changed() {
+                                    *pattern = RulePattern::new(text);
+                                }
+                            }
+                        }
+
+                        if ui.button("🗑").on_hover_text(tr.text("Remove the rule")).clicked() {
+                            to_remove = Some(index);
+                        }
+                    });
+                });
+            }
+
+            if let Some(index) = to_remove {
+                rules.remove(index);
+            }
+
+            if ui
+                .button(tr.text("➕ Add rule"))
+                .on_hover_text(tr.text("Colour the values satisfying a condition, earlier rules take precedence"))
+                .clicked()
+            {
+                rules.push(ColourRule::new(
+                    RuleCondition::ValueEquals(0),
+                    Color32::from_rgba_unmultiplied(200, 60, 60, 90),
+                ));
+            }
+        });
+    }
+}
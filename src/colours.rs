@@ -0,0 +1,195 @@
+//! Decoding the pixel formats of palettes and framebuffers, see [`ColourFormat`].
+use std::ops::Range;
+
+use egui::{Color32, Response, Ui, Vec2};
+
+use crate::instrumentation::{AccessReason, with_access_reason};
+use crate::option_data::Endianness;
+use crate::{Address, MemoryEditor};
+
+/// The maximum amount of colours in the palette preview.
+const MAX_PALETTE_COLOURS: usize = 1024;
+/// The amount of colours in every row of the palette preview, matching the 16 colour sub-palettes of most consoles.
+const PALETTE_COLUMNS: usize = 16;
+
+/// A way of packing a colour into bytes.
+///
+/// ```
+/// # use egui::Color32;
+/// # use egui_memory_editor::colours::ColourFormat;
+/// # use egui_memory_editor::option_data::Endianness;
+/// // Pure red on the SNES and GBA, which store the red component in the low bits.
+/// let red = 0x001Fu16.to_le_bytes();
+/// assert_eq!(ColourFormat::Bgr555.decode(&red, Endianness::Little), Color32::from_rgb(0xFF, 0, 0));
+/// assert_eq!(ColourFormat::Rgb555.decode(&red, Endianness::Little), Color32::from_rgb(0, 0, 0xFF));
+///
+/// let grey = [0x80, 0x80, 0x80, 0xFF];
+/// assert_eq!(ColourFormat::Rgba8888.decode(&grey, Endianness::Big), Color32::from_gray(0x80));
+/// ```
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum ColourFormat {
+    /// 16 bits with 5 bits per component, red in the high bits: `xRRRRRGGGGGBBBBB`.
+    Rgb555,
+    /// 16 bits with 5 bits per component, red in the low bits: `xBBBBBGGGGGRRRRR`, as used by the SNES and GBA.
+    #[default]
+    Bgr555,
+    /// 16 bits with 6 bits for green: `RRRRRGGGGGGBBBBB`.
+    Rgb565,
+    /// 4 bytes in the order red, green, blue, and alpha, regardless of the endianness.
+    Rgba8888,
+}
+
+impl ColourFormat {
+    pub fn iter() -> impl Iterator<Item = ColourFormat> {
+        [
+            ColourFormat::Rgb555,
+            ColourFormat::Bgr555,
+            ColourFormat::Rgb565,
+            ColourFormat::Rgba8888,
+        ]
+        .into_iter()
+    }
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            ColourFormat::Rgb555 => "RGB555",
+            ColourFormat::Bgr555 => "BGR555",
+            ColourFormat::Rgb565 => "RGB565",
+            ColourFormat::Rgba8888 => "RGBA8888",
+        }
+    }
+
+    /// The amount of bytes a colour takes up.
+    pub const fn bytes(&self) -> usize {
+        match self {
+            ColourFormat::Rgb555 | ColourFormat::Bgr555 | ColourFormat::Rgb565 => 2,
+            ColourFormat::Rgba8888 => 4,
+        }
+    }
+
+    /// Decode `bytes`, which should be [`Self::bytes`] long, into a colour.
+    ///
+    /// Components with fewer than 8 bits are scaled up, such that their maximum value becomes `0xFF`.
+    pub fn decode(&self, bytes: &[u8], endianness: Endianness) -> Color32 {
+        let bytes: [u8; 4] = std::array::from_fn(|i| bytes.get(i).copied().unwrap_or(0));
+        let raw = match endianness {
+            Endianness::Big => u16::from_be_bytes([bytes[0], bytes[1]]),
+            Endianness::Little => u16::from_le_bytes([bytes[0], bytes[1]]),
+        };
+
+        match self {
+            ColourFormat::Rgb555 => Color32::from_rgb(expand_5(raw >> 10), expand_5(raw >> 5), expand_5(raw)),
+            ColourFormat::Bgr555 => Color32::from_rgb(expand_5(raw), expand_5(raw >> 5), expand_5(raw >> 10)),
+            ColourFormat::Rgb565 => Color32::from_rgb(expand_5(raw >> 11), expand_6(raw >> 5), expand_5(raw)),
+            ColourFormat::Rgba8888 => Color32::from_rgba_unmultiplied(bytes[0], bytes[1], bytes[2], bytes[3]),
+        }
+    }
+}
+
+/// Scale the low 5 bits of `value` to 8 bits.
+fn expand_5(value: u16) -> u8 {
+    let value = (value & 0x1F) as u8;
+    (value << 3) | (value >> 2)
+}
+
+/// Scale the low 6 bits of `value` to 8 bits.
+fn expand_6(value: u16) -> u8 {
+    let value = (value & 0x3F) as u8;
+    (value << 2) | (value >> 4)
+}
+
+impl MemoryEditor {
+    /// Draw the selection as a grid of colour swatches in the chosen [`ColourFormat`], for browsing palette RAM.
+    ///
+    /// Clicking a swatch moves the cursor to its colour.
+    pub(crate) fn draw_palette_preview<T: ?Sized>(
+        &mut self,
+        ui: &mut Ui,
+        current_address_range: &Range<Address>,
+        mem: &mut T,
+        read: &mut impl FnMut(&mut T, Address) -> Option<u8>,
+    ) {
+        let tr = self.translator();
+        let mut to_jump = None;
+
+        ui.collapsing(tr.text("🎨 Palette"), |ui| {
+            let format = &mut self.options.palette_format;
+            egui::ComboBox::from_label(tr.text("Pixel format"))
+                .selected_text(format.name())
+                .show_ui(ui, |ui| {
+                    for candidate in ColourFormat::iter() {
+                        ui.selectable_value(format, candidate, candidate.name());
+                    }
+                });
+            let format = *format;
+
+            let Some(span) = self.selection_span() else {
+                ui.label(tr.text("Select the palette to preview it"));
+                return;
+            };
+            let endianness = self.options.data_preview.selected_endianness;
+            let read = &mut with_access_reason(self.access_reason.clone(), AccessReason::Render, read);
+            let colours = span
+                .clone()
+                .step_by(format.bytes())
+                .filter(|address| address + format.bytes() <= span.end)
+                .take(MAX_PALETTE_COLOURS)
+                .map(|address| {
+                    let bytes = (address..address + format.bytes())
+                        .map(|address| read(mem, address))
+                        .collect::<Option<Vec<u8>>>();
+                    (address, bytes.map(|bytes| format.decode(&bytes, endianness)))
+                })
+                .collect::<Vec<_>>();
+
+            let size = egui::vec2(16.0, 16.0);
+            egui::Grid::new("palette_preview_grid")
+                .spacing(egui::vec2(2.0, 2.0))
+                .show(ui, |ui| {
+                    for row in colours.chunks(PALETTE_COLUMNS) {
+                        for (address, colour) in row {
+                            let response = match colour {
+                                Some(colour) => draw_colour_swatch(ui, *colour, size),
+                                None => ui.add_sized(size, egui::Label::new(&self.options.none_display_value)),
+                            };
+                            if response.on_hover_text(format!("{:#X}", address)).clicked() {
+                                to_jump = Some(*address);
+                            }
+                        }
+                        ui.end_row();
+                    }
+                });
+
+            let total = span.len() / format.bytes();
+            if total > MAX_PALETTE_COLOURS {
+                ui.label(tr.format("… and {count} more", &[("count", &(total - MAX_PALETTE_COLOURS))]));
+            }
+        });
+
+        if let Some(address) = to_jump {
+            self.jump_to_address(address, current_address_range);
+        }
+    }
+}
+
+/// The colour as `#RRGGBB`, or as `#RRGGBBAA` if it isn't opaque.
+pub(crate) fn colour_to_hex(colour: Color32) -> String {
+    let [r, g, b, a] = colour.to_srgba_unmultiplied();
+
+    if a == u8::MAX {
+        format!("#{:02X}{:02X}{:02X}", r, g, b)
+    } else {
+        format!("#{:02X}{:02X}{:02X}{:02X}", r, g, b, a)
+    }
+}
+
+/// Draw a swatch of `colour`, with its hex value as hover text.
+pub(crate) fn draw_colour_swatch(ui: &mut Ui, colour: Color32, size: Vec2) -> Response {
+    let (rect, response) = ui.allocate_exact_size(size, egui::Sense::click());
+    if ui.is_rect_visible(rect) {
+        egui::color_picker::show_color_at(ui.painter(), colour, rect);
+    }
+
+    response.on_hover_text(colour_to_hex(colour))
+}
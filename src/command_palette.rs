@@ -0,0 +1,265 @@
+use std::ops::Range;
+
+use egui::{Align2, Context, Key, KeyboardShortcut, Modifiers, Window};
+
+use crate::navigation::SearchDirection;
+use crate::snapshot::Snapshot;
+#[cfg(feature = "search")]
+use crate::utilities::parse_search_pattern;
+use crate::{Address, MemoryEditor};
+
+/// Open the command palette.
+pub(crate) const COMMAND_PALETTE_SHORTCUT: KeyboardShortcut =
+    KeyboardShortcut::new(Modifiers::COMMAND.plus(Modifiers::SHIFT), Key::P);
+
+/// The maximum amount of commands listed at once.
+const MAX_LISTED_COMMANDS: usize = 12;
+
+/// The state of the open command palette.
+#[derive(Clone, Debug, Default)]
+pub(crate) struct CommandPalette {
+    query: String,
+    /// The index of the highlighted command among the matching ones.
+    selected: usize,
+}
+
+/// An action which can be run from the command palette.
+#[derive(Clone, Debug, PartialEq)]
+enum Command {
+    GoTo(Address),
+    #[cfg(feature = "search")]
+    Find(SearchDirection),
+    JumpToData(SearchDirection),
+    TakeSnapshot,
+    ClearSnapshot,
+    CopySelection,
+    ToggleAscii,
+    ToggleMenuBar,
+    ShowShortcuts,
+    SwitchRegion(String),
+    #[cfg(feature = "bookmarks")]
+    JumpToBookmark(Address),
+}
+
+impl MemoryEditor {
+    /// Open the command palette with `Ctrl+Shift+P` while the editor has focus, and draw it if it's open.
+    ///
+    /// The palette lists every action the editor can run, filtered by fuzzy matching the typed query against their
+    /// names. A query which is a valid address is offered as a goto.
+    pub(crate) fn draw_command_palette<T: ?Sized>(
+        &mut self,
+        ctx: &Context,
+        mem: &mut T,
+        read_fn: &mut impl FnMut(&mut T, Address) -> Option<u8>,
+        address_range: &Range<Address>,
+    ) {
+        let just_opened = self.frame_data.command_palette.is_none()
+            && self.has_keyboard_focus(ctx)
+            && ctx.input_mut(|i| i.consume_shortcut(&COMMAND_PALETTE_SHORTCUT));
+        if just_opened {
+            self.frame_data.command_palette = Some(CommandPalette::default());
+        }
+        let Some(mut palette) = self.frame_data.command_palette.take() else {
+            return;
+        };
+
+        let tr = self.translator();
+        let commands = self.matching_commands(&palette.query);
+        let listed = commands.len().min(MAX_LISTED_COMMANDS);
+        palette.selected = palette.selected.min(listed.saturating_sub(1));
+
+        let mut to_run = None;
+        let mut is_open = !ctx.input(|i| i.key_pressed(Key::Escape));
+        let window = Window::new(tr.text("Command palette"))
+            .id(egui::Id::new("mem_edit_command_palette"))
+            .title_bar(false)
+            .resizable(false)
+            .anchor(Align2::CENTER_TOP, [0.0, 48.0])
+            .show(ctx, |ui| {
+                let response = ui.add(
+                    egui::TextEdit::singleline(&mut palette.query)
+                        .hint_text(tr.text("Type a command or an address"))
+                        .desired_width(320.0),
+                );
+                if response.changed() {
+                    palette.selected = 0;
+                }
+                if response.lost_focus() && ui.input(|i| i.key_pressed(Key::Enter)) {
+                    to_run = commands.get(palette.selected).map(|(_, command)| command.clone());
+                }
+                // Keep typing into the query while picking a command with the arrow keys.
+                response.request_focus();
+
+                ui.input_mut(|i| {
+                    if i.consume_key(Modifiers::NONE, Key::ArrowDown) {
+                        palette.selected = (palette.selected + 1).min(listed.saturating_sub(1));
+                    }
+                    if i.consume_key(Modifiers::NONE, Key::ArrowUp) {
+                        palette.selected = palette.selected.saturating_sub(1);
+                    }
+                });
+
+                ui.separator();
+                if commands.is_empty() {
+                    ui.weak(tr.text("No matching commands"));
+                }
+                for (i, (label, command)) in commands.iter().enumerate().take(MAX_LISTED_COMMANDS) {
+                    if ui.selectable_label(i == palette.selected, label).clicked() {
+                        to_run = Some(command.clone());
+                    }
+                }
+            });
+
+        // Clicking anywhere else closes the palette.
+        if let Some(window) = window
+            && ctx.input(|i| {
+                i.pointer.any_pressed()
+                    && i.pointer
+                        .interact_pos()
+                        .is_some_and(|position| !window.response.rect.contains(position))
+            })
+        {
+            is_open = false;
+        }
+
+        if let Some(command) = to_run {
+            self.run_command(ctx, mem, read_fn, address_range, command);
+            self.frame_data.is_focused = true;
+        } else if is_open {
+            self.frame_data.command_palette = Some(palette);
+        }
+    }
+
+    /// The commands which are currently available and match the `query`, best matches first.
+    fn matching_commands(&self, query: &str) -> Vec<(String, Command)> {
+        let tr = self.translator();
+        let mut commands = Vec::new();
+
+        #[cfg(feature = "search")]
+        if parse_search_pattern(&self.frame_data.search_string).is_some() {
+            commands.push((
+                tr.text("Find next").into_owned(),
+                Command::Find(SearchDirection::Forward),
+            ));
+            commands.push((
+                tr.text("Find previous").into_owned(),
+                Command::Find(SearchDirection::Backward),
+            ));
+        }
+        commands.push((
+            tr.text("Jump to next data").into_owned(),
+            Command::JumpToData(SearchDirection::Forward),
+        ));
+        commands.push((
+            tr.text("Jump to previous data").into_owned(),
+            Command::JumpToData(SearchDirection::Backward),
+        ));
+        commands.push((tr.text("Take snapshot").into_owned(), Command::TakeSnapshot));
+        if self.frame_data.snapshot.is_some() {
+            commands.push((tr.text("Clear snapshot").into_owned(), Command::ClearSnapshot));
+        }
+        if !self.frame_data.selection.is_empty() {
+            commands.push((tr.text("Copy selection").into_owned(), Command::CopySelection));
+        }
+        commands.push((tr.text("Toggle ASCII sidebar").into_owned(), Command::ToggleAscii));
+        commands.push((tr.text("Toggle menu bar").into_owned(), Command::ToggleMenuBar));
+        commands.push((tr.text("Show shortcuts").into_owned(), Command::ShowShortcuts));
+        for (name, _) in self.ordered_address_ranges() {
+            if *name != self.window.selected_address_range {
+                commands.push((
+                    format!("{} {name}", tr.text("Switch to region")),
+                    Command::SwitchRegion(name.clone()),
+                ));
+            }
+        }
+        #[cfg(feature = "bookmarks")]
+        for bookmark in &self.bookmarks {
+            commands.push((
+                format!("{} {}", tr.text("Jump to bookmark"), bookmark.name),
+                Command::JumpToBookmark(bookmark.address),
+            ));
+        }
+
+        let mut scored: Vec<_> = commands
+            .into_iter()
+            .filter_map(|(label, command)| Some((fuzzy_score(query, &label)?, label, command)))
+            .collect();
+        // The sort is stable, so equally good matches keep their order.
+        scored.sort_by_key(|(score, _, _)| std::cmp::Reverse(*score));
+        let mut commands: Vec<_> = scored.into_iter().map(|(_, label, command)| (label, command)).collect();
+
+        if !query.trim().is_empty()
+            && let Ok((address, _)) = self.evaluate_goto_expression(query.trim())
+        {
+            commands.insert(
+                0,
+                (format!("{} {:#X}", tr.text("Go to"), address), Command::GoTo(address)),
+            );
+        }
+
+        commands
+    }
+
+    fn run_command<T: ?Sized>(
+        &mut self,
+        ctx: &Context,
+        mem: &mut T,
+        read_fn: &mut impl FnMut(&mut T, Address) -> Option<u8>,
+        address_range: &Range<Address>,
+        command: Command,
+    ) {
+        match command {
+            Command::GoTo(address) => self.jump_to_address(address, address_range),
+            #[cfg(feature = "bookmarks")]
+            Command::JumpToBookmark(address) => self.jump_to_address(address, address_range),
+            #[cfg(feature = "search")]
+            Command::Find(direction) => {
+                if let Some(pattern) = parse_search_pattern(&self.frame_data.search_string) {
+                    let found = self.find_pattern(mem, read_fn, &pattern, address_range, direction);
+                    self.frame_data.search_not_found = found.is_none();
+                }
+            }
+            Command::JumpToData(direction) => self.jump_to_data(mem, read_fn, address_range, direction),
+            Command::TakeSnapshot => {
+                self.frame_data.snapshot = Some(Snapshot::capture(mem, read_fn, address_range));
+            }
+            Command::ClearSnapshot => self.frame_data.snapshot = None,
+            Command::CopySelection => self.copy_selection_from_pane(ctx, mem, read_fn),
+            Command::ToggleAscii => self.options.show_ascii = !self.options.show_ascii,
+            Command::ToggleMenuBar => self.options.show_menu_bar = !self.options.show_menu_bar,
+            Command::ShowShortcuts => self.frame_data.show_help = true,
+            Command::SwitchRegion(name) => {
+                self.set_selected_address_range(&name);
+            }
+        }
+    }
+}
+
+/// How well `query` matches `text`, or `None` if the characters of `query` don't appear in order in `text`.
+///
+/// Matches at the start of words and runs of consecutive characters score higher, an empty query matches everything.
+fn fuzzy_score(query: &str, text: &str) -> Option<usize> {
+    let mut score = 0;
+    let mut previous: Option<usize> = None;
+    let mut chars = text.char_indices().map(|(i, c)| (i, c.to_ascii_lowercase()));
+
+    for wanted in query
+        .chars()
+        .filter(|c| !c.is_whitespace())
+        .map(|c| c.to_ascii_lowercase())
+    {
+        let (index, _) = chars.by_ref().find(|(_, c)| *c == wanted)?;
+        let is_word_start = index == 0 || text[..index].ends_with(' ');
+
+        score += 1;
+        if is_word_start {
+            score += 3;
+        }
+        if previous.is_some_and(|previous| previous + 1 == index) {
+            score += 2;
+        }
+        previous = Some(index);
+    }
+
+    Some(score)
+}
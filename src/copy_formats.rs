@@ -0,0 +1,150 @@
+use std::fmt::Write;
+use std::ops::Range;
+
+use crate::{Address, MemoryEditor};
+
+/// The shortest run of zeroes skipped when skipping zero runs is enabled from the UI.
+pub(crate) const DEFAULT_ZERO_RUN: usize = 16;
+
+impl MemoryEditor {
+    /// Export the values in `range` as space separated hex, the same way they're displayed in the editor.
+    ///
+    /// With [`crate::option_data::MemoryEditorOptions::skip_zero_runs`] long runs of `00` are replaced by a line
+    /// with the amount of skipped bytes.
+    ///
+    /// ```
+    /// # use egui_memory_editor::MemoryEditor;
+    /// let mut memory = vec![0x0Au8, 0xFF, 0x42];
+    /// let mut memory_editor = MemoryEditor::new().with_address_range("Memory", 0..0x10);
+    /// let hex = memory_editor.export_hex(&mut memory, |mem, addr| mem.get(addr).copied(), 0..4);
+    ///
+    /// assert_eq!(hex, "0A FF 42 --");
+    ///
+    /// let mut memory = vec![0u8; 0x1000];
+    /// memory[0x1] = 0x12;
+    /// memory[0xFFF] = 0x34;
+    /// memory_editor.options.skip_zero_runs = Some(16);
+    /// let hex = memory_editor.export_hex(&mut memory, |mem, addr| mem.get(addr).copied(), 0..0x1000);
+    ///
+    /// assert_eq!(hex, "00 12\n* skipped 4093 bytes\n34");
+    /// ```
+    pub fn export_hex<T: ?Sized>(
+        &self,
+        mem: &mut T,
+        mut read_fn: impl FnMut(&mut T, Address) -> Option<u8>,
+        range: Range<Address>,
+    ) -> String {
+        let values = range.map(|address| read_fn(mem, address)).collect::<Vec<_>>();
+        let format = |value: &Option<u8>| match value {
+            Some(value) => format!("{:02X}", value),
+            None => self.options.none_display_value.clone(),
+        };
+        let Some(min_run) = self.options.skip_zero_runs else {
+            return values.iter().map(format).collect::<Vec<_>>().join(" ");
+        };
+
+        let mut lines = Vec::new();
+        let mut line = Vec::new();
+        let mut rest = values.as_slice();
+
+        while !rest.is_empty() {
+            let zeroes = rest.iter().take_while(|value| **value == Some(0)).count();
+
+            if zeroes >= min_run.max(1) {
+                if !line.is_empty() {
+                    lines.push(std::mem::take(&mut line).join(" "));
+                }
+                lines.push(format!("* skipped {} bytes", zeroes));
+                rest = &rest[zeroes..];
+            } else {
+                // A run too short to skip is written in full.
+                let written = zeroes.max(1);
+                line.extend(rest[..written].iter().map(format));
+                rest = &rest[written..];
+            }
+        }
+        if !line.is_empty() {
+            lines.push(line.join(" "));
+        }
+
+        lines.join("\n")
+    }
+
+    /// Export the values in `range` as a C array named after the start of the range, for pasting into source code.
+    ///
+    /// Values which can't be read are written as `0x00`.
+    ///
+    /// ```
+    /// # use egui_memory_editor::MemoryEditor;
+    /// let mut memory = vec![0x0Au8, 0xFF, 0x42];
+    /// let memory_editor = MemoryEditor::new().with_address_range("Memory", 0..0x10);
+    /// let array = memory_editor.export_c_array(&mut memory, |mem, addr| mem.get(addr).copied(), 1..3);
+    ///
+    /// assert_eq!(array, "const uint8_t data_1[2] = { 0xFF, 0x42 };");
+    /// ```
+    pub fn export_c_array<T: ?Sized>(
+        &self,
+        mem: &mut T,
+        mut read_fn: impl FnMut(&mut T, Address) -> Option<u8>,
+        range: Range<Address>,
+    ) -> String {
+        let name = format!("data_{:X}", range.start);
+        let len = range.len();
+        let values = range
+            .map(|address| format!("{:#04X}", read_fn(mem, address).unwrap_or(0)))
+            .collect::<Vec<_>>()
+            .join(", ");
+
+        format!("const uint8_t {}[{}] = {{ {} }};", name, len, values)
+    }
+
+    /// Format the rows spanning `rows` as aligned monospace text, the same way they're displayed in the editor.
+    ///
+    /// Each line consists of the address, the values in groups of 8, and the ASCII representation if it is enabled.
+    pub(crate) fn format_rows<T: ?Sized>(
+        &self,
+        mem: &mut T,
+        read_fn: &mut impl FnMut(&mut T, Address) -> Option<u8>,
+        rows: Range<Address>,
+        address_space: &Range<Address>,
+    ) -> String {
+        let column_count = self.options.column_count;
+        let value_width = self.options.none_display_value.chars().count().max(2);
+        let rows = rows.start.max(address_space.start)..rows.end.min(address_space.end);
+        let mut text = String::new();
+
+        for row_start in rows.step_by(column_count) {
+            let values: Vec<Option<Option<u8>>> = (row_start..row_start + column_count)
+                .map(|address| address_space.contains(&address).then(|| read_fn(mem, address)))
+                .collect();
+
+            let _ = write!(text, "{}:", self.address_formatter(address_space)(row_start));
+
+            for (column, value) in values.iter().enumerate() {
+                // Values are displayed in groups of 8.
+                text.push_str(if column % 8 == 0 { "  " } else { " " });
+
+                let value = match value {
+                    Some(Some(value)) => format!("{:02X}", value),
+                    Some(None) => self.options.none_display_value.clone(),
+                    None => String::new(),
+                };
+                let _ = write!(text, "{:<1$}", value, value_width);
+            }
+
+            if self.options.show_ascii {
+                text.push_str(" | ");
+                text.extend(values.iter().flatten().map(|value| match value {
+                    Some(value) if (32..128).contains(value) => *value as char,
+                    _ => self.options.non_printable_style.character().unwrap_or('.'),
+                }));
+            }
+
+            // Trailing whitespace is of no use when pasting.
+            text.truncate(text.trim_end().len());
+            text.push('\n');
+        }
+
+        text
+    }
+}
@@ -0,0 +1,72 @@
+use std::sync::Arc;
+
+use egui::Color32;
+
+use crate::{Address, MemoryEditor};
+
+pub(crate) type CoverageProvider = Arc<dyn Fn(Address) -> bool + Send + Sync>;
+
+impl MemoryEditor {
+    /// Shade the addresses which the host reports as covered, such as the bytes an emulator executed, turning the
+    /// editor into a simple code coverage visualizer.
+    ///
+    /// The provider is asked about every visible address each frame, so it should be cheap, like a lookup in a
+    /// bitmap. Covered values get the [`crate::option_data::MemoryEditorOptions::coverage_colour`] background, and the
+    /// status bar shows the percentage of the visible addresses which are covered.
+    ///
+    /// ```
+    /// # use egui_memory_editor::MemoryEditor;
+    /// use std::sync::{Arc, RwLock};
+    ///
+    /// // One bit per byte of ROM, set by the emulator whenever it executes an instruction there.
+    /// let executed = Arc::new(RwLock::new(vec![0u8; 0x8000 / 8]));
+    /// let bitmap = executed.clone();
+    /// let memory_editor = MemoryEditor::new()
+    ///     .with_address_range("ROM", 0x0000..0x8000)
+    ///     .with_coverage(move |address| {
+    ///         let bitmap = bitmap.read().unwrap();
+    ///         bitmap.get(address / 8).is_some_and(|bits| bits & (1 << (address % 8)) != 0)
+    ///     });
+    ///
+    /// executed.write().unwrap()[0x10] = 0b0000_0011;
+    /// assert!(memory_editor.is_covered(0x80) && memory_editor.is_covered(0x81));
+    /// assert!(!memory_editor.is_covered(0x82));
+    /// ```
+    #[must_use]
+    pub fn with_coverage(mut self, provider: impl Fn(Address) -> bool + Send + Sync + 'static) -> Self {
+        self.set_coverage(provider);
+        self
+    }
+
+    /// Set or replace the coverage provider, see [`Self::with_coverage`].
+    pub fn set_coverage(&mut self, provider: impl Fn(Address) -> bool + Send + Sync + 'static) {
+        self.coverage = Some(Arc::new(provider));
+    }
+
+    /// Stop shading covered addresses.
+    pub fn clear_coverage(&mut self) {
+        self.coverage = None;
+    }
+
+    /// Whether the host reports `address` as covered, see [`Self::with_coverage`].
+    pub fn is_covered(&self, address: Address) -> bool {
+        self.coverage.as_ref().is_some_and(|provider| provider(address))
+    }
+
+    /// The fraction of the addresses visible in the last frame which are covered, from `0.0` to `1.0`, if a
+    /// coverage provider is set.
+    pub fn visible_coverage(&self) -> Option<f32> {
+        let provider = self.coverage.as_ref()?;
+        if self.visible_range.is_empty() {
+            return None;
+        }
+
+        let covered = self.visible_range.clone().filter(|&address| provider(address)).count();
+        Some(covered as f32 / self.visible_range.len() as f32)
+    }
+
+    /// The background of `address` if it's covered and coverage is shown.
+    pub(crate) fn coverage_colour(&self, address: Address) -> Option<Color32> {
+        (self.options.show_coverage && self.is_covered(address)).then_some(self.options.coverage_colour)
+    }
+}
@@ -0,0 +1,84 @@
+use std::time::{Duration, Instant};
+
+use egui::Ui;
+
+use crate::MemoryEditor;
+
+/// Statistics about the work done to draw a single frame of the editor, see [`MemoryEditor::frame_diagnostics`].
+#[derive(Clone, Debug, Default)]
+pub struct FrameDiagnostics {
+    /// The amount of times the read function was called.
+    pub read_calls: usize,
+    /// The amount of read calls which returned a value.
+    pub bytes_fetched: usize,
+    /// The amount of reads of an address which was already read in the same frame, which didn't call the read
+    /// function.
+    pub cached_reads: usize,
+    /// The amount of rows laid out in the scroll area.
+    pub rows: usize,
+    /// The time spent drawing the editor contents, not available on `wasm32`.
+    pub duration: Option<Duration>,
+}
+
+/// The current time, if the platform supports it.
+pub(crate) fn now() -> Option<Instant> {
+    // `Instant::now` panics on `wasm32-unknown-unknown`.
+    if cfg!(target_arch = "wasm32") {
+        None
+    } else {
+        Some(Instant::now())
+    }
+}
+
+impl MemoryEditor {
+    /// Statistics about the last drawn frame, useful for tuning the column count or caching of slow memory backends.
+    ///
+    /// These can also be displayed in the editor by enabling [`crate::option_data::MemoryEditorOptions::show_diagnostics`].
+    ///
+    /// Every feature reads the selected region through a cache which lasts a single frame, so the values around the
+    /// visible rows are read from the host at most once per frame, however many features display them.
+    ///
+    /// ```
+    /// # use egui_memory_editor::MemoryEditor;
+    /// use std::collections::HashMap;
+    ///
+    /// let mut memory_editor = MemoryEditor::new().with_address_range("Memory", 0..0x100);
+    /// let mut memory = vec![0x41u8; 0x100];
+    /// let ctx = egui::Context::default();
+    /// let mut reads = HashMap::new();
+    ///
+    /// // The cached rows are known from the second frame on.
+    /// for _ in 0..2 {
+    ///     reads.clear();
+    ///     let _ = ctx.run(Default::default(), |ctx| {
+    ///         egui::CentralPanel::default().show(ctx, |ui| {
+    ///             memory_editor.draw_editor_contents_read_only(ui, &mut memory, |mem, address| {
+    ///                 *reads.entry(address).or_insert(0) += 1;
+    ///                 mem.get(address).copied()
+    ///             });
+    ///         });
+    ///     });
+    /// }
+    ///
+    /// // Both the hex grid and the ASCII sidebar display every value, but each was only read once.
+    /// assert!(reads.values().all(|&count| count == 1));
+    /// assert!(memory_editor.frame_diagnostics().cached_reads > 0);
+    /// ```
+    pub fn frame_diagnostics(&self) -> &FrameDiagnostics {
+        &self.frame_data.diagnostics
+    }
+
+    /// Draw the statistics of the last frame as a single line of text.
+    pub(crate) fn draw_diagnostics(&self, ui: &mut Ui) {
+        let diagnostics = &self.frame_data.diagnostics;
+        let duration = match diagnostics.duration {
+            Some(duration) => format!("{:.2} ms", duration.as_secs_f64() * 1000.0),
+            None => "n/a".to_string(),
+        };
+
+        ui.weak(format!(
+            "Last frame: {} reads, {} bytes fetched, {} cached reads, {} rows, {}",
+            diagnostics.read_calls, diagnostics.bytes_fetched, diagnostics.cached_reads, diagnostics.rows, duration
+        ));
+    }
+}
@@ -0,0 +1,147 @@
+//! A host provided disassembly of the bytes around the cursor, see [`MemoryEditor::with_disassembler`].
+use std::ops::Range;
+use std::sync::Arc;
+
+use egui::{RichText, Ui};
+
+use crate::{Address, MemoryEditor};
+
+pub(crate) type Disassembler = Arc<dyn Fn(Address, &[u8]) -> Vec<DisasmLine> + Send + Sync>;
+
+/// The amount of bytes before the cursor which are passed to the disassembler.
+const BYTES_BEFORE_CURSOR: usize = 0x10;
+/// The amount of bytes from the cursor onwards which are passed to the disassembler.
+const BYTES_FROM_CURSOR: usize = 0x40;
+
+/// A single disassembled instruction.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct DisasmLine {
+    /// The address of the first byte of the instruction.
+    pub address: Address,
+    /// The amount of bytes the instruction takes up.
+    pub len: usize,
+    /// The instruction as displayed, like `ld a, [hl+]`.
+    pub text: String,
+}
+
+impl DisasmLine {
+    pub fn new(address: Address, len: usize, text: impl Into<String>) -> Self {
+        DisasmLine {
+            address,
+            len,
+            text: text.into(),
+        }
+    }
+
+    /// Whether the instruction includes the given address.
+    pub fn contains(&self, address: Address) -> bool {
+        (self.address..self.address.saturating_add(self.len.max(1))).contains(&address)
+    }
+}
+
+impl MemoryEditor {
+    /// Set a disassembler for the bytes around the cursor, which are shown in a side panel while
+    /// [`crate::option_data::MemoryEditorOptions::show_disassembly`] is enabled.
+    ///
+    /// The callback receives the address of the first byte and the bytes themselves, and returns the disassembled
+    /// instructions. The first byte is a little before the cursor, so for instruction sets with variable length
+    /// instructions the first few lines may be misaligned.
+    ///
+    /// ```
+    /// # use egui_memory_editor::MemoryEditor;
+    /// # use egui_memory_editor::disassembly::DisasmLine;
+    /// // A "disassembler" which displays every byte as data.
+    /// let memory_editor = MemoryEditor::new()
+    ///     .with_address_range("ROM", 0..0x8000)
+    ///     .with_disassembler(|address, bytes| {
+    ///         bytes
+    ///             .iter()
+    ///             .enumerate()
+    ///             .map(|(i, byte)| DisasmLine::new(address + i, 1, format!("db {:#04X}", byte)))
+    ///             .collect()
+    ///     });
+    /// ```
+    #[must_use]
+    pub fn with_disassembler(
+        mut self,
+        disassemble: impl Fn(Address, &[u8]) -> Vec<DisasmLine> + Send + Sync + 'static,
+    ) -> Self {
+        self.disassembler = Some(Arc::new(disassemble));
+        self
+    }
+
+    /// Draw the disassembly of the bytes around the cursor in a side panel, if there is a disassembler.
+    pub(crate) fn draw_disassembly_panel<T: ?Sized>(
+        &mut self,
+        ui: &mut Ui,
+        address_space: &Range<Address>,
+        mem: &mut T,
+        read: &mut impl FnMut(&mut T, Address) -> Option<u8>,
+    ) {
+        let tr = self.translator();
+        let Some(disassembler) = self.disassembler.clone() else {
+            return;
+        };
+        if !self.options.show_disassembly {
+            return;
+        }
+
+        let cursor = self.frame_data.cursor_address();
+        let lines = cursor.map(|cursor| {
+            // Stop at the first unreadable value, as the disassembler can't make sense of the bytes after it anyway.
+            let start = cursor.saturating_sub(BYTES_BEFORE_CURSOR).max(address_space.start);
+            let end = cursor.saturating_add(BYTES_FROM_CURSOR).min(address_space.end);
+            let bytes = (start..end)
+                .map_while(|address| read(mem, address))
+                .collect::<Vec<u8>>();
+            disassembler(start, &bytes)
+        });
+
+        let mut to_jump = None;
+        egui::SidePanel::right(ui.id().with("disassembly_panel"))
+            .resizable(true)
+            .default_width(220.0)
+            .show_inside(ui, |ui| {
+                ui.strong(tr.text("Disassembly"));
+                ui.separator();
+
+                let (Some(cursor), Some(lines)) = (cursor, lines) else {
+                    ui.weak(tr.text("Select a value to disassemble the memory around it"));
+                    return;
+                };
+
+                egui::ScrollArea::vertical()
+                    .id_salt("disassembly_scroll")
+                    .auto_shrink([false, true])
+                    .show(ui, |ui| {
+                        egui::Grid::new("disassembly_grid").show(ui, |ui| {
+                            for line in &lines {
+                                let mut address = RichText::new(format!("{:X}", line.address))
+                                    .text_style(self.options.memory_editor_address_text_style.clone())
+                                    .color(self.options.address_text_colour);
+                                let mut text =
+                                    RichText::new(&line.text).text_style(self.options.memory_editor_text_style.clone());
+                                if line.contains(cursor) {
+                                    address = address.color(self.options.highlight_text_colour);
+                                    text = text.color(self.options.highlight_text_colour);
+                                }
+
+                                if ui
+                                    .add(egui::Label::new(address).sense(egui::Sense::click()))
+                                    .on_hover_text(tr.text("Move the cursor to this instruction"))
+                                    .clicked()
+                                {
+                                    to_jump = Some(line.address);
+                                }
+                                ui.label(text);
+                                ui.end_row();
+                            }
+                        });
+                    });
+            });
+
+        if let Some(address) = to_jump {
+            self.jump_to_address(address, address_space);
+        }
+    }
+}
@@ -0,0 +1,294 @@
+use std::fmt::Write;
+use std::ops::Range;
+use std::sync::Arc;
+
+use egui::Ui;
+
+use crate::{Address, MemoryEditor};
+
+pub(crate) type ExportHandler = Arc<dyn Fn(&str, Vec<u8>) + Send + Sync>;
+
+/// The layout of exported CSV data.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum CsvLayout {
+    /// One `address,value` row per address.
+    AddressValue,
+    /// One row per line of the editor, starting with the address of the line followed by a cell per column.
+    Rows,
+}
+
+impl MemoryEditor {
+    /// Set a handler for saving exports as files, which adds a `Save` menu to the selection options.
+    ///
+    /// The handler receives a suggested file name and the exported bytes. The editor never touches the file system
+    /// itself, so on wasm the host can offer the bytes as a browser download, while native hosts can show a save
+    /// dialog. Copies to the clipboard go through egui's output, and work on every target without a handler.
+    ///
+    /// ```
+    /// # use egui_memory_editor::MemoryEditor;
+    /// let memory_editor = MemoryEditor::new()
+    ///     .with_address_range("Memory", 0..0x100)
+    ///     .with_export_handler(|file_name, bytes| {
+    ///         let _ = std::fs::write(std::env::temp_dir().join(file_name), bytes);
+    ///     });
+    /// ```
+    #[must_use]
+    pub fn with_export_handler(mut self, handler: impl Fn(&str, Vec<u8>) + Send + Sync + 'static) -> Self {
+        self.export_handler = Some(Arc::new(handler));
+        self
+    }
+
+    /// Read every address range into a named binary blob, in the order the ranges are listed, for a complete memory
+    /// dump to attach to bug reports or analyse offline.
+    ///
+    /// Unreadable and unmapped values are dumped as `0`, and mirrors are skipped as their values are those of their
+    /// primary range. With an export handler set, see [`Self::with_export_handler`], the options also offer to save
+    /// the dump, passing every range to the handler as a separate `.bin` file.
+    ///
+    /// ```
+    /// # use egui_memory_editor::MemoryEditor;
+    /// let mut memory = vec![0xAAu8; 0x10000];
+    /// let memory_editor = MemoryEditor::new()
+    ///     .with_address_range("HRAM", 0xFF80..0xFFFF)
+    ///     .with_address_range("OAM", 0xFE00..0xFEA0);
+    ///
+    /// let dump = memory_editor.dump_all_regions(&mut memory, |mem, address| mem.get(address).copied());
+    /// assert_eq!(dump.iter().map(|(name, bytes)| (name.as_str(), bytes.len())).collect::<Vec<_>>(), [("HRAM", 0x7F), ("OAM", 0xA0)]);
+    /// ```
+    pub fn dump_all_regions<T: ?Sized>(
+        &self,
+        mem: &mut T,
+        mut read_fn: impl FnMut(&mut T, Address) -> Option<u8>,
+    ) -> Vec<(String, Vec<u8>)> {
+        self.ordered_address_ranges()
+            .into_iter()
+            .filter(|(name, _)| self.mirror_of(name).is_none())
+            .map(|(name, range)| {
+                let bytes = range
+                    .clone()
+                    .map(|address| {
+                        self.read_unselected_region(name, mem, &mut read_fn, address)
+                            .unwrap_or(0)
+                    })
+                    .collect();
+                (name.clone(), bytes)
+            })
+            .collect()
+    }
+
+    /// Draw the button for saving every address range through the export handler, if one was set.
+    pub(crate) fn draw_dump_button(&mut self, ui: &mut Ui) {
+        let tr = self.translator();
+        if self.export_handler.is_none() {
+            return;
+        }
+
+        if ui
+            .button(tr.text("💾 Dump all regions"))
+            .on_hover_text(tr.text("Save every region as a separate binary file"))
+            .clicked()
+        {
+            // Dumped at the start of the next frame, with the read function of every region.
+            self.frame_data.pending_region_dump = true;
+        }
+    }
+
+    /// Save every address range through the export handler, if a dump was requested from the UI.
+    pub(crate) fn run_pending_region_dump<T: ?Sized>(
+        &mut self,
+        mem: &mut T,
+        read_fn: impl FnMut(&mut T, Address) -> Option<u8>,
+    ) {
+        let Some(handler) = self.export_handler.clone() else {
+            return;
+        };
+        if !std::mem::take(&mut self.frame_data.pending_region_dump) {
+            return;
+        }
+
+        for (name, bytes) in self.dump_all_regions(mem, read_fn) {
+            // Range names are chosen by the host, and may contain characters which aren't valid in file names.
+            let file_stem: String = name
+                .chars()
+                .map(|c| if c.is_alphanumeric() || c == '-' { c } else { '_' })
+                .collect();
+            handler(&format!("{file_stem}.bin"), bytes);
+        }
+    }
+
+    /// Draw the menu for saving the selection through the export handler, if one was set.
+    pub(crate) fn draw_save_menu<T: ?Sized>(
+        &mut self,
+        ui: &mut Ui,
+        mem: &mut T,
+        read: &mut impl FnMut(&mut T, Address) -> Option<u8>,
+    ) {
+        let tr = self.translator();
+        let (Some(handler), Some(span)) = (self.export_handler.clone(), self.selection_span()) else {
+            return;
+        };
+        let ranges = self.frame_data.selection.merged_ranges();
+        let file_stem = format!("memory_{:X}-{:X}", span.start, span.end);
+
+        ui.menu_button(tr.text("💾 Save"), |ui| {
+            let export = if ui.button(tr.text("Binary (.bin)")).clicked() {
+                // Unreadable values are saved as `0`, as in the C array export.
+                let bytes = ranges
+                    .into_iter()
+                    .flatten()
+                    .map(|address| read(mem, address).unwrap_or(0))
+                    .collect();
+                Some(("bin", bytes))
+            } else if ui.button(tr.text("Hex (.txt)")).clicked() {
+                let lines = ranges
+                    .into_iter()
+                    .map(|range| self.export_hex(mem, &mut *read, range))
+                    .collect::<Vec<_>>();
+                Some(("txt", lines.join("\n").into_bytes()))
+            } else if ui.button(tr.text("C array (.h)")).clicked() {
+                let lines = ranges
+                    .into_iter()
+                    .map(|range| self.export_c_array(mem, &mut *read, range))
+                    .collect::<Vec<_>>();
+                Some(("h", lines.join("\n").into_bytes()))
+            } else if ui.button(tr.text("CSV (.csv)")).clicked() {
+                let csv = ranges
+                    .into_iter()
+                    .map(|range| self.export_csv(mem, &mut *read, range, CsvLayout::AddressValue))
+                    .collect::<Vec<_>>();
+                Some(("csv", csv.join("\n").into_bytes()))
+            } else {
+                None
+            };
+
+            if let Some((extension, bytes)) = export {
+                handler(&format!("{file_stem}.{extension}"), bytes);
+                ui.close();
+            }
+        })
+        .response
+        .on_hover_text(tr.text("Save the selected values as a file"));
+    }
+
+    /// Export the values in `range` as CSV, for use in spreadsheets or analysis scripts.
+    ///
+    /// Addresses are written in the [`crate::address_format::AddressFormat`] of the editor, values as decimal, and values
+    /// which can't be read are left empty.
+    /// The [`CsvLayout::Rows`] layout uses the current column count, with rows aligned to the start of the selected
+    /// address range.
+    ///
+    /// ```
+    /// # use egui_memory_editor::MemoryEditor;
+    /// # use egui_memory_editor::export::CsvLayout;
+    /// # use egui_memory_editor::address_format::AddressFormat;
+    /// let mut memory = vec![1u8, 2, 3];
+    /// let memory_editor = MemoryEditor::new().with_address_range("Memory", 0..3);
+    /// let csv = memory_editor.export_csv(&mut memory, |mem, addr| mem.get(addr).copied(), 1..3, CsvLayout::AddressValue);
+    ///
+    /// assert_eq!(csv, "address,value\n0x1,2\n0x2,3\n");
+    ///
+    /// let mut memory_editor = memory_editor;
+    /// memory_editor.options.address_format = AddressFormat::Banked { bank_size: 2 };
+    /// let csv = memory_editor.export_csv(&mut memory, |mem, addr| mem.get(addr).copied(), 1..3, CsvLayout::AddressValue);
+    /// assert_eq!(csv, "address,value\n00:1,2\n01:0,3\n");
+    /// ```
+    pub fn export_csv<T: ?Sized>(
+        &self,
+        mem: &mut T,
+        mut read_fn: impl FnMut(&mut T, Address) -> Option<u8>,
+        range: Range<Address>,
+        layout: CsvLayout,
+    ) -> String {
+        let mut csv = String::new();
+        let mut cell = |mem: &mut T, address| read_fn(mem, address).map(|value| value.to_string()).unwrap_or_default();
+        let format_address = self.address_formatter(&self.selected_address_space());
+
+        match layout {
+            CsvLayout::AddressValue => {
+                csv.push_str("address,value\n");
+
+                for address in range {
+                    let _ = writeln!(csv, "{},{}", format_address(address), cell(mem, address));
+                }
+            }
+            CsvLayout::Rows => {
+                let column_count = self.options.column_count;
+                let base = self
+                    .address_range(&self.window.selected_address_range)
+                    .map_or(range.start, |address_range| address_range.start);
+
+                csv.push_str("address");
+                for column in 0..column_count {
+                    let _ = write!(csv, ",{:02X}", column);
+                }
+                csv.push('\n');
+
+                let first_row = range.start - range.start.saturating_sub(base) % column_count;
+
+                for row_start in (first_row..range.end).step_by(column_count) {
+                    csv.push_str(&format_address(row_start));
+
+                    for address in row_start..row_start + column_count {
+                        csv.push(',');
+                        if range.contains(&address) {
+                            csv.push_str(&cell(mem, address));
+                        }
+                    }
+                    csv.push('\n');
+                }
+            }
+        }
+
+        csv
+    }
+
+    /// Export every value which changed since the snapshot was taken as text, or `None` if there is no snapshot.
+    ///
+    /// Consecutive changed values are combined into a single line of the form `address: old → new`, with the address in
+    /// the [`crate::address_format::AddressFormat`] of the editor.
+    ///
+    /// ```
+    /// # use egui_memory_editor::MemoryEditor;
+    /// let mut memory = vec![0u8; 0x10];
+    /// let mut memory_editor = MemoryEditor::new().with_address_range("Memory", 0..0x10);
+    /// memory_editor.take_snapshot(&mut memory, |mem, addr| mem.get(addr).copied());
+    ///
+    /// memory[0x4..0x6].copy_from_slice(&[0xAB, 0xCD]);
+    /// memory[0xF] = 0x01;
+    /// let diff = memory_editor.export_snapshot_diff(&mut memory, |mem, addr| mem.get(addr).copied());
+    ///
+    /// assert_eq!(diff.as_deref(), Some("0x4: 00 00 → AB CD\n0xF: 00 → 01\n"));
+    /// ```
+    pub fn export_snapshot_diff<T: ?Sized>(
+        &self,
+        mem: &mut T,
+        mut read_fn: impl FnMut(&mut T, Address) -> Option<u8>,
+    ) -> Option<String> {
+        let snapshot = self.frame_data.snapshot.as_ref()?;
+        let format_values = |values: &[Option<u8>]| {
+            values
+                .iter()
+                .map(|value| match value {
+                    Some(value) => format!("{:02X}", value),
+                    None => self.options.none_display_value.clone(),
+                })
+                .collect::<Vec<_>>()
+                .join(" ")
+        };
+        let format_address = self.address_formatter(&self.selected_address_space());
+        let mut text = String::new();
+
+        for run in snapshot.changed_runs(mem, &mut read_fn) {
+            let _ = writeln!(
+                text,
+                "{}: {} → {}",
+                format_address(run.start),
+                format_values(&run.old),
+                format_values(&run.new)
+            );
+        }
+
+        Some(text)
+    }
+}
@@ -0,0 +1,289 @@
+//! Evaluating the address expressions entered in the goto box, see [`MemoryEditor::evaluate_address_expression`].
+use std::collections::BTreeMap;
+
+use std::sync::Arc;
+
+use crate::address_format::AddressFormat;
+use crate::translations::Translator;
+use crate::words::WordSize;
+use crate::{Address, MemoryEditor};
+
+pub(crate) type ExpressionVariables = Arc<dyn Fn(&str) -> Option<Address> + Send + Sync>;
+
+/// The maximum amount of nested parentheses, deeper expressions would overflow the stack of the recursive parser.
+const MAX_NESTING: usize = 64;
+
+/// The values an expression can refer to besides literals.
+///
+/// Expressions are evaluated in word addresses, the addresses of the symbols, cursor, and selection are converted from
+/// byte lane addresses.
+struct ExpressionContext<'a> {
+    symbols: &'a BTreeMap<Address, String>,
+    variables: Option<&'a ExpressionVariables>,
+    address_format: AddressFormat,
+    word_size: WordSize,
+    cursor: Option<Address>,
+    /// Translates the error messages.
+    tr: Translator,
+    selection_start: Option<Address>,
+}
+
+/// A recursive descent parser which evaluates the expression while parsing it.
+///
+/// From lowest to highest precedence: `&`, then `+` and `-`, then `*`, and finally literals, symbols, variables, and
+/// parenthesised expressions.
+struct Parser<'a> {
+    rest: &'a str,
+    context: &'a ExpressionContext<'a>,
+    /// Whether the expression consisted of nothing but a single number.
+    is_literal: bool,
+    /// The amount of parentheses the parser is currently in.
+    nesting: usize,
+}
+
+impl<'a> Parser<'a> {
+    /// Skip whitespace and consume `operator` if it's next.
+    fn eat(&mut self, operator: char) -> bool {
+        self.rest = self.rest.trim_start();
+
+        match self.rest.strip_prefix(operator) {
+            Some(rest) => {
+                self.rest = rest;
+                self.is_literal = false;
+                true
+            }
+            None => false,
+        }
+    }
+
+    fn and(&mut self) -> Result<Address, String> {
+        let mut value = self.sum()?;
+
+        while self.eat('&') {
+            value &= self.sum()?;
+        }
+
+        Ok(value)
+    }
+
+    fn sum(&mut self) -> Result<Address, String> {
+        let mut value = self.product()?;
+
+        loop {
+            if self.eat('+') {
+                value = value
+                    .checked_add(self.product()?)
+                    .ok_or_else(|| self.error("The address is too large"))?;
+            } else if self.eat('-') {
+                value = value
+                    .checked_sub(self.product()?)
+                    .ok_or_else(|| self.error("The address is negative"))?;
+            } else {
+                return Ok(value);
+            }
+        }
+    }
+
+    fn product(&mut self) -> Result<Address, String> {
+        let mut value = self.term()?;
+
+        while self.eat('*') {
+            value = value
+                .checked_mul(self.term()?)
+                .ok_or_else(|| self.error("The address is too large"))?;
+        }
+
+        Ok(value)
+    }
+
+    fn term(&mut self) -> Result<Address, String> {
+        if self.eat('(') {
+            if self.nesting == MAX_NESTING {
+                return Err(self.error("Expression is nested too deeply"));
+            }
+
+            self.nesting += 1;
+            let value = self.and()?;
+            self.nesting -= 1;
+            return if self.eat(')') {
+                Ok(value)
+            } else {
+                Err(self.error("Missing a closing `)`"))
+            };
+        }
+
+        if self.eat('$') {
+            let name = self.word();
+            return match name {
+                "cursor" => self.context.cursor.ok_or_else(|| self.error("There is no cursor")),
+                "sel_start" => self
+                    .context
+                    .selection_start
+                    .ok_or_else(|| self.error("Nothing is selected")),
+                _ => self
+                    .variable(name)
+                    .ok_or_else(|| self.context.tr.format("Unknown variable `${name}`", &[("name", &name)])),
+            };
+        }
+
+        let word = self.word();
+        if word.is_empty() {
+            return Err(match self.rest.chars().next() {
+                Some(character) => self
+                    .context
+                    .tr
+                    .format("Unexpected `{character}`", &[("character", &character)]),
+                None => self.error("Expected an address"),
+            });
+        }
+
+        if let Some((&address, _)) = self.context.symbols.iter().find(|(_, name)| *name == word) {
+            self.is_literal = false;
+            Ok(self.context.word_size.word_address(address))
+        } else if let Some(value) = self.variable(word) {
+            self.is_literal = false;
+            Ok(value)
+        } else if let Some(decimal) = word.strip_prefix('#') {
+            self.is_literal = false;
+            decimal.parse().map_err(|_| {
+                self.context
+                    .tr
+                    .format("Invalid decimal number `{word}`", &[("word", &word)])
+            })
+        } else {
+            // Addresses in the bank or segment notation are always absolute.
+            self.is_literal &= !word.contains(':');
+            self.context.address_format.parse(word).ok_or_else(|| {
+                self.context
+                    .tr
+                    .format("Unknown symbol or invalid address `{word}`", &[("word", &word)])
+            })
+        }
+    }
+
+    /// The translation of an error message.
+    fn error(&self, message: &str) -> String {
+        self.context.tr.text(message).into_owned()
+    }
+
+    /// The current value of the host provided variable with the given name.
+    fn variable(&self, name: &str) -> Option<Address> {
+        self.context.variables.and_then(|variables| variables(name))
+    }
+
+    /// Consume the next literal, symbol, or variable name.
+    fn word(&mut self) -> &'a str {
+        self.rest = self.rest.trim_start();
+        let end = self
+            .rest
+            .char_indices()
+            .find(|&(i, c)| !(c.is_alphanumeric() || c == '_' || c == ':' || c == '.' || (i == 0 && c == '#')))
+            .map_or(self.rest.len(), |(i, _)| i);
+        let (word, rest) = self.rest.split_at(end);
+        self.rest = rest;
+
+        word
+    }
+}
+
+impl MemoryEditor {
+    /// Set a callback which resolves variables in address expressions, such as the registers of an emulated CPU.
+    ///
+    /// The callback receives the name as typed without any `$` prefix, and is called whenever an expression
+    /// is evaluated, so it should return the live value. Like the rest of the expression the values are word
+    /// addresses, see [`crate::words`]. Names are resolved as symbols first, then as variables, and
+    /// only then as hexadecimal numbers, so variables like `bc` take precedence over the number `0xBC`.
+    ///
+    /// ```
+    /// # use egui_memory_editor::MemoryEditor;
+    /// let memory_editor = MemoryEditor::new()
+    ///     .with_address_range("RAM", 0..0x10000)
+    ///     .with_expression_variables(|name| match name.to_ascii_lowercase().as_str() {
+    ///         "sp" => Some(0xDFF0),
+    ///         "pc" => Some(0x0150),
+    ///         _ => None,
+    ///     });
+    ///
+    /// assert_eq!(memory_editor.evaluate_address_expression("sp - 0x10"), Ok(0xDFE0));
+    /// assert_eq!(memory_editor.evaluate_address_expression("$PC + 2"), Ok(0x0152));
+    /// ```
+    #[must_use]
+    pub fn with_expression_variables(
+        mut self,
+        variables: impl Fn(&str) -> Option<Address> + Send + Sync + 'static,
+    ) -> Self {
+        self.expression_variables = Some(Arc::new(variables));
+        self
+    }
+
+    /// Evaluate an address expression like the ones accepted by the goto box, `sprite_table + 4*#12` for example.
+    ///
+    /// Expressions consist of:
+    /// * Hexadecimal addresses, optionally prefixed with `0x` or in the [`crate::address_format::AddressFormat`]
+    ///   notation, and decimal numbers prefixed with `#`.
+    /// * The names of symbols, see [`Self::set_symbol`].
+    /// * The variables `$cursor` and `$sel_start`, the address of the cursor and the start of the selection.
+    /// * The host provided variables, see [`Self::with_expression_variables`].
+    /// * The operators `+`, `-`, `*`, and `&`, with the usual precedence, and parentheses.
+    ///
+    /// For a [`crate::words::WordSize`] larger than a byte the expression is written in word addresses, as displayed in
+    /// the editor, and the result is the address of the first byte lane of the word.
+    ///
+    /// ```
+    /// # use egui_memory_editor::MemoryEditor;
+    /// let mut memory_editor = MemoryEditor::new().with_address_range("RAM", 0..0x10000);
+    /// memory_editor.set_symbol(0x2000, "sprite_table");
+    ///
+    /// assert_eq!(memory_editor.evaluate_address_expression("sprite_table + 4*#12"), Ok(0x2030));
+    /// assert_eq!(memory_editor.evaluate_address_expression("(FF05 - 5) & 0xF0F0"), Ok(0xF000));
+    /// assert!(memory_editor.evaluate_address_expression("$cursor").is_err());
+    /// assert!(memory_editor.evaluate_address_expression(&"(".repeat(100_000)).is_err());
+    ///
+    /// memory_editor.options.word_size = egui_memory_editor::words::WordSize::new(16);
+    /// assert_eq!(memory_editor.evaluate_address_expression("sprite_table + 2"), Ok(0x2004));
+    /// ```
+    pub fn evaluate_address_expression(&self, text: &str) -> Result<Address, String> {
+        self.evaluate_goto_expression(text).map(|(address, _)| address)
+    }
+
+    /// Evaluate an address expression, along with whether it was a single number which may be relative to the start of
+    /// the address range.
+    pub(crate) fn evaluate_goto_expression(&self, text: &str) -> Result<(Address, bool), String> {
+        let word_size = self.options.word_size;
+        let context = ExpressionContext {
+            symbols: &self.symbols,
+            variables: self.expression_variables.as_ref(),
+            address_format: self.options.address_format,
+            word_size,
+            cursor: self
+                .frame_data
+                .cursor_address()
+                .map(|address| word_size.word_address(address)),
+            selection_start: self
+                .frame_data
+                .selection
+                .merged_ranges()
+                .first()
+                .map(|range| word_size.word_address(range.start)),
+            tr: self.translator(),
+        };
+        let mut parser = Parser {
+            rest: text,
+            context: &context,
+            is_literal: true,
+            nesting: 0,
+        };
+
+        let address = parser.and()?;
+        if let Some(character) = parser.rest.trim_start().chars().next() {
+            return Err(context
+                .tr
+                .format("Unexpected `{character}`", &[("character", &character)]));
+        }
+        let address = address
+            .checked_mul(word_size.bytes())
+            .ok_or_else(|| parser.error("The address is too large"))?;
+
+        Ok((address, parser.is_literal))
+    }
+}
@@ -0,0 +1,193 @@
+use std::ops::Range;
+use std::sync::Arc;
+
+use egui::{Context, DroppedFile, Ui, Window};
+
+use crate::notices::Notice;
+use crate::selection::Selection;
+use crate::snapshot::Snapshot;
+use crate::{Address, MemoryEditor};
+
+pub(crate) type FileDropHandler = Arc<dyn Fn(&DroppedFile) -> bool + Send + Sync>;
+
+/// A file dropped onto the editor, waiting for the address to write it to.
+#[derive(Clone, Debug)]
+pub(crate) struct PendingFileDrop {
+    pub name: String,
+    pub bytes: Vec<u8>,
+    /// The address expression of the first address to write to.
+    pub address_text: String,
+}
+
+/// The name of a dropped file, without the directories it's in.
+pub(crate) fn dropped_file_name(file: &DroppedFile) -> String {
+    file.path
+        .as_ref()
+        .and_then(|path| path.file_name())
+        .map_or(file.name.clone(), |name| name.to_string_lossy().into_owned())
+}
+
+/// The contents of a dropped file, read from disk if the platform only provided its path.
+pub(crate) fn dropped_file_bytes(file: &DroppedFile) -> std::io::Result<Vec<u8>> {
+    match (&file.bytes, &file.path) {
+        (Some(bytes), _) => Ok(bytes.to_vec()),
+        #[cfg(not(target_arch = "wasm32"))]
+        (None, Some(path)) => std::fs::read(path),
+        _ => Err(std::io::Error::new(
+            std::io::ErrorKind::NotFound,
+            "the file has no contents",
+        )),
+    }
+}
+
+impl MemoryEditor {
+    /// Set a callback which is given every file dropped onto the editor before the editor handles it. Returning `true`
+    /// marks the file as handled by the host, otherwise the editor asks for the address to write the file to.
+    ///
+    /// ```
+    /// # use egui_memory_editor::MemoryEditor;
+    /// // Load save states dropped onto the editor in the host, and let the editor write any other file to memory.
+    /// let memory_editor = MemoryEditor::new()
+    ///     .with_address_range("RAM", 0..0x10000)
+    ///     .with_file_drop_handler(|file| file.name.ends_with(".state"));
+    /// ```
+    #[must_use]
+    pub fn with_file_drop_handler(mut self, on_drop: impl Fn(&DroppedFile) -> bool + Send + Sync + 'static) -> Self {
+        self.file_drop_handler = Some(Arc::new(on_drop));
+        self
+    }
+
+    /// Handle the files dropped onto the editor which the host didn't handle: IPS patches open a preview with the
+    /// `ips` feature, any other file asks for the address to write it to.
+    pub(crate) fn handle_dropped_files(&mut self, ui: &Ui, address_range: &Range<Address>) {
+        if !ui.ui_contains_pointer() {
+            return;
+        }
+
+        let dropped = ui.input(|i| i.raw.dropped_files.clone());
+        for file in dropped {
+            if self.file_drop_handler.as_ref().is_some_and(|on_drop| on_drop(&file)) {
+                continue;
+            }
+
+            #[cfg(feature = "ips")]
+            if dropped_file_name(&file).to_ascii_lowercase().ends_with(".ips") {
+                self.open_dropped_patch(&file);
+                continue;
+            }
+
+            let name = dropped_file_name(&file);
+            let bytes = match dropped_file_bytes(&file) {
+                Ok(bytes) => bytes,
+                Err(error) => {
+                    self.frame_data.notify(Notice::ImportFailed {
+                        what: name,
+                        error: error.to_string(),
+                    });
+                    continue;
+                }
+            };
+            let address = self
+                .frame_data
+                .cursor_address()
+                .or_else(|| self.selection_span().map(|span| span.start))
+                .unwrap_or(address_range.start);
+
+            self.frame_data.pending_file_drop = Some(PendingFileDrop {
+                name,
+                bytes,
+                address_text: format!("{:X}", address),
+            });
+        }
+    }
+
+    /// Draw the dialog asking where to write the dropped file, if any.
+    pub(crate) fn draw_file_drop_dialog<T: ?Sized>(
+        &mut self,
+        ctx: &Context,
+        mem: &mut T,
+        write_fn: &mut Option<impl FnMut(&mut T, Address, u8)>,
+        address_range: &Range<Address>,
+    ) {
+        let tr = self.translator();
+        let Some(pending) = &self.frame_data.pending_file_drop else {
+            return;
+        };
+        let target = self
+            .evaluate_address_expression(&pending.address_text)
+            .and_then(|start| {
+                let end = start.saturating_add(pending.bytes.len());
+                if address_range.contains(&start) && end <= address_range.end {
+                    Ok(start..end)
+                } else {
+                    let range = format!("{:#X}..{:#X}", start, end);
+                    Err(tr.format("{range} doesn't fit in the address range", &[("range", &range)]))
+                }
+            });
+        let mut address_text = pending.address_text.clone();
+        let mut is_open = true;
+        let mut write = false;
+        let mut compare = false;
+
+        Window::new(format!("{} {}", tr.text("Write"), pending.name))
+            .id(egui::Id::new("mem_edit_file_drop_dialog"))
+            .open(&mut is_open)
+            .collapsible(false)
+            .resizable(false)
+            .show(ctx, |ui| {
+                ui.label(tr.format("{count} byte(s)", &[("count", &pending.bytes.len())]));
+                ui.horizontal(|ui| {
+                    ui.label(tr.text("Address:"));
+                    ui.add(egui::TextEdit::singleline(&mut address_text).desired_width(120.0))
+                        .on_hover_text(tr.text("The address expression of the first value to write"));
+                });
+
+                match &target {
+                    Ok(range) => ui.monospace(format!("0x{:X}–0x{:X}", range.start, range.end.saturating_sub(1))),
+                    Err(error) => ui.colored_label(ui.visuals().error_fg_color, error),
+                };
+
+                ui.horizontal(|ui| {
+                    write = ui
+                        .add_enabled(
+                            write_fn.is_some() && target.is_ok(),
+                            egui::Button::new(tr.text("Write")),
+                        )
+                        .on_disabled_hover_text(tr.text("The memory can't be edited, or the file doesn't fit"))
+                        .clicked();
+                    compare = ui
+                        .add_enabled(target.is_ok(), egui::Button::new(tr.text("Compare")))
+                        .on_hover_text(tr.text(
+                            "Use the file as the snapshot, highlighting the values which differ from it, such as a \
+                            dump from an earlier session",
+                        ))
+                        .on_disabled_hover_text(tr.text("The file doesn't fit"))
+                        .clicked();
+                });
+            });
+
+        if let Some(pending) = &mut self.frame_data.pending_file_drop {
+            pending.address_text = address_text;
+        }
+
+        if compare && let (Ok(range), Some(pending)) = (&target, self.frame_data.pending_file_drop.take()) {
+            self.frame_data.snapshot = Some(Snapshot::from_bytes(range.start, &pending.bytes));
+        }
+
+        if write
+            && let Some(write_fn) = write_fn.as_mut()
+            && let (Ok(range), Some(pending)) = (target, self.frame_data.pending_file_drop.take())
+        {
+            for (address, value) in range.clone().zip(pending.bytes) {
+                write_fn(mem, address, value);
+            }
+            // Select what was written, to show where the file ended up.
+            self.set_selection(Selection::from_range(range.clone()));
+            self.jump_to_address(range.start, address_range);
+        }
+
+        if !is_open {
+            self.frame_data.pending_file_drop = None;
+        }
+    }
+}
@@ -0,0 +1,71 @@
+use egui::{Context, Key, Modifiers, Response, Stroke, StrokeKind, Ui};
+
+use crate::MemoryEditor;
+
+impl MemoryEditor {
+    /// Whether the editor has keyboard focus, which it gains by clicking its values and loses by clicking elsewhere
+    /// or pressing `Escape`. Keyboard shortcuts and navigation only apply while the editor has focus.
+    pub fn has_focus(&self) -> bool {
+        self.frame_data.is_focused
+    }
+
+    /// Give the editor keyboard focus, for example when the host opens it through a shortcut.
+    pub fn request_focus(&mut self) {
+        self.frame_data.is_focused = true;
+    }
+
+    /// Whether the editor's keyboard shortcuts should be handled, which isn't the case while another widget, such as
+    /// a text box in the options, has focus.
+    pub(crate) fn has_keyboard_focus(&self, ctx: &Context) -> bool {
+        self.frame_data.is_focused && ctx.memory(|m| m.focused().is_none())
+    }
+
+    /// Gain focus when the values are clicked, and lose it when something else is clicked or receives focus.
+    pub(crate) fn update_focus(&mut self, ctx: &Context) {
+        let pressed_at = ctx.input(|i| i.pointer.any_pressed().then(|| i.pointer.interact_pos()).flatten());
+        if let (Some(position), Some(rect)) = (pressed_at, self.frame_data.scroll.rect) {
+            self.frame_data.is_focused = rect.contains(position);
+        }
+
+        // While editing a value as text, the focused widget is our own text box.
+        let is_editing_text = self.frame_data.selected_edit_address.is_some() && !self.options.nibble_editing;
+        if !is_editing_text && ctx.memory(|m| m.focused().is_some()) {
+            self.frame_data.is_focused = false;
+        }
+    }
+
+    /// Release focus when `Escape` is pressed, after the shortcuts which use `Escape` themselves had the chance to.
+    pub(crate) fn handle_focus_release(&mut self, ctx: &Context) {
+        if self.has_keyboard_focus(ctx)
+            && self.frame_data.selected_edit_address.is_none()
+            && ctx.input_mut(|i| i.consume_key(Modifiers::NONE, Key::Escape))
+        {
+            self.frame_data.is_focused = false;
+        }
+    }
+}
+
+/// Draw the blinking cursor around the value at the cursor, which blinks like the cursor of a text box.
+pub(crate) fn draw_blinking_cursor(ui: &Ui, response: &Response) {
+    let style = &ui.visuals().text_cursor;
+    let rect = response.rect.expand(1.0);
+    let is_visible = if style.blink {
+        let period = style.on_duration + style.off_duration;
+        let phase = (ui.input(|i| i.time) % period as f64) as f32;
+        let remaining = if phase < style.on_duration {
+            style.on_duration - phase
+        } else {
+            period - phase
+        };
+        ui.ctx().request_repaint_after_secs(remaining);
+
+        phase < style.on_duration
+    } else {
+        true
+    };
+
+    if is_visible {
+        ui.painter()
+            .rect_stroke(rect, 1.0, Stroke::new(1.0, style.stroke.color), StrokeKind::Outside);
+    }
+}
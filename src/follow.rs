@@ -0,0 +1,89 @@
+use std::ops::Range;
+use std::sync::Arc;
+
+use egui::Ui;
+
+use crate::{Address, MemoryEditor};
+
+pub(crate) type FollowProvider = Arc<dyn Fn() -> Option<Address> + Send + Sync>;
+
+impl MemoryEditor {
+    /// Follow an address provided by the host, such as the program counter or a DMA pointer.
+    ///
+    /// The provider is called every frame, and the view scrolls to keep the address it returns visible and
+    /// highlighted. The user can stop and resume following from the options, see also [`Self::set_following`].
+    /// Returning `None` leaves the view where it is.
+    ///
+    /// ```
+    /// # use egui_memory_editor::MemoryEditor;
+    /// use std::sync::Arc;
+    /// use std::sync::atomic::{AtomicUsize, Ordering};
+    ///
+    /// let program_counter = Arc::new(AtomicUsize::new(0x8000));
+    /// let pc = program_counter.clone();
+    /// let memory_editor = MemoryEditor::new()
+    ///     .with_address_range("ROM", 0x8000..0x10000)
+    ///     .with_follow_address(move || Some(pc.load(Ordering::Relaxed)));
+    ///
+    /// assert!(memory_editor.is_following());
+    /// ```
+    #[must_use]
+    pub fn with_follow_address(mut self, provider: impl Fn() -> Option<Address> + Send + Sync + 'static) -> Self {
+        self.follow_provider = Some(Arc::new(provider));
+        self.frame_data.is_following = true;
+        self
+    }
+
+    /// Whether the view follows the address of [`Self::with_follow_address`].
+    pub fn is_following(&self) -> bool {
+        self.follow_provider.is_some() && self.frame_data.is_following
+    }
+
+    /// Start or stop following the address of [`Self::with_follow_address`].
+    pub fn set_following(&mut self, is_following: bool) {
+        self.frame_data.is_following = is_following;
+    }
+
+    /// Scroll to and highlight the followed address whenever it changes, or when it was scrolled out of view.
+    pub(crate) fn follow_host_address(&mut self, address_range: &Range<Address>) {
+        let Some(provider) = &self.follow_provider else {
+            return;
+        };
+        let address = provider();
+        let has_moved = address != self.frame_data.followed_address;
+        self.frame_data.followed_address = address;
+
+        let Some(address) = address.filter(|address| address_range.contains(address)) else {
+            return;
+        };
+        if !self.frame_data.is_following {
+            return;
+        }
+
+        if has_moved {
+            self.frame_data.force_highlight_address(address);
+        }
+        self.scroll_to_address(address, address_range);
+    }
+
+    /// Draw the toggle for following the host's address, if there is one.
+    pub(crate) fn draw_follow_toggle(&mut self, ui: &mut Ui) {
+        let tr = self.translator();
+        if self.follow_provider.is_none() {
+            return;
+        }
+
+        let text = match self.frame_data.followed_address {
+            Some(address) => format!("{} {:#X}", tr.text("📌 Follow"), address),
+            None => tr.text("📌 Follow").into_owned(),
+        };
+        let response = ui
+            .selectable_label(self.frame_data.is_following, text)
+            .on_hover_text(tr.text("Keep the address provided by the host in view, click to stop or resume following"));
+        if response.clicked() {
+            self.frame_data.is_following = !self.frame_data.is_following;
+            // Resuming should move back to the address right away.
+            self.frame_data.followed_address = None;
+        }
+    }
+}
@@ -0,0 +1,73 @@
+use egui::{Color32, FontId, Ui, Vec2};
+
+use crate::MemoryEditor;
+
+/// The digits a value is written with.
+const HEX_DIGITS: &str = "0123456789ABCDEF";
+/// Characters of very different widths in proportional fonts, which all have the same width in monospace fonts.
+const WIDTH_PROBES: &str = "iW0.";
+
+/// The width of the widest hexadecimal digit in `font_id`.
+fn widest_digit_width(ui: &Ui, font_id: &FontId) -> f32 {
+    ui.fonts_mut(|fonts| {
+        HEX_DIGITS
+            .chars()
+            .map(|digit| fonts.glyph_width(font_id, digit))
+            .fold(0.0, f32::max)
+    })
+}
+
+/// Whether every character of `font_id` has the same width, as measured on a few characters which differ in width in
+/// proportional fonts.
+fn is_monospace(ui: &Ui, font_id: &FontId) -> bool {
+    let widths = ui.fonts_mut(|fonts| {
+        WIDTH_PROBES
+            .chars()
+            .map(|character| fonts.glyph_width(font_id, character))
+            .collect::<Vec<_>>()
+    });
+
+    widths.iter().all(|width| (width - widths[0]).abs() < 0.5)
+}
+
+/// Add space after a cell of `width`, so that it takes up the full `cell_width` and the columns stay aligned.
+pub(crate) fn pad_cell(ui: &mut Ui, width: f32, cell_width: f32) {
+    if width < cell_width {
+        ui.add_space(cell_width - width);
+    }
+}
+
+impl MemoryEditor {
+    /// The size of a value cell in the given font, wide enough for two of the widest digit and for the text of
+    /// unreadable values.
+    pub(crate) fn value_cell_size(&self, ui: &Ui, font_id: &FontId) -> Vec2 {
+        let none_width = ui
+            .fonts_mut(|fonts| {
+                fonts.layout_no_wrap(
+                    self.options.none_display_value.clone(),
+                    font_id.clone(),
+                    Color32::PLACEHOLDER,
+                )
+            })
+            .size()
+            .x;
+        let width = (2.0 * widest_digit_width(ui, font_id)).max(none_width);
+
+        Vec2::new(width, ui.fonts_mut(|fonts| fonts.row_height(font_id)))
+    }
+
+    /// Warn that the values are drawn in a proportional font, in which the digits are spaced unevenly.
+    pub(crate) fn draw_font_warning(&self, ui: &mut Ui) {
+        let font_id = self.options.memory_editor_text_style.resolve(ui.style());
+        if is_monospace(ui, &font_id) {
+            return;
+        }
+
+        let tr = self.translator();
+        ui.colored_label(
+            ui.visuals().warn_fg_color,
+            tr.text("⚠ The text style of the values isn't monospace, so the digits are spaced unevenly"),
+        )
+        .on_hover_text(tr.text("Use a monospace text style, like `TextStyle::Monospace`, for evenly spaced values"));
+    }
+}
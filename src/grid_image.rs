@@ -0,0 +1,198 @@
+//! Rendering the hex grid to an image rather than the screen, see [`MemoryEditor::render_image`].
+use std::ops::Range;
+
+use egui::{Color32, ColorImage, Visuals};
+
+use crate::{Address, MemoryEditor};
+
+/// The width of a glyph, in font pixels.
+const GLYPH_WIDTH: usize = 3;
+/// The height of a glyph, in font pixels.
+const GLYPH_HEIGHT: usize = 5;
+/// The horizontal distance between the starts of two digits.
+const DIGIT_ADVANCE: usize = GLYPH_WIDTH + 1;
+/// The width of the two digits of a value.
+const CELL_WIDTH: usize = DIGIT_ADVANCE + GLYPH_WIDTH;
+/// The horizontal distance between the starts of two values.
+const CELL_ADVANCE: usize = CELL_WIDTH + 2;
+/// The extra space between groups of 8 values, and between the addresses and the values.
+const GROUP_GAP: usize = 4;
+/// The height of a row, with a font pixel of padding above and below the glyphs.
+const ROW_HEIGHT: usize = GLYPH_HEIGHT + 2;
+/// The space around the grid.
+const MARGIN: usize = 2;
+
+/// The hexadecimal digits in a 3x5 pixel font, one bit per pixel from the top left, followed by a dash for
+/// unreadable values.
+const GLYPHS: [u16; 17] = [
+    0b111_101_101_101_111,
+    0b010_110_010_010_111,
+    0b111_001_111_100_111,
+    0b111_001_111_001_111,
+    0b101_101_111_001_001,
+    0b111_100_111_001_111,
+    0b111_100_111_101_111,
+    0b111_001_001_001_001,
+    0b111_101_111_101_111,
+    0b111_101_111_001_111,
+    0b010_101_111_101_101,
+    0b110_101_110_101_110,
+    0b111_100_100_100_111,
+    0b110_101_101_101_110,
+    0b111_100_111_100_111,
+    0b111_100_111_100_100,
+    0b000_000_111_000_000,
+];
+/// The index of the dash in [`GLYPHS`].
+const DASH: usize = 16;
+
+/// An image which is drawn on in font pixels, each of which is `scale` by `scale` image pixels.
+struct Canvas {
+    image: ColorImage,
+    scale: usize,
+}
+
+impl Canvas {
+    /// Blend `colour` over the rectangle at `x`, `y` of `width` by `height` font pixels.
+    fn fill(&mut self, x: usize, y: usize, width: usize, height: usize, colour: Color32) {
+        let [image_width, image_height] = self.image.size;
+        for pixel_y in y * self.scale..((y + height) * self.scale).min(image_height) {
+            for pixel_x in x * self.scale..((x + width) * self.scale).min(image_width) {
+                let pixel = &mut self.image.pixels[pixel_y * image_width + pixel_x];
+                *pixel = blend(*pixel, colour);
+            }
+        }
+    }
+
+    /// Draw the glyph with the given index of [`GLYPHS`], with its top left corner at `x`, `y`.
+    fn glyph(&mut self, x: usize, y: usize, glyph: usize, colour: Color32) {
+        for row in 0..GLYPH_HEIGHT {
+            for column in 0..GLYPH_WIDTH {
+                let bit = (GLYPH_HEIGHT - row) * GLYPH_WIDTH - column - 1;
+                if GLYPHS[glyph] & (1 << bit) != 0 {
+                    self.fill(x + column, y + row, 1, 1, colour);
+                }
+            }
+        }
+    }
+
+    /// Draw `value` as hexadecimal digits, padded with zeroes to `digits` digits.
+    fn hex(&mut self, x: usize, y: usize, value: usize, digits: usize, colour: Color32) {
+        for digit in 0..digits {
+            let nibble = (value >> (4 * (digits - digit - 1))) & 0xF;
+            self.glyph(x + digit * DIGIT_ADVANCE, y, nibble, colour);
+        }
+    }
+}
+
+/// Draw the premultiplied `colour` over `background`.
+fn blend(background: Color32, colour: Color32) -> Color32 {
+    let alpha = 255 - colour.a() as u16;
+    let channel = |colour: u8, background: u8| colour.saturating_add(((background as u16 * alpha) / 255) as u8);
+
+    Color32::from_rgba_premultiplied(
+        channel(colour.r(), background.r()),
+        channel(colour.g(), background.g()),
+        channel(colour.b(), background.b()),
+        channel(colour.a(), background.a()),
+    )
+}
+
+impl MemoryEditor {
+    /// Render the values in `range` as a picture of the hex grid, for embedding the state of the memory in reports, or
+    /// as thumbnails in an overview of the regions. Load it with [`egui::Context::load_texture`] to show it in the UI.
+    ///
+    /// The values are laid out like the editor with the current options, using the column count, the address column,
+    /// the zero colour, the snapshot diff, and the background of highlight groups, coverage, the heat map, and
+    /// permissions. The digits are drawn in a small pixel font rather than the editor's text style, where each pixel
+    /// of the font is `scale` by `scale` pixels in the image. Other colours are taken from `visuals`.
+    ///
+    /// ```
+    /// # use egui_memory_editor::MemoryEditor;
+    /// let mut memory = vec![0u8; 0x100];
+    /// let memory_editor = MemoryEditor::new().with_address_range("Memory", 0..0x100);
+    ///
+    /// let visuals = egui::Visuals::dark();
+    /// let image = memory_editor.render_image(&mut memory, |mem, addr| mem.get(addr).copied(), 0..0x20, &visuals, 2);
+    ///
+    /// // Two rows of 16 values.
+    /// assert_eq!(image.size[1], 2 * (2 * 7 + 2 * 2));
+    /// ```
+    pub fn render_image<T: ?Sized>(
+        &self,
+        mem: &mut T,
+        mut read_fn: impl FnMut(&mut T, Address) -> Option<u8>,
+        range: Range<Address>,
+        visuals: &Visuals,
+        scale: usize,
+    ) -> ColorImage {
+        let options = &self.options;
+        let column_count = options.column_count.max(1);
+        let line_count = range.len().div_ceil(column_count);
+        let address_digits = if options.show_address_column {
+            format!("{:X}", range.end.saturating_sub(1)).len()
+        } else {
+            0
+        };
+        let values_x = match address_digits {
+            0 => MARGIN,
+            digits => MARGIN + digits * DIGIT_ADVANCE + GROUP_GAP,
+        };
+        let cell_x = |column: usize| values_x + column * CELL_ADVANCE + (column / 8) * GROUP_GAP;
+
+        let width = cell_x(column_count - 1) + CELL_WIDTH + MARGIN;
+        let height = 2 * MARGIN + line_count * ROW_HEIGHT;
+        let scale = scale.max(1);
+        let mut canvas = Canvas {
+            image: ColorImage::filled([width * scale, height * scale], visuals.extreme_bg_color),
+            scale,
+        };
+
+        for line in 0..line_count {
+            let line_start = range.start + line * column_count;
+            let y = MARGIN + line * ROW_HEIGHT;
+            if line % 2 == 1 {
+                canvas.fill(0, y, width, ROW_HEIGHT, visuals.faint_bg_color);
+            }
+            if address_digits > 0 {
+                canvas.hex(MARGIN, y + 1, line_start, address_digits, options.address_text_colour);
+            }
+
+            for (column, address) in (line_start..(line_start + column_count).min(range.end)).enumerate() {
+                let x = cell_x(column);
+                let background = self
+                    .highlight_group_colour(address)
+                    .or_else(|| self.coverage_colour(address))
+                    .or_else(|| self.heat_map_colour(address))
+                    .or_else(|| self.permission_colour(address));
+                if let Some(background) = background {
+                    canvas.fill(x - 1, y, CELL_WIDTH + 2, ROW_HEIGHT, background);
+                }
+
+                let value = read_fn(mem, address);
+                let is_changed = self
+                    .frame_data
+                    .snapshot
+                    .as_ref()
+                    .is_some_and(|snapshot| snapshot.is_changed(address, value));
+                let colour = if is_changed {
+                    options.diff_text_colour
+                } else if options.show_zero_colour && matches!(value, Some(0) | None) {
+                    options.zero_colour
+                } else {
+                    visuals.text_color()
+                };
+
+                match value {
+                    Some(value) => canvas.hex(x, y + 1, value as usize, 2, colour),
+                    None => {
+                        canvas.glyph(x, y + 1, DASH, colour);
+                        canvas.glyph(x + DIGIT_ADVANCE, y + 1, DASH, colour);
+                    }
+                }
+            }
+        }
+
+        canvas.image
+    }
+}
@@ -0,0 +1,70 @@
+use std::sync::Arc;
+
+use crate::{Address, MemoryEditor};
+
+pub(crate) type LengthProvider = Arc<dyn Fn() -> usize + Send + Sync>;
+
+/// An address range without a fixed end, whose length is provided by the host every frame.
+#[derive(Clone)]
+pub(crate) struct GrowingRange {
+    start: Address,
+    len: LengthProvider,
+}
+
+impl MemoryEditor {
+    /// Add an address range whose length changes over time, such as an append-only log buffer.
+    ///
+    /// The length is asked for every frame, and the amount of rows follows it. While the end of the range is in
+    /// view, the view scrolls along as the range grows, see
+    /// [`crate::option_data::MemoryEditorOptions::scroll_growing_ranges`].
+    ///
+    /// ```
+    /// # use egui_memory_editor::MemoryEditor;
+    /// use std::sync::Arc;
+    /// use std::sync::atomic::{AtomicUsize, Ordering};
+    ///
+    /// let log_length = Arc::new(AtomicUsize::new(0x40));
+    /// let length = log_length.clone();
+    /// let memory_editor = MemoryEditor::new()
+    ///     .with_growing_address_range("Log", 0x1000, move || length.load(Ordering::Relaxed));
+    ///
+    /// assert_eq!(memory_editor.region_len("Log"), Some(0x40));
+    /// ```
+    #[must_use]
+    pub fn with_growing_address_range(
+        mut self,
+        range_name: impl Into<String>,
+        start: Address,
+        len: impl Fn() -> usize + Send + Sync + 'static,
+    ) -> Self {
+        let range_name = range_name.into();
+        let len: LengthProvider = Arc::new(len);
+
+        self.set_address_range(range_name.clone(), start..start.saturating_add(len()));
+        self.growing_ranges.insert(range_name, GrowingRange { start, len });
+        self
+    }
+
+    /// Update the length of every growing address range, scrolling along with the selected range if its end was in
+    /// view.
+    pub(crate) fn update_growing_ranges(&mut self) {
+        let growing_ranges = self.growing_ranges.clone();
+
+        for (range_name, growing) in growing_ranges {
+            let new_range = growing.start..growing.start.saturating_add((growing.len)());
+            let Some(old_range) = self.address_range(&range_name).cloned() else {
+                continue;
+            };
+            if old_range == new_range {
+                continue;
+            }
+            self.set_address_range(range_name.as_str(), new_range.clone());
+
+            let is_selected = self.window.selected_address_range == range_name;
+            let was_at_end = self.visible_range.end >= old_range.end;
+            if is_selected && was_at_end && self.options.scroll_growing_ranges && !new_range.is_empty() {
+                self.scroll_to_address(new_range.end - 1, &new_range);
+            }
+        }
+    }
+}
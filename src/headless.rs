@@ -0,0 +1,78 @@
+use std::ops::Range;
+
+#[cfg(feature = "search")]
+use crate::search::SearchMatch;
+use crate::{Address, MemoryEditor};
+
+impl MemoryEditor {
+    /// Render the rows spanning `range` as text, the same way they're displayed in the editor, without needing an
+    /// `egui` context.
+    ///
+    /// Rows are aligned to the start of the selected address range and use the current options, such as the column
+    /// count and whether to show the ASCII sidebar. Useful for asserting on the editor's state in tests.
+    ///
+    /// ```
+    /// # use egui_memory_editor::MemoryEditor;
+    /// let mut memory = vec![0x41u8; 0x20];
+    /// let mut memory_editor = MemoryEditor::new().with_address_range("Memory", 0..0x20);
+    /// memory_editor.options.column_count = 4;
+    ///
+    /// let text = memory_editor.render_to_string(&mut memory, |mem, addr| mem.get(addr).copied(), 0x4..0x8);
+    ///
+    /// assert_eq!(text, "0x4:  41 41 41 41 | AAAA\n");
+    /// ```
+    pub fn render_to_string<T: ?Sized>(
+        &self,
+        mem: &mut T,
+        mut read_fn: impl FnMut(&mut T, Address) -> Option<u8>,
+        range: Range<Address>,
+    ) -> String {
+        let address_space = self
+            .address_range(&self.window.selected_address_range)
+            .cloned()
+            .unwrap_or_else(|| range.clone());
+        let column_count = self.options.column_count;
+        let row_start = range.start - range.start.saturating_sub(address_space.start) % column_count;
+
+        self.format_rows(mem, &mut read_fn, row_start..range.end, &address_space)
+    }
+
+    /// The name of the address range which is currently displayed.
+    pub fn selected_address_range(&self) -> &str {
+        &self.window.selected_address_range
+    }
+
+    /// Display the address range with the given name, returns `false` if no such address range exists.
+    ///
+    /// The cursor and selection are cleared when another address range is displayed.
+    pub fn set_selected_address_range(&mut self, range_name: &str) -> bool {
+        let exists = self.address_range(range_name).is_some();
+
+        if exists {
+            self.select_address_range(range_name);
+        }
+
+        exists
+    }
+
+    /// The address currently being edited, if any.
+    pub fn edit_address(&self) -> Option<Address> {
+        self.frame_data.selected_edit_address
+    }
+
+    /// The highlighted address, if any.
+    pub fn highlighted_address(&self) -> Option<Address> {
+        self.frame_data.selected_highlight_address
+    }
+
+    /// The address keyboard navigation starts from, which is the edited address if any, or else the highlighted address.
+    pub fn cursor_address(&self) -> Option<Address> {
+        self.frame_data.cursor_address()
+    }
+
+    /// The matches of the last search across all regions made in the UI, see [`Self::find_all_in_regions`].
+    #[cfg(feature = "search")]
+    pub fn search_results(&self) -> &[SearchMatch] {
+        &self.frame_data.search_results
+    }
+}
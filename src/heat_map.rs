@@ -0,0 +1,110 @@
+use std::ops::Range;
+
+use egui::{Color32, Ui};
+
+use crate::{Address, MemoryEditor};
+
+/// The colour of values which rarely change.
+const COOL_COLOUR: Color32 = Color32::from_rgba_premultiplied(90, 70, 0, 90);
+/// The colour of values which change every frame.
+const HOT_COLOUR: Color32 = Color32::from_rgba_premultiplied(170, 20, 20, 170);
+/// Values which changed less often than this are left uncoloured.
+const MIN_HEAT: f32 = 0.01;
+
+/// How often the values of the heat map range changed recently, see
+/// [`crate::option_data::MemoryEditorOptions::heat_map_range`].
+#[derive(Clone, Debug, Default)]
+pub(crate) struct HeatMap {
+    /// The range the heat map was built for, it starts over when the range changes.
+    range: Range<Address>,
+    /// The values of the last frame.
+    values: Vec<Option<u8>>,
+    /// The fraction of the recent frames in which each value changed, from `0.0` to `1.0`.
+    heat: Vec<f32>,
+}
+
+impl HeatMap {
+    /// How often the value at `address` changed recently, or `None` if it's outside the heat map.
+    fn heat(&self, address: Address) -> Option<f32> {
+        self.heat.get(address.checked_sub(self.range.start)?).copied()
+    }
+}
+
+impl MemoryEditor {
+    /// Read every value of the heat map range, and update how often each value changed.
+    ///
+    /// The heat is a moving average over roughly [`crate::option_data::MemoryEditorOptions::heat_map_frames`] frames.
+    pub(crate) fn update_heat_map<T: ?Sized>(
+        &mut self,
+        mem: &mut T,
+        read_fn: &mut impl FnMut(&mut T, Address) -> Option<u8>,
+    ) {
+        let Some(range) = self.options.heat_map_range.clone() else {
+            self.frame_data.heat_map = None;
+            return;
+        };
+        let values = range.clone().map(|address| read_fn(mem, address)).collect::<Vec<_>>();
+        let weight = 1.0 / self.options.heat_map_frames.max(1) as f32;
+
+        match &mut self.frame_data.heat_map {
+            Some(heat_map) if heat_map.range == range => {
+                for ((heat, old), new) in heat_map.heat.iter_mut().zip(&heat_map.values).zip(&values) {
+                    let changed = if old != new { 1.0 } else { 0.0 };
+                    *heat += (changed - *heat) * weight;
+                }
+                heat_map.values = values;
+            }
+            heat_map => {
+                *heat_map = Some(HeatMap {
+                    heat: vec![0.0; values.len()],
+                    range,
+                    values,
+                });
+            }
+        }
+    }
+
+    /// The background of `address` in the heat map, from a faint yellow for values which rarely change to red for
+    /// values which change every frame.
+    pub(crate) fn heat_map_colour(&self, address: Address) -> Option<Color32> {
+        let heat = self.frame_data.heat_map.as_ref()?.heat(address)?;
+
+        (heat >= MIN_HEAT).then(|| COOL_COLOUR.lerp_to_gamma(HOT_COLOUR, heat.sqrt()))
+    }
+
+    /// Draw the controls for picking the range of the heat map.
+    pub(crate) fn draw_heat_map_options(&mut self, ui: &mut Ui) {
+        let tr = self.translator();
+        ui.horizontal(|ui| {
+            ui.label(tr.text("Heat map:")).on_hover_text(tr.text(
+                "Colour the values of a range by how often they changed recently, from yellow to red, to find the \
+                 variables in use. The whole range is read every frame",
+            ));
+
+            let span = self.selection_span();
+            if ui
+                .add_enabled(span.is_some(), egui::Button::new(tr.text("🔥 Selection")))
+                .on_hover_text(tr.text("Colour the selected range by how often it changes"))
+                .on_disabled_hover_text(tr.text("Select the values to track first"))
+                .clicked()
+            {
+                self.options.heat_map_range = span;
+            }
+
+            let Some(range) = self.options.heat_map_range.clone() else {
+                return;
+            };
+            ui.label(format!("{:#X}–{:#X}", range.start, range.end.saturating_sub(1)));
+            ui.add(
+                egui::DragValue::new(&mut self.options.heat_map_frames)
+                    .range(1..=3600)
+                    .suffix(tr.text(" frames")),
+            )
+            .on_hover_text(tr.text("The amount of frames over which the changes are averaged"));
+
+            if ui.button(tr.text("🗑 Clear")).clicked() {
+                self.options.heat_map_range = None;
+            }
+        });
+    }
+}
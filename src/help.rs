@@ -0,0 +1,165 @@
+use egui::{Context, Key, KeyboardShortcut, Modifiers, Ui, Window};
+
+use crate::MemoryEditor;
+use crate::command_palette::COMMAND_PALETTE_SHORTCUT;
+use crate::navigation::{NEXT_DATA_SHORTCUT, PREVIOUS_DATA_SHORTCUT};
+use crate::records::{NEXT_RECORD_SHORTCUT, PREVIOUS_RECORD_SHORTCUT};
+use crate::region_shortcuts::{NEXT_REGION_SHORTCUT, PREVIOUS_REGION_SHORTCUT};
+use crate::undo::{REDO_SHORTCUT, UNDO_SHORTCUT};
+
+impl MemoryEditor {
+    /// Draw the button which toggles the cheat sheet of shortcuts and mouse gestures.
+    pub(crate) fn draw_help_button(&mut self, ui: &mut Ui) {
+        let tr = self.translator();
+
+        if ui
+            .selectable_label(self.frame_data.show_help, "?")
+            .on_hover_text(tr.text("Show the keyboard shortcuts and mouse gestures"))
+            .clicked()
+        {
+            self.frame_data.show_help = !self.frame_data.show_help;
+        }
+    }
+
+    /// Draw the cheat sheet, if it's open, listing only the shortcuts and gestures which currently do something.
+    ///
+    /// Keyboard shortcuts only apply while the editor has focus, see [`Self::has_focus`].
+    pub(crate) fn draw_help_window(&mut self, ctx: &Context, can_write: bool, can_resize: bool) {
+        if !self.frame_data.show_help {
+            return;
+        }
+        let tr = self.translator();
+        let shortcut = |shortcut: &KeyboardShortcut| ctx.format_shortcut(shortcut);
+
+        let mut keyboard = vec![
+            (
+                shortcut(&NEXT_DATA_SHORTCUT),
+                tr.text("Jump to the next value that differs from the cursor"),
+            ),
+            (
+                shortcut(&PREVIOUS_DATA_SHORTCUT),
+                tr.text("Jump to the previous value that differs from the cursor"),
+            ),
+            (
+                shortcut(&KeyboardShortcut::new(Modifiers::COMMAND, Key::A)),
+                tr.text("Select the whole region"),
+            ),
+            (
+                shortcut(&KeyboardShortcut::new(Modifiers::COMMAND, Key::C)),
+                tr.text("Copy the selection, as text if it was selected in the ASCII sidebar"),
+            ),
+            (shortcut(&COMMAND_PALETTE_SHORTCUT), tr.text("Open the command palette")),
+            (
+                "Esc".to_string(),
+                tr.text("Stop editing, or release the editor's focus"),
+            ),
+        ];
+        if self.active_record_size().is_some() {
+            keyboard.push((
+                shortcut(&NEXT_RECORD_SHORTCUT),
+                tr.text("Jump to the same field in the next record"),
+            ));
+            keyboard.push((
+                shortcut(&PREVIOUS_RECORD_SHORTCUT),
+                tr.text("Jump to the same field in the previous record"),
+            ));
+        }
+        if self.frame_data.memory_range_combo_box_enabled {
+            keyboard.extend([
+                (shortcut(&NEXT_REGION_SHORTCUT), tr.text("Switch to the next region")),
+                (
+                    shortcut(&PREVIOUS_REGION_SHORTCUT),
+                    tr.text("Switch to the previous region"),
+                ),
+                (
+                    shortcut(&KeyboardShortcut::new(Modifiers::COMMAND, Key::Num1)) + " … 9",
+                    tr.text("Switch to the first to ninth region"),
+                ),
+            ]);
+        }
+        if can_write {
+            keyboard.extend([
+                ("←↑→↓".to_string(), tr.text("Move the edited value")),
+                ("Home / End".to_string(), tr.text("Move to the start or end of the row")),
+                (
+                    "Ctrl+Home / Ctrl+End".to_string(),
+                    tr.text("Move to the start or end of the region"),
+                ),
+                (
+                    "+ / = / -".to_string(),
+                    tr.text("Increment or decrement the selected values, = and - by 0x10 with Shift"),
+                ),
+                (shortcut(&UNDO_SHORTCUT), tr.text("Undo")),
+                (shortcut(&REDO_SHORTCUT), tr.text("Redo")),
+            ]);
+        }
+        if can_write && self.options.nibble_editing {
+            keyboard.push((
+                "← / →".to_string(),
+                tr.text("Move between the nibbles of the edited value"),
+            ));
+        }
+        if can_resize {
+            keyboard.push((
+                "Insert".to_string(),
+                tr.text("Toggle between inserting and overwriting values"),
+            ));
+        }
+
+        let mut mouse = Vec::new();
+        if self.options.touch_mode {
+            mouse.push((tr.text("Tap"), tr.text("Highlight a value")));
+            if can_write {
+                mouse.push((tr.text("Double tap"), tr.text("Edit a value")));
+            }
+            mouse.push((tr.text("Long press"), tr.text("Open the context menu of a value")));
+        } else {
+            mouse.push((
+                tr.text("Click"),
+                if can_write {
+                    tr.text("Edit a value")
+                } else {
+                    tr.text("Highlight a value")
+                },
+            ));
+            mouse.push((tr.text("Right click"), tr.text("Open the context menu of a value")));
+        }
+        mouse.extend([
+            (tr.text("Drag"), tr.text("Select a range of values")),
+            (tr.text("Shift+Click"), tr.text("Extend the selection")),
+            (tr.text("Ctrl+Click"), tr.text("Add a value to the selection")),
+        ]);
+        if can_write {
+            mouse.push((tr.text("Alt+Drag"), tr.text("Scrub a value up or down")));
+            mouse.push((
+                tr.text("Drag selection"),
+                tr.text("Copy the selected values, hold Shift to move them"),
+            ));
+        }
+
+        let mut is_open = true;
+        Window::new(tr.text("? Shortcuts"))
+            .id(egui::Id::new("mem_edit_help"))
+            .open(&mut is_open)
+            .resizable(false)
+            .collapsible(false)
+            .show(ctx, |ui| {
+                draw_help_section(ui, "help_keyboard_grid", &tr.text("⌨ Keyboard"), &keyboard);
+                ui.separator();
+                draw_help_section(ui, "help_mouse_grid", &tr.text("🖱 Mouse"), &mouse);
+            });
+        self.frame_data.show_help = is_open;
+    }
+}
+
+/// Draw a heading with a grid of inputs and what they do.
+fn draw_help_section(ui: &mut Ui, id_salt: &str, heading: &str, entries: &[(impl AsRef<str>, impl AsRef<str>)]) {
+    ui.strong(heading);
+    egui::Grid::new(id_salt).striped(true).show(ui, |ui| {
+        for (input, description) in entries {
+            ui.monospace(input.as_ref());
+            ui.label(description.as_ref());
+            ui.end_row();
+        }
+    });
+}
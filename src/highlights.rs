@@ -0,0 +1,191 @@
+use std::ops::Range;
+
+use egui::{Color32, Sense, Ui, Vec2};
+
+use crate::value_history::RECENT_CHANGES_GROUP;
+use crate::{Address, MemoryEditor};
+
+/// The legend name of values which changed since the snapshot.
+pub(crate) const CHANGED_GROUP: &str = "Changed";
+/// The legend name of values pointed to by a live pointer.
+pub(crate) const POINTERS_GROUP: &str = "Pointers";
+/// The legend name of the selection, which includes search matches.
+pub(crate) const SELECTION_GROUP: &str = "Selection";
+/// The size of a colour swatch in the legend.
+const SWATCH_SIZE: f32 = 10.0;
+
+/// A named set of address ranges, highlighted with the same background colour.
+#[derive(Clone, Debug)]
+pub(crate) struct HighlightGroup {
+    pub name: String,
+    pub colour: Color32,
+    pub ranges: Vec<Range<Address>>,
+}
+
+/// A single entry in the legend.
+enum LegendEntry {
+    /// A group which can be hidden by name.
+    Group { name: String, colour: Color32 },
+    /// A colour rule, hidden by disabling it.
+    Rule { index: usize },
+}
+
+impl MemoryEditor {
+    /// Add or replace a named group of address ranges, highlighted with the given background colour.
+    ///
+    /// Groups are listed in the highlight legend, where they can be hidden.
+    /// See also [`Self::set_highlight_group`].
+    #[must_use]
+    pub fn with_highlight_group(
+        mut self,
+        name: impl Into<String>,
+        colour: Color32,
+        ranges: impl IntoIterator<Item = Range<Address>>,
+    ) -> Self {
+        self.set_highlight_group(name, colour, ranges);
+        self
+    }
+
+    /// Add or replace a named group of address ranges, highlighted with the given background colour.
+    pub fn set_highlight_group(
+        &mut self,
+        name: impl Into<String>,
+        colour: Color32,
+        ranges: impl IntoIterator<Item = Range<Address>>,
+    ) {
+        let group = HighlightGroup {
+            name: name.into(),
+            colour,
+            ranges: ranges.into_iter().collect(),
+        };
+
+        match self
+            .highlight_groups
+            .iter_mut()
+            .find(|existing| existing.name == group.name)
+        {
+            Some(existing) => *existing = group,
+            None => self.highlight_groups.push(group),
+        }
+    }
+
+    /// Keep the selection as a highlight group with the given name, replacing any group with the same name, see
+    /// [`Self::set_highlight_group`].
+    ///
+    /// Returns `false` if nothing is selected.
+    pub fn highlight_selection(&mut self, name: impl Into<String>, colour: Color32) -> bool {
+        if self.frame_data.selection.is_empty() {
+            return false;
+        }
+
+        self.set_highlight_group(name, colour, self.frame_data.selection.merged_ranges());
+        true
+    }
+
+    /// Remove the highlight group with the given name, returning whether it existed.
+    pub fn remove_highlight_group(&mut self, name: &str) -> bool {
+        let count = self.highlight_groups.len();
+        self.highlight_groups.retain(|group| group.name != name);
+        self.highlight_groups.len() != count
+    }
+
+    /// Whether the highlight group (or built-in highlight) with the given name was hidden in the legend.
+    pub(crate) fn is_group_hidden(&self, name: &str) -> bool {
+        self.options.hidden_highlight_groups.contains(name)
+    }
+
+    /// The colour of the first visible highlight group containing the address, if any.
+    pub(crate) fn highlight_group_colour(&self, address: Address) -> Option<Color32> {
+        self.highlight_groups
+            .iter()
+            .filter(|group| !self.is_group_hidden(&group.name))
+            .find(|group| group.ranges.iter().any(|range| range.contains(&address)))
+            .map(|group| group.colour)
+    }
+
+    /// All sources of highlights which are currently active.
+    fn legend_entries(&self, ui: &Ui) -> Vec<LegendEntry> {
+        let built_in = [
+            (
+                CHANGED_GROUP,
+                self.options.diff_text_colour,
+                self.frame_data.snapshot.is_some(),
+            ),
+            (
+                RECENT_CHANGES_GROUP,
+                self.options.recent_change_colour,
+                self.options.highlight_recent_changes,
+            ),
+            (
+                POINTERS_GROUP,
+                self.options.pointer_colour,
+                !self.live_pointers.is_empty(),
+            ),
+            (
+                SELECTION_GROUP,
+                ui.visuals().selection.bg_fill,
+                !self.frame_data.selection.is_empty(),
+            ),
+        ];
+
+        let groups = self.highlight_groups.iter().map(|group| LegendEntry::Group {
+            name: group.name.clone(),
+            colour: group.colour,
+        });
+        let built_in = built_in
+            .into_iter()
+            .filter(|(_, _, is_active)| *is_active)
+            .map(|(name, colour, _)| LegendEntry::Group {
+                name: name.to_string(),
+                colour,
+            });
+        let rules = (0..self.options.colour_rules.len()).map(|index| LegendEntry::Rule { index });
+
+        groups.chain(rules).chain(built_in).collect()
+    }
+
+    /// Draw a legend of all active highlight sources, if there are multiple, where each can be hidden.
+    pub(crate) fn draw_highlight_legend(&mut self, ui: &mut Ui) {
+        let tr = self.translator();
+        let entries = self.legend_entries(ui);
+
+        if entries.len() < 2 {
+            return;
+        }
+
+        ui.horizontal_wrapped(|ui| {
+            ui.label(tr.text("Legend:"));
+
+            for entry in entries {
+                let (colour, name, is_visible) = match &entry {
+                    LegendEntry::Group { name, colour } => (*colour, name.clone(), !self.is_group_hidden(name)),
+                    LegendEntry::Rule { index } => {
+                        let rule = &self.options.colour_rules[*index];
+                        (rule.colour, rule.condition.to_string(), rule.enabled)
+                    }
+                };
+
+                let (rect, _) = ui.allocate_exact_size(Vec2::splat(SWATCH_SIZE), Sense::hover());
+                ui.painter().rect_filled(rect, 2.0, colour);
+
+                if ui
+                    .selectable_label(is_visible, tr.text(&name))
+                    .on_hover_text(tr.text("Click to show or hide this highlight"))
+                    .clicked()
+                {
+                    match entry {
+                        LegendEntry::Group { name, .. } if is_visible => {
+                            self.options.hidden_highlight_groups.insert(name);
+                        }
+                        LegendEntry::Group { name, .. } => {
+                            self.options.hidden_highlight_groups.remove(&name);
+                        }
+                        LegendEntry::Rule { index } => {
+                            self.options.colour_rules[index].enabled = !is_visible;
+                        }
+                    }
+                }
+            }
+        });
+    }
+}
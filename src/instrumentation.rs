@@ -0,0 +1,131 @@
+use std::sync::{Arc, Mutex};
+
+use crate::{Address, MemoryEditor};
+
+/// Called for every read and write the editor performs, see [`MemoryEditor::with_access_hook`].
+pub(crate) type AccessHook = Arc<dyn Fn(&MemoryAccess) + Send + Sync>;
+/// The reason for the read currently made through the frame's read cache, which is only known where the read is
+/// requested, but only reported below the cache when the read reaches the host, see [`with_access_reason`].
+pub(crate) type CurrentAccessReason = Arc<Mutex<AccessReason>>;
+
+/// Why the editor accessed memory.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum AccessReason {
+    /// Displaying values, including the data preview and markers.
+    Render,
+    /// Searching for data, changes, or a pattern.
+    Search,
+    /// Copying or exporting values.
+    Export,
+    /// Editing values, including the reads needed to modify part of a value.
+    Edit,
+    /// Taking a snapshot to compare against.
+    Snapshot,
+}
+
+/// Whether memory was read or written, along with the value.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum AccessKind {
+    Read { value: Option<u8> },
+    Write { value: u8 },
+}
+
+/// A single read or write performed by the editor.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub struct MemoryAccess {
+    /// The address as displayed in the editor, for mirrored address ranges this is the address in the mirror.
+    pub address: Address,
+    pub kind: AccessKind,
+    pub reason: AccessReason,
+}
+
+impl MemoryEditor {
+    /// Set a callback which is invoked for every read and write the editor performs, along with the reason for it.
+    ///
+    /// Useful to audit how the editor touches memory, for example when reads have side effects.
+    ///
+    /// Only the reads which reach the host's read function are reported, values shared between features within a
+    /// frame are read once.
+    ///
+    /// ```
+    /// # use egui_memory_editor::MemoryEditor;
+    /// use std::sync::{Arc, Mutex};
+    ///
+    /// let accesses = Arc::new(Mutex::new(Vec::new()));
+    /// let hook_accesses = accesses.clone();
+    /// let mut memory_editor = MemoryEditor::new()
+    ///     .with_address_range("Memory", 0..0x100)
+    ///     .with_access_hook(move |access| hook_accesses.lock().unwrap().push(access.address));
+    /// let mut memory = vec![0x41u8; 0x100];
+    /// let ctx = egui::Context::default();
+    ///
+    /// // The cached rows are known from the second frame on.
+    /// for _ in 0..2 {
+    ///     accesses.lock().unwrap().clear();
+    ///     let _ = ctx.run(Default::default(), |ctx| {
+    ///         egui::CentralPanel::default().show(ctx, |ui| {
+    ///             memory_editor.draw_editor_contents_read_only(ui, &mut memory, |mem, address| mem.get(address).copied());
+    ///         });
+    ///     });
+    /// }
+    ///
+    /// let mut addresses = accesses.lock().unwrap().clone();
+    /// let read_count = addresses.len();
+    /// addresses.sort();
+    /// addresses.dedup();
+    /// assert_eq!(addresses.len(), read_count);
+    /// ```
+    #[must_use]
+    pub fn with_access_hook(mut self, on_access: impl Fn(&MemoryAccess) + Send + Sync + 'static) -> Self {
+        self.access_hook = Some(Arc::new(on_access));
+        self
+    }
+}
+
+/// Report a single access to the `hook`, if there is one.
+pub(crate) fn report_access(hook: &Option<AccessHook>, address: Address, kind: AccessKind, reason: AccessReason) {
+    if let Some(hook) = hook {
+        hook(&MemoryAccess { address, kind, reason });
+    }
+}
+
+/// Wrap `read_fn` such that every read is reported to the `hook` with the given `reason`.
+#[cfg(any(feature = "search", feature = "export"))]
+pub(crate) fn instrument_read<T: ?Sized>(
+    hook: Option<AccessHook>,
+    reason: AccessReason,
+    read_fn: &mut impl FnMut(&mut T, Address) -> Option<u8>,
+) -> impl FnMut(&mut T, Address) -> Option<u8> {
+    move |mem, address| {
+        let value = read_fn(mem, address);
+        report_access(&hook, address, AccessKind::Read { value }, reason);
+        value
+    }
+}
+
+/// Wrap a `read_fn` which reads through the frame's read cache, such that the reads which reach the host are
+/// reported with the given `reason`. Reads served from the cache aren't reported.
+pub(crate) fn with_access_reason<T: ?Sized>(
+    current_reason: CurrentAccessReason,
+    reason: AccessReason,
+    read_fn: &mut impl FnMut(&mut T, Address) -> Option<u8>,
+) -> impl FnMut(&mut T, Address) -> Option<u8> {
+    move |mem, address| {
+        *current_reason.lock().unwrap() = reason;
+        read_fn(mem, address)
+    }
+}
+
+/// Wrap `write_fn` such that every write is reported to the `hook` with the given `reason`.
+pub(crate) fn instrument_write<T: ?Sized>(
+    hook: Option<AccessHook>,
+    reason: AccessReason,
+    write_fn: &mut Option<impl FnMut(&mut T, Address, u8)>,
+) -> Option<impl FnMut(&mut T, Address, u8)> {
+    write_fn.as_mut().map(|write_fn| {
+        move |mem: &mut T, address, value| {
+            report_access(&hook, address, AccessKind::Write { value }, reason);
+            write_fn(mem, address, value)
+        }
+    })
+}
@@ -0,0 +1,356 @@
+//! Reading and writing [IPS](https://zerosoft.zophar.net/ips.php) patches, a simple patch format commonly used for
+//! ROM hacks.
+//!
+//! Offsets in a patch are relative to the start of the patched address range.
+//! IPS files dropped onto the editor open a preview of the affected ranges, from which the patch can be applied.
+//! Only available with the `ips` feature.
+use std::fmt::{Display, Formatter};
+use std::ops::Range;
+
+use egui::{Context, DroppedFile, Window};
+
+use crate::file_drop::{dropped_file_bytes, dropped_file_name};
+use crate::notices::Notice;
+use crate::selection::Selection;
+use crate::{Address, MemoryEditor};
+
+const HEADER: &[u8] = b"PATCH";
+const FOOTER: &[u8] = b"EOF";
+/// The largest offset which can be encoded in the 24 bits of a record.
+const MAX_OFFSET: usize = 0xFF_FFFF;
+/// The largest amount of data in a single record.
+const MAX_RECORD_SIZE: usize = 0xFFFF;
+/// A record at this offset would be mistaken for the footer.
+const FOOTER_OFFSET: usize = 0x45_4F46;
+/// The maximum amount of affected ranges listed in the preview.
+const MAX_PREVIEWED_RANGES: usize = 20;
+
+/// A single record of an IPS patch, replacing the data at `offset`.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct IpsRecord {
+    pub offset: usize,
+    pub data: Vec<u8>,
+}
+
+/// The reason an IPS patch couldn't be created or applied.
+#[derive(Debug)]
+pub enum IpsError {
+    /// The offset doesn't fit in the 24 bits available in a record.
+    OffsetTooLarge(usize),
+    /// A record starts at the offset which would be read as the end of the patch, `0x454F46` (`EOF`).
+    FooterOffset,
+    /// The patch doesn't start with `PATCH`.
+    MissingHeader,
+    /// The patch ends in the middle of a record, or without `EOF`.
+    Truncated,
+    /// A record would write past the end of the address range being patched.
+    OutOfRange(usize),
+    /// The patch couldn't be read.
+    Io(std::io::Error),
+}
+
+impl Display for IpsError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            IpsError::OffsetTooLarge(offset) => write!(f, "the offset {:#X} is too large for an IPS patch", offset),
+            IpsError::FooterOffset => write!(f, "a record can't start at offset 0x454F46 in an IPS patch"),
+            IpsError::MissingHeader => write!(f, "not an IPS patch, it doesn't start with `PATCH`"),
+            IpsError::Truncated => write!(f, "the IPS patch ends unexpectedly"),
+            IpsError::OutOfRange(offset) => {
+                write!(f, "the IPS patch writes past the end of the range at {:#X}", offset)
+            }
+            IpsError::Io(error) => write!(f, "the IPS patch couldn't be read: {}", error),
+        }
+    }
+}
+
+impl std::error::Error for IpsError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            IpsError::Io(error) => Some(error),
+            _ => None,
+        }
+    }
+}
+
+impl From<std::io::Error> for IpsError {
+    fn from(error: std::io::Error) -> Self {
+        IpsError::Io(error)
+    }
+}
+
+/// Encode the records as an IPS patch, records larger than the format allows are split.
+///
+/// ```
+/// # use egui_memory_editor::ips::{self, IpsRecord};
+/// let patch = ips::encode_ips(&[IpsRecord { offset: 0x10, data: vec![0xAB, 0xCD] }]).unwrap();
+///
+/// assert_eq!(patch, b"PATCH\x00\x00\x10\x00\x02\xAB\xCDEOF");
+/// ```
+pub fn encode_ips(records: &[IpsRecord]) -> Result<Vec<u8>, IpsError> {
+    let mut patch = HEADER.to_vec();
+
+    for record in records {
+        for (index, data) in record.data.chunks(MAX_RECORD_SIZE).enumerate() {
+            let offset = record.offset + index * MAX_RECORD_SIZE;
+
+            if offset > MAX_OFFSET {
+                return Err(IpsError::OffsetTooLarge(offset));
+            }
+            if offset == FOOTER_OFFSET {
+                return Err(IpsError::FooterOffset);
+            }
+
+            patch.extend_from_slice(&offset.to_be_bytes()[size_of::<usize>() - 3..]);
+            patch.extend_from_slice(&(data.len() as u16).to_be_bytes());
+            patch.extend_from_slice(data);
+        }
+    }
+
+    patch.extend_from_slice(FOOTER);
+    Ok(patch)
+}
+
+/// Decode an IPS patch into its records, expanding run-length encoded records.
+///
+/// ```
+/// # use egui_memory_editor::ips::{self, IpsRecord};
+/// // A record of two bytes at 0x10, and a run of three 0xFF bytes at 0x20.
+/// let patch = b"PATCH\x00\x00\x10\x00\x02\xAB\xCD\x00\x00\x20\x00\x00\x00\x03\xFFEOF";
+///
+/// assert_eq!(
+///     ips::decode_ips(patch).unwrap(),
+///     vec![
+///         IpsRecord { offset: 0x10, data: vec![0xAB, 0xCD] },
+///         IpsRecord { offset: 0x20, data: vec![0xFF; 3] },
+///     ]
+/// );
+/// ```
+pub fn decode_ips(patch: &[u8]) -> Result<Vec<IpsRecord>, IpsError> {
+    let mut rest = patch.strip_prefix(HEADER).ok_or(IpsError::MissingHeader)?;
+    let mut records = Vec::new();
+    let mut take = |count: usize| -> Result<&[u8], IpsError> {
+        let (taken, remaining) = rest.split_at_checked(count).ok_or(IpsError::Truncated)?;
+        rest = remaining;
+        Ok(taken)
+    };
+
+    loop {
+        let offset = take(3)?;
+        if offset == FOOTER {
+            // Anything after the footer is an optional truncation size, which doesn't apply to memory.
+            return Ok(records);
+        }
+
+        let offset = offset.iter().fold(0, |acc, &byte| (acc << 8) | byte as usize);
+        let size = take(2)?;
+        let size = u16::from_be_bytes([size[0], size[1]]) as usize;
+
+        let data = if size == 0 {
+            let run = take(3)?;
+            vec![run[2]; u16::from_be_bytes([run[0], run[1]]) as usize]
+        } else {
+            take(size)?.to_vec()
+        };
+
+        records.push(IpsRecord { offset, data });
+    }
+}
+
+/// An IPS patch dropped onto the editor, waiting to be applied.
+#[derive(Clone, Debug)]
+pub(crate) struct PendingPatch {
+    pub name: String,
+    /// The records of the patch, or the reason it couldn't be decoded.
+    pub records: Result<Vec<IpsRecord>, String>,
+}
+
+impl MemoryEditor {
+    /// The ranges of the selected address range which the IPS patch would write to, without writing anything.
+    pub fn preview_ips(&self, patch: &[u8]) -> Result<Vec<Range<Address>>, IpsError> {
+        self.ips_affected_ranges(&decode_ips(patch)?)
+    }
+
+    /// Apply an IPS patch to the selected address range, returning the ranges which were written to.
+    ///
+    /// Nothing is written if the patch is invalid, or if it writes past the end of the address range.
+    pub fn apply_ips<T: ?Sized>(
+        &mut self,
+        mem: &mut T,
+        mut write_fn: impl FnMut(&mut T, Address, u8),
+        patch: &[u8],
+    ) -> Result<Vec<Range<Address>>, IpsError> {
+        let records = decode_ips(patch)?;
+        let ranges = self.ips_affected_ranges(&records)?;
+        self.write_ips_records(mem, &mut write_fn, &records);
+
+        Ok(ranges)
+    }
+
+    /// The merged ranges of the selected address range which the records write to.
+    fn ips_affected_ranges(&self, records: &[IpsRecord]) -> Result<Vec<Range<Address>>, IpsError> {
+        let address_range = self
+            .address_range(&self.window.selected_address_range)
+            .cloned()
+            .unwrap_or_default();
+        let mut selection = Selection::default();
+
+        for record in records.iter().filter(|record| !record.data.is_empty()) {
+            let start = address_range.start + record.offset;
+            let end = start + record.data.len();
+
+            if end > address_range.end {
+                return Err(IpsError::OutOfRange(record.offset));
+            }
+
+            selection.add_range(start..end);
+        }
+
+        Ok(selection.merged_ranges())
+    }
+
+    fn write_ips_records<T: ?Sized>(
+        &self,
+        mem: &mut T,
+        write_fn: &mut impl FnMut(&mut T, Address, u8),
+        records: &[IpsRecord],
+    ) {
+        let start = self
+            .address_range(&self.window.selected_address_range)
+            .map_or(0, |address_range| address_range.start);
+
+        for record in records {
+            for (index, &value) in record.data.iter().enumerate() {
+                write_fn(mem, start + record.offset + index, value);
+            }
+        }
+    }
+
+    /// Open a preview of an IPS patch dropped onto the editor.
+    pub(crate) fn open_dropped_patch(&mut self, file: &DroppedFile) {
+        let name = dropped_file_name(file);
+        let bytes = dropped_file_bytes(file);
+
+        let records = bytes
+            .map_err(IpsError::Io)
+            .and_then(|bytes| decode_ips(&bytes))
+            .map_err(|error| error.to_string());
+        if let Err(error) = &records {
+            self.frame_data.notify(Notice::ImportFailed {
+                what: name.clone(),
+                error: error.clone(),
+            });
+        }
+        self.frame_data.pending_patch = Some(PendingPatch { name, records });
+    }
+
+    /// Draw the preview of the dropped IPS patch, if any, from which it can be applied.
+    pub(crate) fn draw_patch_dialog<T: ?Sized>(
+        &mut self,
+        ctx: &Context,
+        mem: &mut T,
+        write_fn: &mut Option<impl FnMut(&mut T, Address, u8)>,
+    ) {
+        let tr = self.translator();
+        let Some(patch) = &self.frame_data.pending_patch else {
+            return;
+        };
+        let ranges = match &patch.records {
+            Ok(records) => self.ips_affected_ranges(records).map_err(|error| error.to_string()),
+            Err(error) => Err(error.clone()),
+        };
+        let mut is_open = true;
+        let mut apply = false;
+
+        Window::new(tr.format("Apply {name}", &[("name", &patch.name)]))
+            .id(egui::Id::new("mem_edit_patch_dialog"))
+            .open(&mut is_open)
+            .collapsible(false)
+            .resizable(false)
+            .show(ctx, |ui| match &ranges {
+                Ok(ranges) => {
+                    let size: usize = ranges.iter().map(|range| range.len()).sum();
+                    ui.label(tr.format(
+                        "Writes {size} byte(s) in {count} range(s) of {region}:",
+                        &[
+                            ("size", &size),
+                            ("count", &ranges.len()),
+                            ("region", &self.window.selected_address_range),
+                        ],
+                    ));
+
+                    for range in ranges.iter().take(MAX_PREVIEWED_RANGES) {
+                        ui.monospace(format!("0x{:X}–0x{:X}", range.start, range.end - 1));
+                    }
+                    if ranges.len() > MAX_PREVIEWED_RANGES {
+                        let count = ranges.len() - MAX_PREVIEWED_RANGES;
+                        ui.label(tr.format("… and {count} more", &[("count", &count)]));
+                    }
+
+                    apply = ui
+                        .add_enabled(write_fn.is_some(), egui::Button::new(tr.text("Apply")))
+                        .on_disabled_hover_text(tr.text("The memory can't be edited"))
+                        .clicked();
+                }
+                Err(error) => {
+                    ui.colored_label(ui.visuals().error_fg_color, error);
+                }
+            });
+
+        if apply
+            && let Some(write_fn) = write_fn.as_mut()
+            && let Some(Ok(records)) = self.frame_data.pending_patch.as_ref().map(|patch| &patch.records)
+        {
+            self.write_ips_records(mem, write_fn, records);
+            is_open = false;
+        }
+
+        if !is_open {
+            self.frame_data.pending_patch = None;
+        }
+    }
+
+    /// Export every value which changed since the snapshot was taken as an IPS patch, or `None` if there is no
+    /// snapshot.
+    ///
+    /// Offsets are relative to the start of the snapshot, values which can no longer be read are left out.
+    pub fn export_snapshot_ips<T: ?Sized>(
+        &self,
+        mem: &mut T,
+        mut read_fn: impl FnMut(&mut T, Address) -> Option<u8>,
+    ) -> Option<Result<Vec<u8>, IpsError>> {
+        let snapshot = self.frame_data.snapshot.as_ref()?;
+        let start = snapshot.range().start;
+        let mut records: Vec<IpsRecord> = Vec::new();
+
+        for run in snapshot.changed_runs(mem, &mut read_fn) {
+            // Unreadable values split a run, as there is nothing to write for them.
+            for (index, value) in run.new.iter().enumerate() {
+                let Some(value) = *value else {
+                    continue;
+                };
+                let offset = run.start - start + index;
+
+                match records.last_mut() {
+                    Some(record) if record.offset + record.data.len() == offset => record.data.push(value),
+                    _ => records.push(IpsRecord {
+                        offset,
+                        data: vec![value],
+                    }),
+                }
+            }
+        }
+
+        // Rather than failing on the offset of the footer, start such a record one value earlier.
+        for record in &mut records {
+            if record.offset == FOOTER_OFFSET
+                && let Some(previous) = read_fn(mem, start + FOOTER_OFFSET - 1)
+            {
+                record.offset -= 1;
+                record.data.insert(0, previous);
+            }
+        }
+
+        Some(encode_ips(&records))
+    }
+}
@@ -4,20 +4,191 @@
 //! Primarily intended for emulation development.
 //!
 //! Look at [`MemoryEditor`] to get started.
-use std::collections::BTreeMap;
+use std::cell::{Cell, RefCell};
+use std::collections::{BTreeMap, BTreeSet};
 use std::ops::Range;
-
-use egui::{Context, Label, Margin, RichText, ScrollArea, Sense, TextEdit, TextWrapMode, Ui, Vec2, Widget, Window};
-
-use crate::option_data::{BetweenFrameData, MemoryEditorOptions};
-
+use std::sync::{Arc, Mutex};
+
+use egui::scroll_area::ScrollSource;
+use egui::{
+    Color32, Context, Label, Margin, RichText, ScrollArea, Sense, TextEdit, TextWrapMode, Ui, Vec2, Widget, Window,
+};
+
+use crate::accessibility::CellState;
+use crate::ascii_sidebar::draw_ascii_sidebar_toggle;
+#[cfg(feature = "bookmarks")]
+use crate::bookmarks::Bookmark;
+use crate::cell_renderer::{CellInfo, CellRenderer};
+use crate::coverage::CoverageProvider;
+#[cfg(feature = "tools")]
+use crate::disassembly::Disassembler;
+#[cfg(feature = "export")]
+use crate::export::ExportHandler;
+use crate::expression::ExpressionVariables;
+use crate::file_drop::FileDropHandler;
+use crate::follow::FollowProvider;
+use crate::growing::GrowingRange;
+use crate::highlights::{CHANGED_GROUP, HighlightGroup, POINTERS_GROUP, SELECTION_GROUP};
+#[cfg(any(feature = "search", feature = "export"))]
+use crate::instrumentation::instrument_read;
+use crate::instrumentation::{
+    AccessHook, AccessKind, AccessReason, CurrentAccessReason, instrument_write, report_access, with_access_reason,
+};
+use crate::markers::MarkerKind;
+use crate::mirror::Mirror;
+use crate::nibble_edit::draw_nibble_cursor;
+use crate::option_data::{BetweenFrameData, MemoryEditorOptions, ReadOnlyReason, RegionOptions, UnreadableStyle};
+use crate::overlay::OverlayPainter;
+use crate::permissions::{Permissions, permissions_at};
+use crate::range_brackets::RANGE_BRACKET_MARGIN;
+use crate::read_cache::FrameReads;
+use crate::resize::DummyResizeFunctions;
+use crate::ring_buffer::RingBufferProvider;
+use crate::row_layout::{CollapsedKind, Row};
+use crate::scrolling::is_vertical_scroll_bar_dragged;
+use crate::snapshot::Snapshot;
+use crate::style_override::StyleOverride;
+use crate::tags::Tag;
+use crate::tail_follow::WriteCursorProvider;
+#[cfg(feature = "tools")]
+use crate::transforms::Transform;
+use crate::translations::Translator;
+use crate::triggers::Trigger;
+use crate::undo::UndoWrite;
+use crate::unmapped::paint_hatching;
+use crate::value_history::RECENT_CHANGES_GROUP;
+use crate::views::View;
+use crate::watches::Watch;
+use crate::write_guard::WriteGuard;
+
+mod accessibility;
+pub mod address_format;
+mod address_links;
+pub mod address_list;
+#[cfg(feature = "tools")]
+pub mod analysis;
+mod ascii_sidebar;
+#[cfg(feature = "bookmarks")]
+pub mod bookmarks;
+mod bounds;
+pub mod cell_renderer;
+#[cfg(feature = "plot")]
+pub mod chart;
+pub mod checksum;
+pub mod colour_rules;
+#[cfg(feature = "inspector")]
+pub mod colours;
+mod command_palette;
+mod copy_formats;
+mod coverage;
+mod diagnostics;
+#[cfg(feature = "tools")]
+pub mod disassembly;
+#[cfg(feature = "export")]
+pub mod export;
+mod expression;
+mod file_drop;
+mod focus;
+mod follow;
+mod font_metrics;
+mod grid_image;
+mod growing;
+mod headless;
+mod heat_map;
+mod help;
+mod highlights;
+pub mod instrumentation;
+#[cfg(feature = "ips")]
+pub mod ips;
+mod locked_options;
+mod markers;
+pub mod memory_map;
+mod mirror;
+mod multi_cursor;
+mod navigation;
+mod nibble_edit;
+mod notices;
 pub mod option_data;
 mod option_ui;
+mod option_validation;
+pub mod overlay;
+mod panels;
+pub mod permissions;
+mod pinned_row;
+#[cfg(feature = "tools")]
+pub mod pointer_scan;
+mod pointers;
+mod range_brackets;
+mod range_validation;
+mod read_cache;
+mod records;
+pub mod region_fill;
+mod region_options;
+mod region_shortcuts;
+pub mod registers;
+mod repeated_lines;
+mod resize;
+pub mod ring_buffer;
+mod row_layout;
+mod scratch;
+#[cfg(feature = "scripting")]
+pub mod scripting;
+mod scrolling;
+#[cfg(feature = "search")]
+mod search;
+pub mod selection;
+mod selection_drag;
+pub mod session;
+#[cfg(feature = "tools")]
+pub mod signatures;
+mod snapshot;
+mod status_bar;
+mod style_override;
+mod symbols;
+pub mod tags;
+mod tail_follow;
+#[cfg(feature = "inspector")]
+pub mod timestamps;
+pub mod transfer;
+#[cfg(feature = "tools")]
+mod transforms;
+mod translations;
+pub mod triggers;
+mod undo;
+mod unmapped;
 mod utilities;
+mod value_edit;
+mod value_history;
+pub mod viewer;
+mod views;
+pub mod watches;
+mod window_state;
+pub mod words;
+mod write_guard;
+
+pub use diagnostics::FrameDiagnostics;
+pub use navigation::SearchDirection;
+pub use notices::Notice;
+pub use option_validation::OptionProblem;
+pub use range_validation::AddressRangeError;
+pub use resize::ResizeFunctions;
+#[cfg(feature = "search")]
+pub use search::SearchMatch;
+pub use window_state::{Layout, ViewLayout, WindowState};
 
 /// A memory address that should be read from/written to.
 pub type Address = usize;
 
+// This needs to exist due to the fact we want to use generics, and `Option` needs to know the size of its contents.
+type DummyWriteFunction<T> = fn(&mut T, Address, u8);
+
+/// The horizontal distance the mouse needs to be dragged to change a scrubbed value by one.
+const SCRUB_PIXELS_PER_STEP: f32 = 4.0;
+/// The extra height of every row in touch mode, to make values easier to tap.
+const TOUCH_ROW_PADDING: f32 = 12.0;
+/// The horizontal space between values in touch mode.
+const TOUCH_VALUE_SPACING: f32 = 10.0;
+
 /// The main struct for the editor window.
 /// This should persist between frames as it keeps track of quite a bit of state.
 #[derive(Clone)]
@@ -27,10 +198,88 @@ pub struct MemoryEditor {
     /// The collection of address ranges, the GUI will start at the lower bound and go up to the upper bound.
     ///
     /// Note this *currently* only supports ranges that have a max of `2^(24+log_2(column_count))` due to `ScrollArea` limitations.
-    address_ranges: BTreeMap<String, Range<Address>>,
+    /// The ranges are kept in the order they were first added.
+    address_ranges: Vec<(String, Range<Address>)>,
+    /// Options overridden for specific address ranges, by name.
+    region_options: BTreeMap<String, RegionOptions>,
+    /// The address ranges which mirror another address range, by name.
+    mirrors: BTreeMap<String, Mirror>,
+    /// The address ranges whose length is provided by the host every frame, by name.
+    growing_ranges: BTreeMap<String, GrowingRange>,
+    /// The address ranges shown as a ring buffer, with the host's head and tail, by name.
+    ring_buffers: BTreeMap<String, RingBufferProvider>,
+    /// The data of the address ranges which aren't read from the host's memory, by name.
+    scratch_regions: BTreeMap<String, Arc<[u8]>>,
+    /// The names of the address ranges which can't be edited.
+    read_only_address_ranges: BTreeSet<String>,
+    /// Named addresses, displayed in the gutter.
+    #[cfg(feature = "bookmarks")]
+    bookmarks: Vec<Bookmark>,
+    /// Free text notes for specific addresses, displayed in the gutter.
+    annotations: BTreeMap<Address, String>,
+    /// Expressions whose values are displayed in the watch list.
+    watches: Vec<Watch>,
+    /// Addresses whose changes capture memory, see [`Trigger`].
+    triggers: Vec<Trigger>,
+    /// Named addresses, usually provided by the host from e.g. a linker map.
+    symbols: BTreeMap<Address, String>,
+    /// Host provided pointers, such as the stack pointer, whose targets are highlighted.
+    live_pointers: Vec<(String, Address)>,
+    /// Address ranges which are never read, such as holes in a memory map.
+    unmapped_address_ranges: Vec<Range<Address>>,
+    /// The permissions of address ranges, later ranges take precedence.
+    permissions: Vec<(Range<Address>, Permissions)>,
+    /// Host provided groups of address ranges, highlighted in the group's colour.
+    highlight_groups: Vec<HighlightGroup>,
+    /// User-defined tags on ranges of memory, see [`Self::with_tag`].
+    tags: Vec<Tag>,
+    /// Host provided breakpoints, displayed in the gutter.
+    breakpoints: BTreeSet<Address>,
+    /// Called for every read and write the editor performs.
+    access_hook: Option<AccessHook>,
+    /// The reason for the read currently made through the frame's read cache, reported along with the reads which
+    /// reach the host.
+    access_reason: CurrentAccessReason,
+    /// Called when the user wants to toggle a breakpoint at an address.
+    breakpoint_toggle: Option<Arc<dyn Fn(Address) + Send + Sync>>,
+    /// Consulted before every write from the UI, blocking the write if it returns `false`.
+    write_guard: Option<WriteGuard>,
+    /// Resolves host provided variables, such as registers, in address expressions.
+    expression_variables: Option<ExpressionVariables>,
+    /// Provides the address to keep in view, such as the program counter.
+    follow_provider: Option<FollowProvider>,
+    /// Tells which addresses are covered, such as executed by the host, see [`Self::with_coverage`].
+    coverage: Option<CoverageProvider>,
+    /// Provides the most recently written address of a streaming buffer, to pin the view to.
+    write_cursor_provider: Option<WriteCursorProvider>,
+    /// Disassembles the bytes around the cursor for the disassembly panel.
+    #[cfg(feature = "tools")]
+    disassembler: Option<Disassembler>,
+    /// Draws the value cells the host wants to draw itself.
+    cell_renderer: Option<CellRenderer>,
+    /// Paints on top of the values.
+    overlay_painter: Option<OverlayPainter>,
+    /// Adjusts the style while the editor is drawn.
+    style_override: Option<StyleOverride>,
+    /// Host provided transforms which can be applied to the selection, by name.
+    #[cfg(feature = "tools")]
+    transforms: Vec<(String, Transform)>,
+    /// Receives the files exported from the UI, such as the selection saved as binary.
+    #[cfg(feature = "export")]
+    export_handler: Option<ExportHandler>,
+    /// Is given the files dropped onto the editor first, to handle them itself.
+    file_drop_handler: Option<FileDropHandler>,
+    /// Translates the built-in text of the UI.
+    translator: Translator,
+    /// Whether the options can't be changed from the UI, see [`Self::with_locked_options`].
+    options_locked: bool,
     /// A collection of options relevant for the `MemoryEditor` window.
     /// Can optionally be serialized/deserialized with `serde`
     pub options: MemoryEditorOptions,
+    /// Whether the window is open, the selected address range, and the scroll offset, see [`WindowState`].
+    window: WindowState,
+    /// The additional windows showing the same memory, see [`Self::spawn_view`].
+    views: Vec<View>,
     /// Data for layout between frames, rather hacky.
     frame_data: BetweenFrameData,
     /// The visible range of addresses from the last frame.
@@ -47,17 +296,56 @@ impl MemoryEditor {
     /// # use egui_memory_editor::MemoryEditor;
     /// # let ctx = egui::Context::default();
     /// let mut memory_base = vec![0xFF; 0xFF];
-    /// let mut is_open = true;
     /// let mut memory_editor = MemoryEditor::new().with_address_range("Memory", 0..0xFF);
     ///
     /// // Show a read-only window
-    /// memory_editor.window_ui_read_only(&ctx, &mut is_open, &mut memory_base, |mem, addr| mem[addr].into());
+    /// memory_editor.window_ui_read_only(&ctx, &mut memory_base, |mem, addr| mem[addr].into());
     /// ```
     pub fn new() -> Self {
         MemoryEditor {
             window_name: "Memory Editor".to_string(),
-            address_ranges: BTreeMap::new(),
+            address_ranges: Vec::new(),
+            region_options: BTreeMap::new(),
+            mirrors: BTreeMap::new(),
+            growing_ranges: BTreeMap::new(),
+            ring_buffers: BTreeMap::new(),
+            scratch_regions: BTreeMap::new(),
+            read_only_address_ranges: BTreeSet::new(),
+            #[cfg(feature = "bookmarks")]
+            bookmarks: Vec::new(),
+            annotations: BTreeMap::new(),
+            watches: Vec::new(),
+            triggers: Vec::new(),
+            symbols: BTreeMap::new(),
+            live_pointers: Vec::new(),
+            unmapped_address_ranges: Vec::new(),
+            permissions: Vec::new(),
+            highlight_groups: Vec::new(),
+            tags: Vec::new(),
+            breakpoints: BTreeSet::new(),
+            breakpoint_toggle: None,
+            expression_variables: None,
+            follow_provider: None,
+            coverage: None,
+            write_cursor_provider: None,
+            #[cfg(feature = "tools")]
+            disassembler: None,
+            cell_renderer: None,
+            overlay_painter: None,
+            style_override: None,
+            #[cfg(feature = "tools")]
+            transforms: Vec::new(),
+            #[cfg(feature = "export")]
+            export_handler: None,
+            file_drop_handler: None,
+            translator: Translator::default(),
+            options_locked: false,
+            write_guard: None,
+            access_hook: None,
+            access_reason: Arc::new(Mutex::new(AccessReason::Render)),
             options: Default::default(),
+            window: Default::default(),
+            views: Vec::new(),
             frame_data: Default::default(),
             visible_range: Default::default(),
         }
@@ -72,6 +360,9 @@ impl MemoryEditor {
 
     /// Create a read-only window and render the memory editor contents within.
     ///
+    /// The window is only shown while it's open, which the user can change with its close button and the host with
+    /// [`Self::set_open`].
+    ///
     /// If you want to make your own window/container to be used for the editor contents, you can use [`Self::draw_editor_contents`].
     /// If you wish to be able to write to the memory, you can use [`Self::window_ui`].
     ///
@@ -85,14 +376,16 @@ impl MemoryEditor {
     pub fn window_ui_read_only<T: ?Sized>(
         &mut self,
         ctx: &Context,
-        is_open: &mut bool,
         mem: &mut T,
         read_fn: impl FnMut(&mut T, Address) -> Option<u8>,
     ) {
-        // This needs to exist due to the fact we want to use generics, and `Option` needs to know the size of its contents.
-        type DummyWriteFunction<T> = fn(&mut T, Address, u8);
-
-        self.window_ui_impl(ctx, is_open, mem, read_fn, None::<DummyWriteFunction<T>>);
+        self.window_ui_impl(
+            ctx,
+            mem,
+            read_fn,
+            None::<DummyWriteFunction<T>>,
+            None::<DummyResizeFunctions<T>>,
+        );
     }
 
     /// Create a window and render the memory editor contents within.
@@ -111,31 +404,76 @@ impl MemoryEditor {
     pub fn window_ui<T: ?Sized>(
         &mut self,
         ctx: &Context,
-        is_open: &mut bool,
         mem: &mut T,
         read_fn: impl FnMut(&mut T, Address) -> Option<u8>,
         write_fn: impl FnMut(&mut T, Address, u8),
     ) {
-        self.window_ui_impl(ctx, is_open, mem, read_fn, Some(write_fn));
+        self.window_ui_impl(ctx, mem, read_fn, Some(write_fn), None::<DummyResizeFunctions<T>>);
+    }
+
+    /// Create a window and render the memory editor contents within, allowing the memory to grow and shrink.
+    ///
+    /// This enables insert-mode typing, inserting `N` bytes, and deleting the selection in the UI.
+    /// After a resize the end of the selected address range is updated automatically. Deleting bytes respects the
+    /// permissions and write guard, but neither inserting nor deleting bytes can be undone.
+    ///
+    /// See [`Self::window_ui`] for the other arguments, and [`ResizeFunctions`] for the functions which should be provided.
+    pub fn window_ui_resizable<T: ?Sized>(
+        &mut self,
+        ctx: &Context,
+        mem: &mut T,
+        read_fn: impl FnMut(&mut T, Address) -> Option<u8>,
+        write_fn: impl FnMut(&mut T, Address, u8),
+        resize_fns: ResizeFunctions<impl FnMut(&mut T, Address, usize), impl FnMut(&mut T, Range<Address>)>,
+    ) {
+        self.window_ui_impl(ctx, mem, read_fn, Some(write_fn), Some(resize_fns));
     }
 
     fn window_ui_impl<T: ?Sized>(
         &mut self,
         ctx: &Context,
-        is_open: &mut bool,
+        mem: &mut T,
+        mut read_fn: impl FnMut(&mut T, Address) -> Option<u8>,
+        mut write_fn: Option<impl FnMut(&mut T, Address, u8)>,
+        mut resize_fns: Option<ResizeFunctions<impl FnMut(&mut T, Address, usize), impl FnMut(&mut T, Range<Address>)>>,
+    ) {
+        self.draw_window(
+            ctx,
+            mem,
+            &mut read_fn,
+            write_fn.as_mut(),
+            resize_fns.as_mut().map(ResizeFunctions::by_ref),
+        );
+        self.draw_views(ctx, mem, &mut read_fn, &mut write_fn, &mut resize_fns);
+    }
+
+    /// Draw the editor's window, if it's open.
+    fn draw_window<T: ?Sized>(
+        &mut self,
+        ctx: &Context,
         mem: &mut T,
         read_fn: impl FnMut(&mut T, Address) -> Option<u8>,
         write_fn: Option<impl FnMut(&mut T, Address, u8)>,
+        resize_fns: Option<ResizeFunctions<impl FnMut(&mut T, Address, usize), impl FnMut(&mut T, Range<Address>)>>,
     ) {
-        Window::new(self.window_name.clone())
-            .open(is_open)
+        let mut is_open = self.window.is_open;
+        let window = Window::new(self.window_name.clone())
+            .open(&mut is_open)
             .hscroll(false)
             .vscroll(false)
-            .resizable(true)
-            .show(ctx, |ui| {
-                self.shrink_window_ui(ui);
-                self.draw_editor_contents_impl(ui, mem, read_fn, write_fn);
-            });
+            .resizable(true);
+        let response = self.apply_window_geometry(window).show(ctx, |ui| {
+            let size = ui.max_rect().size();
+            self.shrink_window_ui(ui);
+            self.draw_editor_contents_impl(ui, mem, read_fn, write_fn, resize_fns);
+            size
+        });
+        self.window.is_open = is_open;
+
+        if let Some(response) = response {
+            self.window.position = Some(response.response.rect.min);
+            self.window.size = response.inner.or(self.window.size);
+        }
     }
 
     /// Draws the actual memory viewer/editor.
@@ -145,16 +483,22 @@ impl MemoryEditor {
     /// Use [`Self::window_ui`] if you want to have a window with the contents instead.
     ///
     /// This is the read-only variant. See [`Self::draw_editor_contents`] for the read-write variant.
+    ///
+    /// Trying to edit a value shows a notice that no write function was provided, unless
+    /// [`MemoryEditorOptions::is_read_only`] is set as well.
     pub fn draw_editor_contents_read_only<T: ?Sized>(
         &mut self,
         ui: &mut Ui,
         mem: &mut T,
         read_fn: impl FnMut(&mut T, Address) -> Option<u8>,
     ) {
-        // This needs to exist due to the fact we want to use generics, and `Option` needs to know the size of its contents.
-        type DummyWriteFunction<T> = fn(&mut T, Address, u8);
-
-        self.draw_editor_contents_impl(ui, mem, read_fn, None::<DummyWriteFunction<T>>);
+        self.draw_editor_contents_impl(
+            ui,
+            mem,
+            read_fn,
+            None::<DummyWriteFunction<T>>,
+            None::<DummyResizeFunctions<T>>,
+        );
     }
 
     /// Draws the actual memory viewer/editor.
@@ -171,83 +515,571 @@ impl MemoryEditor {
         read_fn: impl FnMut(&mut T, Address) -> Option<u8>,
         write_fn: impl FnMut(&mut T, Address, u8),
     ) {
-        self.draw_editor_contents_impl(ui, mem, read_fn, Some(write_fn));
+        self.draw_editor_contents_impl(ui, mem, read_fn, Some(write_fn), None::<DummyResizeFunctions<T>>);
+    }
+
+    /// Draws the actual memory viewer/editor, allowing the memory to grow and shrink.
+    ///
+    /// See [`Self::window_ui_resizable`] for more information.
+    pub fn draw_editor_contents_resizable<T: ?Sized>(
+        &mut self,
+        ui: &mut Ui,
+        mem: &mut T,
+        read_fn: impl FnMut(&mut T, Address) -> Option<u8>,
+        write_fn: impl FnMut(&mut T, Address, u8),
+        resize_fns: ResizeFunctions<impl FnMut(&mut T, Address, usize), impl FnMut(&mut T, Range<Address>)>,
+    ) {
+        self.draw_editor_contents_impl(ui, mem, read_fn, Some(write_fn), Some(resize_fns));
     }
 
     fn draw_editor_contents_impl<T: ?Sized>(
+        &mut self,
+        ui: &mut Ui,
+        mem: &mut T,
+        read_fn: impl FnMut(&mut T, Address) -> Option<u8>,
+        write_fn: Option<impl FnMut(&mut T, Address, u8)>,
+        resize_fns: Option<ResizeFunctions<impl FnMut(&mut T, Address, usize), impl FnMut(&mut T, Range<Address>)>>,
+    ) {
+        let locked_options = self.options_locked.then(|| self.options.clone());
+
+        // The override is applied to a child `Ui`, so the host's style is left as it was.
+        match self.style_override.clone() {
+            Some(style_override) => {
+                ui.scope(|ui| {
+                    style_override(ui.style_mut());
+                    self.draw_editor_frame(ui, mem, read_fn, write_fn, resize_fns);
+                });
+            }
+            None => self.draw_editor_frame(ui, mem, read_fn, write_fn, resize_fns),
+        }
+
+        if let Some(locked_options) = locked_options {
+            self.restore_locked_options(locked_options);
+        }
+    }
+
+    // Only the searches and dumps of every region read with the host's own read function, before it's wrapped.
+    #[cfg_attr(not(any(feature = "search", feature = "export")), allow(unused_mut))]
+    fn draw_editor_frame<T: ?Sized>(
         &mut self,
         ui: &mut Ui,
         mem: &mut T,
         mut read_fn: impl FnMut(&mut T, Address) -> Option<u8>,
         mut write_fn: Option<impl FnMut(&mut T, Address, u8)>,
+        mut resize_fns: Option<ResizeFunctions<impl FnMut(&mut T, Address, usize), impl FnMut(&mut T, Range<Address>)>>,
     ) {
-        assert!(
-            !self.address_ranges.is_empty(),
-            "At least one address range needs to be added to render the contents!"
+        let tr = self.translator();
+        self.begin_notice_frame(ui.input(|i| i.time));
+        if self.address_ranges.is_empty() {
+            self.frame_data.notify(Notice::NoAddressRanges);
+            ui.weak(tr.text("Add an address range to show the memory"));
+            return;
+        }
+        self.update_growing_ranges();
+        self.validate_options_for_frame();
+
+        let frame_start = diagnostics::now();
+        let applied_region_options = self.apply_region_options();
+        self.frame_data.word_size = self.options.word_size;
+
+        // Rather than checking everywhere whether we're allowed to edit, simply act as if no write function was provided.
+        self.frame_data.read_only_reason = self.read_only_reason(write_fn.is_some());
+        if self.frame_data.read_only_reason.is_some() {
+            write_fn = None;
+            resize_fns = None;
+        }
+
+        // Mirrors route all reads and writes through their primary address range, and can't be resized.
+        let mirror_mapping = self.current_mirror_mapping();
+        self.frame_data.mirror_of = self.mirror_of(&self.window.selected_address_range).map(str::to_string);
+        self.frame_data.ring_buffer = self.ring_buffer_cursors(&self.window.selected_address_range);
+        if self.frame_data.mirror_of.is_some() {
+            resize_fns = None;
+        }
+        let read_calls = Cell::new(0);
+        let bytes_fetched = Cell::new(0);
+        let cached_reads = Cell::new(0);
+        // Shared by every feature reading the selected region, which are wrapped around the read function below.
+        let frame_reads = FrameReads::new(&self.visible_range);
+        // Inserting or deleting moves the values after it, so none of the values read so far can be trusted.
+        let mut resize_fns = resize_fns.map(|mut resize_fns| {
+            let frame_reads = &frame_reads;
+            ResizeFunctions::new(
+                move |mem: &mut T, address, count| {
+                    (resize_fns.insert_fn)(mem, address, count);
+                    frame_reads.clear();
+                },
+                move |mem: &mut T, range| {
+                    (resize_fns.delete_fn)(mem, range);
+                    frame_reads.clear();
+                },
+            )
+        });
+        let unmapped_address_ranges = self.unmapped_address_ranges.clone();
+        let permissions = self.permissions.clone();
+        let scratch_data = self.selected_scratch_data();
+        // Other regions are searched with the host's own read function, as the mapping below only applies to the
+        // selected region.
+        #[cfg(feature = "search")]
+        if let Some(pattern) = self.frame_data.pending_region_search.take() {
+            let read = instrument_read(self.access_hook.clone(), AccessReason::Search, &mut read_fn);
+            self.frame_data.search_results = self.find_all_in_regions(mem, read, &pattern);
+            self.frame_data.search_result_len = pattern.len();
+            self.frame_data.search_not_found = self.frame_data.search_results.is_empty();
+        }
+        #[cfg(feature = "export")]
+        self.run_pending_region_dump(
+            mem,
+            instrument_read(self.access_hook.clone(), AccessReason::Export, &mut read_fn),
         );
+        // Shared with the write function, which reads the values it replaces to be able to undo the writes.
+        let raw_read_fn = RefCell::new(read_fn);
+        let access_hook = self.access_hook.clone();
+        let access_reason = self.access_reason.clone();
+        let mut read_fn = |mem: &mut T, address| {
+            // Scratch regions hold their own data, the host's memory isn't involved at all.
+            if let Some(data) = &scratch_data {
+                return data.get(address).copied();
+            }
+
+            if unmapped_address_ranges.iter().any(|range| range.contains(&address))
+                || !permissions_at(&permissions, address).read
+            {
+                return None;
+            }
+
+            let (value, is_cached) = frame_reads.read(address, || {
+                read_calls.set(read_calls.get() + 1);
+                let value = (raw_read_fn.borrow_mut())(mem, mirror_mapping.translate(address));
+                let reason = *access_reason.lock().unwrap();
+                report_access(&access_hook, address, AccessKind::Read { value }, reason);
+                value
+            });
+            if is_cached {
+                cached_reads.set(cached_reads.get() + 1);
+            } else {
+                bytes_fetched.set(bytes_fetched.get() + usize::from(value.is_some()));
+            }
+            value
+        };
+        let blocked_write = Cell::new(None);
+        let undo_writes = RefCell::new(Vec::new());
+        let record_undo = self.options.undo_history_size > 0;
+        let verify_writes = self.options.verify_writes;
+        let verified_writes = RefCell::new(Vec::new());
+        let is_recording = self.is_recording();
+        let recorded_writes = RefCell::new(Vec::new());
+        let write_guard = self.write_guard.clone();
+        let mut write_fn = write_fn.map(|mut write_fn| {
+            let blocked_write = &blocked_write;
+            let permissions = &permissions;
+            let raw_read_fn = &raw_read_fn;
+            let undo_writes = &undo_writes;
+            let verified_writes = &verified_writes;
+            let recorded_writes = &recorded_writes;
+            let frame_reads = &frame_reads;
+            let access_hook = &access_hook;
+            move |mem: &mut T, address, value| {
+                if permissions_at(permissions, address).write && Self::is_write_allowed(&write_guard, address, value) {
+                    let translated = mirror_mapping.translate(address);
+                    if record_undo {
+                        let previous = (raw_read_fn.borrow_mut())(mem, translated);
+                        report_access(
+                            access_hook,
+                            address,
+                            AccessKind::Read { value: previous },
+                            AccessReason::Edit,
+                        );
+                        undo_writes.borrow_mut().push(UndoWrite {
+                            address,
+                            previous,
+                            value,
+                        });
+                    }
+                    write_fn(mem, translated, value);
+                    frame_reads.forget(address);
+                    if is_recording {
+                        recorded_writes.borrow_mut().push((translated, value));
+                    }
+
+                    if verify_writes {
+                        let read_back = (raw_read_fn.borrow_mut())(mem, translated);
+                        report_access(
+                            access_hook,
+                            address,
+                            AccessKind::Read { value: read_back },
+                            AccessReason::Edit,
+                        );
+                        verified_writes.borrow_mut().push((address, value, read_back));
+                    }
+                } else {
+                    blocked_write.set(Some(address));
+                }
+            }
+        });
+
+        self.draw_notice_banner(ui);
+
+        self.draw_option_problems(ui);
+
+        if self.options.show_address_range_warnings {
+            for problem in self.address_range_problems() {
+                ui.colored_label(ui.visuals().warn_fg_color, format!("⚠ {}", problem.message(&tr)));
+            }
+        }
 
-        self.draw_options_area(ui, mem, &mut read_fn);
+        if self.options.show_diagnostics {
+            self.draw_diagnostics(ui);
+        }
 
-        ui.separator();
+        if !self.options_locked {
+            self.draw_options_area(ui, mem, &mut read_fn, &mut write_fn, &mut resize_fns);
+            ui.separator();
+        }
 
         let MemoryEditorOptions {
-            show_ascii,
+            show_address_column,
+            show_gutter_markers,
             column_count,
             address_text_colour,
             highlight_text_colour,
-            selected_address_range,
             memory_editor_address_text_style,
             ..
         } = self.options.clone();
+        let selected_address_range = self.window.selected_address_range.clone();
 
         let line_height = self.get_line_height(ui);
-        let address_space = self.address_ranges.get(&selected_address_range).unwrap().clone();
-        // Calculate how many hex characters we need for a consistent display of the addresses in the left column
-        let address_characters = address_space.end.next_power_of_two().ilog2() as usize / 4;
+        let address_space = self.selected_address_space();
+
+        // There is nothing to lay out for an empty (or reversed) range, the options can still be used to grow it.
+        if address_space.is_empty() {
+            ui.weak(tr.text("Region is empty"));
+            self.frame_data.notify(Notice::EmptyAddressRange {
+                range_name: selected_address_range,
+            });
+            self.visible_range = address_space.start..address_space.start;
+            self.apply_pending_range_resize();
+            self.restore_region_options(applied_region_options);
+            return;
+        }
+
+        // Every address is padded to the same width for a consistent display of the addresses in the left column
+        let format_address = self.address_formatter(&address_space);
+        let address_characters = format_address(address_space.start).chars().count();
         let max_lines = address_space.len().div_ceil(column_count);
+        self.update_repeated_lines(
+            mem,
+            &mut with_access_reason(self.access_reason.clone(), AccessReason::Render, &mut read_fn),
+            &address_space,
+            ui.input(|i| i.time),
+        );
+        let row_layout = self.row_layout(&address_space);
 
+        self.update_focus(ui.ctx());
         // For when we're editing memory, don't use the `Response` object as that would screw over downward scrolling.
+        self.handle_nibble_edit_input(
+            ui.ctx(),
+            mem,
+            &mut with_access_reason(self.access_reason.clone(), AccessReason::Edit, &mut read_fn),
+            &mut instrument_write(self.access_hook.clone(), AccessReason::Edit, &mut write_fn),
+            &mut resize_fns,
+            &address_space,
+        );
+        // Undoing isn't an edit which can be undone itself, unlike the edits made before it in this frame.
+        let undo_writes_len = undo_writes.borrow().len();
+        self.handle_undo_shortcuts(
+            ui.ctx(),
+            mem,
+            &mut instrument_write(self.access_hook.clone(), AccessReason::Edit, &mut write_fn),
+        );
+        undo_writes.borrow_mut().truncate(undo_writes_len);
+        self.handle_multi_cursor_input(
+            ui.ctx(),
+            mem,
+            &mut instrument_write(self.access_hook.clone(), AccessReason::Edit, &mut write_fn),
+            &address_space,
+        );
         self.handle_keyboard_edit_input(&address_space, ui.ctx());
+        self.follow_pointer(&address_space);
+        self.follow_host_address(&address_space);
+        self.follow_write_cursor(&address_space);
+        self.check_triggers(
+            mem,
+            with_access_reason(self.access_reason.clone(), AccessReason::Snapshot, &mut read_fn),
+            ui.input(|i| i.time),
+        );
+        if resize_fns.is_some() {
+            self.handle_insert_mode_shortcut(ui.ctx());
+        }
+        self.handle_navigation_shortcuts(
+            ui.ctx(),
+            mem,
+            &mut with_access_reason(self.access_reason.clone(), AccessReason::Search, &mut read_fn),
+            &address_space,
+        );
+        self.continue_pending_jump(
+            mem,
+            &mut with_access_reason(self.access_reason.clone(), AccessReason::Search, &mut read_fn),
+            &address_space,
+        );
+        if self.frame_data.pending_jump.is_some() {
+            ui.ctx().request_repaint();
+        }
+        self.handle_record_shortcuts(ui.ctx(), &address_space);
+        self.handle_region_shortcuts(ui.ctx());
+        #[cfg(feature = "search")]
+        self.run_pending_byte_search(
+            mem,
+            &mut with_access_reason(self.access_reason.clone(), AccessReason::Search, &mut read_fn),
+            &address_space,
+        );
+        self.handle_selection_shortcuts(
+            ui.ctx(),
+            mem,
+            &mut with_access_reason(self.access_reason.clone(), AccessReason::Export, &mut read_fn),
+            &address_space,
+        );
+        self.handle_selection_edit_shortcuts(
+            ui.ctx(),
+            mem,
+            &mut with_access_reason(self.access_reason.clone(), AccessReason::Edit, &mut read_fn),
+            &mut instrument_write(self.access_hook.clone(), AccessReason::Edit, &mut write_fn),
+        );
+        self.handle_focus_release(ui.ctx());
+
+        if self.options.show_highlight_legend {
+            self.draw_highlight_legend(ui);
+        }
+
+        if self.options.show_column_header {
+            self.draw_column_header(ui, address_characters, &address_space);
+        }
+
+        if self.options.register_view.is_none() {
+            self.draw_pinned_row(
+                ui,
+                mem,
+                &mut with_access_reason(self.access_reason.clone(), AccessReason::Render, &mut read_fn),
+                &mut instrument_write(self.access_hook.clone(), AccessReason::Edit, &mut write_fn),
+                &mut resize_fns,
+                &address_space,
+            );
+        }
+
+        #[cfg(feature = "tools")]
+        self.draw_disassembly_panel(
+            ui,
+            &address_space,
+            mem,
+            &mut with_access_reason(self.access_reason.clone(), AccessReason::Render, &mut read_fn),
+        );
 
         let mut scroll = ScrollArea::vertical()
             .id_salt(selected_address_range)
-            .max_height(f32::INFINITY)
+            .max_height((ui.available_height() - self.status_bar_height(ui)).max(line_height))
             .auto_shrink([false, true]);
 
+        if self.handles_mouse_wheel() {
+            scroll = scroll.scroll_source(ScrollSource {
+                mouse_wheel: false,
+                ..ScrollSource::ALL
+            });
+
+            if let Some(offset) = self.wheel_scroll_offset(ui, line_height + ui.spacing().item_spacing.y) {
+                scroll = scroll.vertical_scroll_offset(offset);
+            }
+        }
+
+        if std::mem::take(&mut self.frame_data.scroll.restore_offset) {
+            scroll = scroll.vertical_scroll_offset(self.window.scroll_offset);
+        }
+
+        // The register view has a row for every register, rather than for every line of the grid.
+        let row_count = match self.options.register_view {
+            Some(width) => address_space.len().div_ceil(width.bytes()),
+            None => row_layout.row_count(),
+        };
+
         // Scroll to the goto area address line.
         if let Some(line) = self.frame_data.goto_address_line.take() {
-            let new_offset = (line_height + ui.spacing().item_spacing.y) * (line as f32);
-            scroll = scroll.vertical_scroll_offset(new_offset);
+            let row = match (self.options.register_view, self.frame_data.selected_highlight_address) {
+                (Some(width), Some(address)) => address.saturating_sub(address_space.start) / width.bytes(),
+                (Some(width), None) => line * column_count / width.bytes(),
+                (None, _) => row_layout.row_of_line(line),
+            };
+            let new_offset = (line_height + ui.spacing().item_spacing.y) * (row as f32);
+
+            if self.options.animate_jumps {
+                self.start_jump_animation(new_offset, ui.input(|i| i.time));
+            } else {
+                scroll = scroll.vertical_scroll_offset(new_offset);
+            }
         }
+        if let Some(offset) = self.animated_scroll_offset(ui) {
+            scroll = scroll.vertical_scroll_offset(offset);
+        }
+
+        self.frame_data
+            .read_cache
+            .begin_frame(self.options.max_reads_per_frame, &self.visible_range);
+        self.frame_data
+            .value_history
+            .begin_frame(ui.input(|i| i.time), self.options.recent_change_fade_seconds);
+        if self.options.highlight_recent_changes && self.frame_data.value_history.is_fading() {
+            ui.ctx().request_repaint();
+        }
+        self.update_heat_map(
+            mem,
+            &mut with_access_reason(self.access_reason.clone(), AccessReason::Render, &mut read_fn),
+        );
+        // The heat map only samples changes in the frames which are drawn.
+        if self.frame_data.heat_map.is_some() {
+            ui.ctx().request_repaint();
+        }
+        let mut rows_laid_out = 0;
+        // The rows which would have been drawn, and the rows which were drawn, when limited by `max_cells_per_frame`.
+        let mut capped_rows = None;
+        // Copying a row from its context menu is done after drawing, as the rows are drawn with the render reads.
+        let mut row_to_copy = None;
+        // Only the cells of the scrolling rows are kept, not those of the pinned row.
+        self.frame_data.cell_rects.clear();
+        self.frame_data.range_bracket_margin = None;
+        let scroll_output = scroll.show_rows(ui, line_height, row_count, |ui, mut row_range| {
+            if let Some(width) = self.options.register_view {
+                rows_laid_out += row_range.len();
+                self.draw_register_rows(
+                    ui,
+                    width,
+                    row_range,
+                    mem,
+                    &mut with_access_reason(self.access_reason.clone(), AccessReason::Render, &mut read_fn),
+                    &mut instrument_write(self.access_hook.clone(), AccessReason::Edit, &mut write_fn),
+                    &address_space,
+                );
+                self.frame_data.previous_frame_editor_width = ui.min_rect().width();
+                return;
+            }
+
+            if let Some(max_cells) = self.options.max_cells_per_frame {
+                let max_rows = (max_cells / column_count).max(1);
+                if row_range.len() > max_rows {
+                    capped_rows = Some((row_range.len(), max_rows));
+                    row_range.end = row_range.start + max_rows;
+                }
+            }
 
-        scroll.show_rows(ui, line_height, max_lines, |ui, line_range| {
             // Persist the visible range for future queries.
-            let start_address_range = address_space.start + (line_range.start * column_count);
-            let end_address_range = address_space.start + (line_range.end * column_count);
-            self.visible_range = start_address_range..end_address_range;
+            let visible_lines = row_layout.visible_lines(row_range.clone());
+            let start_address_range = address_space.start + (visible_lines.start * column_count);
+            let end_address_range = address_space.start + (visible_lines.end * column_count);
+            self.visible_range = start_address_range..end_address_range.min(address_space.end);
+            let mut render_read = with_access_reason(self.access_reason.clone(), AccessReason::Render, &mut read_fn);
 
-            egui::Grid::new("mem_edit_grid")
+            let mut grid = egui::Grid::new("mem_edit_grid")
                 .striped(true)
-                .spacing(Vec2::new(15.0, ui.style().spacing.item_spacing.y))
-                .show(ui, |ui| {
+                .spacing(Vec2::new(self.options.group_spacing, ui.style().spacing.item_spacing.y));
+            // Padded rows are taller than their text, which the grid has to know about to stay aligned with the scroll
+            // area.
+            if self.row_padding() > 0.0 {
+                grid = grid.min_row_height(line_height);
+            }
+
+            grid.show(ui, |ui| {
                     ui.style_mut().wrap_mode = Some(TextWrapMode::Extend);
-                    ui.style_mut().spacing.item_spacing.x = 3.0;
+                    ui.style_mut().spacing.item_spacing.x = self.value_spacing();
+
+                    for row in row_range.clone() {
+                        rows_laid_out += 1;
+                        let start_row = match row_layout.row(row) {
+                            Row::Line(line) => line,
+                            Row::Separator(label) => {
+                                self.draw_separator_row(ui, label, Sense::hover());
+                                ui.end_row();
+                                continue;
+                            }
+                            Row::Collapsed(lines, CollapsedKind::Unmapped) => {
+                                let label = self.collapsed_row_label(&lines, &address_space);
+                                self.draw_separator_row(ui, &label, Sense::hover());
+                                ui.end_row();
+                                continue;
+                            }
+                            Row::Collapsed(lines, CollapsedKind::Untagged) => {
+                                let label = tr.format("* {count} untagged lines", &[("count", &lines.len())]);
+                                self.draw_separator_row(ui, &label, Sense::hover());
+                                ui.end_row();
+                                continue;
+                            }
+                            Row::Collapsed(lines, CollapsedKind::Repeated) => {
+                                let label = tr.format("* {count} identical lines", &[("count", &lines.len())]);
+                                let response = self.draw_separator_row(ui, &label, Sense::click());
 
-                    for start_row in line_range.clone() {
+                                if response.on_hover_text(tr.text("Click to expand")).clicked() {
+                                    self.expand_repeated_lines(lines.start);
+                                }
+                                ui.end_row();
+                                continue;
+                            }
+                        };
                         let start_address = address_space.start + (start_row * column_count);
                         let line_range = start_address..start_address + column_count;
                         let highlight_in_range = matches!(self.frame_data.selected_highlight_address, Some(address) if line_range.contains(&address));
 
-                        let start_text = RichText::new(format!("0x{:01$X}:", start_address, address_characters))
+                        let start_text = RichText::new(format!("{}:", format_address(start_address)))
                             .color(if highlight_in_range { highlight_text_colour } else { address_text_colour })
                             .text_style(memory_editor_address_text_style.clone());
 
-                        ui.label(start_text);
+                        ui.horizontal(|ui| {
+                            if show_gutter_markers {
+                                let row_range = start_address..(start_address + column_count).min(address_space.end);
+                                self.draw_marker_lane(ui, mem, &mut render_read, row_range, &address_space);
+                            }
+
+                            if show_address_column {
+                                let response = Label::new(start_text).sense(Sense::click()).ui(ui);
+
+                                if response.clicked() {
+                                    let modifiers = ui.input(|i| i.modifiers);
+                                    self.select_row(start_address, &address_space, modifiers.command);
+                                }
 
-                        self.draw_memory_values(ui, mem, &mut read_fn, &mut write_fn, start_address, &address_space);
+                                response.context_menu(|ui| {
+                                    let row = start_address..(start_address + column_count).min(address_space.end);
+
+                                    if ui.button(tr.text("Copy row as hex")).clicked() {
+                                        row_to_copy = Some((row.clone(), false));
+                                        ui.close();
+                                    }
+                                    if ui.button(tr.text("Copy row as C array")).clicked() {
+                                        row_to_copy = Some((row, true));
+                                        ui.close();
+                                    }
+                                    if ui.button(tr.text("Select row")).clicked() {
+                                        self.select_row(start_address, &address_space, false);
+                                        ui.close();
+                                    }
+                                    if ui.button(tr.text("📌 Pin row")).clicked() {
+                                        self.frame_data.pinned_row = Some(start_address);
+                                        ui.close();
+                                    }
+                                });
+                            }
+                        });
+
+                        let mut write_fn =
+                            instrument_write(self.access_hook.clone(), AccessReason::Edit, &mut write_fn);
+                        self.draw_row_values(
+                            ui,
+                            mem,
+                            &mut render_read,
+                            &mut write_fn,
+                            &mut resize_fns,
+                            start_address,
+                            &address_space,
+                        );
 
-                        if show_ascii {
-                            self.draw_ascii_sidebar(ui, mem, &mut read_fn, start_address, &address_space);
+                        if self.options.show_range_brackets {
+                            let (_, margin) = ui.allocate_space(Vec2::new(RANGE_BRACKET_MARGIN, 0.0));
+                            self.frame_data.range_bracket_margin = Some(margin.left());
                         }
 
                         ui.end_row();
@@ -257,23 +1089,350 @@ impl MemoryEditor {
             // In case it has become smaller we'll shrink the window.
             self.frame_data.previous_frame_editor_width = ui.min_rect().width();
         });
+
+        if let Some((row, as_c_array)) = row_to_copy {
+            let read = with_access_reason(self.access_reason.clone(), AccessReason::Export, &mut read_fn);
+            let text = if as_c_array {
+                self.export_c_array(mem, read, row)
+            } else {
+                self.export_hex(mem, read, row)
+            };
+            ui.ctx().copy_text(text);
+        }
+
+        self.window.scroll_offset = scroll_output.state.offset.y;
+        self.frame_data.scroll.rect = Some(scroll_output.inner_rect);
+        self.paint_range_brackets(ui, scroll_output.inner_rect, &address_space);
+        self.paint_overlay(ui, scroll_output.inner_rect);
+        self.paint_selection_drag_preview(ui, scroll_output.inner_rect);
+
+        // Share the reads left over between the scan for repeated lines and refreshing the values that were already cached.
+        if let Some(reads) = self.frame_data.read_cache.take_budget() {
+            self.continue_repeated_lines_scan(
+                mem,
+                &mut with_access_reason(self.access_reason.clone(), AccessReason::Render, &mut read_fn),
+                reads,
+            );
+        }
+        self.frame_data.read_cache.refresh(
+            mem,
+            &mut with_access_reason(self.access_reason.clone(), AccessReason::Render, &mut read_fn),
+            self.visible_range.clone(),
+        );
+
+        // While the scrollbar is being dragged show which address would end up at the top of the view.
+        if is_vertical_scroll_bar_dragged(ui.ctx(), scroll_output.id) && self.options.register_view.is_none() {
+            let row_height = line_height + ui.spacing().item_spacing.y;
+            let top_row = (scroll_output.state.offset.y / row_height) as usize;
+            let top_line = row_layout.line_at_or_after(top_row).min(max_lines.saturating_sub(1));
+            let top_address = address_space.start + top_line * column_count;
+
+            egui::Tooltip::always_open(
+                ui.ctx().clone(),
+                ui.layer_id(),
+                scroll_output.id.with("scroll_tooltip"),
+                egui::PopupAnchor::Pointer,
+            )
+            .gap(12.0)
+            .show(|ui| {
+                ui.label(
+                    RichText::new(format_address(top_address)).text_style(memory_editor_address_text_style.clone()),
+                );
+            });
+        }
+
+        if let Some((wanted_rows, drawn_rows)) = capped_rows {
+            ui.colored_label(
+                ui.visuals().warn_fg_color,
+                tr.format(
+                    "⚠ Showing {drawn_rows} of {wanted_rows} rows, as too many values are visible",
+                    &[("drawn_rows", &drawn_rows), ("wanted_rows", &wanted_rows)],
+                ),
+            )
+            .on_hover_text(tr.text("Reduce the column count or the height of the window to show every row"));
+        }
+
+        self.draw_drag_selection_badge(ui);
+        self.finish_selection_drag(
+            ui,
+            mem,
+            &mut with_access_reason(self.access_reason.clone(), AccessReason::Edit, &mut read_fn),
+            &mut instrument_write(self.access_hook.clone(), AccessReason::Edit, &mut write_fn),
+            &address_space,
+        );
+        self.draw_selection_move_dialog(
+            ui.ctx(),
+            mem,
+            &mut with_access_reason(self.access_reason.clone(), AccessReason::Edit, &mut read_fn),
+            &mut instrument_write(self.access_hook.clone(), AccessReason::Edit, &mut write_fn),
+        );
+
+        if self.options.show_status_bar {
+            self.draw_status_bar(ui, resize_fns.is_some());
+        }
+
+        self.draw_value_edit_dialog(
+            ui.ctx(),
+            mem,
+            &mut instrument_write(self.access_hook.clone(), AccessReason::Edit, &mut write_fn),
+            &address_space,
+        );
+        self.handle_dropped_files(ui, &address_space);
+        #[cfg(feature = "ips")]
+        self.draw_patch_dialog(
+            ui.ctx(),
+            mem,
+            &mut instrument_write(self.access_hook.clone(), AccessReason::Edit, &mut write_fn),
+        );
+        self.draw_file_drop_dialog(
+            ui.ctx(),
+            mem,
+            &mut instrument_write(self.access_hook.clone(), AccessReason::Edit, &mut write_fn),
+            &address_space,
+        );
+        #[cfg(feature = "scripting")]
+        self.draw_console_window(
+            ui.ctx(),
+            mem,
+            &mut with_access_reason(self.access_reason.clone(), AccessReason::Edit, &mut read_fn),
+            &mut instrument_write(self.access_hook.clone(), AccessReason::Edit, &mut write_fn),
+            &address_space,
+        );
+        #[cfg(feature = "tools")]
+        self.draw_transform_views(ui.ctx());
+        self.draw_command_palette(
+            ui.ctx(),
+            mem,
+            &mut with_access_reason(self.access_reason.clone(), AccessReason::Search, &mut read_fn),
+            &address_space,
+        );
+        self.draw_help_window(ui.ctx(), write_fn.is_some(), resize_fns.is_some());
+        self.draw_write_notice(ui, blocked_write.get());
+        for (address, written, read_back) in verified_writes.take() {
+            self.frame_data.record_write_verification(address, written, read_back);
+        }
+        self.frame_data
+            .undo_history
+            .record(undo_writes.take(), ui.input(|i| i.time), self.options.undo_history_size);
+        if let Some(recorder) = &mut self.frame_data.recorder {
+            recorder.record(recorded_writes.take(), ui.input(|i| i.time));
+        }
+
+        self.apply_pending_range_resize();
+        self.frame_data.diagnostics = FrameDiagnostics {
+            read_calls: read_calls.get(),
+            bytes_fetched: bytes_fetched.get(),
+            cached_reads: cached_reads.get(),
+            rows: rows_laid_out,
+            duration: frame_start.map(|start| start.elapsed()),
+        };
+
+        self.restore_region_options(applied_region_options);
+    }
+
+    /// Draw a labelled separator row, without affecting the width of the grid's columns.
+    ///
+    /// The returned response covers the label.
+    fn draw_separator_row(&self, ui: &mut Ui, label: &str, sense: Sense) -> egui::Response {
+        let line_height = self.get_line_height(ui);
+        let (rect, response) = ui.allocate_exact_size(Vec2::new(0.0, line_height), Sense::hover());
+        let font_id = self.options.memory_editor_address_text_style.resolve(ui.style());
+
+        let label_rect = ui.painter().text(
+            rect.left_center(),
+            egui::Align2::LEFT_CENTER,
+            format!("— {} —", label),
+            font_id,
+            self.options.address_text_colour,
+        );
+
+        ui.interact(label_rect, response.id.with("label"), sense)
+    }
+
+    /// Draw a header above the memory values, indicating the offset of each column within its row.
+    ///
+    /// Clicking an offset selects that column for all visible rows.
+    fn draw_column_header(&mut self, ui: &mut Ui, address_characters: usize, address_space: &Range<Address>) {
+        let tr = self.translator();
+        let ascii_glyph_width = self.ascii_glyph_width(ui);
+        let cell_width = self
+            .value_cell_size(ui, &self.options.memory_editor_text_style.resolve(ui.style()))
+            .x;
+        let bytes_per_character = self.bytes_per_character();
+        let options = &self.options;
+        let options_locked = self.options_locked;
+        let value_spacing = self.value_spacing();
+        let mut clicked_column = None;
+        let mut toggle_ascii_sidebar = false;
+
+        egui::Grid::new("mem_edit_header_grid")
+            .spacing(Vec2::new(options.group_spacing, ui.style().spacing.item_spacing.y))
+            .show(ui, |ui| {
+                ui.style_mut().wrap_mode = Some(TextWrapMode::Extend);
+                ui.style_mut().spacing.item_spacing.x = value_spacing;
+
+                // Empty space with the same width as the gutter, to keep the offsets aligned with the values.
+                ui.horizontal(|ui| {
+                    if options.show_gutter_markers {
+                        ui.add_space(MarkerKind::lane_width());
+                    }
+
+                    if options.show_address_column {
+                        ui.label(
+                            RichText::new(" ".repeat(address_characters + 1))
+                                .text_style(options.memory_editor_address_text_style.clone()),
+                        );
+                    }
+                });
+
+                for grid_column in 0..options.column_count.div_ceil(8) {
+                    ui.horizontal(|ui| {
+                        let column_count = (options.column_count - 8 * grid_column).min(8);
+
+                        for column_index in 0..column_count {
+                            let column = 8 * grid_column + column_index;
+                            let text = RichText::new(format!("{:02X}", column % 0x100))
+                                .color(options.address_text_colour)
+                                .text_style(options.memory_editor_text_style.clone());
+
+                            let response = Label::new(text).sense(Sense::click()).ui(ui);
+                            font_metrics::pad_cell(ui, response.rect.width(), cell_width);
+                            if response.clicked() {
+                                clicked_column = Some(column);
+                            }
+                        }
+                    });
+
+                    // Leave room for the characters interleaved after every grid column.
+                    if options.show_ascii && options.interleave_ascii {
+                        let characters = (options.column_count - 8 * grid_column)
+                            .min(8)
+                            .div_ceil(bytes_per_character);
+                        ui.allocate_space(Vec2::new(ascii_glyph_width * characters as f32, 0.0));
+                    }
+                }
+
+                if options.show_ascii
+                    && !options.interleave_ascii
+                    && !options_locked
+                    && draw_ascii_sidebar_toggle(ui, &tr, options.ascii_sidebar_collapsed)
+                {
+                    toggle_ascii_sidebar = true;
+                }
+
+                ui.end_row();
+            });
+
+        if let Some(column) = clicked_column {
+            self.select_column(column, address_space);
+        }
+
+        if toggle_ascii_sidebar {
+            self.options.ascii_sidebar_collapsed = !self.options.ascii_sidebar_collapsed;
+        }
     }
 
+    /// Draw the values of the row starting at `start_address`, along with their characters and the row's checksum.
+    #[allow(clippy::too_many_arguments)]
+    fn draw_row_values<T: ?Sized>(
+        &mut self,
+        ui: &mut Ui,
+        mem: &mut T,
+        read_fn: &mut impl FnMut(&mut T, Address) -> Option<u8>,
+        write_fn: &mut Option<impl FnMut(&mut T, Address, u8)>,
+        resize_fns: &mut Option<
+            ResizeFunctions<impl FnMut(&mut T, Address, usize), impl FnMut(&mut T, Range<Address>)>,
+        >,
+        start_address: Address,
+        address_space: &Range<Address>,
+    ) {
+        let column_count = self.options.column_count;
+        let grid_columns = 0..column_count.div_ceil(8);
+
+        if self.options.show_ascii && self.options.interleave_ascii {
+            // Every 8 byte grid column is directly followed by its characters.
+            for grid_column in grid_columns {
+                self.draw_memory_values(
+                    ui,
+                    mem,
+                    read_fn,
+                    write_fn,
+                    resize_fns,
+                    start_address,
+                    grid_column..grid_column + 1,
+                    address_space,
+                );
+
+                let group_start = start_address + 8 * grid_column;
+                let group_end = (group_start + 8).min(start_address + column_count);
+                self.draw_ascii_group(ui, mem, read_fn, group_start..group_end, address_space);
+            }
+        } else {
+            self.draw_memory_values(
+                ui,
+                mem,
+                read_fn,
+                write_fn,
+                resize_fns,
+                start_address,
+                grid_columns,
+                address_space,
+            );
+
+            if self.options.show_ascii {
+                self.draw_ascii_sidebar(ui, mem, read_fn, start_address, address_space);
+            }
+        }
+
+        let row = start_address..start_address + column_count;
+        self.draw_row_checksum(ui, mem, read_fn, row, address_space);
+    }
+
+    #[allow(clippy::too_many_arguments)]
     fn draw_memory_values<T: ?Sized>(
         &mut self,
         ui: &mut Ui,
         mem: &mut T,
         read_fn: &mut impl FnMut(&mut T, Address) -> Option<u8>,
         write_fn: &mut Option<impl FnMut(&mut T, Address, u8)>,
+        resize_fns: &mut Option<
+            ResizeFunctions<impl FnMut(&mut T, Address, usize), impl FnMut(&mut T, Range<Address>)>,
+        >,
         start_address: Address,
+        grid_columns: Range<usize>,
         address_space: &Range<Address>,
     ) {
+        let tr = self.translator();
+        let row = start_address..(start_address + self.options.column_count).min(address_space.end);
+        let rule_colours = self.row_rule_colours(mem, read_fn, row.clone(), address_space);
+        let group_colours: Vec<Option<Color32>> = row
+            .clone()
+            .map(|address| self.highlight_group_colour(address))
+            .collect();
+        let permission_colours: Vec<Option<Color32>> =
+            row.clone().map(|address| self.permission_colour(address)).collect();
+        #[cfg(feature = "tools")]
+        let stride_colours: Vec<Option<Color32>> =
+            row.clone().map(|address| self.stride_overlay_colour(address)).collect();
+        let untagged: Vec<bool> = row.clone().map(|address| self.is_dimmed_untagged(address)).collect();
+        let coverage_colours: Vec<Option<Color32>> = row.clone().map(|address| self.coverage_colour(address)).collect();
+        let heat_colours: Vec<Option<Color32>> = row.map(|address| self.heat_map_colour(address)).collect();
+        let row_start = start_address;
+        let show_changes = !self.is_group_hidden(CHANGED_GROUP);
+        let show_pointers = !self.is_group_hidden(POINTERS_GROUP);
+        let show_selection = !self.is_group_hidden(SELECTION_GROUP);
+        let show_recent_changes = self.options.highlight_recent_changes && !self.is_group_hidden(RECENT_CHANGES_GROUP);
+        let record_size = self.active_record_size();
+        let cell_renderer = self.cell_renderer.clone();
+        let cell_font_id = self.options.memory_editor_text_style.resolve(ui.style());
+        let cell_size = self.value_cell_size(ui, &cell_font_id);
         let frame_data = &mut self.frame_data;
         let options = &self.options;
+        let live_pointers = &self.live_pointers;
         let mut read_only = frame_data.selected_edit_address.is_none() || write_fn.is_none();
+        let mut open_value_edit_dialog = None;
 
-        // div_ceil
-        for grid_column in 0..options.column_count.div_ceil(8) {
+        for grid_column in grid_columns {
             let start_address = start_address + 8 * grid_column;
 
             // Each grid column is 8 bytes, where each byte is one 'sub-column'.
@@ -283,35 +1442,63 @@ impl MemoryEditor {
                 for column_index in 0..column_count {
                     let memory_address = start_address + column_index;
 
+                    // Keep the byte lanes of a word together by adding a gap between words.
+                    let word_bytes = options.word_size.bytes();
+                    if column_index > 0
+                        && word_bytes > 1
+                        && (memory_address - address_space.start).is_multiple_of(word_bytes)
+                    {
+                        ui.add_space(ui.spacing().item_spacing.x);
+                    }
+
+                    // The tail of a partial last row is left blank, keeping the columns aligned with the rows above.
                     if !address_space.contains(&memory_address) {
-                        break;
+                        ui.allocate_exact_size(cell_size, Sense::hover());
+                        continue;
                     }
 
-                    let mem_val: Option<u8> = read_fn(mem, memory_address);
+                    let mem_val: Option<u8> = frame_data.read_cache.read(mem, read_fn, memory_address);
+                    frame_data.value_history.record(memory_address, mem_val);
                     // If the read function can't read for whatever reason we'll just assume some temporary `--` value.
-                    let label_text = match mem_val {
-                        Some(val) => format!("{:02X}", val),
-                        None => options.none_display_value.clone(),
+                    let label_text = match (mem_val, options.unreadable_style) {
+                        (Some(val), _) => format!("{:02X}", val),
+                        (None, UnreadableStyle::Text) => options.none_display_value.clone(),
+                        (None, UnreadableStyle::Blank | UnreadableStyle::Hatched) => "  ".to_string(),
                     };
 
                     // Memory Value Labels
-                    if !read_only
-                        && matches!(frame_data.selected_edit_address, Some(address) if address == memory_address)
-                    {
+                    let is_edited = !read_only
+                        && matches!(frame_data.selected_edit_address, Some(address) if address == memory_address);
+
+                    if is_edited && options.nibble_editing {
+                        let font_id = options.memory_editor_text_style.resolve(ui.style());
+                        let response = draw_nibble_cursor(ui, mem_val, frame_data.edit_low_nibble, font_id);
+                        font_metrics::pad_cell(ui, response.rect.width(), cell_size.x);
+                        frame_data.cell_rects.insert(memory_address, response.rect);
+                    } else if is_edited {
                         // For Editing
                         let response = ui.add(
                             TextEdit::singleline(&mut frame_data.selected_edit_address_string)
-                                .desired_width(frame_data.previous_frame_text_edit_size)
+                                .desired_width(cell_size.x)
                                 .margin(Margin::symmetric(0, 0))
                                 .font(options.memory_editor_text_style.clone())
                                 .hint_text(label_text)
                                 .id_source(frame_data.selected_edit_address),
                         );
 
+                        frame_data.cell_rects.insert(memory_address, response.rect);
+
                         if frame_data.selected_edit_address_request_focus {
                             frame_data.selected_edit_address_request_focus = false;
                             response.request_focus();
                         }
+                        if response.has_focus() {
+                            frame_data.is_focused = true;
+                        }
+
+                        if response.double_clicked() {
+                            open_value_edit_dialog = Some(memory_address);
+                        }
 
                         // Filter out any non Hex-Digit, there doesn't seem to be a method in TextEdit for this.
                         frame_data
@@ -323,10 +1510,18 @@ impl MemoryEditor {
                             let next_address = memory_address + 1;
                             let new_value = u8::from_str_radix(&frame_data.selected_edit_address_string[0..2], 16);
 
-                            if let Ok(value) = new_value {
-                                if let Some(write_fns) = write_fn.as_mut() {
-                                    write_fns(mem, memory_address, value);
+                            if let Ok(value) = new_value
+                                && let Some(write_fns) = write_fn.as_mut()
+                            {
+                                // In insert mode we first make room for the new value.
+                                if frame_data.insert_mode
+                                    && let Some(resize_fns) = resize_fns.as_mut()
+                                {
+                                    (resize_fns.insert_fn)(mem, memory_address, 1);
+                                    frame_data.pending_range_resize += 1;
                                 }
+
+                                write_fns(mem, memory_address, value);
                             }
 
                             frame_data.set_selected_edit_address(Some(next_address), address_space);
@@ -342,10 +1537,39 @@ impl MemoryEditor {
 
                         if options.show_zero_colour && (matches!(mem_val, Some(val) if val == 0) || mem_val.is_none()) {
                             text = text.color(options.zero_colour);
+                        } else if frame_data
+                            .read_only_reason
+                            .is_some_and(|reason| reason != ReadOnlyReason::NoWriteFunction)
+                        {
+                            text = text.color(options.non_editable_colour);
                         } else {
                             text = text.color(ui.style().visuals.text_color());
                         };
 
+                        if (frame_data.mirror_of.is_some() && options.grey_mirrored_values)
+                            || frame_data.read_cache.is_stale(memory_address)
+                            || frame_data
+                                .ring_buffer
+                                .is_some_and(|ring| !ring.contains(memory_address))
+                            || untagged.get(memory_address - row_start) == Some(&true)
+                        {
+                            text = text.color(ui.style().visuals.weak_text_color());
+                        }
+
+                        if show_changes
+                            && frame_data
+                                .snapshot
+                                .as_ref()
+                                .is_some_and(|snapshot| snapshot.is_changed(memory_address, mem_val))
+                        {
+                            text = text.color(options.diff_text_colour);
+                        }
+
+                        let write_mismatch = frame_data.write_mismatches.get(&memory_address).copied();
+                        if write_mismatch.is_some() {
+                            text = text.color(options.write_mismatch_colour);
+                        }
+
                         if frame_data.should_highlight(memory_address) {
                             text = text.color(options.highlight_text_colour);
                         }
@@ -355,70 +1579,270 @@ impl MemoryEditor {
                             text = text.background_color(ui.style().visuals.code_bg_color);
                         }
 
-                        let response = Label::new(text).sense(Sense::click()).ui(ui);
-                        // For use with the `Edit` widget, keep track of the size of ordinary display to keep column jitter at bay
-                        frame_data.previous_frame_text_edit_size = response.rect.width();
+                        if show_recent_changes
+                            && let Some(strength) = frame_data
+                                .value_history
+                                .change_strength(memory_address, options.recent_change_fade_seconds)
+                        {
+                            text = text.background_color(options.recent_change_colour.gamma_multiply(strength));
+                        }
+
+                        if let Some(strength) = frame_data.scroll.flash_strength(memory_address, ui.input(|i| i.time)) {
+                            text = text.background_color(options.highlight_text_colour.gamma_multiply(strength * 0.5));
+                        }
+
+                        if let Some(Some(colour)) = permission_colours.get(memory_address - row_start) {
+                            text = text.background_color(*colour);
+                        }
+
+                        #[cfg(feature = "tools")]
+                        if let Some(Some(colour)) = stride_colours.get(memory_address - row_start) {
+                            text = text.background_color(*colour);
+                        }
+
+                        if let Some(Some(colour)) = coverage_colours.get(memory_address - row_start) {
+                            text = text.background_color(*colour);
+                        }
+
+                        if let Some(Some(colour)) = heat_colours.get(memory_address - row_start) {
+                            text = text.background_color(*colour);
+                        }
+
+                        if let Some(Some(colour)) = group_colours.get(memory_address - row_start) {
+                            text = text.background_color(*colour);
+                        }
+
+                        if let Some(Some(colour)) = rule_colours.get(memory_address - row_start) {
+                            text = text.background_color(*colour);
+                        }
+
+                        let pointer_labels: Vec<&str> = live_pointers
+                            .iter()
+                            .filter(|(_, pointer)| *pointer == memory_address)
+                            .map(|(label, _)| label.as_str())
+                            .collect();
+                        if show_pointers && !pointer_labels.is_empty() {
+                            text = text.background_color(options.pointer_colour);
+                        }
+
+                        if show_selection && frame_data.selection.contains(memory_address) {
+                            text = text.background_color(ui.style().visuals.selection.bg_fill);
+                        }
+
+                        // In touch mode dragging scrolls the view, rather than selecting values.
+                        let sense = if options.touch_mode {
+                            Sense::click()
+                        } else {
+                            Sense::click_and_drag()
+                        };
+                        let custom_response = cell_renderer.as_ref().and_then(|renderer| {
+                            let cell = CellInfo {
+                                address: memory_address,
+                                value: mem_val,
+                                font_id: cell_font_id.clone(),
+                                size: cell_size,
+                                is_selected: frame_data.selection.contains(memory_address),
+                                is_cursor: frame_data.cursor_address() == Some(memory_address),
+                                is_highlighted: frame_data.should_highlight(memory_address),
+                            };
+                            renderer(ui, cell)
+                        });
+                        let mut response = match custom_response {
+                            Some(response) => response.interact(sense),
+                            None => Label::new(text).sense(sense).ui(ui),
+                        };
+                        font_metrics::pad_cell(ui, response.rect.width(), cell_size.x);
+                        if options.touch_mode {
+                            // Let the gaps around the value count towards its target as well.
+                            let touch_rect = response
+                                .rect
+                                .expand2(Vec2::new(TOUCH_VALUE_SPACING, TOUCH_ROW_PADDING) / 2.0);
+                            response |= ui.interact(touch_rect, response.id.with("touch"), Sense::click());
+                        }
+                        frame_data.cell_rects.insert(memory_address, response.rect);
+                        if frame_data.is_focused && frame_data.cursor_address() == Some(memory_address) {
+                            focus::draw_blinking_cursor(ui, &response);
+                        }
+                        accessibility::describe_cell(
+                            &response,
+                            &tr,
+                            options.word_size.word_address(memory_address),
+                            mem_val,
+                            CellState {
+                                is_cursor: frame_data.cursor_address() == Some(memory_address),
+                                is_selected: frame_data.selection.contains(memory_address),
+                            },
+                        );
+
+                        if mem_val.is_none() && options.unreadable_style == UnreadableStyle::Hatched {
+                            paint_hatching(ui, response.rect, options.zero_colour);
+                        }
+
+                        // In record mode a separator marks the start of every record.
+                        if record_size.is_some_and(|size| {
+                            memory_address != address_space.start
+                                && (memory_address - address_space.start).is_multiple_of(size)
+                        }) {
+                            let x = response.rect.left() - ui.spacing().item_spacing.x / 2.0;
+                            ui.painter().vline(
+                                x,
+                                response.rect.y_range(),
+                                egui::Stroke::new(1.0, options.address_text_colour),
+                            );
+                        }
+
+                        if !pointer_labels.is_empty() {
+                            let labels = pointer_labels.join(", ");
+                            response =
+                                response.on_hover_text(tr.format("Pointed to by {labels}", &[("labels", &labels)]));
+                        }
+
+                        if let Some((written, read_back)) = write_mismatch {
+                            let read_back =
+                                read_back.map_or(options.none_display_value.clone(), |value| format!("{:#04X}", value));
+                            let written = format!("{:#04X}", written);
+                            response = response.on_hover_text(tr.format(
+                                "⚠ Wrote {written}, but read back {read_back}",
+                                &[("written", &written), ("read_back", &read_back)],
+                            ));
+                        }
+
+                        // If editing was disabled despite having a write function, explain why.
+                        if let Some(reason) = frame_data.read_only_reason
+                            && reason != ReadOnlyReason::NoWriteFunction
+                        {
+                            response = response.on_hover_text(tr.text(reason.description()));
+                        }
+                        // Without the address column the address is only available when hovering.
+                        if !options.show_address_column {
+                            response = response
+                                .on_hover_text(format!("0x{:X}", options.word_size.word_address(memory_address)));
+                        }
+
+                        // Alt+drag scrubs the value, like a `DragValue`.
+                        if let (Some(write_fn), Some(value)) = (write_fn.as_mut(), mem_val)
+                            && ui.input(|i| i.modifiers.alt)
+                        {
+                            if response.hovered() || response.dragged() {
+                                ui.ctx().set_cursor_icon(egui::CursorIcon::ResizeHorizontal);
+                            }
+
+                            if response.dragged() {
+                                frame_data.scrub_accumulator += response.drag_delta().x;
+                                let steps = (frame_data.scrub_accumulator / SCRUB_PIXELS_PER_STEP).trunc();
+
+                                if steps != 0.0 {
+                                    frame_data.scrub_accumulator -= steps * SCRUB_PIXELS_PER_STEP;
+                                    let new_value = (value as i32 + steps as i32).clamp(0, u8::MAX as i32) as u8;
+                                    write_fn(mem, memory_address, new_value);
+                                }
+                            }
+                        }
+
+                        if response.drag_stopped() {
+                            frame_data.scrub_accumulator = 0.0;
+                        }
+
+                        // Dragging without Alt selects the values dragged across, or copies the selection when it
+                        // starts on a selected run.
+                        if response.drag_started() && !ui.input(|i| i.modifiers.alt) {
+                            let add = ui.input(|i| i.modifiers.command);
+                            if add || write_fn.is_none() || !frame_data.start_selection_drag(memory_address) {
+                                frame_data.start_drag_selection(memory_address, add);
+                            }
+                        } else if frame_data.drag_selecting && ui.rect_contains_pointer(response.rect) {
+                            frame_data.extend_drag_selection(memory_address);
+                        } else if frame_data.selection_drag.is_some() && ui.rect_contains_pointer(response.rect) {
+                            frame_data.update_selection_drag(memory_address);
+                        }
+
+                        #[cfg(feature = "search")]
+                        if let Some(value) = mem_val {
+                            response.context_menu(|ui| {
+                                let directions = [
+                                    ("Find next", SearchDirection::Forward),
+                                    ("Find previous", SearchDirection::Backward),
+                                ];
+
+                                for (text, direction) in directions {
+                                    if ui.button(format!("{} 0x{:02X}", tr.text(text), value)).clicked() {
+                                        frame_data.pending_byte_search = Some((memory_address, value, direction));
+                                        ui.close();
+                                    }
+                                }
+                            });
+                        }
 
                         // Right click always selects.
                         if response.secondary_clicked() {
                             frame_data.set_highlight_address(memory_address);
                         }
 
-                        // Left click depends on read only mode.
+                        // Left click with modifiers changes the selection, without modifiers it depends on read only mode.
                         if response.clicked() {
-                            if write_fn.is_some() {
-                                frame_data.set_selected_edit_address(Some(memory_address), address_space);
+                            let modifiers = ui.input(|i| i.modifiers);
+
+                            if modifiers.shift || modifiers.command {
+                                // With a write function every `Ctrl` clicked value also becomes a cursor.
+                                if modifiers.command && !modifiers.shift && write_fn.is_some() {
+                                    frame_data.add_cursor(memory_address);
+                                }
+                                frame_data.modify_selection(memory_address, modifiers);
                             } else {
-                                frame_data.set_highlight_address(memory_address);
+                                frame_data.cursors.clear();
+                                frame_data.selection.clear();
+                                frame_data.selection_anchor = Some(memory_address);
+                                frame_data.selected_from_ascii = false;
+
+                                // In touch mode a single tap only highlights, as tapping is less precise.
+                                if write_fn.is_some() && !options.touch_mode {
+                                    frame_data.set_selected_edit_address(Some(memory_address), address_space);
+                                } else {
+                                    frame_data.set_highlight_address(memory_address);
+                                }
                             }
                         }
+
+                        if options.touch_mode && response.double_clicked() && write_fn.is_some() {
+                            frame_data.set_selected_edit_address(Some(memory_address), address_space);
+                        }
+
+                        // Editing is disabled without a write function. Unless editing was also disabled in the
+                        // options, explain why nothing happens when trying to edit.
+                        if response.double_clicked()
+                            && frame_data.read_only_reason == Some(ReadOnlyReason::NoWriteFunction)
+                            && !options.is_read_only
+                        {
+                            let notice = tr.text("🔒 Editing is disabled: no write function was provided");
+                            frame_data.write_notice = Some((notice.into_owned(), ui.input(|i| i.time)));
+                            frame_data.notify(Notice::EditingDisabled);
+                        }
                     }
                 }
             });
         }
-    }
-
-    fn draw_ascii_sidebar<T: ?Sized>(
-        &mut self,
-        ui: &mut Ui,
-        mem: &mut T,
-        read_fn: &mut impl FnMut(&mut T, Address) -> Option<u8>,
-        start_address: Address,
-        address_space: &Range<Address>,
-    ) {
-        let options = &self.options;
-
-        ui.horizontal(|ui| {
-            ui.add(egui::Separator::default().vertical().spacing(3.0));
-            ui.style_mut().spacing.item_spacing.x = 0.0;
-
-            ui.horizontal(|ui| {
-                for i in 0..options.column_count {
-                    let memory_address = start_address + i;
-
-                    if !address_space.contains(&memory_address) {
-                        break;
-                    }
 
-                    let mem_val: u8 = read_fn(mem, memory_address).unwrap_or(0);
-                    // Check if it's a printable ASCII character
-                    let character = if !(32..128).contains(&mem_val) {
-                        '.'
-                    } else {
-                        mem_val as char
-                    };
-                    let mut text = RichText::new(character).text_style(options.memory_editor_ascii_text_style.clone());
-
-                    if self.frame_data.should_highlight(memory_address) {
-                        text = text
-                            .color(self.options.highlight_text_colour)
-                            .background_color(ui.style().visuals.code_bg_color);
-                    }
+        if let Some(address) = open_value_edit_dialog {
+            self.open_value_edit_dialog(mem, read_fn, address, address_space);
+        }
+    }
 
-                    ui.label(text);
-                }
-            });
-        });
+    /// Returns why the currently selected address range can't be edited, if it can't.
+    fn read_only_reason(&self, has_write_fn: bool) -> Option<ReadOnlyReason> {
+        if !has_write_fn {
+            Some(ReadOnlyReason::NoWriteFunction)
+        } else if self.scratch_regions.contains_key(&self.window.selected_address_range) {
+            Some(ReadOnlyReason::ScratchRegion)
+        } else if self.options.is_read_only {
+            Some(ReadOnlyReason::Locked)
+        } else if self
+            .read_only_address_ranges
+            .contains(&self.window.selected_address_range)
+        {
+            Some(ReadOnlyReason::ReadOnlyRange)
+        } else {
+            None
+        }
     }
 
     /// Return the line height for the current provided `Ui` and selected `TextStyle`s
@@ -426,7 +1850,26 @@ impl MemoryEditor {
         let address_size = ui.text_style_height(&self.options.memory_editor_address_text_style);
         let body_size = ui.text_style_height(&self.options.memory_editor_text_style);
         let ascii_size = ui.text_style_height(&self.options.memory_editor_ascii_text_style);
-        address_size.max(body_size).max(ascii_size)
+
+        address_size.max(body_size).max(ascii_size) + self.row_padding()
+    }
+
+    /// The horizontal space between values, see [`MemoryEditorOptions::value_spacing`].
+    pub(crate) fn value_spacing(&self) -> f32 {
+        if self.options.touch_mode {
+            self.options.value_spacing.max(TOUCH_VALUE_SPACING)
+        } else {
+            self.options.value_spacing
+        }
+    }
+
+    /// The extra height of every row, see [`MemoryEditorOptions::row_padding`].
+    fn row_padding(&self) -> f32 {
+        if self.options.touch_mode {
+            self.options.row_padding.max(TOUCH_ROW_PADDING)
+        } else {
+            self.options.row_padding
+        }
     }
 
     /// Shrink the window to the previous frame's memory viewer's width.
@@ -436,33 +1879,70 @@ impl MemoryEditor {
         ui.set_max_width(self.frame_data.previous_frame_editor_width);
     }
 
-    /// Check for arrow keys when we're editing a memory value at an address.
+    /// Check for navigation keys when we're editing a memory value at an address.
+    ///
+    /// Arrow keys move by one cell, `Home`/`End` move to the start/end of the current row and
+    /// `Ctrl+Home`/`Ctrl+End` move to the start/end of the selected address range.
     fn handle_keyboard_edit_input(&mut self, address_range: &Range<Address>, ctx: &Context) {
         use egui::Key::*;
-        const KEYS: [egui::Key; 4] = [ArrowLeft, ArrowRight, ArrowDown, ArrowUp];
+        const KEYS: [egui::Key; 6] = [ArrowLeft, ArrowRight, ArrowDown, ArrowUp, Home, End];
 
-        let Some(current_address) = self.frame_data.selected_edit_address else {
+        let Some(current_address) = self
+            .frame_data
+            .selected_edit_address
+            .filter(|address| address_range.contains(address))
+        else {
             return;
         };
+        if !self.frame_data.is_focused {
+            return;
+        }
 
         let key_pressed = KEYS.iter().find(|&&k| ctx.input(|i| i.key_pressed(k)));
         if let Some(key) = key_pressed {
+            let column_count = self.options.column_count;
+            let ctrl_held = ctx.input(|i| i.modifiers.command);
+            let row_start = current_address - (current_address - address_range.start) % column_count;
+            let last_address = address_range.end.saturating_sub(1);
+
             let next_address = match key {
-                ArrowDown => current_address + self.options.column_count,
+                ArrowDown => current_address + column_count,
                 ArrowLeft => current_address.saturating_sub(1),
                 ArrowRight => current_address.saturating_add(1),
-                ArrowUp => {
-                    if current_address < self.options.column_count {
-                        0
-                    } else {
-                        current_address - self.options.column_count
-                    }
-                }
+                ArrowUp => current_address.saturating_sub(column_count),
+                Home if ctrl_held => address_range.start,
+                End if ctrl_held => last_address,
+                Home => row_start,
+                End => (row_start + column_count - 1).min(last_address),
                 _ => unreachable!(),
             };
 
             self.frame_data
                 .set_selected_edit_address(Some(next_address), address_range);
+
+            if self.frame_data.selected_edit_address.is_some() {
+                self.scroll_to_address(next_address, address_range);
+            }
+        }
+    }
+
+    /// Scroll the view (next frame) such that the provided `address` is visible.
+    ///
+    /// Does nothing if the address was already visible in the last frame, or lies before the start of the address range.
+    pub(crate) fn scroll_to_address(&mut self, address: Address, address_range: &Range<Address>) {
+        let Some(offset) = address.checked_sub(address_range.start) else {
+            return;
+        };
+        let column_count = self.options.column_count;
+        let line = offset / column_count;
+
+        if address < self.visible_range.start {
+            self.frame_data.goto_address_line = Some(line);
+        } else if address >= self.visible_range.end {
+            // Keep the line at the bottom of the view, rather than jumping it all the way to the top.
+            // The last visible line is usually only partially visible, so we leave one extra line of room.
+            let visible_lines = self.visible_range.len() / column_count;
+            self.frame_data.goto_address_line = Some((line + 2).saturating_sub(visible_lines));
         }
     }
 
@@ -489,18 +1969,263 @@ impl MemoryEditor {
         self
     }
 
+    /// Add multiple address ranges at once, in order, for example from a memory map.
+    ///
+    /// See also [`Self::with_address_range`]
+    ///
+    /// ```
+    /// # use egui_memory_editor::MemoryEditor;
+    /// let memory_map = [("ROM", 0x0000..0x8000), ("VRAM", 0x8000..0xA000), ("WRAM", 0xC000..0xE000)];
+    /// let memory_editor = MemoryEditor::new().with_address_ranges(memory_map);
+    ///
+    /// assert_eq!(memory_editor.regions().count(), 3);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn with_address_ranges<N: Into<String>>(
+        mut self,
+        address_ranges: impl IntoIterator<Item = (N, Range<Address>)>,
+    ) -> Self {
+        self.extend_address_ranges(address_ranges);
+        self
+    }
+
+    /// Add or update multiple address ranges at once.
+    ///
+    /// See also [`Self::with_address_ranges`]
+    pub fn extend_address_ranges<N: Into<String>>(
+        &mut self,
+        address_ranges: impl IntoIterator<Item = (N, Range<Address>)>,
+    ) {
+        for (range_name, address_range) in address_ranges {
+            self.set_address_range(range_name, address_range);
+        }
+    }
+
     /// Add or update an address range.
     ///
     /// See also [`Self::with_address_range`]
     pub fn set_address_range(&mut self, range_name: impl Into<String>, address_range: Range<Address>) {
-        self.address_ranges.insert(range_name.into(), address_range);
+        let range_name = range_name.into();
+
+        match self.address_range_mut(&range_name) {
+            Some(range) => *range = address_range,
+            None => self.address_ranges.push((range_name, address_range)),
+        }
         self.frame_data.memory_range_combo_box_enabled = self.address_ranges.len() > 1;
 
         // Only update the current selected range if nothing else has been selected to prevent annoying jitter.
-        if self.options.selected_address_range.is_empty() {
-            if let Some((name, _)) = self.address_ranges.iter().next() {
-                self.options.selected_address_range = name.clone();
-            }
+        if self.window.selected_address_range.is_empty()
+            && let Some((name, _)) = self.address_ranges.first()
+        {
+            self.select_address_range(name.clone());
+        }
+    }
+
+    /// The address range with the given name, if it exists.
+    pub(crate) fn address_range(&self, range_name: &str) -> Option<&Range<Address>> {
+        self.address_ranges
+            .iter()
+            .find(|(name, _)| name == range_name)
+            .map(|(_, range)| range)
+    }
+
+    /// Display the address range with the given name, clearing the cursors and selection, which only apply to the
+    /// address range displayed before.
+    pub(crate) fn select_address_range(&mut self, range_name: impl Into<String>) {
+        let range_name = range_name.into();
+        if self.window.selected_address_range == range_name {
+            return;
+        }
+        self.window.selected_address_range = range_name;
+
+        let frame_data = &mut self.frame_data;
+        frame_data.selected_edit_address = None;
+        frame_data.selected_edit_address_string.clear();
+        frame_data.edit_low_nibble = false;
+        frame_data.selected_highlight_address = None;
+        frame_data.selection.clear();
+        frame_data.selection_anchor = None;
+        frame_data.cursors.clear();
+        frame_data.pending_jump = None;
+    }
+
+    /// The selected address range, or the first address range if the selected one doesn't exist.
+    pub(crate) fn selected_address_space(&self) -> Range<Address> {
+        self.address_range(&self.window.selected_address_range)
+            .or_else(|| self.address_ranges.first().map(|(_, range)| range))
+            .cloned()
+            .unwrap_or_default()
+    }
+
+    /// The amount of addresses in the address range with the given name, if it exists.
+    ///
+    /// Ranges whose end lies before their start are empty.
+    ///
+    /// ```
+    /// # use egui_memory_editor::MemoryEditor;
+    /// let memory_editor = MemoryEditor::new()
+    ///     .with_address_range("Memory", 0x100..0x200)
+    ///     .with_address_range("Empty", 0x10..0x10);
+    ///
+    /// assert_eq!(memory_editor.region_len("Memory"), Some(0x100));
+    /// assert_eq!(memory_editor.region_len("Empty"), Some(0));
+    /// assert_eq!(memory_editor.region_len("Missing"), None);
+    /// ```
+    pub fn region_len(&self, range_name: &str) -> Option<usize> {
+        self.address_range(range_name).map(|range| range.len())
+    }
+
+    /// All address ranges with their names, in the order they were added.
+    ///
+    /// Unlike the range selection box this ignores [`MemoryEditorOptions::sort_address_ranges_alphabetically`], so
+    /// the order stays stable for building your own region pickers and menus.
+    ///
+    /// ```
+    /// # use egui_memory_editor::MemoryEditor;
+    /// let memory_editor = MemoryEditor::new()
+    ///     .with_address_range("RAM", 0x1000..0x2000)
+    ///     .with_address_range("IO", 0x0..0x100);
+    ///
+    /// let regions: Vec<_> = memory_editor.regions().collect();
+    /// assert_eq!(regions, [("RAM", 0x1000..0x2000), ("IO", 0x0..0x100)]);
+    /// assert_eq!(memory_editor.region("IO"), Some(0x0..0x100));
+    /// assert_eq!(memory_editor.region("Missing"), None);
+    /// ```
+    pub fn regions(&self) -> impl Iterator<Item = (&str, Range<Address>)> {
+        self.address_ranges
+            .iter()
+            .map(|(name, range)| (name.as_str(), range.clone()))
+    }
+
+    /// The address range with the given name, if it exists, see [`Self::regions`].
+    pub fn region(&self, range_name: &str) -> Option<Range<Address>> {
+        self.address_range(range_name).cloned()
+    }
+
+    /// The address range with the given name, if it exists.
+    pub(crate) fn address_range_mut(&mut self, range_name: &str) -> Option<&mut Range<Address>> {
+        self.address_ranges
+            .iter_mut()
+            .find(|(name, _)| name == range_name)
+            .map(|(_, range)| range)
+    }
+
+    /// All address ranges, either in the order they were added or alphabetically depending on
+    /// [`MemoryEditorOptions::sort_address_ranges_alphabetically`].
+    pub(crate) fn ordered_address_ranges(&self) -> Vec<&(String, Range<Address>)> {
+        let mut ranges: Vec<_> = self.address_ranges.iter().collect();
+
+        if self.options.sort_address_ranges_alphabetically {
+            ranges.sort_by(|(a, _), (b, _)| a.cmp(b));
+        }
+
+        ranges
+    }
+
+    /// Read `address` of the address range `range_name` with the host's own `read_fn`, for features which read other
+    /// ranges than the selected one. Scratch regions are read from their own data, and unmapped or unreadable values
+    /// are `None`. Mirrors aren't translated, they should be skipped as their values are those of their primary range.
+    #[cfg(any(feature = "search", feature = "export"))]
+    pub(crate) fn read_unselected_region<T: ?Sized>(
+        &self,
+        range_name: &str,
+        mem: &mut T,
+        read_fn: &mut impl FnMut(&mut T, Address) -> Option<u8>,
+        address: Address,
+    ) -> Option<u8> {
+        if let Some(data) = self.scratch_regions.get(range_name) {
+            return data.get(address).copied();
+        }
+
+        let is_unmapped = self
+            .unmapped_address_ranges
+            .iter()
+            .any(|range| range.contains(&address));
+        if is_unmapped || !self.permissions_at(address).read {
+            return None;
+        }
+
+        read_fn(mem, address)
+    }
+
+    /// Capture the values in the selected address range, values which change afterwards are highlighted.
+    ///
+    /// This replaces the previous snapshot, if any.
+    pub fn take_snapshot<T: ?Sized>(&mut self, mem: &mut T, mut read_fn: impl FnMut(&mut T, Address) -> Option<u8>) {
+        if let Some(address_range) = self.address_range(&self.window.selected_address_range) {
+            self.frame_data.snapshot = Some(Snapshot::capture(mem, &mut read_fn, address_range));
+        }
+    }
+
+    /// Compare the memory against a saved dump of the address range `range_name`, such as one saved by
+    /// [`Self::dump_all_regions`] in an earlier session or on another machine, rather than against a snapshot taken
+    /// now. Returns `false` if the address range doesn't exist.
+    ///
+    /// The dump starts at the start of the range, and bytes beyond the end of the range are ignored. This replaces
+    /// the previous snapshot, if any.
+    ///
+    #[cfg_attr(
+        feature = "export",
+        doc = r#"
+```
+# use egui_memory_editor::MemoryEditor;
+let mut memory = vec![0u8; 0x10];
+let mut memory_editor = MemoryEditor::new().with_address_range("Memory", 0..0x10);
+let dump = memory_editor.dump_all_regions(&mut memory, |mem, addr| mem.get(addr).copied());
+
+// Later, possibly after restarting the application.
+memory[0x8] = 0x42;
+assert!(memory_editor.load_snapshot_dump("Memory", &dump[0].1));
+let diff = memory_editor.export_snapshot_diff(&mut memory, |mem, addr| mem.get(addr).copied());
+
+assert_eq!(diff.as_deref(), Some("0x8: 00 → 42\n"));
+```"#
+    )]
+    pub fn load_snapshot_dump(&mut self, range_name: &str, bytes: &[u8]) -> bool {
+        let Some(address_range) = self.address_range(range_name) else {
+            return false;
+        };
+
+        let len = bytes.len().min(address_range.len());
+        self.frame_data.snapshot = Some(Snapshot::from_bytes(address_range.start, &bytes[..len]));
+        true
+    }
+
+    /// Whether a snapshot of memory is currently being compared against.
+    pub fn has_snapshot(&self) -> bool {
+        self.frame_data.snapshot.is_some()
+    }
+
+    /// Discard the current snapshot, if any, which disables the highlighting of changed values.
+    pub fn clear_snapshot(&mut self) {
+        self.frame_data.snapshot = None;
+    }
+
+    /// Add an address range which can't be edited, even if a write function was provided.
+    ///
+    /// See also [`Self::with_address_range`]
+    #[inline]
+    #[must_use]
+    pub fn with_read_only_address_range(
+        mut self,
+        range_name: impl Into<String>,
+        address_range: Range<Address>,
+    ) -> Self {
+        let range_name = range_name.into();
+        self.set_address_range(range_name.clone(), address_range);
+        self.set_address_range_read_only(range_name, true);
+        self
+    }
+
+    /// Set whether the address range with the given name can be edited, even if a write function was provided.
+    pub fn set_address_range_read_only(&mut self, range_name: impl Into<String>, read_only: bool) {
+        let range_name = range_name.into();
+
+        if read_only {
+            self.read_only_address_ranges.insert(range_name);
+        } else {
+            self.read_only_address_ranges.remove(&range_name);
         }
     }
 
@@ -0,0 +1,41 @@
+use crate::MemoryEditor;
+use crate::option_data::MemoryEditorOptions;
+
+impl MemoryEditor {
+    /// Lock the options for a fixed presentation, such as a kiosk-like view embedded in a larger UI.
+    ///
+    /// The options header and the other option controls, like the ASCII sidebar toggle, are hidden, and any change to
+    /// the options made from the UI is undone at the end of the frame. The host can still change
+    /// [`Self::options`] between frames. See also [`Self::set_options_locked`].
+    ///
+    /// ```
+    /// # use egui_memory_editor::MemoryEditor;
+    /// let mut memory_editor = MemoryEditor::new()
+    ///     .with_address_range("VRAM", 0x8000..0xA000)
+    ///     .with_locked_options();
+    /// assert!(memory_editor.are_options_locked());
+    ///
+    /// // The host can still change the presentation.
+    /// memory_editor.options.column_count = 32;
+    /// ```
+    #[must_use]
+    pub fn with_locked_options(mut self) -> Self {
+        self.options_locked = true;
+        self
+    }
+
+    /// Lock or unlock the options, see [`Self::with_locked_options`].
+    pub fn set_options_locked(&mut self, locked: bool) {
+        self.options_locked = locked;
+    }
+
+    pub fn are_options_locked(&self) -> bool {
+        self.options_locked
+    }
+
+    /// Undo the changes made to the options during the frame, the selected address range is part of the
+    /// [`crate::WindowState`] and is kept.
+    pub(crate) fn restore_locked_options(&mut self, locked: MemoryEditorOptions) {
+        self.options = locked;
+    }
+}
@@ -0,0 +1,238 @@
+use std::collections::BTreeMap;
+use std::ops::Range;
+use std::sync::Arc;
+
+use egui::{Color32, Sense, Ui, Vec2};
+
+use crate::address_links::draw_linked_text;
+use crate::{Address, MemoryEditor};
+
+/// The diameter of a marker glyph in the gutter.
+const MARKER_SIZE: f32 = 6.0;
+/// The space between two marker glyphs in the gutter.
+const MARKER_SPACING: f32 = 2.0;
+
+/// The different types of markers which can be displayed in the gutter, in order of display.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub(crate) enum MarkerKind {
+    Breakpoint,
+    Bookmark,
+    Annotation,
+    Diff,
+}
+
+impl MarkerKind {
+    pub const ALL: [MarkerKind; 4] = [
+        MarkerKind::Breakpoint,
+        MarkerKind::Bookmark,
+        MarkerKind::Annotation,
+        MarkerKind::Diff,
+    ];
+
+    pub fn colour(&self) -> Color32 {
+        match self {
+            MarkerKind::Breakpoint => Color32::from_rgb(220, 40, 40),
+            MarkerKind::Bookmark => Color32::from_rgb(70, 130, 230),
+            MarkerKind::Annotation => Color32::from_rgb(200, 170, 40),
+            MarkerKind::Diff => Color32::from_rgb(210, 90, 30),
+        }
+    }
+
+    /// The width of the marker lane in the gutter.
+    pub fn lane_width() -> f32 {
+        Self::ALL.len() as f32 * (MARKER_SIZE + MARKER_SPACING)
+    }
+}
+
+/// A marker in a single row of the gutter.
+struct RowMarker {
+    kind: MarkerKind,
+    colour: Color32,
+    /// The address the marker refers to, used as the jump target when clicking the marker.
+    address: Address,
+    description: String,
+}
+
+impl MemoryEditor {
+    /// All annotations, indexed by address.
+    pub fn annotations(&self) -> &BTreeMap<Address, String> {
+        &self.annotations
+    }
+
+    /// Add or replace the annotation at the given address, annotations are shown in the gutter.
+    pub fn set_annotation(&mut self, address: Address, text: impl Into<String>) {
+        self.annotations.insert(address, text.into());
+    }
+
+    /// Remove the annotation at the given address, returning it if there was one.
+    pub fn remove_annotation(&mut self, address: Address) -> Option<String> {
+        self.annotations.remove(&address)
+    }
+
+    /// Set the breakpoints (or watchpoints) to display in the gutter.
+    ///
+    /// The editor never changes these itself, clicking the gutter calls the callback set by
+    /// [`Self::with_breakpoint_toggle`] instead.
+    pub fn set_breakpoints(&mut self, breakpoints: impl IntoIterator<Item = Address>) {
+        self.breakpoints = breakpoints.into_iter().collect();
+    }
+
+    /// Set the callback invoked when the user clicks the gutter to toggle a breakpoint at an address.
+    ///
+    /// The address is the breakpoint in the clicked row if there is one, otherwise the highlighted address if it is part
+    /// of the row, or else the first address of the row.
+    /// The host is expected to update the displayed breakpoints with [`Self::set_breakpoints`] afterwards.
+    #[must_use]
+    pub fn with_breakpoint_toggle(mut self, on_toggle: impl Fn(Address) + Send + Sync + 'static) -> Self {
+        self.breakpoint_toggle = Some(Arc::new(on_toggle));
+        self
+    }
+
+    /// Draw the marker lane in the gutter for the row spanning `row_range`.
+    ///
+    /// Hovering the lane shows a description of all markers in the row, clicking a marker jumps to its address.
+    /// Addresses written in the descriptions, like in annotations and bookmark notes, are links which jump to them.
+    /// If a breakpoint toggle callback was set, clicking the lane anywhere else (or on a breakpoint) toggles a breakpoint.
+    pub(crate) fn draw_marker_lane<T: ?Sized>(
+        &mut self,
+        ui: &mut Ui,
+        mem: &mut T,
+        read_fn: &mut impl FnMut(&mut T, Address) -> Option<u8>,
+        row_range: Range<Address>,
+        address_space: &Range<Address>,
+    ) {
+        let tr = self.translator();
+        let line_height = self.get_line_height(ui);
+        let (rect, response) = ui.allocate_exact_size(Vec2::new(MarkerKind::lane_width(), line_height), Sense::click());
+        let markers = self.row_markers(mem, read_fn, row_range.clone());
+        let slot_width = MARKER_SIZE + MARKER_SPACING;
+
+        if !markers.is_empty() {
+            let painter = ui.painter_at(rect);
+
+            for (slot, kind) in MarkerKind::ALL.into_iter().enumerate() {
+                if let Some(marker) = markers.iter().find(|marker| marker.kind == kind) {
+                    let center = egui::pos2(
+                        rect.left() + slot as f32 * slot_width + MARKER_SIZE / 2.0,
+                        rect.center().y,
+                    );
+                    painter.circle_filled(center, MARKER_SIZE / 2.0, marker.colour);
+                }
+            }
+        }
+
+        let clicked_marker = response
+            .clicked()
+            .then(|| response.interact_pointer_pos())
+            .flatten()
+            .and_then(|pos| MarkerKind::ALL.get(((pos.x - rect.left()) / slot_width) as usize))
+            .and_then(|&kind| markers.iter().find(|marker| marker.kind == kind));
+
+        match (clicked_marker, &self.breakpoint_toggle) {
+            (Some(marker), _) if marker.kind != MarkerKind::Breakpoint => {
+                let address = marker.address;
+                self.jump_to_address(address, address_space);
+            }
+            (clicked_marker, Some(on_toggle)) if response.clicked() => {
+                let address = clicked_marker.map(|marker| marker.address).unwrap_or_else(|| {
+                    self.frame_data
+                        .selected_highlight_address
+                        .filter(|address| row_range.contains(address))
+                        .unwrap_or(row_range.start)
+                });
+
+                on_toggle(address);
+            }
+            _ => {}
+        }
+
+        if !markers.is_empty() {
+            let mut linked_address = None;
+            response.on_hover_ui(|ui| {
+                for marker in &markers {
+                    if let Some(address) = draw_linked_text(ui, &marker.description, Some(marker.colour)) {
+                        linked_address = Some(address);
+                    }
+                }
+            });
+            if let Some(address) = linked_address {
+                self.jump_to_address(address, address_space);
+            }
+        } else if self.breakpoint_toggle.is_some() {
+            response.on_hover_text(tr.text("Click to toggle a breakpoint"));
+        }
+    }
+
+    /// Collect all markers for the given row.
+    fn row_markers<T: ?Sized>(
+        &self,
+        mem: &mut T,
+        read_fn: &mut impl FnMut(&mut T, Address) -> Option<u8>,
+        row_range: Range<Address>,
+    ) -> Vec<RowMarker> {
+        let tr = self.translator();
+        let format_address = self.address_formatter(&self.selected_address_space());
+        let breakpoints = self.breakpoints.range(row_range.clone()).map(|&address| RowMarker {
+            kind: MarkerKind::Breakpoint,
+            colour: MarkerKind::Breakpoint.colour(),
+            address,
+            description: tr.format("⏺ {address}: Breakpoint", &[("address", &format_address(address))]),
+        });
+
+        #[cfg(feature = "bookmarks")]
+        let bookmarks = self
+            .bookmarks
+            .iter()
+            .filter(|bookmark| row_range.contains(&bookmark.address))
+            .map(|bookmark| RowMarker {
+                kind: MarkerKind::Bookmark,
+                colour: bookmark.colour.unwrap_or(MarkerKind::Bookmark.colour()),
+                address: bookmark.address,
+                description: if bookmark.notes.is_empty() {
+                    format!("🔖 {}: {}", bookmark.location(&format_address), bookmark.name)
+                } else {
+                    format!(
+                        "🔖 {}: {}\n{}",
+                        bookmark.location(&format_address),
+                        bookmark.name,
+                        bookmark.notes
+                    )
+                },
+            });
+        #[cfg(not(feature = "bookmarks"))]
+        let bookmarks = std::iter::empty();
+
+        let annotations = self
+            .annotations
+            .range(row_range.clone())
+            .map(|(&address, text)| RowMarker {
+                kind: MarkerKind::Annotation,
+                colour: MarkerKind::Annotation.colour(),
+                address,
+                description: format!("🗊 {}: {}", format_address(address), text),
+            });
+
+        let mut markers: Vec<RowMarker> = breakpoints.chain(bookmarks).chain(annotations).collect();
+
+        if let Some(snapshot) = &self.frame_data.snapshot {
+            let changed: Vec<Address> = row_range
+                .filter(|&address| snapshot.value(address).is_some())
+                .filter(|&address| snapshot.is_changed(address, read_fn(mem, address)))
+                .collect();
+
+            if let Some(&first) = changed.first() {
+                markers.push(RowMarker {
+                    kind: MarkerKind::Diff,
+                    colour: MarkerKind::Diff.colour(),
+                    address: first,
+                    description: tr.format(
+                        "Δ {count} value(s) changed since the snapshot",
+                        &[("count", &changed.len())],
+                    ),
+                });
+            }
+        }
+
+        markers
+    }
+}
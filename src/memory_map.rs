@@ -0,0 +1,200 @@
+//! Describing all address ranges of a system at once, for example loaded from a file, see [`MemoryMap`].
+use std::ops::Range;
+
+use crate::permissions::Permissions;
+use crate::{Address, MemoryEditor};
+
+/// A description of the address ranges of a system, which can be shipped as a file instead of a chain of builder
+/// calls, see [`MemoryEditor::with_memory_map`].
+///
+/// With the `serde` feature a map can be read from JSON, where addresses are numbers or hexadecimal strings, and the
+/// permissions and description of a region are optional:
+///
+/// ```json
+/// {
+///     "regions": [
+///         { "name": "ROM", "start": "0x0000", "end": "0x8000", "permissions": "r-x", "description": "Cartridge ROM" },
+///         { "name": "WRAM", "start": "0xC000", "end": "0xE000" }
+///     ]
+/// }
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct MemoryMap {
+    pub regions: Vec<MemoryMapRegion>,
+}
+
+/// A single address range of a [`MemoryMap`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct MemoryMapRegion {
+    /// The name of the address range.
+    pub name: String,
+    /// The first address of the range.
+    #[cfg_attr(feature = "serde", serde(with = "serde_address"))]
+    pub start: Address,
+    /// The exclusive end of the range.
+    #[cfg_attr(feature = "serde", serde(with = "serde_address"))]
+    pub end: Address,
+    /// The permissions of the range, a range which can't be written is read-only. `None` leaves the range readable
+    /// and writable.
+    #[cfg_attr(feature = "serde", serde(default, with = "serde_permissions"))]
+    pub permissions: Option<Permissions>,
+    /// A description of the range, shown in the gutter at its first address.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub description: Option<String>,
+}
+
+impl MemoryMapRegion {
+    pub fn new(name: impl Into<String>, range: Range<Address>) -> Self {
+        MemoryMapRegion {
+            name: name.into(),
+            start: range.start,
+            end: range.end,
+            permissions: None,
+            description: None,
+        }
+    }
+
+    #[must_use]
+    pub fn with_permissions(mut self, permissions: Permissions) -> Self {
+        self.permissions = Some(permissions);
+        self
+    }
+
+    #[must_use]
+    pub fn with_description(mut self, description: impl Into<String>) -> Self {
+        self.description = Some(description.into());
+        self
+    }
+
+    pub fn range(&self) -> Range<Address> {
+        self.start..self.end
+    }
+}
+
+impl MemoryMap {
+    /// Read a memory map from JSON, see [`MemoryMap`] for the format.
+    ///
+    /// ```
+    /// # use egui_memory_editor::memory_map::MemoryMap;
+    /// # use egui_memory_editor::permissions::Permissions;
+    /// let map = MemoryMap::from_json(
+    ///     r#"{ "regions": [{ "name": "ROM", "start": "0x0000", "end": 32768, "permissions": "r-x" }] }"#,
+    /// )
+    /// .unwrap();
+    /// assert_eq!(map.regions[0].range(), 0x0000..0x8000);
+    /// assert_eq!(map.regions[0].permissions, Some(Permissions::READ_EXECUTE));
+    ///
+    /// assert!(MemoryMap::from_json(r#"{ "regions": [{ "name": "ROM", "start": "0xZZ", "end": 0 }] }"#).is_err());
+    /// ```
+    #[cfg(feature = "serde")]
+    pub fn from_json(json: &str) -> Result<Self, String> {
+        serde_json::from_str(json).map_err(|error| error.to_string())
+    }
+
+    /// The memory map as pretty printed JSON, with hexadecimal addresses.
+    #[cfg(feature = "serde")]
+    pub fn to_json(&self) -> String {
+        serde_json::to_string_pretty(self).unwrap_or_default()
+    }
+}
+
+impl MemoryEditor {
+    /// Add all regions of a memory map, see [`Self::load_memory_map`].
+    ///
+    /// ```
+    /// # use egui_memory_editor::MemoryEditor;
+    /// # use egui_memory_editor::memory_map::{MemoryMap, MemoryMapRegion};
+    /// # use egui_memory_editor::permissions::Permissions;
+    /// let map = MemoryMap {
+    ///     regions: vec![
+    ///         MemoryMapRegion::new("ROM", 0x0000..0x8000)
+    ///             .with_permissions(Permissions::READ_EXECUTE)
+    ///             .with_description("Cartridge ROM"),
+    ///         MemoryMapRegion::new("WRAM", 0xC000..0xE000),
+    ///     ],
+    /// };
+    /// let memory_editor = MemoryEditor::new().with_memory_map(&map);
+    ///
+    /// assert_eq!(memory_editor.region_len("ROM"), Some(0x8000));
+    /// assert_eq!(memory_editor.permissions_at(0x100), Permissions::READ_EXECUTE);
+    /// assert_eq!(memory_editor.annotations().get(&0x0000).map(String::as_str), Some("Cartridge ROM"));
+    /// ```
+    #[must_use]
+    pub fn with_memory_map(mut self, map: &MemoryMap) -> Self {
+        self.load_memory_map(map);
+        self
+    }
+
+    /// Add or replace the address ranges of a memory map.
+    ///
+    /// Ranges with permissions get those permissions, and are read-only if they can't be written. Descriptions are
+    /// added as annotations at the start of their range.
+    pub fn load_memory_map(&mut self, map: &MemoryMap) {
+        for region in &map.regions {
+            self.set_address_range(region.name.clone(), region.range());
+
+            if let Some(permissions) = region.permissions {
+                self.set_permissions(region.range(), permissions);
+                self.set_address_range_read_only(region.name.clone(), !permissions.write);
+            }
+            if let Some(description) = &region.description {
+                self.set_annotation(region.start, description.clone());
+            }
+        }
+    }
+}
+
+/// Addresses as either a number or a string of a hexadecimal (`0x` prefixed) or decimal number.
+#[cfg(feature = "serde")]
+mod serde_address {
+    use serde::{Deserialize, Deserializer, Serializer};
+
+    use crate::Address;
+
+    #[derive(Deserialize)]
+    #[serde(untagged)]
+    enum RawAddress {
+        Number(Address),
+        Text(String),
+    }
+
+    pub fn serialize<S: Serializer>(address: &Address, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&format!("{:#X}", address))
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Address, D::Error> {
+        match RawAddress::deserialize(deserializer)? {
+            RawAddress::Number(address) => Ok(address),
+            RawAddress::Text(text) => {
+                let parsed = match text.strip_prefix("0x").or_else(|| text.strip_prefix("0X")) {
+                    Some(hex) => Address::from_str_radix(hex, 16),
+                    None => text.parse(),
+                };
+                parsed.map_err(|_| serde::de::Error::custom(format!("`{text}` is not an address")))
+            }
+        }
+    }
+}
+
+/// Permissions as a string like `r-x`, see [`Permissions`]'s `FromStr` implementation.
+#[cfg(feature = "serde")]
+mod serde_permissions {
+    use serde::{Deserialize, Deserializer, Serializer};
+
+    use crate::permissions::Permissions;
+
+    pub fn serialize<S: Serializer>(permissions: &Option<Permissions>, serializer: S) -> Result<S::Ok, S::Error> {
+        match permissions {
+            Some(permissions) => serializer.serialize_some(&permissions.to_string()),
+            None => serializer.serialize_none(),
+        }
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Option<Permissions>, D::Error> {
+        Option::<String>::deserialize(deserializer)?
+            .map(|text| text.parse().map_err(serde::de::Error::custom))
+            .transpose()
+    }
+}
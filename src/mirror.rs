@@ -0,0 +1,108 @@
+use std::ops::Range;
+
+use crate::{Address, MemoryEditor};
+
+/// An address range which mirrors (part of) another address range.
+#[derive(Clone, Debug)]
+pub(crate) struct Mirror {
+    /// The name of the address range being mirrored.
+    pub primary: String,
+    /// The offset into the primary address range which the start of the mirror corresponds to.
+    pub offset: Address,
+}
+
+/// Translates addresses in a mirror to addresses in its primary address range.
+#[derive(Copy, Clone, Debug)]
+pub(crate) struct MirrorMapping {
+    mirror_start: Address,
+    primary: Option<(Address, usize)>,
+    offset: Address,
+}
+
+impl MirrorMapping {
+    /// A mapping which doesn't translate any address.
+    pub fn identity() -> Self {
+        MirrorMapping {
+            mirror_start: 0,
+            primary: None,
+            offset: 0,
+        }
+    }
+
+    /// Translate an address in the mirror to the address in the primary range.
+    ///
+    /// If the mirror is larger than the primary range it wraps around, as is common for mirrored RAM.
+    pub fn translate(&self, address: Address) -> Address {
+        match self.primary {
+            Some((start, len)) => start + (self.offset + (address - self.mirror_start)) % len,
+            None => address,
+        }
+    }
+}
+
+impl MemoryEditor {
+    /// Add an address range which mirrors the address range named `primary`, such as RAM mirrored at multiple
+    /// addresses.
+    ///
+    /// All reads and writes in the mirror are routed to the primary range, with the start of the mirror corresponding to
+    /// `offset` bytes into the primary range. Addresses past the end of the primary range wrap around.
+    ///
+    /// See also [`Self::with_address_range`]
+    #[inline]
+    #[must_use]
+    pub fn with_mirror_address_range(
+        mut self,
+        range_name: impl Into<String>,
+        address_range: Range<Address>,
+        primary: impl Into<String>,
+        offset: Address,
+    ) -> Self {
+        self.set_mirror_address_range(range_name, address_range, primary, offset);
+        self
+    }
+
+    /// Add or update an address range which mirrors the address range named `primary`.
+    ///
+    /// See also [`Self::with_mirror_address_range`]
+    pub fn set_mirror_address_range(
+        &mut self,
+        range_name: impl Into<String>,
+        address_range: Range<Address>,
+        primary: impl Into<String>,
+        offset: Address,
+    ) {
+        let range_name = range_name.into();
+        self.set_address_range(range_name.clone(), address_range);
+        self.mirrors.insert(
+            range_name,
+            Mirror {
+                primary: primary.into(),
+                offset,
+            },
+        );
+    }
+
+    /// The name of the address range mirrored by the given address range, if it is a mirror.
+    pub fn mirror_of(&self, range_name: &str) -> Option<&str> {
+        self.mirrors.get(range_name).map(|mirror| mirror.primary.as_str())
+    }
+
+    /// The address translation for the currently selected address range.
+    pub(crate) fn current_mirror_mapping(&self) -> MirrorMapping {
+        let Some(mirror) = self.mirrors.get(&self.window.selected_address_range) else {
+            return MirrorMapping::identity();
+        };
+        let Some(mirror_range) = self.address_range(&self.window.selected_address_range) else {
+            return MirrorMapping::identity();
+        };
+
+        MirrorMapping {
+            mirror_start: mirror_range.start,
+            primary: self
+                .address_range(&mirror.primary)
+                .filter(|primary| !primary.is_empty())
+                .map(|primary| (primary.start, primary.len())),
+            offset: mirror.offset,
+        }
+    }
+}
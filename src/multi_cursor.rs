@@ -0,0 +1,84 @@
+use std::ops::Range;
+
+use egui::{Context, Event, Key, Modifiers};
+
+use crate::{Address, MemoryEditor};
+
+impl MemoryEditor {
+    /// Handle typing while multiple cursors are placed, see [`crate::option_data::BetweenFrameData::add_cursor`].
+    ///
+    /// Every pair of typed hex digits is written at every cursor, after which all cursors advance by one value.
+    /// The arrow keys move all cursors together, and `Escape` removes them.
+    pub(crate) fn handle_multi_cursor_input<T: ?Sized>(
+        &mut self,
+        ctx: &Context,
+        mem: &mut T,
+        write_fn: &mut Option<impl FnMut(&mut T, Address, u8)>,
+        address_range: &Range<Address>,
+    ) {
+        if self.frame_data.cursors.len() < 2 || !self.has_keyboard_focus(ctx) {
+            return;
+        }
+        let Some(write_fn) = write_fn.as_mut() else {
+            self.frame_data.cursors.clear();
+            return;
+        };
+
+        if ctx.input_mut(|i| i.consume_key(Modifiers::NONE, Key::Escape)) {
+            self.frame_data.cursors.clear();
+            self.frame_data.multi_cursor_digit = None;
+            return;
+        }
+
+        if ctx.input_mut(|i| i.consume_key(Modifiers::NONE, Key::ArrowRight)) {
+            self.move_cursors(1, address_range);
+        }
+        if ctx.input_mut(|i| i.consume_key(Modifiers::NONE, Key::ArrowLeft)) {
+            self.move_cursors(-1, address_range);
+        }
+
+        let digits: Vec<u8> = ctx.input(|i| {
+            i.events
+                .iter()
+                .filter_map(|event| match event {
+                    Event::Text(text) => Some(text.chars()),
+                    _ => None,
+                })
+                .flatten()
+                .filter_map(|c| c.to_digit(16))
+                .map(|digit| digit as u8)
+                .collect()
+        });
+
+        for digit in digits {
+            let Some(high_nibble) = self.frame_data.multi_cursor_digit.take() else {
+                self.frame_data.multi_cursor_digit = Some(digit);
+                continue;
+            };
+
+            let value = (high_nibble << 4) | digit;
+            for &address in &self.frame_data.cursors {
+                write_fn(mem, address, value);
+            }
+
+            self.move_cursors(1, address_range);
+        }
+    }
+
+    /// Move every cursor by `offset`, unless that would move any of them outside of the address range.
+    fn move_cursors(&mut self, offset: isize, address_range: &Range<Address>) {
+        let cursors = &mut self.frame_data.cursors;
+        let fits = cursors.iter().all(|&address| {
+            address
+                .checked_add_signed(offset)
+                .is_some_and(|address| address_range.contains(&address))
+        });
+
+        if fits {
+            for address in cursors.iter_mut() {
+                *address = address.wrapping_add_signed(offset);
+            }
+            self.frame_data.multi_cursor_digit = None;
+        }
+    }
+}
@@ -0,0 +1,202 @@
+use std::ops::Range;
+
+use egui::{Context, Key, KeyboardShortcut, Modifiers};
+
+use crate::notices::Notice;
+use crate::{Address, MemoryEditor};
+
+/// Jump to the next address containing data that differs from [`crate::option_data::MemoryEditorOptions::navigation_fill_value`].
+pub(crate) const NEXT_DATA_SHORTCUT: KeyboardShortcut = KeyboardShortcut::new(Modifiers::COMMAND, Key::ArrowRight);
+/// Jump to the previous address containing data that differs from [`crate::option_data::MemoryEditorOptions::navigation_fill_value`].
+pub(crate) const PREVIOUS_DATA_SHORTCUT: KeyboardShortcut = KeyboardShortcut::new(Modifiers::COMMAND, Key::ArrowLeft);
+
+/// The most addresses scanned per frame when jumping to the next data or change, larger ranges are scanned across
+/// frames.
+const MAX_JUMP_SCAN_PER_FRAME: usize = 256 * 1024;
+
+/// What a jump scans the memory for.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub(crate) enum JumpTarget {
+    /// A value which differs from the fill value.
+    Data,
+    /// A value which differs from the snapshot.
+    Change,
+}
+
+/// A jump to the next (or previous) data or change, whose scan may take several frames.
+#[derive(Clone, Debug)]
+pub(crate) struct PendingJump {
+    target: JumpTarget,
+    direction: SearchDirection,
+    /// The addresses which still have to be scanned.
+    remaining: Range<Address>,
+}
+
+/// The direction in which to search for the next point of interest.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum SearchDirection {
+    Forward,
+    Backward,
+}
+
+impl MemoryEditor {
+    /// Handle the shortcuts for jumping between points of interest, only active when there is a cursor to start from.
+    pub(crate) fn handle_navigation_shortcuts<T: ?Sized>(
+        &mut self,
+        ctx: &Context,
+        mem: &mut T,
+        read_fn: &mut impl FnMut(&mut T, Address) -> Option<u8>,
+        address_range: &Range<Address>,
+    ) {
+        if self.frame_data.cursor_address().is_none() || !self.has_keyboard_focus(ctx) {
+            return;
+        }
+
+        if ctx.input_mut(|i| i.consume_shortcut(&NEXT_DATA_SHORTCUT)) {
+            self.jump_to_data(mem, read_fn, address_range, SearchDirection::Forward);
+        } else if ctx.input_mut(|i| i.consume_shortcut(&PREVIOUS_DATA_SHORTCUT)) {
+            self.jump_to_data(mem, read_fn, address_range, SearchDirection::Backward);
+        }
+    }
+
+    /// Search for the value picked from the context menu of a value, starting at the address it was picked from.
+    #[cfg(feature = "search")]
+    pub(crate) fn run_pending_byte_search<T: ?Sized>(
+        &mut self,
+        mem: &mut T,
+        read_fn: &mut impl FnMut(&mut T, Address) -> Option<u8>,
+        address_range: &Range<Address>,
+    ) {
+        let Some((address, value, direction)) = self.frame_data.pending_byte_search.take() else {
+            return;
+        };
+
+        if self.frame_data.selected_edit_address.is_some() {
+            self.frame_data.set_selected_edit_address(Some(address), address_range);
+        }
+        self.frame_data.force_highlight_address(address);
+        self.frame_data.search_not_found = self
+            .find_pattern(mem, read_fn, &[value], address_range, direction)
+            .is_none();
+    }
+
+    /// Move the cursor to the next/previous address which contains a value that is not equal to the fill value.
+    ///
+    /// Addresses for which the `read_fn` returns `None` are skipped. Large address ranges are scanned across frames,
+    /// see [`Self::continue_pending_jump`].
+    pub(crate) fn jump_to_data<T: ?Sized>(
+        &mut self,
+        mem: &mut T,
+        read_fn: &mut impl FnMut(&mut T, Address) -> Option<u8>,
+        address_range: &Range<Address>,
+        direction: SearchDirection,
+    ) {
+        self.start_jump(JumpTarget::Data, direction, address_range);
+        self.continue_pending_jump(mem, read_fn, address_range);
+    }
+
+    /// Move the cursor to the next/previous address whose value differs from the active snapshot.
+    ///
+    /// Large address ranges are scanned across frames, see [`Self::continue_pending_jump`].
+    pub(crate) fn jump_to_change<T: ?Sized>(
+        &mut self,
+        mem: &mut T,
+        read_fn: &mut impl FnMut(&mut T, Address) -> Option<u8>,
+        address_range: &Range<Address>,
+        direction: SearchDirection,
+    ) {
+        if self.frame_data.snapshot.is_none() {
+            return;
+        }
+
+        self.start_jump(JumpTarget::Change, direction, address_range);
+        self.continue_pending_jump(mem, read_fn, address_range);
+    }
+
+    /// Start scanning the addresses after (or before) the cursor for the `target`, replacing any earlier scan.
+    ///
+    /// If there is no cursor the scan starts at the start (or end) of the `address_range`.
+    fn start_jump(&mut self, target: JumpTarget, direction: SearchDirection, address_range: &Range<Address>) {
+        let cursor = self.frame_data.cursor_address();
+        let remaining = match direction {
+            SearchDirection::Forward => {
+                cursor.map_or(address_range.start, |address| (address + 1).max(address_range.start))..address_range.end
+            }
+            SearchDirection::Backward => {
+                address_range.start..cursor.map_or(address_range.end, |address| address.min(address_range.end))
+            }
+        };
+
+        self.frame_data.pending_jump = Some(PendingJump {
+            target,
+            direction,
+            remaining,
+        });
+    }
+
+    /// Scan at most [`MAX_JUMP_SCAN_PER_FRAME`] addresses for the pending jump, moving the cursor to the first match.
+    ///
+    /// Called every frame, so scanning a large address range doesn't freeze the UI.
+    pub(crate) fn continue_pending_jump<T: ?Sized>(
+        &mut self,
+        mem: &mut T,
+        read_fn: &mut impl FnMut(&mut T, Address) -> Option<u8>,
+        address_range: &Range<Address>,
+    ) {
+        let Some(jump) = self.frame_data.pending_jump.take() else {
+            return;
+        };
+        let fill_value = self.options.navigation_fill_value;
+        let snapshot = &self.frame_data.snapshot;
+        let mut is_match = |address| match jump.target {
+            JumpTarget::Data => matches!(read_fn(mem, address), Some(value) if value != fill_value),
+            JumpTarget::Change => snapshot
+                .as_ref()
+                .is_some_and(|snapshot| snapshot.is_changed(address, read_fn(mem, address))),
+        };
+
+        let remaining = jump.remaining.start.max(address_range.start)..jump.remaining.end.min(address_range.end);
+        let (found, remaining) = match jump.direction {
+            SearchDirection::Forward => {
+                let scanned = remaining.start..remaining.end.min(remaining.start + MAX_JUMP_SCAN_PER_FRAME);
+                (
+                    scanned.clone().find(|&address| is_match(address)),
+                    scanned.end..remaining.end,
+                )
+            }
+            SearchDirection::Backward => {
+                let scanned = remaining
+                    .start
+                    .max(remaining.end.saturating_sub(MAX_JUMP_SCAN_PER_FRAME))
+                    ..remaining.end;
+                (
+                    scanned.clone().rev().find(|&address| is_match(address)),
+                    remaining.start..scanned.start,
+                )
+            }
+        };
+
+        match found {
+            Some(address) => self.jump_to_address(address, address_range),
+            None if !remaining.is_empty() => self.frame_data.pending_jump = Some(PendingJump { remaining, ..jump }),
+            None => {}
+        }
+    }
+
+    /// Move the cursor to the given address and ensure it is visible.
+    ///
+    /// If we're currently editing a value the edit cursor moves along, otherwise the address is highlighted.
+    pub(crate) fn jump_to_address(&mut self, address: Address, address_range: &Range<Address>) {
+        if !address_range.contains(&address) {
+            self.frame_data.notify(Notice::JumpOutOfBounds { address });
+            return;
+        }
+
+        if self.frame_data.selected_edit_address.is_some() {
+            self.frame_data.set_selected_edit_address(Some(address), address_range);
+        }
+
+        self.frame_data.force_highlight_address(address);
+        self.scroll_to_address(address, address_range);
+    }
+}
@@ -0,0 +1,142 @@
+use std::ops::Range;
+
+use egui::text::LayoutJob;
+use egui::{Context, Event, Key, Modifiers, Response, TextFormat, Ui};
+
+use crate::resize::ResizeFunctions;
+use crate::{Address, MemoryEditor};
+
+impl MemoryEditor {
+    /// Handle typing hex digits and moving the nibble cursor, when nibble editing is enabled.
+    ///
+    /// Every typed digit immediately replaces the active nibble of the edited value, after which the cursor advances
+    /// by one nibble.
+    pub(crate) fn handle_nibble_edit_input<T: ?Sized>(
+        &mut self,
+        ctx: &Context,
+        mem: &mut T,
+        read_fn: &mut impl FnMut(&mut T, Address) -> Option<u8>,
+        write_fn: &mut Option<impl FnMut(&mut T, Address, u8)>,
+        resize_fns: &mut Option<
+            ResizeFunctions<impl FnMut(&mut T, Address, usize), impl FnMut(&mut T, Range<Address>)>,
+        >,
+        address_range: &Range<Address>,
+    ) {
+        if !self.options.nibble_editing || !self.has_keyboard_focus(ctx) {
+            return;
+        }
+        let (Some(address), Some(write_fn)) = (self.frame_data.selected_edit_address, write_fn.as_mut()) else {
+            return;
+        };
+        // A cursor left outside of the displayed address range must never be written through.
+        if !address_range.contains(&address) {
+            self.frame_data.set_selected_edit_address(None, address_range);
+            return;
+        }
+
+        if ctx.input_mut(|i| i.consume_key(Modifiers::NONE, Key::Escape)) {
+            self.frame_data.set_selected_edit_address(None, address_range);
+            return;
+        }
+
+        // Left and right move by a single nibble, rather than by a whole value.
+        if ctx.input_mut(|i| i.consume_key(Modifiers::NONE, Key::ArrowRight)) {
+            self.advance_nibble(address, address_range);
+            return;
+        }
+        if ctx.input_mut(|i| i.consume_key(Modifiers::NONE, Key::ArrowLeft)) {
+            if self.frame_data.edit_low_nibble {
+                self.frame_data.edit_low_nibble = false;
+            } else if address > address_range.start {
+                self.frame_data
+                    .set_selected_edit_address(Some(address - 1), address_range);
+                self.frame_data.edit_low_nibble = true;
+            }
+            return;
+        }
+
+        // The typed text is consumed, so it doesn't also reach other widgets or shortcuts.
+        let mut digits: Vec<u8> = Vec::new();
+        ctx.input_mut(|i| {
+            i.events.retain(|event| match event {
+                Event::Text(text) => {
+                    digits.extend(text.chars().filter_map(|c| c.to_digit(16)).map(|digit| digit as u8));
+                    false
+                }
+                _ => true,
+            })
+        });
+
+        let mut address = address;
+        for digit in digits {
+            let low_nibble = self.frame_data.edit_low_nibble;
+
+            // In insert mode typing the high nibble starts a new value.
+            let old_value = if !low_nibble
+                && self.frame_data.insert_mode
+                && let Some(resize_fns) = resize_fns.as_mut()
+            {
+                (resize_fns.insert_fn)(mem, address, 1);
+                self.frame_data.pending_range_resize += 1;
+                0
+            } else {
+                read_fn(mem, address).unwrap_or(0)
+            };
+
+            let new_value = if low_nibble {
+                (old_value & 0xF0) | digit
+            } else {
+                (old_value & 0x0F) | (digit << 4)
+            };
+            write_fn(mem, address, new_value);
+
+            self.advance_nibble(address, address_range);
+            match self.frame_data.selected_edit_address {
+                Some(next_address) => address = next_address,
+                None => break,
+            }
+        }
+    }
+
+    /// Move the nibble cursor one nibble forward, onto the next value if needed.
+    fn advance_nibble(&mut self, address: Address, address_range: &Range<Address>) {
+        if self.frame_data.edit_low_nibble {
+            self.frame_data
+                .set_selected_edit_address(Some(address + 1), address_range);
+            self.scroll_to_address(address + 1, address_range);
+        } else {
+            self.frame_data.edit_low_nibble = true;
+        }
+    }
+}
+
+/// Draw the value being edited with the active nibble emphasised.
+pub(crate) fn draw_nibble_cursor(
+    ui: &mut Ui,
+    value: Option<u8>,
+    low_nibble_active: bool,
+    font_id: egui::FontId,
+) -> Response {
+    let text = value.map_or_else(|| "--".to_string(), |value| format!("{:02X}", value));
+    let visuals = ui.visuals();
+    let mut job = LayoutJob::default();
+
+    for (i, digit) in text.char_indices() {
+        let is_active = (i == 1) == low_nibble_active;
+        let format = if is_active {
+            TextFormat {
+                font_id: font_id.clone(),
+                color: visuals.strong_text_color(),
+                background: visuals.selection.bg_fill,
+                underline: visuals.selection.stroke,
+                ..Default::default()
+            }
+        } else {
+            TextFormat::simple(font_id.clone(), visuals.text_color())
+        };
+
+        job.append(&digit.to_string(), 0.0, format);
+    }
+
+    ui.label(job)
+}
@@ -0,0 +1,124 @@
+use std::fmt;
+
+use egui::Ui;
+
+use crate::option_data::BetweenFrameData;
+use crate::translations::Translator;
+use crate::{Address, MemoryEditor};
+
+/// How long a notice stays in the banner after it was last raised.
+const NOTICE_BANNER_SECONDS: f64 = 5.0;
+
+/// Something which went wrong while drawing or using the editor, reported instead of panicking, see
+/// [`MemoryEditor::notices`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Notice {
+    /// No address ranges were added, so there is nothing to draw.
+    NoAddressRanges,
+    /// The selected address range doesn't contain any addresses.
+    EmptyAddressRange { range_name: String },
+    /// Importing from the UI failed, such as pasted bookmarks or a dropped patch.
+    ImportFailed { what: String, error: String },
+    /// A write to the address was blocked by the write guard.
+    WriteRejected { address: Address },
+    /// A value couldn't be edited, as no write function was provided.
+    EditingDisabled,
+    /// A jump to an address outside of the selected address range was ignored.
+    JumpOutOfBounds { address: Address },
+}
+
+impl Notice {
+    /// The translated description of the notice.
+    pub(crate) fn message(&self, tr: &Translator) -> String {
+        match self {
+            Notice::NoAddressRanges => tr.text("No address ranges were added").into_owned(),
+            Notice::EmptyAddressRange { range_name } => {
+                tr.format("Address range `{name}` is empty", &[("name", range_name)])
+            }
+            Notice::ImportFailed { what, error } => tr.format(
+                "Importing {what} failed: {error}",
+                &[("what", &tr.text(what)), ("error", error)],
+            ),
+            Notice::WriteRejected { address } => tr.format(
+                "Writing to {address} was blocked",
+                &[("address", &format!("{:#X}", address))],
+            ),
+            Notice::EditingDisabled => tr
+                .text("Editing is disabled, no write function was provided")
+                .into_owned(),
+            Notice::JumpOutOfBounds { address } => tr.format(
+                "{address} is outside of the address range",
+                &[("address", &format!("{:#X}", address))],
+            ),
+        }
+    }
+}
+
+impl fmt::Display for Notice {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.message(&Translator::default()))
+    }
+}
+
+impl BetweenFrameData {
+    /// Report a notice for this frame, once.
+    pub fn notify(&mut self, notice: Notice) {
+        if !self.notices.contains(&notice) {
+            self.notices.push(notice);
+        }
+    }
+}
+
+impl MemoryEditor {
+    /// The notices raised in the last frame, such as rejected writes, failed imports, or an empty address range.
+    ///
+    /// ```
+    /// # use egui_memory_editor::{MemoryEditor, Notice};
+    /// let mut memory_editor = MemoryEditor::new();
+    /// let mut memory = vec![0u8; 0x100];
+    ///
+    /// // Drawing without any address range reports a notice, rather than panicking.
+    /// let _ = egui::Context::default().run(Default::default(), |ctx| {
+    ///     egui::CentralPanel::default().show(ctx, |ui| {
+    ///         memory_editor.draw_editor_contents_read_only(ui, &mut memory, |mem, address| mem.get(address).copied());
+    ///     });
+    /// });
+    /// assert_eq!(memory_editor.notices(), [Notice::NoAddressRanges]);
+    /// ```
+    pub fn notices(&self) -> &[Notice] {
+        &self.frame_data.notices
+    }
+
+    /// Start collecting the notices of a new frame, keeping those of the last frame in the banner for a while.
+    pub(crate) fn begin_notice_frame(&mut self, time: f64) {
+        let frame_data = &mut self.frame_data;
+
+        for notice in frame_data.notices.drain(..) {
+            match frame_data
+                .banner_notices
+                .iter_mut()
+                .find(|(recent, _)| *recent == notice)
+            {
+                Some((_, raised_at)) => *raised_at = time,
+                None => frame_data.banner_notices.push((notice, time)),
+            }
+        }
+        frame_data
+            .banner_notices
+            .retain(|(_, raised_at)| time - raised_at <= NOTICE_BANNER_SECONDS);
+    }
+
+    /// Draw the recent notices above the options, if enabled in the options.
+    pub(crate) fn draw_notice_banner(&mut self, ui: &mut Ui) {
+        if !self.options.show_notice_banner || self.frame_data.banner_notices.is_empty() {
+            return;
+        }
+        let tr = self.translator();
+
+        for (notice, _) in &self.frame_data.banner_notices {
+            ui.colored_label(ui.visuals().warn_fg_color, format!("⚠ {}", notice.message(&tr)));
+        }
+        // Keep drawing frames until the notices expire.
+        ui.ctx().request_repaint();
+    }
+}
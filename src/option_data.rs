@@ -1,5 +1,50 @@
 use crate::Address;
+use crate::address_format::AddressFormat;
+#[cfg(feature = "bookmarks")]
+use crate::address_list::AddressListFormat;
+#[cfg(feature = "tools")]
+use crate::analysis::{Analysis, DetectedStride};
+use crate::checksum::RowChecksum;
+use crate::colour_rules::ColourRule;
+#[cfg(feature = "inspector")]
+use crate::colours::ColourFormat;
+use crate::command_palette::CommandPalette;
+use crate::diagnostics::FrameDiagnostics;
+use crate::file_drop::PendingFileDrop;
+use crate::heat_map::HeatMap;
+use crate::navigation::PendingJump;
+#[cfg(feature = "search")]
+use crate::navigation::SearchDirection;
+use crate::notices::Notice;
+use crate::option_validation::OptionProblem;
+use crate::overlay::CellRects;
+#[cfg(feature = "tools")]
+use crate::pointer_scan::PointerScanState;
+use crate::read_cache::ReadCache;
+use crate::region_fill::RegionFill;
+use crate::registers::RegisterWidth;
+use crate::repeated_lines::RepeatedLines;
+use crate::ring_buffer::RingBufferCursors;
+use crate::scrolling::ScrollState;
+#[cfg(feature = "search")]
+use crate::search::SearchMatch;
+use crate::selection::Selection;
+use crate::selection_drag::{PendingSelectionMove, SelectionDrag};
+use crate::session::Recorder;
+#[cfg(feature = "tools")]
+use crate::signatures::SignatureScanState;
+use crate::snapshot::Snapshot;
+use crate::tags::UntaggedStyle;
+#[cfg(feature = "tools")]
+use crate::transforms::TransformView;
+use crate::triggers::TriggerCapture;
+use crate::undo::UndoHistory;
+use crate::value_edit::ValueEditDialog;
+use crate::value_history::ValueHistory;
+use crate::watches::WatchHistories;
+use crate::words::WordSize;
 use egui::{Color32, TextStyle};
+use std::collections::{BTreeMap, BTreeSet, HashMap};
 use std::ops::Range;
 
 #[derive(Copy, Clone, Debug, Eq, PartialEq)]
@@ -15,7 +60,7 @@ impl Endianness {
     }
 }
 
-#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum DataFormatType {
     U8,
@@ -47,12 +92,171 @@ impl DataFormatType {
     }
 }
 
+/// How values which can't be read are displayed.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum UnreadableStyle {
+    /// Display [`MemoryEditorOptions::none_display_value`], `--` by default.
+    Text,
+    /// Display nothing.
+    Blank,
+    /// Display a hatched background.
+    Hatched,
+}
+
+/// How characters which can't be printed are displayed in the sidebar.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum NonPrintableStyle {
+    /// Display a `.`, like most hex editors.
+    Dot,
+    /// Display a `·`, which is less prominent than a `.`.
+    MiddleDot,
+    /// Display nothing, making text stand out.
+    Space,
+    /// Display the value as small, dimmed, hexadecimal digits.
+    Hex,
+}
+
+impl NonPrintableStyle {
+    pub fn iter() -> impl Iterator<Item = NonPrintableStyle> {
+        [
+            NonPrintableStyle::Dot,
+            NonPrintableStyle::MiddleDot,
+            NonPrintableStyle::Space,
+            NonPrintableStyle::Hex,
+        ]
+        .into_iter()
+    }
+
+    /// The character substituted for non-printable characters, or `None` if the value is displayed instead.
+    pub const fn character(&self) -> Option<char> {
+        match self {
+            NonPrintableStyle::Dot => Some('.'),
+            NonPrintableStyle::MiddleDot => Some('·'),
+            NonPrintableStyle::Space => Some(' '),
+            NonPrintableStyle::Hex => None,
+        }
+    }
+
+    /// The name of the style, as shown in the UI.
+    pub const fn name(&self) -> &'static str {
+        match self {
+            NonPrintableStyle::Dot => ".",
+            NonPrintableStyle::MiddleDot => "·",
+            NonPrintableStyle::Space => "Space",
+            NonPrintableStyle::Hex => "Hex",
+        }
+    }
+}
+
+/// How the values are decoded into characters in the sidebar.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum SidebarEncoding {
+    /// Every value (or word) is a single ASCII character.
+    Ascii,
+    /// Every pair of values is a little endian UTF-16 code unit, as used for wide strings on Windows.
+    Utf16Le,
+    /// Every pair of values is a big endian UTF-16 code unit.
+    Utf16Be,
+}
+
+impl SidebarEncoding {
+    pub fn iter() -> impl Iterator<Item = SidebarEncoding> {
+        [
+            SidebarEncoding::Ascii,
+            SidebarEncoding::Utf16Le,
+            SidebarEncoding::Utf16Be,
+        ]
+        .into_iter()
+    }
+
+    /// The name of the encoding, as shown in the UI.
+    pub const fn name(&self) -> &'static str {
+        match self {
+            SidebarEncoding::Ascii => "ASCII",
+            SidebarEncoding::Utf16Le => "UTF-16 LE",
+            SidebarEncoding::Utf16Be => "UTF-16 BE",
+        }
+    }
+}
+
+/// A fixed-point number format, like the `8.8` and `16.16` formats common in retro games and embedded firmware.
+///
+/// ```
+/// # use egui_memory_editor::option_data::{Endianness, FixedPointFormat};
+/// let format = FixedPointFormat::new(2, 8, true);
+/// assert_eq!(format.to_string(), "s7.8");
+/// assert_eq!(format.decode(&[0x80, 0x01], Endianness::Little), 1.5);
+/// assert_eq!(format.decode(&[0xFF, 0x80], Endianness::Big), -0.5);
+/// ```
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct FixedPointFormat {
+    /// The size of a value, `1`, `2`, `4`, or `8` bytes.
+    pub bytes: usize,
+    /// The amount of bits after the binary point.
+    pub fraction_bits: u32,
+    /// Whether the value is a two's complement signed number.
+    pub signed: bool,
+}
+
+impl FixedPointFormat {
+    pub const fn new(bytes: usize, fraction_bits: u32, signed: bool) -> Self {
+        FixedPointFormat {
+            bytes,
+            fraction_bits,
+            signed,
+        }
+    }
+
+    /// Decode `bytes`, which should be [`Self::bytes`] long, as a fixed-point number.
+    pub fn decode(&self, bytes: &[u8], endianness: Endianness) -> f64 {
+        let bits = (8 * bytes.len()) as u32;
+        let raw = match endianness {
+            Endianness::Big => bytes.iter().fold(0u64, |acc, &byte| (acc << 8) | byte as u64),
+            Endianness::Little => bytes.iter().rev().fold(0u64, |acc, &byte| (acc << 8) | byte as u64),
+        };
+        let value = if self.signed && bits > 0 {
+            // Sign extend by shifting the sign bit into the top bit.
+            (((raw << (64 - bits)) as i64) >> (64 - bits)) as f64
+        } else {
+            raw as f64
+        };
+
+        value / 2f64.powi(self.fraction_bits as i32)
+    }
+}
+
+impl Default for FixedPointFormat {
+    fn default() -> Self {
+        FixedPointFormat::new(2, 8, false)
+    }
+}
+
+impl std::fmt::Display for FixedPointFormat {
+    /// Formatted as the integer and fraction bits, like `8.8`, prefixed with `s` for signed formats.
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let bits = 8 * self.bytes as u32;
+        let fraction_bits = self.fraction_bits.min(bits);
+        let integer_bits = bits - fraction_bits - u32::from(self.signed && fraction_bits < bits);
+
+        if self.signed {
+            write!(f, "s")?;
+        }
+        write!(f, "{}.{}", integer_bits, fraction_bits)
+    }
+}
+
 #[derive(Copy, Clone, Debug)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[cfg_attr(feature = "serde", serde(default))]
 pub struct DataPreviewOptions {
     pub selected_endianness: Endianness,
     pub selected_data_format: DataFormatType,
+    /// The fixed-point format shown alongside the selected data format.
+    pub fixed_point: FixedPointFormat,
 }
 
 impl Default for DataPreviewOptions {
@@ -60,6 +264,7 @@ impl Default for DataPreviewOptions {
         DataPreviewOptions {
             selected_endianness: Endianness::Little,
             selected_data_format: DataFormatType::U32,
+            fixed_point: FixedPointFormat::default(),
         }
     }
 }
@@ -70,22 +275,121 @@ impl Default for DataPreviewOptions {
 pub struct MemoryEditorOptions {
     /// Whether to show the ASCII representation of all the `u8` values in the main UI.
     pub show_ascii: bool,
+    /// How values are decoded into the characters of the sidebar.
+    /// Default is [`SidebarEncoding::Ascii`].
+    pub sidebar_encoding: SidebarEncoding,
+    /// How non-printable characters are displayed in the sidebar.
+    /// Default is [`NonPrintableStyle::Dot`].
+    pub non_printable_style: NonPrintableStyle,
+    /// Whether to show the characters of every group of 8 values directly after those values, rather than in a
+    /// sidebar after all values. This takes up less space on narrow panels.
+    /// Default is `false`.
+    pub interleave_ascii: bool,
+    /// Whether the ASCII sidebar is collapsed, which can be toggled from the column header.
+    /// Default is `false`.
+    pub ascii_sidebar_collapsed: bool,
+    /// The width of the ASCII sidebar as resized by the user, or `None` to fit the characters exactly.
+    /// Default is `None`.
+    pub ascii_sidebar_width: Option<f32>,
     /// Whether `0x00` values in the main UI should use the [`MemoryEditorOptions::zero_colour`].
     pub show_zero_colour: bool,
     /// The value to display if the `read_fn` ever returns [Option::None].
     pub none_display_value: String,
+    /// How to display values which can't be read, either because `read_fn` returned [Option::None] or because they're
+    /// in an unmapped address range.
+    /// Default is [`UnreadableStyle::Text`].
+    pub unreadable_style: UnreadableStyle,
+    /// Whether to collapse long stretches of lines in unmapped address ranges into a single row.
+    /// Default is `false`.
+    pub collapse_unmapped_lines: bool,
+    /// Whether to collapse consecutive identical lines into a single `*` row, like `xxd`. Clicking the row expands it.
+    ///
+    /// The whole address range is read once a second to find these lines, so this is best suited to small to medium
+    /// sized address ranges. With [`Self::max_reads_per_frame`] set, the scan is spread across frames and takes at most
+    /// half of the reads left after drawing.
+    /// Default is `false`.
+    pub collapse_repeated_lines: bool,
+    /// Whether to show a lane of markers next to the addresses, indicating breakpoints, bookmarks, annotations, and changes
+    /// since the snapshot.
+    pub show_gutter_markers: bool,
+    /// Whether to show a labelled separator row before every line containing a symbol or annotation.
+    /// Default is `false`.
+    pub show_symbol_separators: bool,
+    /// Whether to show the address of each row on the left of the main UI, hiding it saves space in narrow panels.
+    /// Default is `true`.
+    pub show_address_column: bool,
+    /// The amount of edits which can be undone with `Ctrl+Z`, where edits made in quick succession count as one.
+    /// The editor reads every value before writing it to be able to undo the write, `0` disables this.
+    /// Default is `100`.
+    pub undo_history_size: usize,
+    /// Whether to shade the background of values according to the permissions of their address range, see
+    /// [`crate::MemoryEditor::set_permissions`]. Read-only memory is shaded blue, executable memory red, and
+    /// inaccessible memory grey.
+    /// Default is `true`.
+    pub show_permissions: bool,
+    /// Whether to show the disassembly of the bytes around the cursor in a side panel, see
+    /// [`crate::MemoryEditor::with_disassembler`].
+    /// Default is `true`.
+    #[cfg(feature = "tools")]
+    pub show_disassembly: bool,
+    /// Show one register of the given width per row, with its symbol name and bits, rather than the grid of values.
+    /// Intended for small I/O regions, usually enabled for them through [`RegionOptions::register_view`].
+    /// Default is `None`.
+    pub register_view: Option<RegisterWidth>,
+    /// The checksum of every row to show in a column after the values, or `None` to hide the column.
+    /// Default is `None`.
+    pub row_checksum: Option<RowChecksum>,
+    /// The pixel format of the colours in the palette preview.
+    /// Default is [`ColourFormat::Bgr555`].
+    #[cfg(feature = "inspector")]
+    pub palette_format: ColourFormat,
+    /// The notation of the addresses in the address column, the goto box, and exports.
+    /// Default is [`AddressFormat::Linear`].
+    pub address_format: AddressFormat,
+    /// The length from which runs of `00` values are replaced by a `* skipped N bytes` line in hex exports and copies,
+    /// like `hexdump` does, keeping the output of sparse memory compact. `None` writes every value.
+    /// Default is `None`.
+    pub skip_zero_runs: Option<usize>,
+    /// Whether to show a status bar below the main UI, with the cursor, selection and typing mode.
+    /// Default is `true`.
+    pub show_status_bar: bool,
+    /// Whether to show the offset of each column above the main UI.
+    /// Clicking an offset selects that column for all visible rows.
+    pub show_column_header: bool,
+    /// Whether editing is disabled, even if a write function was provided.
+    /// Default is `false`.
+    pub is_read_only: bool,
     /// Whether the options header is collapsed by default or not.
     /// Default is `false`.
     pub is_options_collapsed: bool,
+    /// Whether to show the options in a compact `File`/`Edit`/`View`/`Tools` menu bar above the grid, rather than
+    /// in the `Options` collapsing header.
+    /// Default is `false`.
+    pub show_menu_bar: bool,
     /// The options which determine how to interpret selected data, concerning endianness and number type.
     pub data_preview: DataPreviewOptions,
+    /// How the selected values are plotted in the data preview, using the format and endianness of the
+    /// [`MemoryEditorOptions::data_preview`].
+    /// Default is [`crate::chart::ChartStyle::Line`].
+    #[cfg(feature = "plot")]
+    pub chart_style: crate::chart::ChartStyle,
     /// The amount of columns for the main UI, this amount directly impacts the possible size of your address space.
     ///
     /// At the moment, you'll at most be able to display the range: `0..2^(24 + log_2(column_count))`.
     pub column_count: usize,
+    /// The size of a single addressable unit of memory, see [`crate::words`].
+    /// Default is [`WordSize::BYTE`].
+    pub word_size: WordSize,
+    /// Whether the edit cursor addresses individual nibbles, where every typed hex digit immediately replaces the
+    /// active nibble, like most desktop hex editors.
+    /// Default is `false`.
+    pub nibble_editing: bool,
     /// Whether column size can be modified
     /// Default is `true`.
     pub is_resizable_column: bool,
+    /// The maximum amount of columns which can be selected in the UI.
+    /// Default is `128`.
+    pub max_column_count: usize,
     /// A custom colour for `0x00`. By default will be grey.
     pub zero_colour: Color32,
     /// The colour for address indicators on the very left of the UI.
@@ -93,36 +397,332 @@ pub struct MemoryEditorOptions {
     /// The highlight colour for both the main UI and the ASCII sidebar.
     /// This will be enabled when you right-click an address, or when using the `goto address` function in the UI.
     pub highlight_text_colour: Color32,
+    /// Rules which colour the background of the values satisfying them, the first applicable rule determines the
+    /// colour. See [`crate::colour_rules`].
+    /// Default is empty.
+    pub colour_rules: Vec<ColourRule>,
+    /// Whether to show a legend of the highlight colours above the main UI, when multiple highlights are active.
+    /// Each highlight can be hidden by clicking it in the legend.
+    /// Default is `true`.
+    pub show_highlight_legend: bool,
+    /// Whether to draw a labelled bracket after the values for every highlight group range and range bookmark
+    /// spanning multiple rows, so multi-row objects read as one unit.
+    /// Default is `false`.
+    pub show_range_brackets: bool,
+    /// The names of the highlight groups, and built-in highlights like `Selection`, hidden in the legend.
+    /// Default is empty.
+    pub hidden_highlight_groups: BTreeSet<String>,
+    /// The background colour for values pointed to by a live pointer.
+    pub pointer_colour: Color32,
+    /// The colour for values which differ from the snapshot, if one has been taken.
+    pub diff_text_colour: Color32,
+    /// The colour for values which can't be edited, because editing was disabled in the options or the address range
+    /// is read-only.
+    pub non_editable_colour: Color32,
+    /// Whether to read every value again right after writing it, marking values which read back differently, as is
+    /// common for memory mapped registers and ROM.
+    /// Default is `false`.
+    pub verify_writes: bool,
+    /// Whether to adapt the interaction to touch screens, with larger values to tap, and dragging to scroll rather
+    /// than to select. Tapping a value highlights it, double tapping edits it, and a long press opens its menu.
+    /// Default is `false`.
+    pub touch_mode: bool,
+    /// The horizontal space between the values in a group of 8 values, in points. Touch mode uses at least `10.0`.
+    /// Default is `3.0`.
+    pub value_spacing: f32,
+    /// The horizontal space between the groups of 8 values, and between the columns of the editor, in points.
+    /// Default is `15.0`.
+    pub group_spacing: f32,
+    /// The extra height of every row, in points. Touch mode uses at least `12.0`.
+    /// Default is `0.0`.
+    pub row_padding: f32,
+    /// The rows to scroll per notch of the mouse wheel, or `None` to scroll at egui's speed.
+    /// Default is `None`.
+    pub scroll_rows_per_notch: Option<usize>,
+    /// Whether to snap scrolling to the boundaries of rows, such that the top row is never cut off.
+    /// Default is `false`.
+    pub snap_scroll_to_rows: bool,
+    /// Whether to animate the scroll of jumps, such as to a bookmark or search result, and briefly flash the value
+    /// jumped to, to keep the spatial context.
+    /// Default is `false`.
+    pub animate_jumps: bool,
+    /// The colour for values which read back differently than they were written, see [`Self::verify_writes`].
+    pub write_mismatch_colour: Color32,
+    /// Whether to highlight values which changed between frames, the highlight fades out over
+    /// [`Self::recent_change_fade_seconds`].
+    /// Default is `false`.
+    pub highlight_recent_changes: bool,
+    /// The background colour for values which just changed, see [`Self::highlight_recent_changes`].
+    pub recent_change_colour: Color32,
+    /// The amount of seconds it takes for the highlight of a changed value to fade out.
+    /// Default is `1.0`.
+    pub recent_change_fade_seconds: f32,
+    /// Whether to highlight the values in the watch list which changed, fading out like
+    /// [`Self::highlight_recent_changes`].
+    /// Default is `true`.
+    pub highlight_watch_changes: bool,
+    /// The amount of frames of which the values of every watch are kept, drawn as a sparkline next to the watch.
+    /// `None` hides the sparklines.
+    /// Default is `None`.
+    pub watch_sparkline_length: Option<usize>,
+    /// The range whose values are coloured by how often they changed recently, to find the variables in use in a
+    /// large block of memory. The whole range is read every frame, so it should be kept small.
+    /// Default is `None`.
+    pub heat_map_range: Option<Range<Address>>,
+    /// The amount of frames over which the changes in the [`Self::heat_map_range`] are averaged.
+    /// Default is `60`.
+    pub heat_map_frames: usize,
+    /// Whether to shade the addresses which the host reports as covered, see
+    /// [`crate::MemoryEditor::with_coverage`].
+    /// Default is `true`.
+    pub show_coverage: bool,
+    /// The background colour of covered addresses, see [`Self::show_coverage`].
+    pub coverage_colour: Color32,
+    /// How the values without a tag are displayed, see [`crate::MemoryEditor::with_tag`].
+    /// Default is [`UntaggedStyle::Normal`].
+    pub untagged_style: UntaggedStyle,
+    /// Only the tags with this name are listed, and count as tagged for the [`Self::untagged_style`], if set.
+    /// Default is `None`.
+    pub tag_filter: Option<String>,
     /// The [`egui::TextStyle`] for the main UI, indicating the values.
     /// Default is [`egui::TextStyle::Monospace`]
     pub memory_editor_text_style: TextStyle,
     /// The [`egui::TextStyle`] for the addresses in the main UI on the left.
     /// Default is [`egui::TextStyle::Monospace`]
     pub memory_editor_address_text_style: TextStyle,
+    /// The text colour for the ASCII sidebar, or `None` to use the text colour of the theme.
+    /// Default is `None`.
+    pub ascii_text_colour: Option<Color32>,
+    /// The background colour of the ASCII sidebar.
+    /// Default is [`Color32::TRANSPARENT`].
+    pub ascii_background_colour: Color32,
+    /// Whether to colour non-printable characters in the ASCII sidebar with [`Self::non_printable_colour`], making
+    /// text stand out from binary data.
+    /// Default is `false`.
+    pub highlight_non_printable: bool,
+    /// The colour of non-printable characters in the ASCII sidebar, see [`Self::highlight_non_printable`].
+    pub non_printable_colour: Color32,
     /// The [`egui::TextStyle`] for the ASCII values in the right side-bar (if they're enabled).
     /// Default is [`egui::TextStyle::Monospace`]
     pub memory_editor_ascii_text_style: TextStyle,
-    /// The selected address range, always applicable, not really relevant for consumers of the editor.
-    pub(crate) selected_address_range: String,
+    /// Only find search matches at addresses which are a multiple of this many bytes.
+    /// Default is `1`, which finds matches at any address.
+    pub search_alignment: usize,
+    /// Whether the search box finds every match in all address ranges, listed by region, rather than the next match
+    /// in the selected range.
+    /// Default is `false`.
+    pub search_all_regions: bool,
+    /// The size of a record when the memory is an array of fixed size records, or `None` to disable record mode.
+    ///
+    /// In record mode separators are drawn between records, and the cursor can jump to the same field in the
+    /// next/previous record with `Alt+↓`/`Alt+↑`.
+    /// Default is `None`.
+    pub record_size: Option<usize>,
+    /// The value which is considered 'empty' when jumping to the next/previous data in the UI.
+    /// Default is `0x00`.
+    pub navigation_fill_value: u8,
+    /// Whether to grey out the values of an address range which mirrors another.
+    /// Default is `true`.
+    pub grey_mirrored_values: bool,
+    /// Whether to show a warning above the options for every empty, reversed or overlapping address range.
+    /// Default is `false`.
+    pub show_address_range_warnings: bool,
+    /// Whether to show a banner above the options listing the recent notices, such as failed imports and rejected
+    /// writes, see [`crate::MemoryEditor::notices`].
+    /// Default is `false`.
+    pub show_notice_banner: bool,
+    /// Whether the view scrolls along with a growing address range while its end is in view, see
+    /// [`crate::MemoryEditor::with_growing_address_range`].
+    /// Default is `true`.
+    pub scroll_growing_ranges: bool,
+    /// Whether ring buffers are shown in logical order, starting at the line of their tail, see
+    /// [`crate::MemoryEditor::with_ring_buffer`].
+    /// Default is `false`.
+    pub unroll_ring_buffers: bool,
+    /// The maximum amount of values to read per frame, or `None` to read every visible value each frame.
+    ///
+    /// When set, values which haven't been refreshed recently are displayed using their cached value with a weaker
+    /// colour. This bounds the time spent per frame if the read function is expensive.
+    /// Default is `None`.
+    pub max_reads_per_frame: Option<usize>,
+    /// The maximum amount of values to draw per frame, or `None` to draw every visible row.
+    ///
+    /// When a tall window with many columns would exceed it, fewer rows are drawn and a notice is shown below the
+    /// values. This prevents accidental multi-second frames on slow targets, such as wasm.
+    /// Default is `Some(16384)`.
+    pub max_cells_per_frame: Option<usize>,
+    /// Whether to show statistics about the last frame, such as the amount of read calls, above the options.
+    /// Default is `false`.
+    pub show_diagnostics: bool,
+    /// Whether to list the address ranges alphabetically, rather than in the order they were added.
+    /// Default is `false`.
+    pub sort_address_ranges_alphabetically: bool,
+    /// Whether to show how much of the selected address range is non-zero in the options header, like
+    /// `RAM 37% non-zero`. Every value is read when an address range is first shown, after which it's only measured
+    /// again on demand, see [`crate::MemoryEditor::measure_region_fill`].
+    /// Default is `false`.
+    pub show_region_fill: bool,
 }
 
 impl Default for MemoryEditorOptions {
     fn default() -> Self {
         MemoryEditorOptions {
             data_preview: Default::default(),
+            #[cfg(feature = "plot")]
+            chart_style: Default::default(),
             show_ascii: true,
+            sidebar_encoding: SidebarEncoding::Ascii,
+            non_printable_style: NonPrintableStyle::Dot,
+            interleave_ascii: false,
+            ascii_sidebar_collapsed: false,
+            ascii_sidebar_width: None,
             show_zero_colour: true,
+            show_address_column: true,
+            show_column_header: true,
+            undo_history_size: 100,
+            show_permissions: true,
+            #[cfg(feature = "tools")]
+            show_disassembly: true,
+            register_view: None,
+            row_checksum: None,
+            #[cfg(feature = "inspector")]
+            palette_format: ColourFormat::Bgr555,
+            address_format: AddressFormat::Linear,
+            show_status_bar: true,
+            show_gutter_markers: true,
+            show_symbol_separators: false,
+            is_read_only: false,
             none_display_value: "--".to_string(),
+            skip_zero_runs: None,
+            unreadable_style: UnreadableStyle::Text,
+            collapse_unmapped_lines: false,
+            collapse_repeated_lines: false,
             is_options_collapsed: false,
+            show_menu_bar: false,
             zero_colour: Color32::from_gray(80),
+            word_size: WordSize::BYTE,
+            nibble_editing: false,
             is_resizable_column: true,
+            max_column_count: 128,
             column_count: 16,
             address_text_colour: Color32::from_rgb(125, 0, 125),
             highlight_text_colour: Color32::from_rgb(0, 140, 140),
+            colour_rules: Vec::new(),
+            show_highlight_legend: true,
+            show_range_brackets: false,
+            hidden_highlight_groups: BTreeSet::new(),
+            pointer_colour: Color32::from_rgba_unmultiplied(60, 160, 60, 90),
+            diff_text_colour: Color32::from_rgb(210, 90, 30),
+            non_editable_colour: Color32::from_gray(120),
+            verify_writes: false,
+            touch_mode: false,
+            value_spacing: 3.0,
+            group_spacing: 15.0,
+            row_padding: 0.0,
+            scroll_rows_per_notch: None,
+            snap_scroll_to_rows: false,
+            animate_jumps: false,
+            write_mismatch_colour: Color32::from_rgb(230, 180, 0),
+            highlight_recent_changes: false,
+            recent_change_colour: Color32::from_rgba_unmultiplied(220, 160, 40, 120),
+            recent_change_fade_seconds: 1.0,
+            highlight_watch_changes: true,
+            watch_sparkline_length: None,
+            heat_map_range: None,
+            heat_map_frames: 60,
+            show_coverage: true,
+            coverage_colour: Color32::from_rgba_unmultiplied(40, 120, 200, 70),
+            untagged_style: UntaggedStyle::Normal,
+            tag_filter: None,
             memory_editor_text_style: TextStyle::Monospace,
             memory_editor_address_text_style: TextStyle::Monospace,
+            ascii_text_colour: None,
+            ascii_background_colour: Color32::TRANSPARENT,
+            highlight_non_printable: false,
+            non_printable_colour: Color32::from_gray(90),
             memory_editor_ascii_text_style: TextStyle::Monospace,
-            selected_address_range: "".to_string(),
+            search_alignment: 1,
+            search_all_regions: false,
+            record_size: None,
+            navigation_fill_value: 0,
+            grey_mirrored_values: true,
+            show_address_range_warnings: false,
+            show_notice_banner: false,
+            scroll_growing_ranges: true,
+            unroll_ring_buffers: false,
+            max_reads_per_frame: None,
+            max_cells_per_frame: Some(16384),
+            show_diagnostics: false,
+            sort_address_ranges_alphabetically: false,
+            show_region_fill: false,
+        }
+    }
+}
+
+/// Options which can be overridden for a single address range, any option left as `None` uses the value from
+/// [`MemoryEditorOptions`].
+///
+/// Changes made in the UI to an overridden option only apply to that address range.
+#[derive(Clone, Debug, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+pub struct RegionOptions {
+    /// See [`MemoryEditorOptions::column_count`].
+    pub column_count: Option<usize>,
+    /// See [`MemoryEditorOptions::show_ascii`].
+    pub show_ascii: Option<bool>,
+    /// See [`MemoryEditorOptions::sidebar_encoding`].
+    pub sidebar_encoding: Option<SidebarEncoding>,
+    /// See [`MemoryEditorOptions::word_size`], which groups the bytes of this address range into words.
+    pub word_size: Option<WordSize>,
+    /// See [`MemoryEditorOptions::data_preview`].
+    pub data_preview: Option<DataPreviewOptions>,
+    /// See [`MemoryEditorOptions::register_view`], `Some(None)` shows the grid of values for this address range.
+    pub register_view: Option<Option<RegisterWidth>>,
+}
+
+impl RegionOptions {
+    /// Set the overridden options in `options`, returning a [`RegionOptions`] with the values they replaced.
+    pub(crate) fn apply(&self, options: &mut MemoryEditorOptions) -> RegionOptions {
+        RegionOptions {
+            column_count: self
+                .column_count
+                .map(|value| std::mem::replace(&mut options.column_count, value)),
+            show_ascii: self
+                .show_ascii
+                .map(|value| std::mem::replace(&mut options.show_ascii, value)),
+            sidebar_encoding: self
+                .sidebar_encoding
+                .map(|value| std::mem::replace(&mut options.sidebar_encoding, value)),
+            word_size: self
+                .word_size
+                .map(|value| std::mem::replace(&mut options.word_size, value)),
+            data_preview: self
+                .data_preview
+                .map(|value| std::mem::replace(&mut options.data_preview, value)),
+            register_view: self
+                .register_view
+                .map(|value| std::mem::replace(&mut options.register_view, value)),
+        }
+    }
+}
+
+/// The reason the memory can't be edited in the UI.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub(crate) enum ReadOnlyReason {
+    NoWriteFunction,
+    Locked,
+    ReadOnlyRange,
+    ScratchRegion,
+}
+
+impl ReadOnlyReason {
+    pub fn description(&self) -> &'static str {
+        match self {
+            ReadOnlyReason::NoWriteFunction => "Read-only: no write function was provided",
+            ReadOnlyReason::Locked => "Read-only: editing has been disabled in the options",
+            ReadOnlyReason::ReadOnlyRange => "Read-only: this address range can't be edited",
+            ReadOnlyReason::ScratchRegion => "Read-only: this is a scratch region of derived data",
         }
     }
 }
@@ -132,13 +732,23 @@ impl Default for MemoryEditorOptions {
 pub(crate) struct BetweenFrameData {
     /// Used to ensure we can resize the window in height, but not in width.
     pub previous_frame_editor_width: f32,
-    pub previous_frame_text_edit_size: f32,
     /// The address a user clicked on in the UI in the previous frame, used for DataPreview
     pub selected_edit_address: Option<Address>,
     pub selected_edit_address_string: String,
     pub selected_edit_address_request_focus: bool,
+    /// Whether the low nibble of the edited value is active, when nibble editing is enabled.
+    pub edit_low_nibble: bool,
 
     pub memory_range_combo_box_enabled: bool,
+    /// Why the memory can't be edited this frame, if it can't.
+    pub read_only_reason: Option<ReadOnlyReason>,
+    /// The name of the primary address range if the current address range is a mirror of it.
+    pub mirror_of: Option<String>,
+    /// The head and tail of the current address range if it's a ring buffer, see
+    /// [`crate::MemoryEditor::with_ring_buffer`].
+    pub ring_buffer: Option<RingBufferCursors>,
+    /// Whether the position of the window state should be applied to the window, after it was restored.
+    pub restore_window_position: bool,
 
     pub selected_highlight_address: Option<Address>,
     /// Whether to show additional highlights around items after the current selected item when they'd be part
@@ -146,7 +756,217 @@ pub(crate) struct BetweenFrameData {
     pub show_additional_highlights: bool,
 
     pub goto_address_string: String,
+    /// The word size of the options, as the goto box displays word addresses.
+    pub word_size: WordSize,
     pub goto_address_line: Option<usize>,
+    pub scroll: ScrollState,
+    /// Whether the editor has keyboard focus, see [`crate::MemoryEditor::has_focus`].
+    pub is_focused: bool,
+    /// The screen rectangles of the value cells drawn this frame.
+    pub cell_rects: CellRects,
+    /// The left edge of the margin in which the range brackets are drawn, if it was drawn this frame.
+    pub range_bracket_margin: Option<f32>,
+    /// The row pinned above the scrolling rows, see [`crate::MemoryEditor::set_pinned_row`].
+    pub pinned_row: Option<Address>,
+    /// Whether the cheat sheet of shortcuts and mouse gestures is open.
+    pub show_help: bool,
+    /// The command palette, while it's open.
+    pub command_palette: Option<CommandPalette>,
+    /// The edit session being recorded, see [`crate::MemoryEditor::start_recording`].
+    pub(crate) recorder: Option<Recorder>,
+    /// Why the last address entered in the goto box couldn't be moved to.
+    pub goto_error: Option<String>,
+
+    /// The current (multi-)selection, and the address from which `Shift` clicks extend it.
+    pub selection: Selection,
+    pub selection_anchor: Option<Address>,
+    /// The amount of records to select a field in from the UI, `0` selects every following record.
+    pub record_field_count: usize,
+    /// The offset within a record of the field to select in every record of a range.
+    pub record_field_offset: usize,
+    /// The size of the field to select in every record of a range.
+    pub record_field_width: usize,
+    /// The cursors placed with `Ctrl` clicks, typed values are written at every cursor when there are multiple.
+    pub cursors: Vec<Address>,
+    /// The high nibble typed at the cursors, waiting for the low nibble.
+    pub multi_cursor_digit: Option<u8>,
+    /// Whether the user is selecting a run by dragging across values, the run starts at the anchor.
+    pub drag_selecting: bool,
+    /// Whether the selection was made in the ASCII sidebar, which makes copying it default to text rather than hex.
+    pub selected_from_ascii: bool,
+    /// The selected run being dragged to another address, if any.
+    pub selection_drag: Option<SelectionDrag>,
+    /// A copy or move of the selection onto an overlapping range, waiting for confirmation.
+    pub pending_selection_move: Option<PendingSelectionMove>,
+    /// The value to write when filling the selection from the UI.
+    pub selection_fill_value: u8,
+
+    /// Whether typed values are inserted before the cursor, rather than overwriting it.
+    /// Only relevant if resize functions were provided.
+    pub insert_mode: bool,
+    /// The amount of bytes to insert when clicking the insert button in the UI.
+    pub insert_count: usize,
+    /// The amount of bytes inserted (positive) or deleted (negative) this frame, applied to the selected range at the
+    /// end of the frame.
+    pub pending_range_resize: isize,
+
+    /// The horizontal drag distance not yet applied to the value being scrubbed with `Alt+drag`.
+    pub scrub_accumulator: f32,
+
+    /// The name for the next bookmark added in the UI.
+    #[cfg(feature = "bookmarks")]
+    pub new_bookmark_name: String,
+    /// The name for the next tag added in the UI.
+    pub new_tag_name: String,
+    /// The category of the next bookmark added from the UI, empty for none.
+    #[cfg(feature = "bookmarks")]
+    pub new_bookmark_category: String,
+    /// Only the bookmarks of this category are listed, if set.
+    #[cfg(feature = "bookmarks")]
+    pub bookmark_category_filter: Option<String>,
+    /// The index of the bookmark whose notes are being edited, if any.
+    #[cfg(feature = "bookmarks")]
+    pub editing_bookmark_notes: Option<usize>,
+    /// The JSON pasted by the user to import bookmarks from.
+    #[cfg(all(feature = "bookmarks", feature = "serde"))]
+    pub bookmark_json: String,
+    /// The result of the last bookmark import, the amount of bookmarks added or why the import failed.
+    #[cfg(all(feature = "bookmarks", feature = "serde"))]
+    pub bookmark_import_result: Option<Result<usize, String>>,
+    /// The address list pasted by the user to import symbols or bookmarks from.
+    #[cfg(feature = "bookmarks")]
+    pub address_list_text: String,
+    /// The format of the pasted address list.
+    #[cfg(feature = "bookmarks")]
+    pub address_list_format: AddressListFormat,
+    /// The result of the last address list import, the amount of addresses imported or why the import failed.
+    #[cfg(feature = "bookmarks")]
+    pub address_list_import_result: Option<Result<usize, String>>,
+    /// Why the last edit was rejected, such as a write blocked by the write guard, and the time at which it was
+    /// rejected.
+    pub write_notice: Option<(String, f64)>,
+    /// The values which read back differently than they were last written, by address, along with the written value
+    /// and the value which was read back.
+    pub write_mismatches: BTreeMap<Address, (u8, Option<u8>)>,
+    /// The edits which can be undone and redone.
+    pub undo_history: UndoHistory,
+    /// The watch being entered in the watch list.
+    pub new_watch_text: String,
+    /// Why the entered watch couldn't be added.
+    pub new_watch_error: Option<String>,
+    /// The last value of every watch, to highlight changes.
+    pub watch_history: WatchHistories,
+    /// The address expression of the trigger being added.
+    pub new_trigger_text: String,
+    /// Whether the trigger being added takes a snapshot.
+    pub new_trigger_snapshot: bool,
+    /// Why the trigger couldn't be added.
+    pub new_trigger_error: Option<String>,
+    /// The values of the trigger addresses at the last check.
+    pub trigger_values: HashMap<Address, Option<u8>>,
+    /// The changes caught by the triggers, oldest first.
+    pub trigger_captures: Vec<TriggerCapture>,
+    /// The problems found in the options, shown until dismissed.
+    pub option_problems: Vec<OptionProblem>,
+    /// The notices raised in this frame, see [`crate::MemoryEditor::notices`].
+    pub notices: Vec<Notice>,
+    /// The notices shown in the banner, along with the time they were last raised.
+    pub banner_notices: Vec<(Notice, f64)>,
+    /// How often the values of the heat map range changed recently.
+    pub heat_map: Option<HeatMap>,
+
+    /// The dialog for editing a typed value, if open.
+    pub value_edit_dialog: Option<ValueEditDialog>,
+
+    /// The snapshot of memory to compare the current values against, if any.
+    pub snapshot: Option<Snapshot>,
+
+    /// The label of the live pointer to keep in view, if any.
+    pub followed_pointer: Option<String>,
+    /// Whether to keep the host's address in view, see [`crate::MemoryEditor::with_follow_address`].
+    pub is_following: bool,
+    /// The host's address to follow as of the last frame.
+    pub followed_address: Option<Address>,
+    /// Whether to keep the host's write cursor at the bottom of the view, see
+    /// [`crate::MemoryEditor::with_write_cursor`].
+    pub is_following_tail: bool,
+    /// The host's write cursor as of the last frame.
+    pub write_cursor: Option<Address>,
+
+    /// The pattern entered in the search box.
+    #[cfg(feature = "search")]
+    pub search_string: String,
+    /// Whether the last search didn't find anything.
+    #[cfg(feature = "search")]
+    pub search_not_found: bool,
+    /// The pattern to find in all regions at the start of the next frame.
+    #[cfg(feature = "search")]
+    pub pending_region_search: Option<Vec<u8>>,
+    /// The matches of the last search across all regions.
+    #[cfg(feature = "search")]
+    pub search_results: Vec<SearchMatch>,
+    /// The length of the pattern of the last search across all regions.
+    #[cfg(feature = "search")]
+    pub search_result_len: usize,
+    /// Whether to save every region through the export handler at the start of the next frame.
+    #[cfg(feature = "export")]
+    pub pending_region_dump: bool,
+    /// The IPS patch dropped onto the editor, shown in a preview before applying it.
+    #[cfg(feature = "ips")]
+    pub pending_patch: Option<crate::ips::PendingPatch>,
+    /// The file dropped onto the editor, waiting for the address to write it to.
+    pub pending_file_drop: Option<PendingFileDrop>,
+    /// Whether the script console is open.
+    #[cfg(feature = "scripting")]
+    pub show_console: bool,
+    /// The script entered in the console.
+    #[cfg(feature = "scripting")]
+    pub console_script: String,
+    /// The lines printed by the scripts run in the console.
+    #[cfg(feature = "scripting")]
+    pub console_output: Vec<String>,
+    /// Why the last script run in the console stopped early.
+    #[cfg(feature = "scripting")]
+    pub console_error: Option<String>,
+    /// The settings and results of the pointer scan.
+    #[cfg(feature = "tools")]
+    pub pointer_scan: PointerScanState,
+    /// The result of the last analysis of the selection.
+    #[cfg(feature = "tools")]
+    pub analysis: Option<Analysis>,
+    /// The settings and results of the signature scan.
+    #[cfg(feature = "tools")]
+    pub signature_scan: SignatureScanState,
+    /// The open views of the output of transforms.
+    #[cfg(feature = "tools")]
+    pub transform_views: Vec<TransformView>,
+    /// The error of the last transform, if it failed.
+    #[cfg(feature = "tools")]
+    pub transform_error: Option<String>,
+    /// The stride detected in the selection, overlaid on the selection until it's cleared.
+    #[cfg(feature = "tools")]
+    pub detected_stride: Option<DetectedStride>,
+    /// Whether the last stride detection didn't find a stride.
+    #[cfg(feature = "tools")]
+    pub stride_not_found: bool,
+    /// The address, value, and direction of a search for a single value requested from the context menu.
+    #[cfg(feature = "search")]
+    pub pending_byte_search: Option<(Address, u8, SearchDirection)>,
+    /// The jump to the next data or change whose scan hasn't finished yet.
+    pub pending_jump: Option<PendingJump>,
+    /// The last measured fill of every address range, by name.
+    pub region_fill: HashMap<String, RegionFill>,
+    /// Whether the fill of the selected address range should be measured again.
+    pub refresh_region_fill: bool,
+
+    /// Statistics about the last frame.
+    pub diagnostics: FrameDiagnostics,
+    /// The values displayed in the scroll area, if the amount of reads per frame is limited.
+    pub read_cache: ReadCache,
+    /// The values displayed in the last two frames.
+    pub value_history: ValueHistory,
+    /// The result of the last scan for repeated lines, if collapsing them is enabled.
+    pub repeated_lines: Option<RepeatedLines>,
 }
 
 impl BetweenFrameData {
@@ -157,13 +977,20 @@ impl BetweenFrameData {
             self.goto_address_string.clear();
             None
         } else {
-            self.goto_address_string = format!("{:X}", new_address);
+            self.goto_address_string = format!("{:X}", self.word_size.word_address(new_address));
             Some(new_address)
         };
     }
 
+    /// Highlight the given address, unlike [`Self::set_highlight_address`] this will never unselect.
+    pub fn force_highlight_address(&mut self, new_address: Address) {
+        self.goto_address_string = format!("{:X}", self.word_size.word_address(new_address));
+        self.selected_highlight_address = Some(new_address);
+    }
+
     pub fn set_selected_edit_address(&mut self, new_address: Option<Address>, address_space: &Range<Address>) {
         self.selected_edit_address_string.clear();
+        self.edit_low_nibble = false;
         if matches!(new_address, Some(address) if address_space.contains(&address)) {
             self.set_highlight_address(new_address.unwrap());
             self.selected_edit_address_request_focus = true;
@@ -173,10 +1000,95 @@ impl BetweenFrameData {
         }
     }
 
+    /// Update the selection for a click on the given address while holding `Shift` and/or `Ctrl`.
+    ///
+    /// * `Shift` extends the current run from the anchor (the last clicked or highlighted address) to the address.
+    /// * `Ctrl` adds a new, disjoint, run starting at the address.
+    pub fn modify_selection(&mut self, address: Address, modifiers: egui::Modifiers) {
+        if modifiers.shift {
+            let anchor = *self
+                .selection_anchor
+                .get_or_insert(self.selected_highlight_address.unwrap_or(address));
+            self.selection
+                .replace_last_range(anchor.min(address)..anchor.max(address) + 1);
+        } else if modifiers.command {
+            self.selection_anchor = Some(address);
+            self.selection.add_range(address..address + 1);
+        }
+
+        self.selected_edit_address = None;
+        self.force_highlight_address(address);
+    }
+
+    /// Record the value read back right after writing `written` at `address`, a matching value clears an earlier
+    /// mismatch.
+    pub fn record_write_verification(&mut self, address: Address, written: u8, read_back: Option<u8>) {
+        if read_back == Some(written) {
+            self.write_mismatches.remove(&address);
+        } else {
+            self.write_mismatches.insert(address, (written, read_back));
+        }
+    }
+
+    /// Place an additional cursor at `address`, the first additional cursor also turns the previously clicked value
+    /// into a cursor.
+    pub fn add_cursor(&mut self, address: Address) {
+        if self.cursors.is_empty()
+            && let Some(anchor) = self.selection_anchor
+        {
+            self.cursors.push(anchor);
+        }
+
+        if !self.cursors.contains(&address) {
+            self.cursors.push(address);
+        }
+        self.multi_cursor_digit = None;
+    }
+
+    /// Start selecting a run by dragging from `address`, adding to the selection if `add` is set.
+    pub fn start_drag_selection(&mut self, address: Address, add: bool) {
+        if !add {
+            self.selection.clear();
+        }
+
+        self.selection_anchor = Some(address);
+        self.selection.add_range(address..address + 1);
+        self.drag_selecting = true;
+        self.selected_from_ascii = false;
+        self.selected_edit_address = None;
+        self.force_highlight_address(address);
+    }
+
+    /// Extend the run being drag selected from its anchor to `address`.
+    pub fn extend_drag_selection(&mut self, address: Address) {
+        if let Some(anchor) = self.selection_anchor {
+            self.selection
+                .replace_last_range(anchor.min(address)..anchor.max(address) + 1);
+            self.force_highlight_address(address);
+        }
+    }
+
+    /// Extend the run being drag selected in the ASCII sidebar over every value of the characters from the one at
+    /// the anchor to the one made up of `cell`.
+    pub fn extend_drag_selection_characters(&mut self, cell: Range<Address>) {
+        if let Some(anchor) = self.selection_anchor {
+            self.selection
+                .replace_last_range(anchor.min(cell.start)..(anchor + cell.len()).max(cell.end));
+            self.force_highlight_address(cell.start);
+        }
+    }
+
+    /// The address that navigation should start from, the edit cursor takes precedence over the highlight.
+    #[inline]
+    pub fn cursor_address(&self) -> Option<Address> {
+        self.selected_edit_address.or(self.selected_highlight_address)
+    }
+
     #[inline]
     pub fn should_highlight(&self, address: Address) -> bool {
         (self.selected_highlight_address == Some(address))
             || (self.selected_edit_address == Some(address))
+            || self.cursors.contains(&address)
     }
 
     pub fn should_subtle_highlight(&self, address: Address, data_format: DataFormatType) -> bool {
@@ -1,8 +1,42 @@
 use std::ops::Range;
 
 use egui::Ui;
+#[cfg(feature = "bookmarks")]
+use egui::color_picker::Alpha;
 
-use crate::option_data::{DataFormatType, DataPreviewOptions, Endianness};
+use crate::address_format::AddressFormat;
+#[cfg(feature = "bookmarks")]
+use crate::address_links::draw_linked_text;
+#[cfg(feature = "bookmarks")]
+use crate::bookmarks::Bookmark;
+use crate::bounds;
+use crate::checksum::RowChecksum;
+#[cfg(feature = "inspector")]
+use crate::colours::{self, ColourFormat};
+use crate::copy_formats::DEFAULT_ZERO_RUN;
+#[cfg(feature = "export")]
+use crate::export::CsvLayout;
+use crate::instrumentation::{AccessReason, instrument_write, with_access_reason};
+#[cfg(feature = "bookmarks")]
+use crate::markers::MarkerKind;
+use crate::navigation::{NEXT_DATA_SHORTCUT, PREVIOUS_DATA_SHORTCUT, SearchDirection};
+use crate::notices::Notice;
+#[cfg(feature = "inspector")]
+use crate::option_data::{DataFormatType, Endianness};
+use crate::option_data::{DataPreviewOptions, NonPrintableStyle, ReadOnlyReason, SidebarEncoding};
+use crate::registers::RegisterWidth;
+use crate::resize::ResizeFunctions;
+use crate::scrolling::DEFAULT_ROWS_PER_NOTCH;
+#[cfg(feature = "search")]
+use crate::search::MAX_SEARCH_RESULTS;
+#[cfg(feature = "bookmarks")]
+use crate::selection::Selection;
+use crate::snapshot::Snapshot;
+#[cfg(feature = "inspector")]
+use crate::timestamps::TimestampFormat;
+use crate::utilities::format_byte_size;
+#[cfg(feature = "search")]
+use crate::utilities::{draw_alignment_combo, parse_search_pattern};
 use crate::{Address, MemoryEditor};
 
 impl MemoryEditor {
@@ -12,74 +46,289 @@ impl MemoryEditor {
         ui: &mut Ui,
         mem: &mut T,
         read: &mut impl FnMut(&mut T, Address) -> Option<u8>,
+        write: &mut Option<impl FnMut(&mut T, Address, u8)>,
+        resize: &mut Option<ResizeFunctions<impl FnMut(&mut T, Address, usize), impl FnMut(&mut T, Range<Address>)>>,
     ) {
-        let current_address_range = self
-            .address_ranges
-            .get(&self.options.selected_address_range)
-            .unwrap()
-            .clone();
+        let current_address_range = self.selected_address_space();
 
-        egui::CollapsingHeader::new("🛠 Options")
+        self.update_region_fill(
+            mem,
+            &mut with_access_reason(self.access_reason.clone(), AccessReason::Search, read),
+        );
+
+        let tr = self.translator();
+        let mut header_text = tr.text("🛠 Options").into_owned();
+        if self.frame_data.read_only_reason.is_some() {
+            header_text.push_str(" 🔒");
+        }
+        if self.options.show_region_fill
+            && let Some(fill_text) = self.region_fill_text()
+        {
+            header_text.push_str(&format!(" · {fill_text}"));
+        }
+
+        if self.options.show_menu_bar {
+            self.draw_menu_bar(ui, &current_address_range, mem, read, write, resize);
+            return;
+        }
+
+        let response = egui::CollapsingHeader::new(header_text)
+            .id_salt("🛠 Options")
             .default_open(!self.options.is_options_collapsed)
             .show(ui, |ui| {
                 self.draw_main_options(ui, &current_address_range);
 
+                self.draw_font_warning(ui);
+
+                self.draw_unreadable_options(ui);
+
+                self.draw_navigation_options(ui, &current_address_range, mem, read);
+
+                self.draw_record_options(ui, &current_address_range);
+
+                #[cfg(feature = "search")]
+                self.draw_search_options(ui, &current_address_range, mem, read);
+
+                #[cfg(feature = "tools")]
+                self.draw_pointer_scan_options(ui, &current_address_range, mem, read);
+
+                #[cfg(feature = "tools")]
+                self.draw_signature_options(ui, &current_address_range, mem, read);
+
+                #[cfg(feature = "tools")]
+                self.draw_analysis_options(ui, mem, read);
+
+                #[cfg(feature = "inspector")]
+                self.draw_palette_preview(ui, &current_address_range, mem, read);
+
+                self.draw_pointer_options(ui, &current_address_range);
+
+                self.draw_follow_toggle(ui);
+                self.draw_tail_follow_toggle(ui);
+
+                self.draw_snapshot_options(ui, &current_address_range, mem, read);
+
+                #[cfg(feature = "export")]
+                self.draw_dump_button(ui);
+
+                self.draw_triggers(ui, &current_address_range);
+
+                self.draw_heat_map_options(ui);
+
+                self.draw_selection_options(ui, mem, read, write);
+
+                if let Some(resize) = resize {
+                    self.draw_resize_options(ui, &current_address_range, mem, resize);
+                }
+
+                #[cfg(feature = "bookmarks")]
+                self.draw_bookmarks(ui, &current_address_range);
+
+                self.draw_tag_options(ui, &current_address_range);
+
+                self.draw_colour_rule_options(ui);
+
+                let read = &mut with_access_reason(self.access_reason.clone(), AccessReason::Render, read);
+                self.draw_watches(ui, &current_address_range, mem, read);
+
+                #[cfg(feature = "inspector")]
                 self.draw_data_preview(ui, &current_address_range, mem, read);
             });
+
+        if let Some(reason) = self.frame_data.read_only_reason {
+            response.header_response.on_hover_text(tr.text(reason.description()));
+        }
+    }
+
+    /// Draw the options as a compact `File`/`Edit`/`View`/`Tools` menu bar, rather than the `Options` header.
+    fn draw_menu_bar<T: ?Sized>(
+        &mut self,
+        ui: &mut Ui,
+        current_address_range: &Range<Address>,
+        mem: &mut T,
+        read: &mut impl FnMut(&mut T, Address) -> Option<u8>,
+        write: &mut Option<impl FnMut(&mut T, Address, u8)>,
+        resize: &mut Option<ResizeFunctions<impl FnMut(&mut T, Address, usize), impl FnMut(&mut T, Range<Address>)>>,
+    ) {
+        let tr = self.translator();
+        // The menus contain text fields and checkboxes, which shouldn't close the menu when clicked.
+        let config =
+            egui::containers::menu::MenuConfig::new().close_behavior(egui::PopupCloseBehavior::CloseOnClickOutside);
+
+        egui::MenuBar::new().config(config).ui(ui, |ui| {
+            ui.menu_button(tr.text("File"), |ui| {
+                self.draw_snapshot_options(ui, current_address_range, mem, read);
+                #[cfg(feature = "bookmarks")]
+                self.draw_bookmarks(ui, current_address_range);
+                self.draw_tag_options(ui, current_address_range);
+                #[cfg(feature = "export")]
+                self.draw_dump_button(ui);
+            });
+
+            ui.menu_button(tr.text("Edit"), |ui| {
+                self.draw_selection_options(ui, mem, read, write);
+
+                if let Some(resize) = resize {
+                    self.draw_resize_options(ui, current_address_range, mem, resize);
+                }
+
+                #[cfg(feature = "search")]
+                self.draw_search_options(ui, current_address_range, mem, read);
+            });
+
+            ui.menu_button(tr.text("View"), |ui| {
+                self.draw_main_options(ui, current_address_range);
+                self.draw_font_warning(ui);
+                self.draw_unreadable_options(ui);
+                self.draw_record_options(ui, current_address_range);
+                self.draw_heat_map_options(ui);
+                self.draw_colour_rule_options(ui);
+            });
+
+            ui.menu_button(tr.text("Tools"), |ui| {
+                self.draw_navigation_options(ui, current_address_range, mem, read);
+                #[cfg(feature = "tools")]
+                self.draw_pointer_scan_options(ui, current_address_range, mem, read);
+                #[cfg(feature = "tools")]
+                self.draw_signature_options(ui, current_address_range, mem, read);
+                #[cfg(feature = "tools")]
+                self.draw_analysis_options(ui, mem, read);
+                #[cfg(feature = "inspector")]
+                self.draw_palette_preview(ui, current_address_range, mem, read);
+                self.draw_pointer_options(ui, current_address_range);
+                self.draw_follow_toggle(ui);
+                self.draw_tail_follow_toggle(ui);
+                self.draw_triggers(ui, current_address_range);
+            });
+
+            if let Some(reason) = self.frame_data.read_only_reason {
+                ui.label("🔒").on_hover_text(tr.text(reason.description()));
+            }
+            if self.options.show_region_fill
+                && let Some(fill_text) = self.region_fill_text()
+            {
+                ui.weak(fill_text);
+            }
+        });
+
+        // The watches and the data preview are consulted while editing, so they stay visible below the menu bar.
+        let read = &mut with_access_reason(self.access_reason.clone(), AccessReason::Render, read);
+        self.draw_watches(ui, current_address_range, mem, read);
+        #[cfg(feature = "inspector")]
+        self.draw_data_preview(ui, current_address_range, mem, read);
     }
 
     /// Draw the main options, including the column selection and goto address.
     fn draw_main_options(&mut self, ui: &mut Ui, current_address_range: &Range<Address>) {
+        let tr = self.translator();
         egui::Grid::new("options_grid").show(ui, |ui| {
             // Memory region selection
             if self.frame_data.memory_range_combo_box_enabled {
-                let selected_address_range = &mut self.options.selected_address_range;
-                let address_ranges = &self.address_ranges;
+                let mut selected_address_range = self.window.selected_address_range.clone();
 
                 ui.horizontal(|ui| {
-                    ui.label("Region:");
+                    ui.label(tr.text("Region:"));
 
                     egui::ComboBox::from_id_salt("RegionCombo")
                         .selected_text(selected_address_range.clone())
                         .show_ui(ui, |ui| {
-                            address_ranges.iter().for_each(|(range_name, _)| {
-                                ui.selectable_value(selected_address_range, range_name.clone(), range_name);
-                            });
+                            for (range_name, range) in self.ordered_address_ranges() {
+                                let text = format!(
+                                    "{} (0x{:X}–0x{:X}, {})",
+                                    range_name,
+                                    range.start,
+                                    range.end.saturating_sub(1),
+                                    format_byte_size(range.len())
+                                );
+                                ui.selectable_value(&mut selected_address_range, range_name.clone(), text);
+                            }
                         });
+
+                    if self.frame_data.read_only_reason == Some(ReadOnlyReason::ScratchRegion)
+                        && ui
+                            .small_button("✖")
+                            .on_hover_text(tr.text("Close the scratch region, discarding its data"))
+                            .clicked()
+                    {
+                        self.close_scratch_region(&selected_address_range);
+                        selected_address_range = self.window.selected_address_range.clone();
+                    }
+
+                    if let Some(primary) = &self.frame_data.mirror_of {
+                        ui.label(tr.format("Mirror of {region}", &[("region", primary)]))
+                            .on_hover_text(tr.text("Reads and writes in this region go to the mirrored region"));
+                        ui.checkbox(&mut self.options.grey_mirrored_values, tr.text("Grey"));
+                    }
+
+                    self.draw_ring_buffer_options(ui, current_address_range);
+
+                    ui.checkbox(&mut self.options.sort_address_ranges_alphabetically, tr.text("Sort"))
+                        .on_hover_text(
+                            tr.text("List the regions alphabetically, rather than in the order they were added"),
+                        );
+
+                    ui.checkbox(&mut self.options.show_region_fill, tr.text("Fill"))
+                        .on_hover_text(tr.text("Show how much of the region is non-zero in the options header"));
+                    if self.options.show_region_fill {
+                        let hover = match self.region_fill(&self.window.selected_address_range) {
+                            Some(fill) => format!(
+                                "{} of {} values are non-zero, {} are unreadable\nClick to measure again",
+                                fill.non_zero, fill.len, fill.unreadable
+                            ),
+                            None => "Measure how much of the region is non-zero".to_string(),
+                        };
+                        if ui.small_button("⟳").on_hover_text(hover).clicked() {
+                            self.frame_data.refresh_region_fill = true;
+                        }
+                    }
                 });
+
+                self.select_address_range(selected_address_range);
             };
 
             // Column dragger
-            let mut columns_u8 = self.options.column_count as u8;
-
             if self.options.is_resizable_column {
                 ui.add(
-                    egui::DragValue::new(&mut columns_u8)
-                        .range(1.0..=64.0)
-                        .prefix("Columns: ")
+                    egui::DragValue::new(&mut self.options.column_count)
+                        .range(1..=self.options.max_column_count.max(1))
+                        .prefix(tr.text("Columns: "))
                         .speed(0.5),
                 );
             } else {
-                ui.add(egui::Label::new(format!("Columns: {}", columns_u8)));
+                ui.add(egui::Label::new(
+                    tr.format("Columns: {count}", &[("count", &self.options.column_count)]),
+                ));
             }
 
-            self.options.column_count = columns_u8 as usize;
-
             // Goto address
             let response = ui
                 .add_sized(
                     ui.available_size(),
                     egui::TextEdit::singleline(&mut self.frame_data.goto_address_string).hint_text("0000"),
                 )
-                .on_hover_text(
+                .on_hover_text(tr.text(
                     "Goto an address, format: \n\
                     * An address like `0xAA` can be written as `AA`\n\
                     * Offset from the base address, if the base is `0xFF00` then one can enter `5` to go to `0xFF05`\n\
+                    * In the bank or segment notation of the addresses, like `3:0005`\n\
+                    * An expression with `+ - * &`, decimal numbers like `#12`, symbol names, and the `$cursor` and \
+                    `$sel_start` variables, host variables like registers, e.g. `sprite_table + 4*#12`\n\
                     Press enter to move to the address",
-                );
-            ui.label(format!("Goto: {:#X?}", current_address_range));
-
-            self.frame_data.goto_address_string.retain(|c| c.is_ascii_hexdigit());
+                ));
+            if response.changed() {
+                self.frame_data.goto_error = None;
+            }
+            match &self.frame_data.goto_error {
+                Some(error) => ui.colored_label(ui.visuals().error_fg_color, error),
+                None => ui.label(format!(
+                    "{} {:#X?}",
+                    tr.text("Goto:"),
+                    self.options.word_size.word_range(current_address_range.clone())
+                )),
+            };
+            self.draw_help_button(ui);
+            #[cfg(feature = "scripting")]
+            self.draw_console_button(ui);
 
             // For some reason egui is triggering response.clicked() when we press enter at the moment
             // (didn't used to do this). The additional check for not having enter pressed will need to stay until that is fixed.
@@ -89,15 +338,15 @@ impl MemoryEditor {
 
             // If we pressed enter, move to the address
             if response.lost_focus() && ui.input(|i| i.key_pressed(egui::Key::Enter)) {
+                let evaluated = self.evaluate_goto_expression(&self.frame_data.goto_address_string);
+                let word_size = self.options.word_size;
                 let goto_address_string = &mut self.frame_data.goto_address_string;
 
-                if goto_address_string.starts_with("0x") || goto_address_string.starts_with("0X") {
-                    *goto_address_string = goto_address_string[2..].to_string();
-                }
-
-                let address = Address::from_str_radix(goto_address_string, 16).ok().and_then(|addr| {
+                let address = evaluated.clone().ok().and_then(|(addr, is_literal)| {
                     if current_address_range.contains(&addr) {
                         Some(addr)
+                    } else if !is_literal {
+                        None
                     } else {
                         // For brevity the user should be able to elide the base address, e.g when using the range
                         // 0xFF00..0xFFFF the user can write 0x5 to go to 0xFF05
@@ -105,7 +354,7 @@ impl MemoryEditor {
 
                         if current_address_range.contains(&offset_addr) {
                             // We're doing an offset jump, we should update the string to reflect the absolute address
-                            *goto_address_string = format!("{:X}", offset_addr);
+                            *goto_address_string = format!("{:X}", word_size.word_address(offset_addr));
                             Some(offset_addr)
                         } else {
                             None
@@ -117,6 +366,15 @@ impl MemoryEditor {
                     .and_then(|addr| addr.checked_sub(current_address_range.start))
                     .map(|addr| addr / self.options.column_count);
                 self.frame_data.selected_highlight_address = address;
+                self.frame_data.goto_error = match evaluated {
+                    Err(error) => Some(error),
+                    Ok((addr, _)) if address.is_none() => {
+                        self.frame_data.notify(Notice::JumpOutOfBounds { address: addr });
+                        let address = format!("{:#X}", word_size.word_address(addr));
+                        Some(tr.format("{address} is outside of the region", &[("address", &address)]))
+                    }
+                    Ok(_) => None,
+                };
 
                 response.surrender_focus();
             }
@@ -127,17 +385,801 @@ impl MemoryEditor {
             let show_ascii_sidebar = &mut self.options.show_ascii;
             let show_zero_colour = &mut self.options.show_zero_colour;
 
-            ui.checkbox(show_ascii_sidebar, "Show ASCII").on_hover_text(format!(
-                "{} the ASCII representation view",
-                if *show_ascii_sidebar { "Disable" } else { "Enable" }
+            ui.checkbox(show_ascii_sidebar, tr.text("Show ASCII"))
+                .on_hover_text(tr.text(if *show_ascii_sidebar {
+                    "Disable the ASCII representation view"
+                } else {
+                    "Enable the ASCII representation view"
+                }));
+
+            ui.checkbox(show_zero_colour, tr.text("Custom zero colour"))
+                .on_hover_text(tr.text("If enabled memory values of '0x00' will be coloured differently"));
+
+            ui.add_enabled_ui(self.options.show_ascii, |ui| {
+                let encoding = &mut self.options.sidebar_encoding;
+                egui::ComboBox::from_id_salt("SidebarEncoding")
+                    .selected_text(encoding.name())
+                    .show_ui(ui, |ui| {
+                        for option in SidebarEncoding::iter() {
+                            ui.selectable_value(encoding, option, option.name());
+                        }
+                    })
+                    .response
+                    .on_hover_text(
+                        tr.text("How values are decoded into characters, UTF-16 uses two values per character"),
+                    );
+
+                let style = &mut self.options.non_printable_style;
+                egui::ComboBox::from_id_salt("NonPrintableStyle")
+                    .selected_text(style.name())
+                    .show_ui(ui, |ui| {
+                        for option in NonPrintableStyle::iter() {
+                            ui.selectable_value(style, option, option.name());
+                        }
+                    })
+                    .response
+                    .on_hover_text(tr.text("How characters which can't be printed are displayed"));
+            });
+
+            ui.add_enabled(
+                self.options.show_ascii,
+                egui::Checkbox::new(&mut self.options.interleave_ascii, tr.text("Interleave ASCII")),
+            )
+            .on_hover_text(tr.text("Show the characters of every group of 8 values directly after them"));
+
+            ui.checkbox(&mut self.options.highlight_non_printable, tr.text("Dim non-printable"))
+                .on_hover_text(
+                    tr.text("Colour non-printable characters in the ASCII sidebar differently, making text stand out"),
+                );
+
+            ui.checkbox(&mut self.options.nibble_editing, tr.text("Nibble cursor"))
+                .on_hover_text(
+                    tr.text("Edit values one hex digit at a time, every typed digit is written immediately"),
+                );
+
+            ui.checkbox(&mut self.options.show_address_column, tr.text("Show addresses"))
+                .on_hover_text(tr.text("Show the address of each row, when hidden hover a value to see its address"));
+
+            ui.checkbox(&mut self.options.show_menu_bar, tr.text("Menu bar"))
+                .on_hover_text(tr.text("Show the options in a compact menu bar, rather than a collapsing header"));
+
+            ui.horizontal(|ui| {
+                let format = &mut self.options.address_format;
+                egui::ComboBox::from_id_salt("AddressFormat")
+                    .selected_text(format.name())
+                    .show_ui(ui, |ui| {
+                        for option in AddressFormat::iter() {
+                            let is_selected = std::mem::discriminant(format) == std::mem::discriminant(&option);
+                            // Keep the bank size when the banked notation is selected again.
+                            if ui.selectable_label(is_selected, option.name()).clicked() && !is_selected {
+                                *format = option;
+                            }
+                        }
+                    })
+                    .response
+                    .on_hover_text(
+                        tr.text("The notation of addresses, in the address column, the goto box, and exports"),
+                    );
+
+                if let AddressFormat::Banked { bank_size } = format {
+                    ui.add(
+                        egui::DragValue::new(bank_size)
+                            .range(1..=usize::MAX)
+                            .hexadecimal(1, false, true)
+                            .prefix(tr.text("Bank: 0x")),
+                    )
+                    .on_hover_text(tr.text("The size of a single bank"));
+                }
+            });
+
+            ui.checkbox(&mut self.options.show_column_header, tr.text("Show offsets"))
+                .on_hover_text(tr.text("Show the offset of each column, click an offset to select that column"));
+
+            ui.checkbox(&mut self.options.show_gutter_markers, tr.text("Show markers"))
+                .on_hover_text(tr.text("Show markers for bookmarks, annotations, and changes next to the addresses"));
+
+            ui.checkbox(&mut self.options.show_symbol_separators, tr.text("Section rows"))
+                .on_hover_text(tr.text("Show a labelled row before every line containing a symbol or annotation"));
+
+            ui.checkbox(&mut self.options.highlight_recent_changes, tr.text("Flash changes"))
+                .on_hover_text(
+                    tr.text("Highlight values which change between frames, the highlight fades out over time"),
+                );
+
+            ui.checkbox(&mut self.options.show_highlight_legend, tr.text("Show legend"))
+                .on_hover_text(tr.text("Show the colours of the active highlights, click one to hide or show it"));
+
+            ui.checkbox(&mut self.options.show_range_brackets, tr.text("Range brackets"))
+                .on_hover_text(
+                    tr.text("Show a labelled bracket next to highlights and bookmarks spanning multiple rows"),
+                );
+
+            if !self.permissions.is_empty() {
+                ui.checkbox(&mut self.options.show_permissions, tr.text("Shade permissions"))
+                    .on_hover_text(
+                        tr.text("Shade read-only memory blue, executable memory red, and inaccessible memory grey"),
+                    );
+            }
+
+            #[cfg(feature = "tools")]
+            if self.disassembler.is_some() {
+                ui.checkbox(&mut self.options.show_disassembly, tr.text("Disassembly"))
+                    .on_hover_text(tr.text("Show the disassembly of the memory around the cursor in a side panel"));
+            }
+
+            egui::ComboBox::from_id_salt("RowChecksumCombo")
+                .selected_text(match self.options.row_checksum {
+                    Some(checksum) => tr.format("Checksum: {checksum}", &[("checksum", &checksum.name())]),
+                    None => tr.text("Checksum: None").into_owned(),
+                })
+                .show_ui(ui, |ui| {
+                    ui.selectable_value(&mut self.options.row_checksum, None, tr.text("None"));
+                    for checksum in RowChecksum::iter() {
+                        ui.selectable_value(&mut self.options.row_checksum, Some(checksum), checksum.name());
+                    }
+                })
+                .response
+                .on_hover_text(tr.text("Show a checksum of every row after the values"));
+
+            egui::ComboBox::from_id_salt("RegisterViewCombo")
+                .selected_text(match self.options.register_view {
+                    Some(width) => format!("{} {}", width.name(), tr.text("registers")),
+                    None => tr.text("Hex grid").into_owned(),
+                })
+                .show_ui(ui, |ui| {
+                    ui.selectable_value(&mut self.options.register_view, None, tr.text("Hex grid"));
+                    for width in RegisterWidth::iter() {
+                        let text = format!("{} {}", width.name(), tr.text("registers"));
+                        ui.selectable_value(&mut self.options.register_view, Some(width), text);
+                    }
+                })
+                .response
+                .on_hover_text(tr.text("Show one register per row with its name and bits, for small I/O regions"));
+
+            ui.checkbox(&mut self.options.verify_writes, tr.text("Verify writes"))
+                .on_hover_text(
+                    tr.text("Read values again after writing them, marking values which read back differently"),
+                );
+
+            ui.checkbox(&mut self.options.touch_mode, tr.text("Touch mode"))
+                .on_hover_text(tr.text(
+                    "Larger values to tap, drag to scroll, double tap to edit and long press for the context menu",
+                ));
+
+            ui.horizontal(|ui| {
+                ui.label(tr.text("Spacing:"));
+                ui.add(
+                    egui::DragValue::new(&mut self.options.value_spacing)
+                        .range(0.0..=32.0)
+                        .speed(0.1),
+                )
+                .on_hover_text(tr.text("The space between values"));
+                ui.add(
+                    egui::DragValue::new(&mut self.options.group_spacing)
+                        .range(0.0..=64.0)
+                        .speed(0.1),
+                )
+                .on_hover_text(tr.text("The space between groups of 8 values and between columns"));
+                ui.add(
+                    egui::DragValue::new(&mut self.options.row_padding)
+                        .range(0.0..=32.0)
+                        .speed(0.1),
+                )
+                .on_hover_text(tr.text("The extra height of every row"));
+            });
+
+            let mut custom_scroll_speed = self.options.scroll_rows_per_notch.is_some();
+            if ui
+                .checkbox(&mut custom_scroll_speed, tr.text("Scroll speed:"))
+                .on_hover_text(tr.text("Scroll a fixed amount of rows per notch of the mouse wheel"))
+                .changed()
+            {
+                self.options.scroll_rows_per_notch = custom_scroll_speed.then_some(DEFAULT_ROWS_PER_NOTCH);
+            }
+            if let Some(rows) = &mut self.options.scroll_rows_per_notch {
+                ui.add(egui::DragValue::new(rows).range(1..=100).suffix(tr.text(" rows")));
+            }
+
+            ui.checkbox(&mut self.options.snap_scroll_to_rows, tr.text("Snap to rows"))
+                .on_hover_text(tr.text("Scroll by whole rows, such that the top row is never cut off"));
+
+            ui.checkbox(&mut self.options.animate_jumps, tr.text("Animate jumps"))
+                .on_hover_text(tr.text("Scroll smoothly to the target of a jump, and flash the value jumped to"));
+
+            ui.checkbox(&mut self.options.collapse_repeated_lines, tr.text("Collapse repeats"))
+                .on_hover_text(
+                    tr.text("Show consecutive identical lines as a single `*` row, click the row to expand it"),
+                );
+
+            // Locking only makes sense if we could write in the first place.
+            if self.frame_data.read_only_reason != Some(ReadOnlyReason::NoWriteFunction) {
+                ui.checkbox(&mut self.options.is_read_only, tr.text("🔒 Read-only"))
+                    .on_hover_text(tr.text("Disable editing of the memory"));
+            }
+        });
+    }
+
+    /// Draw the buttons for jumping between the data in the current address range.
+    fn draw_navigation_options<T: ?Sized>(
+        &mut self,
+        ui: &mut Ui,
+        current_address_range: &Range<Address>,
+        mem: &mut T,
+        read: &mut impl FnMut(&mut T, Address) -> Option<u8>,
+    ) {
+        let tr = self.translator();
+        ui.horizontal(|ui| {
+            ui.label(tr.text("Data:"));
+
+            let previous_shortcut = ui.ctx().format_shortcut(&PREVIOUS_DATA_SHORTCUT);
+            if ui
+                .button(tr.text("⏴ Previous"))
+                .on_hover_text(format!(
+                    "Jump to the previous value which isn't the fill value ({previous_shortcut})"
+                ))
+                .clicked()
+            {
+                let read = &mut with_access_reason(self.access_reason.clone(), AccessReason::Search, read);
+                self.jump_to_data(mem, read, current_address_range, SearchDirection::Backward);
+            }
+
+            let next_shortcut = ui.ctx().format_shortcut(&NEXT_DATA_SHORTCUT);
+            if ui
+                .button(tr.text("Next ⏵"))
+                .on_hover_text(format!(
+                    "Jump to the next value which isn't the fill value ({next_shortcut})"
+                ))
+                .clicked()
+            {
+                let read = &mut with_access_reason(self.access_reason.clone(), AccessReason::Search, read);
+                self.jump_to_data(mem, read, current_address_range, SearchDirection::Forward);
+            }
+
+            ui.add(
+                egui::DragValue::new(&mut self.options.navigation_fill_value)
+                    .hexadecimal(2, false, true)
+                    .prefix(tr.text("Fill: 0x")),
+            )
+            .on_hover_text(tr.text("Values equal to the fill value are skipped when jumping"));
+
+            ui.separator();
+
+            if ui
+                .button(tr.text("📋 Copy view"))
+                .on_hover_text(tr.text("Copy the visible rows as text, formatted like they are displayed"))
+                .clicked()
+            {
+                let read = &mut with_access_reason(self.access_reason.clone(), AccessReason::Export, read);
+                let text = self.format_rows(mem, read, self.visible_range.clone(), current_address_range);
+                ui.ctx().copy_text(text);
+            }
+        });
+    }
+
+    /// Draw the search box for finding byte patterns or text.
+    #[cfg(feature = "search")]
+    fn draw_search_options<T: ?Sized>(
+        &mut self,
+        ui: &mut Ui,
+        current_address_range: &Range<Address>,
+        mem: &mut T,
+        read: &mut impl FnMut(&mut T, Address) -> Option<u8>,
+    ) {
+        let tr = self.translator();
+        ui.horizontal(|ui| {
+            ui.label(tr.text("Find:"));
+
+            let response = ui
+                .add(
+                    egui::TextEdit::singleline(&mut self.frame_data.search_string)
+                        .hint_text("DE AD BE EF")
+                        .desired_width(150.0),
+                )
+                .on_hover_text(tr.text(
+                    "Search for hexadecimal bytes like `DE AD BE EF`, or text in quotes like `\"Hello\"`.\n\
+                    Press enter to find the next occurrence",
+                ));
+
+            let pattern = parse_search_pattern(&self.frame_data.search_string);
+            let submitted = response.lost_focus() && ui.input(|i| i.key_pressed(egui::Key::Enter));
+            let search_all_regions = self.options.search_all_regions && self.frame_data.memory_range_combo_box_enabled;
+            let mut direction = (submitted && !search_all_regions).then_some(SearchDirection::Forward);
+            let mut find_all = submitted && search_all_regions;
+
+            ui.add_enabled_ui(pattern.is_some(), |ui| {
+                if search_all_regions {
+                    find_all |= ui
+                        .button(tr.text("Find all"))
+                        .on_hover_text(tr.text("Find every occurrence in all regions"))
+                        .clicked();
+                    return;
+                }
+
+                if ui
+                    .button("⏴")
+                    .on_hover_text(tr.text("Find the previous occurrence"))
+                    .clicked()
+                {
+                    direction = Some(SearchDirection::Backward);
+                }
+                if ui
+                    .button("⏵")
+                    .on_hover_text(tr.text("Find the next occurrence"))
+                    .clicked()
+                {
+                    direction = Some(SearchDirection::Forward);
+                }
+            });
+
+            if let Some(pattern) = pattern.clone().filter(|_| find_all) {
+                // Searched at the start of the next frame, with the read function of every region.
+                self.frame_data.pending_region_search = Some(pattern);
+            } else if let (Some(pattern), Some(direction)) = (pattern, direction) {
+                let read = &mut with_access_reason(self.access_reason.clone(), AccessReason::Search, read);
+                let found = self.find_pattern(mem, read, &pattern, current_address_range, direction);
+                self.frame_data.search_not_found = found.is_none();
+            } else if response.changed() {
+                self.frame_data.search_not_found = false;
+            }
+
+            draw_alignment_combo(ui, &tr, "SearchAlignment", &mut self.options.search_alignment);
+
+            if self.frame_data.memory_range_combo_box_enabled {
+                ui.checkbox(&mut self.options.search_all_regions, tr.text("All regions"))
+                    .on_hover_text(tr.text("Find every occurrence in all regions, listed by region"));
+            }
+
+            if self.frame_data.search_not_found {
+                ui.colored_label(ui.visuals().warn_fg_color, tr.text("Not found"));
+            }
+        });
+
+        self.draw_search_results(ui);
+    }
+
+    /// Draw the matches of the last search across all regions grouped by region, clicking a match switches to its
+    /// region and selects it.
+    #[cfg(feature = "search")]
+    fn draw_search_results(&mut self, ui: &mut Ui) {
+        if self.frame_data.search_results.is_empty() {
+            return;
+        }
+        let tr = self.translator();
+
+        let mut to_select = None;
+        let mut clear = false;
+        let results = &self.frame_data.search_results;
+        let header = if results.len() >= MAX_SEARCH_RESULTS {
+            format!("{} {} {}", tr.text("First"), results.len(), tr.text("matches"))
+        } else {
+            format!("{} {}", results.len(), tr.text("matches"))
+        };
+
+        egui::CollapsingHeader::new(header)
+            .id_salt("mem_edit_search_results")
+            .default_open(true)
+            .show(ui, |ui| {
+                egui::ScrollArea::vertical().max_height(160.0).show(ui, |ui| {
+                    for group in results.chunk_by(|a, b| a.region == b.region) {
+                        ui.strong(format!("{} ({})", group[0].region, group.len()));
+                        let format_address =
+                            self.address_formatter(&self.address_range(&group[0].region).cloned().unwrap_or_default());
+                        ui.horizontal_wrapped(|ui| {
+                            for found in group {
+                                if ui.link(format_address(found.address)).clicked() {
+                                    to_select = Some(found.clone());
+                                }
+                            }
+                        });
+                    }
+                });
+
+                clear = ui.small_button(tr.text("Clear")).clicked();
+            });
+
+        if let Some(found) = to_select {
+            self.select_search_match(&found, self.frame_data.search_result_len);
+        }
+        if clear {
+            self.frame_data.search_results.clear();
+        }
+    }
+
+    /// Draw the controls for taking a snapshot of the current address range, and jumping between the changes since.
+    fn draw_snapshot_options<T: ?Sized>(
+        &mut self,
+        ui: &mut Ui,
+        current_address_range: &Range<Address>,
+        mem: &mut T,
+        read: &mut impl FnMut(&mut T, Address) -> Option<u8>,
+    ) {
+        let tr = self.translator();
+        ui.horizontal(|ui| {
+            ui.label(tr.text("Snapshot:"));
+
+            if ui
+                .button(tr.text("📷 Take"))
+                .on_hover_text(
+                    tr.text("Capture the current address range, values which change afterwards will be highlighted"),
+                )
+                .clicked()
+            {
+                let read = &mut with_access_reason(self.access_reason.clone(), AccessReason::Snapshot, read);
+                self.frame_data.snapshot = Some(Snapshot::capture(mem, read, current_address_range));
+            }
+
+            ui.add_enabled_ui(self.frame_data.snapshot.is_some(), |ui| {
+                if ui
+                    .button(tr.text("🗑 Clear"))
+                    .on_hover_text(tr.text("Discard the snapshot"))
+                    .clicked()
+                {
+                    self.frame_data.snapshot = None;
+                }
+
+                if ui
+                    .button(tr.text("⏴ Previous change"))
+                    .on_hover_text(tr.text("Jump to the previous value which differs from the snapshot"))
+                    .clicked()
+                {
+                    let read = &mut with_access_reason(self.access_reason.clone(), AccessReason::Search, read);
+                    self.jump_to_change(mem, read, current_address_range, SearchDirection::Backward);
+                }
+
+                if ui
+                    .button(tr.text("Next change ⏵"))
+                    .on_hover_text(tr.text("Jump to the next value which differs from the snapshot"))
+                    .clicked()
+                {
+                    let read = &mut with_access_reason(self.access_reason.clone(), AccessReason::Search, read);
+                    self.jump_to_change(mem, read, current_address_range, SearchDirection::Forward);
+                }
+
+                #[cfg(feature = "export")]
+                if ui
+                    .button(tr.text("📋 Copy diff"))
+                    .on_hover_text(
+                        tr.text("Copy every value which changed since the snapshot, as `address: old → new`"),
+                    )
+                    .clicked()
+                {
+                    let read = with_access_reason(self.access_reason.clone(), AccessReason::Export, read);
+                    if let Some(diff) = self.export_snapshot_diff(mem, read) {
+                        ui.ctx().copy_text(diff);
+                    }
+                }
+            });
+        });
+    }
+
+    /// Draw a summary of the current selection, and the operations which can be applied to it.
+    fn draw_selection_options<T: ?Sized>(
+        &mut self,
+        ui: &mut Ui,
+        mem: &mut T,
+        read: &mut impl FnMut(&mut T, Address) -> Option<u8>,
+        write: &mut Option<impl FnMut(&mut T, Address, u8)>,
+    ) {
+        let tr = self.translator();
+        ui.horizontal(|ui| {
+            ui.label(tr.text("Selection:")).on_hover_text(tr.text(
+                "Shift+click a value to select a range from the highlighted value, \
+                Ctrl+click to add another disjoint range.\n\
+                Click an address to select its row, and Ctrl+A to select everything.\n\
+                Press +/- to increment/decrement the selected values, hold Shift to change them by 0x10",
+            ));
+
+            let selection = &self.frame_data.selection;
+
+            if selection.is_empty() {
+                ui.label(tr.text("None"));
+                return;
+            }
+
+            ui.label(format!(
+                "{} bytes in {} run(s)",
+                selection.len(),
+                selection.ranges().len()
             ));
 
-            ui.checkbox(show_zero_colour, "Custom zero colour")
-                .on_hover_text("If enabled memory values of '0x00' will be coloured differently");
+            if ui
+                .button(tr.text("📋 Copy"))
+                .on_hover_text(tr.text("Copy the selected values as hex"))
+                .clicked()
+            {
+                let read = &mut with_access_reason(self.access_reason.clone(), AccessReason::Export, read);
+                self.copy_selection(ui.ctx(), mem, read);
+            }
+
+            let mut skip_zero_runs = self.options.skip_zero_runs.is_some();
+            if ui
+                .checkbox(&mut skip_zero_runs, tr.text("Skip zeros"))
+                .on_hover_text(
+                    tr.text("Replace long runs of 00 by a line with the amount of skipped bytes in hex copies"),
+                )
+                .changed()
+            {
+                self.options.skip_zero_runs = skip_zero_runs.then_some(DEFAULT_ZERO_RUN);
+            }
+            if let Some(min_run) = &mut self.options.skip_zero_runs {
+                ui.add(egui::DragValue::new(min_run).range(1..=usize::MAX))
+                    .on_hover_text(tr.text("The shortest run of zeroes which is skipped"));
+            }
+
+            ui.menu_button(tr.text("Text"), |ui| {
+                for (escape_non_printable, text) in [(false, "Skip non-printable"), (true, "Escape non-printable")] {
+                    if ui.button(tr.text(text)).clicked() {
+                        let read = &mut with_access_reason(self.access_reason.clone(), AccessReason::Export, read);
+                        self.copy_selection_as_text(ui.ctx(), mem, read, escape_non_printable);
+                        ui.close();
+                    }
+                }
+            })
+            .response
+            .on_hover_text(
+                tr.text("Copy the selected values as text in the sidebar's encoding, such as strings in memory"),
+            );
+
+            #[cfg(feature = "export")]
+            ui.menu_button(tr.text("CSV"), |ui| {
+                for (layout, text) in [
+                    (CsvLayout::AddressValue, "Copy as address,value"),
+                    (CsvLayout::Rows, "Copy as rows"),
+                ] {
+                    if ui.button(tr.text(text)).clicked() {
+                        let csv = self
+                            .frame_data
+                            .selection
+                            .merged_ranges()
+                            .into_iter()
+                            .map(|range| {
+                                let read = &mut with_access_reason(
+                                    self.access_reason.clone(),
+                                    AccessReason::Export,
+                                    &mut *read,
+                                );
+                                self.export_csv(mem, read, range, layout)
+                            })
+                            .collect::<Vec<_>>()
+                            .join("\n");
+
+                        ui.ctx().copy_text(csv);
+                        ui.close();
+                    }
+                }
+            })
+            .response
+            .on_hover_text(tr.text("Copy the selected values as CSV, for use in spreadsheets"));
+
+            #[cfg(any(feature = "export", feature = "tools"))]
+            let read = &mut with_access_reason(self.access_reason.clone(), AccessReason::Export, &mut *read);
+            #[cfg(feature = "export")]
+            self.draw_save_menu(ui, mem, read);
+            #[cfg(feature = "bookmarks")]
+            self.draw_keep_selection_menu(ui);
+            #[cfg(feature = "tools")]
+            self.draw_transform_menu(ui, mem, read);
+
+            ui.add_enabled_ui(write.is_some(), |ui| {
+                ui.add(
+                    egui::DragValue::new(&mut self.frame_data.selection_fill_value)
+                        .hexadecimal(2, false, true)
+                        .prefix("0x"),
+                );
+
+                if ui
+                    .button(tr.text("Fill"))
+                    .on_hover_text(tr.text("Write the value to every selected address"))
+                    .clicked()
+                    && let Some(write) = instrument_write(self.access_hook.clone(), AccessReason::Edit, write).as_mut()
+                {
+                    self.fill_selection(mem, write, self.frame_data.selection_fill_value);
+                }
+            });
+
+            if ui.button(tr.text("🗑 Clear")).clicked() {
+                self.frame_data.selection.clear();
+            }
+        });
+
+        #[cfg(feature = "tools")]
+        if let Some(error) = &self.frame_data.transform_error {
+            ui.colored_label(ui.visuals().error_fg_color, error);
+        }
+    }
+
+    /// Draw the controls for growing and shrinking the memory, only available when resize functions were provided.
+    fn draw_resize_options<T: ?Sized>(
+        &mut self,
+        ui: &mut Ui,
+        current_address_range: &Range<Address>,
+        mem: &mut T,
+        resize: &mut ResizeFunctions<impl FnMut(&mut T, Address, usize), impl FnMut(&mut T, Range<Address>)>,
+    ) {
+        let tr = self.translator();
+        ui.horizontal(|ui| {
+            ui.label(tr.text("Resize:"));
+
+            ui.checkbox(&mut self.frame_data.insert_mode, tr.text("Insert mode"))
+                .on_hover_text(
+                    tr.text("Typed values are inserted before the edited value, rather than overwriting it (Insert)"),
+                );
+
+            ui.add(
+                egui::DragValue::new(&mut self.frame_data.insert_count)
+                    .range(1..=0x10000)
+                    .prefix(tr.text("Bytes: ")),
+            );
+
+            if ui
+                .button(tr.text("Insert"))
+                .on_hover_text(
+                    tr.text(
+                        "Insert zeroed bytes before the highlighted value, or at the end if nothing is highlighted",
+                    ),
+                )
+                .clicked()
+            {
+                let address = self
+                    .frame_data
+                    .cursor_address()
+                    .filter(|address| current_address_range.contains(address))
+                    .unwrap_or(current_address_range.end);
+
+                self.insert_bytes(mem, resize, address, self.frame_data.insert_count);
+            }
+
+            ui.add_enabled_ui(!self.frame_data.selection.is_empty(), |ui| {
+                if ui.button(tr.text("Delete selection")).clicked() {
+                    self.delete_selection(mem, resize, current_address_range);
+                }
+            });
         });
     }
 
+    /// Draws the list of bookmarks underneath a collapsing header.
+    #[cfg(feature = "bookmarks")]
+    fn draw_bookmarks(&mut self, ui: &mut Ui, current_address_range: &Range<Address>) {
+        let tr = self.translator();
+        egui::CollapsingHeader::new(tr.text("🔖 Bookmarks"))
+            .default_open(false)
+            .show(ui, |ui| {
+                let mut to_remove = None;
+                let mut to_jump = None;
+                let mut to_jump_address = None;
+
+                let categories = self.bookmark_categories();
+                if !categories.is_empty() {
+                    let filter = &mut self.frame_data.bookmark_category_filter;
+                    egui::ComboBox::from_label(tr.text("Category"))
+                        .selected_text(filter.clone().unwrap_or_else(|| tr.text("All").into_owned()))
+                        .show_ui(ui, |ui| {
+                            ui.selectable_value(filter, None, tr.text("All"));
+                            for category in categories {
+                                ui.selectable_value(filter, Some(category.clone()), category);
+                            }
+                        });
+                }
+
+                let format_address = self.address_formatter(current_address_range);
+                egui::Grid::new("bookmarks_grid").show(ui, |ui| {
+                    let filter = &self.frame_data.bookmark_category_filter;
+                    let editing_notes = &mut self.frame_data.editing_bookmark_notes;
+                    for (i, bookmark) in self.bookmarks.iter_mut().enumerate() {
+                        if filter.is_some() && bookmark.category != *filter {
+                            continue;
+                        }
+                        let in_range = current_address_range.contains(&bookmark.address);
+
+                        let mut colour = bookmark.colour.unwrap_or(MarkerKind::Bookmark.colour());
+                        if egui::color_picker::color_edit_button_srgba(ui, &mut colour, Alpha::Opaque).changed() {
+                            bookmark.colour = Some(colour);
+                        }
+
+                        if ui
+                            .add_enabled(in_range, egui::Button::new(bookmark.location(&format_address)))
+                            .on_hover_text(tr.text("Jump to the bookmark"))
+                            .on_disabled_hover_text(tr.text("The bookmark is not part of the selected address range"))
+                            .clicked()
+                        {
+                            to_jump = Some(bookmark.clone());
+                        }
+
+                        let response = ui.label(&bookmark.name);
+                        if !bookmark.notes.is_empty() {
+                            response.on_hover_ui(|ui| {
+                                if let Some(address) = draw_linked_text(ui, &bookmark.notes, None) {
+                                    to_jump_address = Some(address);
+                                }
+                            });
+                        }
+                        ui.weak(bookmark.category.as_deref().unwrap_or_default());
+
+                        let is_editing = *editing_notes == Some(i);
+                        if ui
+                            .selectable_label(is_editing, "📝")
+                            .on_hover_text(tr.text("Edit the notes of the bookmark"))
+                            .clicked()
+                        {
+                            *editing_notes = if is_editing { None } else { Some(i) };
+                        }
+
+                        if ui
+                            .small_button("🗑")
+                            .on_hover_text(tr.text("Remove the bookmark"))
+                            .clicked()
+                        {
+                            to_remove = Some(i);
+                        }
+
+                        ui.end_row();
+                    }
+                });
+
+                if let Some(address) = to_jump_address {
+                    self.jump_to_address(address, current_address_range);
+                }
+                if let Some(bookmark) = to_jump {
+                    // Range bookmarks select their range, to keep it visible while looking at the start.
+                    if bookmark.end.is_some() {
+                        self.set_selection(Selection::from_range(bookmark.range()));
+                    }
+                    self.jump_to_address(bookmark.address, current_address_range);
+                }
+                if let Some(index) = to_remove {
+                    self.remove_bookmark(index);
+                    self.frame_data.editing_bookmark_notes = None;
+                }
+
+                if let Some(bookmark) = self
+                    .frame_data
+                    .editing_bookmark_notes
+                    .and_then(|index| self.bookmarks.get_mut(index))
+                {
+                    ui.label(format!("{} {}", tr.text("Notes of"), bookmark.name));
+                    ui.add(egui::TextEdit::multiline(&mut bookmark.notes).desired_rows(3));
+                }
+
+                ui.horizontal(|ui| {
+                    ui.add(
+                        egui::TextEdit::singleline(&mut self.frame_data.new_bookmark_name)
+                            .hint_text(tr.text("Name"))
+                            .desired_width(120.0),
+                    );
+                    ui.add(
+                        egui::TextEdit::singleline(&mut self.frame_data.new_bookmark_category)
+                            .hint_text(tr.text("Category"))
+                            .desired_width(80.0),
+                    );
+
+                    let highlighted = self.frame_data.selected_highlight_address;
+                    if ui
+                        .add_enabled(
+                            highlighted.is_some(),
+                            egui::Button::new(tr.text("Bookmark highlighted")),
+                        )
+                        .on_disabled_hover_text(tr.text("Right click a value to highlight it first"))
+                        .clicked()
+                        && let Some(address) = highlighted
+                    {
+                        let name = std::mem::take(&mut self.frame_data.new_bookmark_name);
+                        let mut bookmark = Bookmark::new(name, address);
+                        if !self.frame_data.new_bookmark_category.is_empty() {
+                            bookmark = bookmark.with_category(self.frame_data.new_bookmark_category.clone());
+                        }
+                        self.add_bookmark(bookmark);
+                    }
+                });
+
+                #[cfg(feature = "serde")]
+                self.draw_bookmark_json_options(ui);
+
+                self.draw_address_list_import(ui);
+            });
+    }
+
     /// Draws the data preview underneath a collapsing header.
+    #[cfg(feature = "inspector")]
     fn draw_data_preview<T: ?Sized>(
         &mut self,
         ui: &mut Ui,
@@ -145,13 +1187,14 @@ impl MemoryEditor {
         mem: &mut T,
         read: &mut impl FnMut(&mut T, Address) -> Option<u8>,
     ) {
-        let response = egui::CollapsingHeader::new("⛃ Data Preview")
+        let tr = self.translator();
+        let response = egui::CollapsingHeader::new(tr.text("⛃ Data Preview"))
             .default_open(false)
             .show(ui, |ui| {
                 egui::Grid::new("data_preview_grid").show(ui, |ui| {
                     let data_preview_options = &mut self.options.data_preview;
                     // Format selection
-                    egui::ComboBox::from_label("Endianness")
+                    egui::ComboBox::from_label(tr.text("Endianness"))
                         .selected_text(format!("{:?}", data_preview_options.selected_endianness))
                         .show_ui(ui, |ui| {
                             for endian in Endianness::iter() {
@@ -163,9 +1206,9 @@ impl MemoryEditor {
                             }
                         })
                         .response
-                        .on_hover_text("Select the endianness of the data");
+                        .on_hover_text(tr.text("Select the endianness of the data"));
 
-                    egui::ComboBox::from_label("Format")
+                    egui::ComboBox::from_label(tr.text("Format"))
                         .selected_text(format!("{:?}", data_preview_options.selected_data_format))
                         .show_ui(ui, |ui| {
                             for format in DataFormatType::iter() {
@@ -177,24 +1220,91 @@ impl MemoryEditor {
                             }
                         })
                         .response
-                        .on_hover_text("Select the number type for data interpretation");
+                        .on_hover_text(tr.text("Select the number type for data interpretation"));
 
                     ui.end_row();
 
                     // Read and display the value
-                    let hover_text = "Right click a value in the UI to select it, right click again to unselect";
+                    let hover_text =
+                        tr.text("Right click a value in the UI to select it, right click again to unselect");
 
                     if let Some(address) = self.frame_data.selected_highlight_address {
                         let value =
                             Self::read_mem_value(mem, read, address, *data_preview_options, current_address_range);
-                        ui.label(format!("Value at {:#X} (decimal): ", address))
+                        let address = format!("{:#X}", address);
+                        ui.label(tr.format("Value at {address} (decimal): ", &[("address", &address)]))
                             .on_hover_text(hover_text);
                         ui.label(value);
                     } else {
-                        ui.label("Value (decimal): ").on_hover_text(hover_text);
-                        ui.label("None");
+                        ui.label(tr.text("Value (decimal): ")).on_hover_text(hover_text);
+                        ui.label(tr.text("None"));
+                    }
+
+                    ui.end_row();
+
+                    // The fixed-point and BCD interpretations, alongside the selected format.
+                    let fixed_point = &mut data_preview_options.fixed_point;
+                    ui.horizontal(|ui| {
+                        ui.label(tr.text("Fixed point:"));
+                        egui::ComboBox::from_id_salt("FixedPointSize")
+                            .selected_text(tr.format("{count} bytes", &[("count", &fixed_point.bytes)]))
+                            .width(70.0)
+                            .show_ui(ui, |ui| {
+                                for bytes in [1, 2, 4, 8] {
+                                    ui.selectable_value(
+                                        &mut fixed_point.bytes,
+                                        bytes,
+                                        tr.format("{count} bytes", &[("count", &bytes)]),
+                                    );
+                                }
+                            });
+                        let bits = 8 * fixed_point.bytes as u32;
+                        ui.add(
+                            egui::DragValue::new(&mut fixed_point.fraction_bits)
+                                .range(0..=bits)
+                                .prefix(tr.text("Fraction: ")),
+                        )
+                        .on_hover_text(tr.text("The amount of bits after the binary point"));
+                        ui.checkbox(&mut fixed_point.signed, tr.text("Signed"));
+                    });
+                    let fixed_point = *fixed_point;
+                    let endianness = data_preview_options.selected_endianness;
+
+                    match self.frame_data.selected_highlight_address {
+                        Some(address) => {
+                            let bytes =
+                                bounds::read_padded(mem, read, address, fixed_point.bytes, current_address_range);
+                            ui.label(format!("{} ({})", fixed_point.decode(&bytes, endianness), fixed_point));
+                        }
+                        None => {
+                            ui.label(tr.text("None"));
+                        }
+                    }
+
+                    ui.end_row();
+
+                    ui.label(tr.text("Packed BCD:"))
+                        .on_hover_text(tr.text("Two decimal digits per byte, using the size of the selected format"));
+                    match self.frame_data.selected_highlight_address {
+                        Some(address) => {
+                            let len = data_preview_options.selected_data_format.bytes_to_read();
+                            let bytes = bounds::read_padded(mem, read, address, len, current_address_range);
+                            ui.label(
+                                crate::utilities::slice_to_bcd_string(endianness, &bytes)
+                                    .unwrap_or_else(|| "Invalid".to_string()),
+                            );
+                        }
+                        None => {
+                            ui.label(tr.text("None"));
+                        }
                     }
                 });
+
+                self.draw_timestamp_preview(ui, current_address_range, mem, read);
+                self.draw_colour_preview(ui, current_address_range, mem, read);
+
+                #[cfg(feature = "plot")]
+                self.draw_selection_chart(ui, mem, read, current_address_range);
             });
 
         // Currently relies on the header being open_default(false), otherwise we'd enable the highlight when closing the preview!
@@ -203,24 +1313,76 @@ impl MemoryEditor {
         }
     }
 
-    fn read_mem_value<T: ?Sized>(
+    pub(crate) fn read_mem_value<T: ?Sized>(
         mem: &mut T,
         read_fn: &mut impl FnMut(&mut T, Address) -> Option<u8>,
         address: Address,
         data_preview: DataPreviewOptions,
         address_space: &Range<Address>,
     ) -> String {
-        let bytes = (0..data_preview.selected_data_format.bytes_to_read())
-            .map(|i| {
-                let read_address = address + i;
-                if address_space.contains(&read_address) {
-                    read_fn(mem, read_address).unwrap_or(0)
-                } else {
-                    0
-                }
-            })
-            .collect::<Vec<u8>>();
+        let len = data_preview.selected_data_format.bytes_to_read();
+        let bytes = bounds::read_padded(mem, read_fn, address, len, address_space);
 
         crate::utilities::slice_to_decimal_string(data_preview, &bytes)
     }
+
+    /// Draw the value at the highlighted address decoded as every timestamp format.
+    #[cfg(feature = "inspector")]
+    fn draw_timestamp_preview<T: ?Sized>(
+        &mut self,
+        ui: &mut Ui,
+        current_address_range: &Range<Address>,
+        mem: &mut T,
+        read: &mut impl FnMut(&mut T, Address) -> Option<u8>,
+    ) {
+        let tr = self.translator();
+        let Some(address) = self.frame_data.selected_highlight_address else {
+            return;
+        };
+        let endianness = self.options.data_preview.selected_endianness;
+
+        ui.collapsing(tr.text("🕒 Timestamps"), |ui| {
+            egui::Grid::new("timestamp_preview_grid").show(ui, |ui| {
+                for format in TimestampFormat::iter() {
+                    let bytes = bounds::read_padded(mem, read, address, format.bytes(), current_address_range);
+                    ui.label(format!("{}:", format.name()));
+                    ui.label(
+                        format
+                            .decode(&bytes, endianness)
+                            .unwrap_or_else(|| "Invalid".to_string()),
+                    );
+                    ui.end_row();
+                }
+            });
+        });
+    }
+
+    /// Draw the value at the highlighted address decoded as every colour format, with a swatch of each colour.
+    #[cfg(feature = "inspector")]
+    fn draw_colour_preview<T: ?Sized>(
+        &mut self,
+        ui: &mut Ui,
+        current_address_range: &Range<Address>,
+        mem: &mut T,
+        read: &mut impl FnMut(&mut T, Address) -> Option<u8>,
+    ) {
+        let tr = self.translator();
+        let Some(address) = self.frame_data.selected_highlight_address else {
+            return;
+        };
+        let endianness = self.options.data_preview.selected_endianness;
+
+        ui.collapsing(tr.text("🎨 Colours"), |ui| {
+            egui::Grid::new("colour_preview_grid").show(ui, |ui| {
+                for format in ColourFormat::iter() {
+                    let bytes = bounds::read_padded(mem, read, address, format.bytes(), current_address_range);
+                    let colour = format.decode(&bytes, endianness);
+                    ui.label(format!("{}:", format.name()));
+                    colours::draw_colour_swatch(ui, colour, egui::vec2(32.0, ui.spacing().interact_size.y));
+                    ui.label(colours::colour_to_hex(colour));
+                    ui.end_row();
+                }
+            });
+        });
+    }
 }
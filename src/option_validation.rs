@@ -0,0 +1,177 @@
+use std::fmt;
+
+use egui::Ui;
+
+use crate::MemoryEditor;
+use crate::option_data::MemoryEditorOptions;
+use crate::translations::Translator;
+
+/// A problem found in the options, and how it was fixed, see [`MemoryEditor::validate_options`].
+#[derive(Clone, Debug, PartialEq)]
+pub enum OptionProblem {
+    /// The column count was outside of `1..=max_column_count`, and was clamped.
+    ColumnCount { column_count: usize, clamped: usize },
+    /// The selected address range doesn't exist, such as after restoring persisted options, and the first address
+    /// range was selected instead.
+    MissingAddressRange { range_name: String, fallback: String },
+    /// The search alignment was `0`, and was set to `1`.
+    SearchAlignment,
+    /// The record size was `0`, and records were disabled.
+    RecordSize,
+    /// The fade duration of recent changes was negative or not a number, and was reset to the default.
+    FadeSeconds { seconds: f32 },
+    /// A spacing or padding was negative or not a number, and was reset to the default.
+    Spacing { spacing: f32 },
+}
+
+impl OptionProblem {
+    /// The translated description of the problem.
+    pub(crate) fn message(&self, tr: &Translator) -> String {
+        match self {
+            OptionProblem::ColumnCount { column_count, clamped } => tr.format(
+                "Column count {count} is out of range, using {clamped}",
+                &[("count", column_count), ("clamped", clamped)],
+            ),
+            OptionProblem::MissingAddressRange { range_name, fallback } => tr.format(
+                "Address range `{name}` doesn't exist, showing `{fallback}`",
+                &[("name", range_name), ("fallback", fallback)],
+            ),
+            OptionProblem::SearchAlignment => tr.text("Search alignment 0 is invalid, using 1").into_owned(),
+            OptionProblem::RecordSize => tr.text("Record size 0 is invalid, records are disabled").into_owned(),
+            OptionProblem::FadeSeconds { seconds } => tr.format(
+                "Change fade duration {seconds} is invalid, using the default",
+                &[("seconds", seconds)],
+            ),
+            OptionProblem::Spacing { spacing } => tr.format(
+                "Spacing {spacing} is invalid, using the default",
+                &[("spacing", spacing)],
+            ),
+        }
+    }
+}
+
+impl fmt::Display for OptionProblem {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.message(&Translator::default()))
+    }
+}
+
+impl std::error::Error for OptionProblem {}
+
+impl MemoryEditor {
+    /// Fix options which can't be used as they are, such as persisted options which no longer match the address
+    /// ranges, by clamping them or falling back to a sensible value.
+    ///
+    /// This is done every frame before drawing, with the problems shown above the options until dismissed. Returns
+    /// the problems which were fixed, if any.
+    ///
+    /// ```
+    /// # use egui_memory_editor::{MemoryEditor, OptionProblem};
+    /// # use egui_memory_editor::option_data::MemoryEditorOptions;
+    /// let mut options = MemoryEditorOptions::default();
+    /// options.column_count = 0;
+    /// options.record_size = Some(0);
+    /// let mut memory_editor = MemoryEditor::new()
+    ///     .with_address_range("RAM", 0..0x100)
+    ///     .with_options(options);
+    ///
+    /// let problems = memory_editor.validate_options().unwrap_err();
+    /// assert_eq!(problems, [OptionProblem::ColumnCount { column_count: 0, clamped: 1 }, OptionProblem::RecordSize]);
+    /// assert_eq!(memory_editor.options.column_count, 1);
+    /// assert_eq!(memory_editor.validate_options(), Ok(()));
+    /// ```
+    pub fn validate_options(&mut self) -> Result<(), Vec<OptionProblem>> {
+        let options = &mut self.options;
+        let mut problems = Vec::new();
+
+        let clamped = options.column_count.clamp(1, options.max_column_count.max(1));
+        if clamped != options.column_count {
+            problems.push(OptionProblem::ColumnCount {
+                column_count: options.column_count,
+                clamped,
+            });
+            options.column_count = clamped;
+        }
+
+        // Without any address ranges there's nothing to fall back to yet, the first range added will be selected.
+        let is_missing = !self
+            .address_ranges
+            .iter()
+            .any(|(name, _)| *name == self.window.selected_address_range);
+        if is_missing && let Some((fallback, _)) = self.address_ranges.first() {
+            let range_name = std::mem::replace(&mut self.window.selected_address_range, fallback.clone());
+            // Default options don't select any range, which isn't a problem.
+            if !range_name.is_empty() {
+                problems.push(OptionProblem::MissingAddressRange {
+                    range_name,
+                    fallback: fallback.clone(),
+                });
+            }
+        }
+
+        if options.search_alignment == 0 {
+            problems.push(OptionProblem::SearchAlignment);
+            options.search_alignment = 1;
+        }
+
+        if options.record_size == Some(0) {
+            problems.push(OptionProblem::RecordSize);
+            options.record_size = None;
+        }
+
+        let seconds = options.recent_change_fade_seconds;
+        if !(seconds.is_finite() && seconds >= 0.0) {
+            problems.push(OptionProblem::FadeSeconds { seconds });
+            options.recent_change_fade_seconds = MemoryEditorOptions::default().recent_change_fade_seconds;
+        }
+
+        let defaults = MemoryEditorOptions::default();
+        for (spacing, default) in [
+            (&mut options.value_spacing, defaults.value_spacing),
+            (&mut options.group_spacing, defaults.group_spacing),
+            (&mut options.row_padding, defaults.row_padding),
+        ] {
+            if !(spacing.is_finite() && *spacing >= 0.0) {
+                problems.push(OptionProblem::Spacing { spacing: *spacing });
+                *spacing = default;
+            }
+        }
+
+        if problems.is_empty() { Ok(()) } else { Err(problems) }
+    }
+
+    /// The problems found in the options since they were last dismissed.
+    pub fn option_problems(&self) -> &[OptionProblem] {
+        &self.frame_data.option_problems
+    }
+
+    /// Validate the options, keeping any problems to show them until they're dismissed.
+    pub(crate) fn validate_options_for_frame(&mut self) {
+        if let Err(problems) = self.validate_options() {
+            self.frame_data.option_problems.extend(problems);
+        }
+    }
+
+    /// Draw a warning for every problem found in the options, with a button to dismiss them.
+    pub(crate) fn draw_option_problems(&mut self, ui: &mut Ui) {
+        if self.frame_data.option_problems.is_empty() {
+            return;
+        }
+        let tr = self.translator();
+
+        ui.horizontal(|ui| {
+            ui.vertical(|ui| {
+                for problem in &self.frame_data.option_problems {
+                    ui.colored_label(ui.visuals().warn_fg_color, format!("⚠ {}", problem.message(&tr)));
+                }
+            });
+            if ui
+                .small_button("✖")
+                .on_hover_text(tr.text("Dismiss the warnings"))
+                .clicked()
+            {
+                self.frame_data.option_problems.clear();
+            }
+        });
+    }
+}
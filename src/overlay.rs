@@ -0,0 +1,93 @@
+//! Letting the host paint on top of the values, such as arrows between related addresses, see
+//! [`MemoryEditor::with_overlay_painter`].
+use std::collections::BTreeMap;
+use std::ops::Range;
+use std::sync::Arc;
+
+use egui::{Painter, Rect, Ui};
+
+use crate::{Address, MemoryEditor};
+
+pub(crate) type OverlayPainter = Arc<dyn Fn(&Painter, &CellRects) + Send + Sync>;
+
+/// The screen rectangles of the value cells drawn in a frame, by address.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct CellRects {
+    rects: BTreeMap<Address, Rect>,
+}
+
+impl CellRects {
+    /// The rectangle of the value cell at `address`, or `None` if it wasn't drawn.
+    pub fn get(&self, address: Address) -> Option<Rect> {
+        self.rects.get(&address).copied()
+    }
+
+    /// The drawn cells in ascending order of address.
+    pub fn iter(&self) -> impl Iterator<Item = (Address, Rect)> + '_ {
+        self.rects.iter().map(|(address, rect)| (*address, *rect))
+    }
+
+    /// The rectangles of the drawn cells of `range`, one per row, each spanning from the first to the last cell of
+    /// the range in that row.
+    pub fn row_spans(&self, range: Range<Address>) -> Vec<Rect> {
+        let mut spans: Vec<Rect> = Vec::new();
+
+        for (_, rect) in self.rects.range(range) {
+            match spans.last_mut() {
+                Some(span) if span.y_range() == rect.y_range() => *span = span.union(*rect),
+                _ => spans.push(*rect),
+            }
+        }
+
+        spans
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.rects.is_empty()
+    }
+
+    pub(crate) fn insert(&mut self, address: Address, rect: Rect) {
+        self.rects.insert(address, rect);
+    }
+
+    pub(crate) fn clear(&mut self) {
+        self.rects.clear();
+    }
+}
+
+impl MemoryEditor {
+    /// Paint on top of the values every frame, such as arrows, braces, or boxes connecting related addresses.
+    ///
+    /// The painter is clipped to the scrolling rows, and the [`CellRects`] resolve the addresses drawn this frame to
+    /// their position on screen.
+    ///
+    /// ```
+    /// # use egui_memory_editor::MemoryEditor;
+    /// let memory_editor = MemoryEditor::new()
+    ///     .with_address_range("RAM", 0..0x10000)
+    ///     .with_overlay_painter(|painter, cells| {
+    ///         // Connect a pointer at 0x100 to its target at 0x180.
+    ///         if let (Some(from), Some(to)) = (cells.get(0x100), cells.get(0x180)) {
+    ///             let stroke = egui::Stroke::new(1.5, egui::Color32::LIGHT_BLUE);
+    ///             painter.arrow(from.center(), to.center() - from.center(), stroke);
+    ///         }
+    ///     });
+    /// ```
+    #[must_use]
+    pub fn with_overlay_painter(mut self, painter: impl Fn(&Painter, &CellRects) + Send + Sync + 'static) -> Self {
+        self.overlay_painter = Some(Arc::new(painter));
+        self
+    }
+
+    /// The screen rectangles of the value cells drawn in the last frame.
+    pub fn cell_rects(&self) -> &CellRects {
+        &self.frame_data.cell_rects
+    }
+
+    /// Call the host's overlay painter, clipped to `clip_rect`.
+    pub(crate) fn paint_overlay(&self, ui: &Ui, clip_rect: Rect) {
+        if let Some(overlay_painter) = &self.overlay_painter {
+            overlay_painter(&ui.painter().with_clip_rect(clip_rect), &self.frame_data.cell_rects);
+        }
+    }
+}
@@ -0,0 +1,62 @@
+use egui::Context;
+use egui::panel::Side;
+
+use crate::resize::DummyResizeFunctions;
+use crate::{Address, MemoryEditor};
+
+impl MemoryEditor {
+    /// Render the memory editor contents in a side panel, rather than a window.
+    ///
+    /// The panel is as wide as the editor contents, growing and shrinking along with them.
+    /// Should be called before any central panel, see [`egui::SidePanel`].
+    ///
+    /// See [`Self::window_ui`] for the arguments.
+    ///
+    /// ```no_run
+    /// # use egui_memory_editor::MemoryEditor;
+    /// # let ctx = egui::Context::default();
+    /// let mut memory = vec![0u8; 0x100];
+    /// let mut memory_editor = MemoryEditor::new().with_address_range("Memory", 0..0x100);
+    ///
+    /// memory_editor.side_panel_ui(
+    ///     &ctx,
+    ///     egui::panel::Side::Right,
+    ///     &mut memory,
+    ///     |mem, addr| mem.get(addr).copied(),
+    ///     |mem, addr, value| mem[addr] = value,
+    /// );
+    /// ```
+    pub fn side_panel_ui<T: ?Sized>(
+        &mut self,
+        ctx: &Context,
+        side: Side,
+        mem: &mut T,
+        read_fn: impl FnMut(&mut T, Address) -> Option<u8>,
+        write_fn: impl FnMut(&mut T, Address, u8),
+    ) {
+        egui::SidePanel::new(side, egui::Id::new(&self.window_name))
+            .resizable(false)
+            .show(ctx, |ui| {
+                self.shrink_window_ui(ui);
+                self.draw_editor_contents_impl(ui, mem, read_fn, Some(write_fn), None::<DummyResizeFunctions<T>>);
+            });
+    }
+
+    /// Render the memory editor contents in the central panel, taking up the space left by any other panels.
+    ///
+    /// Unlike the window and side panel, the width of the central panel is determined by the other panels, so it isn't
+    /// shrunk to the editor contents. Should be called after all other panels, see [`egui::CentralPanel`].
+    ///
+    /// See [`Self::window_ui`] for the arguments.
+    pub fn central_panel_ui<T: ?Sized>(
+        &mut self,
+        ctx: &Context,
+        mem: &mut T,
+        read_fn: impl FnMut(&mut T, Address) -> Option<u8>,
+        write_fn: impl FnMut(&mut T, Address, u8),
+    ) {
+        egui::CentralPanel::default().show(ctx, |ui| {
+            self.draw_editor_contents_impl(ui, mem, read_fn, Some(write_fn), None::<DummyResizeFunctions<T>>);
+        });
+    }
+}
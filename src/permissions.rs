@@ -0,0 +1,136 @@
+//! Access permissions of address ranges, like the page protections of a process, see [`Permissions`].
+use std::ops::Range;
+
+use egui::Color32;
+
+use crate::{Address, MemoryEditor};
+
+/// Whether memory can be read, written, and executed.
+///
+/// Values which can't be read are never read and displayed as unreadable, values which can't be written can't be
+/// edited, and the background of values is shaded according to their permissions.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Permissions {
+    pub read: bool,
+    pub write: bool,
+    pub execute: bool,
+}
+
+impl Permissions {
+    pub const NONE: Self = Self::new(false, false, false);
+    pub const READ: Self = Self::new(true, false, false);
+    pub const READ_WRITE: Self = Self::new(true, true, false);
+    pub const READ_EXECUTE: Self = Self::new(true, false, true);
+    pub const ALL: Self = Self::new(true, true, true);
+
+    pub const fn new(read: bool, write: bool, execute: bool) -> Self {
+        Permissions { read, write, execute }
+    }
+
+    /// The background shading for values with these permissions, plain read/write memory isn't shaded.
+    fn shade_colour(&self) -> Option<Color32> {
+        match (self.read, self.write, self.execute) {
+            (false, ..) => Some(Color32::from_rgba_unmultiplied(128, 128, 128, 40)),
+            (true, _, true) => Some(Color32::from_rgba_unmultiplied(220, 60, 60, 40)),
+            (true, false, false) => Some(Color32::from_rgba_unmultiplied(60, 120, 220, 40)),
+            (true, true, false) => None,
+        }
+    }
+}
+
+impl std::fmt::Display for Permissions {
+    /// Formatted like process memory maps, e.g. `r-x`.
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let flag = |set: bool, c: char| if set { c } else { '-' };
+        write!(
+            f,
+            "{}{}{}",
+            flag(self.read, 'r'),
+            flag(self.write, 'w'),
+            flag(self.execute, 'x')
+        )
+    }
+}
+
+impl std::str::FromStr for Permissions {
+    type Err = String;
+
+    /// Parse permissions formatted like process memory maps, where every flag is either set or a `-`.
+    ///
+    /// ```
+    /// # use egui_memory_editor::permissions::Permissions;
+    /// assert_eq!("r-x".parse(), Ok(Permissions::READ_EXECUTE));
+    /// assert_eq!("rw".parse(), Ok(Permissions::READ_WRITE));
+    /// assert!("rwz".parse::<Permissions>().is_err());
+    /// ```
+    fn from_str(text: &str) -> Result<Self, Self::Err> {
+        if let Some(invalid) = text.chars().find(|c| !matches!(c, 'r' | 'w' | 'x' | '-')) {
+            return Err(format!(
+                "`{invalid}` is not a permission, expected `r`, `w`, `x`, or `-`"
+            ));
+        }
+
+        Ok(Permissions::new(
+            text.contains('r'),
+            text.contains('w'),
+            text.contains('x'),
+        ))
+    }
+}
+
+impl MemoryEditor {
+    /// Set the permissions of an address range, see [`Self::set_permissions`].
+    #[must_use]
+    pub fn with_permissions(mut self, address_range: Range<Address>, permissions: Permissions) -> Self {
+        self.set_permissions(address_range, permissions);
+        self
+    }
+
+    /// Set the permissions of an address range, where ranges set later take precedence over overlapping earlier ones.
+    /// Addresses outside of every range can be read and written.
+    ///
+    /// ```
+    /// # use egui_memory_editor::MemoryEditor;
+    /// # use egui_memory_editor::permissions::Permissions;
+    /// let mut memory_editor = MemoryEditor::new()
+    ///     .with_address_range("Process", 0..0x3000)
+    ///     .with_permissions(0x0000..0x1000, Permissions::READ_EXECUTE)
+    ///     .with_permissions(0x2000..0x3000, Permissions::NONE);
+    ///
+    /// assert_eq!(memory_editor.permissions_at(0x0800), Permissions::READ_EXECUTE);
+    /// assert_eq!(memory_editor.permissions_at(0x1800), Permissions::READ_WRITE);
+    /// assert_eq!(memory_editor.permissions_at(0x2800).to_string(), "---");
+    /// ```
+    pub fn set_permissions(&mut self, address_range: Range<Address>, permissions: Permissions) {
+        self.permissions.push((address_range, permissions));
+    }
+
+    /// Remove all permissions, making all memory readable and writable again.
+    pub fn clear_permissions(&mut self) {
+        self.permissions.clear();
+    }
+
+    /// The permissions of the given address.
+    pub fn permissions_at(&self, address: Address) -> Permissions {
+        permissions_at(&self.permissions, address)
+    }
+
+    /// The background shading of the given address according to its permissions.
+    pub(crate) fn permission_colour(&self, address: Address) -> Option<Color32> {
+        if self.options.show_permissions {
+            self.permissions_at(address).shade_colour()
+        } else {
+            None
+        }
+    }
+}
+
+/// The permissions of `address` according to the ranges in `permissions`, the last matching range takes precedence.
+pub(crate) fn permissions_at(permissions: &[(Range<Address>, Permissions)], address: Address) -> Permissions {
+    permissions
+        .iter()
+        .rev()
+        .find(|(range, _)| range.contains(&address))
+        .map_or(Permissions::READ_WRITE, |(_, permissions)| *permissions)
+}
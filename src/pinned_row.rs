@@ -0,0 +1,79 @@
+use std::ops::Range;
+
+use egui::{Label, RichText, Sense, TextWrapMode, Ui, Vec2, Widget};
+
+use crate::markers::MarkerKind;
+use crate::resize::ResizeFunctions;
+use crate::{Address, MemoryEditor};
+
+impl MemoryEditor {
+    /// The start of the row pinned as a reference above the scrolling rows, if any.
+    pub fn pinned_row(&self) -> Option<Address> {
+        self.frame_data.pinned_row
+    }
+
+    /// Pin the row containing `address` above the scrolling rows, to compare it with rows far away from it, or unpin
+    /// the row with `None`.
+    pub fn set_pinned_row(&mut self, address: Option<Address>) {
+        self.frame_data.pinned_row = address;
+    }
+
+    /// Draw the pinned row, if it's part of the address range, laid out like the rows underneath it.
+    ///
+    /// Clicking its address unpins the row.
+    #[allow(clippy::too_many_arguments)]
+    pub(crate) fn draw_pinned_row<T: ?Sized>(
+        &mut self,
+        ui: &mut Ui,
+        mem: &mut T,
+        read_fn: &mut impl FnMut(&mut T, Address) -> Option<u8>,
+        write_fn: &mut Option<impl FnMut(&mut T, Address, u8)>,
+        resize_fns: &mut Option<
+            ResizeFunctions<impl FnMut(&mut T, Address, usize), impl FnMut(&mut T, Range<Address>)>,
+        >,
+        address_space: &Range<Address>,
+    ) {
+        let tr = self.translator();
+        let Some(pinned) = self
+            .frame_data
+            .pinned_row
+            .filter(|address| address_space.contains(address))
+        else {
+            return;
+        };
+        let column_count = self.options.column_count;
+        let start_address = pinned - (pinned - address_space.start) % column_count;
+        let address_text = self.address_formatter(address_space)(start_address);
+        let mut unpin = false;
+
+        egui::Grid::new("mem_edit_pinned_row")
+            .spacing(Vec2::new(self.options.group_spacing, ui.style().spacing.item_spacing.y))
+            .show(ui, |ui| {
+                ui.style_mut().wrap_mode = Some(TextWrapMode::Extend);
+                ui.style_mut().spacing.item_spacing.x = self.value_spacing();
+
+                ui.horizontal(|ui| {
+                    if self.options.show_gutter_markers {
+                        ui.add_space(MarkerKind::lane_width());
+                    }
+
+                    let text = RichText::new(format!("{address_text}:"))
+                        .color(self.options.highlight_text_colour)
+                        .text_style(self.options.memory_editor_address_text_style.clone());
+                    unpin = Label::new(text)
+                        .sense(Sense::click())
+                        .ui(ui)
+                        .on_hover_text(tr.text("📌 Pinned as a reference, click to unpin"))
+                        .clicked();
+                });
+
+                self.draw_row_values(ui, mem, read_fn, write_fn, resize_fns, start_address, address_space);
+                ui.end_row();
+            });
+        ui.separator();
+
+        if unpin {
+            self.frame_data.pinned_row = None;
+        }
+    }
+}
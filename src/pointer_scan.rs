@@ -0,0 +1,252 @@
+//! Scanning memory for values which look like pointers to a given address, useful for reverse engineering data
+//! structures.
+//!
+//! ```
+//! # use egui_memory_editor::pointer_scan::{self, PointerScan, PointerWidth};
+//! # use egui_memory_editor::option_data::Endianness;
+//! let mut memory = vec![0u8; 0x20];
+//! memory[0x8..0xC].copy_from_slice(&0x1234u32.to_le_bytes());
+//! memory[0x13..0x17].copy_from_slice(&0x1234u32.to_le_bytes());
+//!
+//! let scan = PointerScan {
+//!     width: PointerWidth::Bits32,
+//!     endianness: Endianness::Little,
+//!     alignment: 1,
+//! };
+//! let references = pointer_scan::find_pointers_to(&mut memory, |mem, addr| mem.get(addr).copied(), 0..0x20, 0x1234, scan);
+//! assert_eq!(references, vec![0x8, 0x13]);
+//!
+//! let aligned = PointerScan { alignment: 4, ..scan };
+//! let references = pointer_scan::find_pointers_to(&mut memory, |mem, addr| mem.get(addr).copied(), 0..0x20, 0x1234, aligned);
+//! assert_eq!(references, vec![0x8]);
+//! ```
+use std::ops::Range;
+
+use egui::Ui;
+
+use crate::instrumentation::{AccessReason, with_access_reason};
+use crate::option_data::Endianness;
+use crate::utilities::draw_alignment_combo;
+use crate::{Address, MemoryEditor};
+
+/// The maximum amount of results listed in the UI.
+const MAX_LISTED_RESULTS: usize = 1000;
+
+/// The size of a pointer in memory.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum PointerWidth {
+    Bits16,
+    Bits32,
+    Bits64,
+}
+
+impl PointerWidth {
+    pub fn iter() -> impl Iterator<Item = PointerWidth> {
+        [PointerWidth::Bits16, PointerWidth::Bits32, PointerWidth::Bits64].into_iter()
+    }
+
+    /// The amount of bytes a pointer takes up in memory.
+    pub const fn bytes(&self) -> usize {
+        match self {
+            PointerWidth::Bits16 => 2,
+            PointerWidth::Bits32 => 4,
+            PointerWidth::Bits64 => 8,
+        }
+    }
+}
+
+/// How pointers are encoded in memory.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct PointerScan {
+    pub width: PointerWidth,
+    pub endianness: Endianness,
+    /// Pointers are only expected at addresses which are a multiple of this many bytes, `1` allows any address.
+    pub alignment: usize,
+}
+
+impl Default for PointerScan {
+    fn default() -> Self {
+        PointerScan {
+            width: PointerWidth::Bits32,
+            endianness: Endianness::Little,
+            alignment: 1,
+        }
+    }
+}
+
+/// The state of the pointer scan in the UI.
+#[derive(Clone, Debug, Default)]
+pub(crate) struct PointerScanState {
+    pub target_string: String,
+    pub scan: PointerScan,
+    /// Whether to only scan the selection, rather than the whole address range.
+    pub selection_only: bool,
+    /// The target and the addresses referencing it, of the last scan.
+    pub results: Option<(Address, Vec<Address>)>,
+}
+
+/// Find every address in `range` at which the pointer `target` is stored, encoded as described by `scan`.
+///
+/// Pointers which would extend past the end of the range, or which contain an unreadable byte, are never matched.
+pub fn find_pointers_to<T: ?Sized>(
+    mem: &mut T,
+    mut read_fn: impl FnMut(&mut T, Address) -> Option<u8>,
+    range: Range<Address>,
+    target: Address,
+    scan: PointerScan,
+) -> Vec<Address> {
+    let width = scan.width.bytes();
+    let target = target as u64;
+    let mut bytes = [0u8; 8];
+    let mut found = Vec::new();
+
+    // A target which doesn't fit in the pointer width can never be found.
+    if width < 8 && target >> (8 * width) != 0 {
+        return found;
+    }
+
+    for address in range.start..range.end.saturating_sub(width - 1) {
+        if !address.is_multiple_of(scan.alignment.max(1)) {
+            continue;
+        }
+
+        let pointer = &mut bytes[..width];
+        let is_readable = pointer
+            .iter_mut()
+            .enumerate()
+            .all(|(offset, byte)| read_fn(mem, address + offset).map(|value| *byte = value).is_some());
+
+        if !is_readable {
+            continue;
+        }
+
+        let value = match scan.endianness {
+            Endianness::Big => pointer.iter().fold(0u64, |acc, &byte| (acc << 8) | byte as u64),
+            Endianness::Little => pointer.iter().rev().fold(0u64, |acc, &byte| (acc << 8) | byte as u64),
+        };
+
+        if value == target {
+            found.push(address);
+        }
+    }
+
+    found
+}
+
+impl MemoryEditor {
+    /// Draw the pointer scan, which lists every address in the current address range referencing a target address.
+    pub(crate) fn draw_pointer_scan_options<T: ?Sized>(
+        &mut self,
+        ui: &mut Ui,
+        current_address_range: &Range<Address>,
+        mem: &mut T,
+        read: &mut impl FnMut(&mut T, Address) -> Option<u8>,
+    ) {
+        let tr = self.translator();
+        let cursor = self.frame_data.cursor_address();
+        let mut to_jump = None;
+
+        ui.collapsing(tr.text("Pointer scan"), |ui| {
+            let state = &mut self.frame_data.pointer_scan;
+
+            ui.horizontal(|ui| {
+                ui.label(tr.text("Target:"));
+                let hint = cursor.map_or_else(|| "0000".to_string(), |address| format!("{:X}", address));
+                ui.add(
+                    egui::TextEdit::singleline(&mut state.target_string)
+                        .hint_text(hint)
+                        .desired_width(100.0),
+                )
+                .on_hover_text(tr.text("The address to find references to, defaults to the cursor"));
+                state
+                    .target_string
+                    .retain(|c| c.is_ascii_hexdigit() || c == 'x' || c == 'X');
+
+                egui::ComboBox::from_id_salt("PointerScanWidth")
+                    .selected_text(tr.format("{count} bytes", &[("count", &state.scan.width.bytes())]))
+                    .show_ui(ui, |ui| {
+                        for width in PointerWidth::iter() {
+                            ui.selectable_value(
+                                &mut state.scan.width,
+                                width,
+                                tr.format("{count} bytes", &[("count", &width.bytes())]),
+                            );
+                        }
+                    });
+
+                egui::ComboBox::from_id_salt("PointerScanEndianness")
+                    .selected_text(format!("{:?}", state.scan.endianness))
+                    .show_ui(ui, |ui| {
+                        for endianness in Endianness::iter() {
+                            ui.selectable_value(&mut state.scan.endianness, endianness, format!("{:?}", endianness));
+                        }
+                    });
+
+                draw_alignment_combo(ui, &tr, "PointerScanAlignment", &mut state.scan.alignment);
+                ui.checkbox(&mut state.selection_only, tr.text("Selection only"));
+            });
+
+            let target_string = state.target_string.trim_start_matches("0x").trim_start_matches("0X");
+            let target = if target_string.is_empty() {
+                cursor
+            } else {
+                Address::from_str_radix(target_string, 16).ok()
+            };
+
+            ui.horizontal(|ui| {
+                if ui
+                    .add_enabled(target.is_some(), egui::Button::new(tr.text("🔍 Scan")))
+                    .on_hover_text(tr.text("List every address which appears to reference the target"))
+                    .clicked()
+                    && let Some(target) = target
+                {
+                    let ranges = if state.selection_only {
+                        self.frame_data.selection.merged_ranges()
+                    } else {
+                        vec![current_address_range.clone()]
+                    };
+                    let read = &mut with_access_reason(self.access_reason.clone(), AccessReason::Search, read);
+                    let scan = state.scan;
+                    let found = ranges
+                        .into_iter()
+                        .flat_map(|range| find_pointers_to(mem, &mut *read, range, target, scan))
+                        .collect();
+
+                    state.results = Some((target, found));
+                }
+
+                if let Some((target, results)) = &state.results {
+                    let target = format!("{:#X}", target);
+                    ui.label(tr.format(
+                        "{count} reference(s) to {target}",
+                        &[("count", &results.len()), ("target", &target)],
+                    ));
+                }
+            });
+
+            if let Some((_, results)) = &state.results {
+                egui::ScrollArea::vertical()
+                    .id_salt("PointerScanResults")
+                    .max_height(150.0)
+                    .show(ui, |ui| {
+                        for &address in results.iter().take(MAX_LISTED_RESULTS) {
+                            if ui.link(format!("{:#X}", address)).clicked() {
+                                to_jump = Some(address);
+                            }
+                        }
+
+                        if results.len() > MAX_LISTED_RESULTS {
+                            let count = results.len() - MAX_LISTED_RESULTS;
+                            ui.label(tr.format("… and {count} more", &[("count", &count)]));
+                        }
+                    });
+            }
+        });
+
+        if let Some(address) = to_jump {
+            self.jump_to_address(address, current_address_range);
+        }
+    }
+}
@@ -0,0 +1,89 @@
+use std::ops::Range;
+
+use egui::Ui;
+
+use crate::{Address, MemoryEditor};
+
+impl MemoryEditor {
+    /// All live pointers, such as the stack pointer, by label.
+    pub fn live_pointers(&self) -> &[(String, Address)] {
+        &self.live_pointers
+    }
+
+    /// Add or update a live pointer, the byte it points to is highlighted in the editor.
+    ///
+    /// Pointers are expected to be updated by the host between frames, as the registers they represent change.
+    pub fn set_live_pointer(&mut self, label: impl Into<String>, address: Address) {
+        let label = label.into();
+
+        match self.live_pointers.iter_mut().find(|(name, _)| *name == label) {
+            Some((_, pointer)) => *pointer = address,
+            None => self.live_pointers.push((label, address)),
+        }
+    }
+
+    /// Remove the live pointer with the given label, returning the address it pointed to if it existed.
+    pub fn remove_live_pointer(&mut self, label: &str) -> Option<Address> {
+        let index = self.live_pointers.iter().position(|(name, _)| name == label)?;
+
+        if self.frame_data.followed_pointer.as_deref() == Some(label) {
+            self.frame_data.followed_pointer = None;
+        }
+
+        Some(self.live_pointers.remove(index).1)
+    }
+
+    /// Keep the followed pointer in view as it changes.
+    pub(crate) fn follow_pointer(&mut self, address_range: &Range<Address>) {
+        let Some(followed) = &self.frame_data.followed_pointer else {
+            return;
+        };
+        let Some(&(_, address)) = self.live_pointers.iter().find(|(label, _)| label == followed) else {
+            return;
+        };
+
+        if address_range.contains(&address) && !self.visible_range.contains(&address) {
+            self.scroll_to_address(address, address_range);
+        }
+    }
+
+    /// Draw a button for every live pointer to jump to it, and a toggle to keep following it.
+    pub(crate) fn draw_pointer_options(&mut self, ui: &mut Ui, current_address_range: &Range<Address>) {
+        let tr = self.translator();
+        if self.live_pointers.is_empty() {
+            return;
+        }
+
+        let mut to_jump = None;
+
+        ui.horizontal_wrapped(|ui| {
+            ui.label(tr.text("Pointers:"));
+
+            for (label, address) in &self.live_pointers {
+                let in_range = current_address_range.contains(address);
+
+                if ui
+                    .add_enabled(in_range, egui::Button::new(format!("{} ➜ {:#X}", label, address)))
+                    .on_hover_text(tr.text("Jump to the pointed to value"))
+                    .on_disabled_hover_text(tr.text("The pointer is not part of the selected address range"))
+                    .clicked()
+                {
+                    to_jump = Some(*address);
+                }
+
+                let is_followed = self.frame_data.followed_pointer.as_ref() == Some(label);
+                if ui
+                    .selectable_label(is_followed, "👁")
+                    .on_hover_text(tr.text("Keep the pointed to value in view as the pointer changes"))
+                    .clicked()
+                {
+                    self.frame_data.followed_pointer = if is_followed { None } else { Some(label.clone()) };
+                }
+            }
+        });
+
+        if let Some(address) = to_jump {
+            self.jump_to_address(address, current_address_range);
+        }
+    }
+}
@@ -0,0 +1,108 @@
+use std::ops::Range;
+
+use egui::{Align2, Color32, Rect, Stroke, TextStyle, Ui, Vec2, pos2};
+
+use crate::{Address, MemoryEditor};
+
+/// The width of the margin after the values in which the brackets are drawn.
+pub(crate) const RANGE_BRACKET_MARGIN: f32 = 120.0;
+/// The horizontal distance between nested brackets.
+const NESTED_BRACKET_INDENT: f32 = 6.0;
+/// The length of the ticks at the ends of a bracket, pointing towards the values.
+const BRACKET_TICK: f32 = 4.0;
+
+/// A labelled range spanning multiple rows.
+struct RangeBracket {
+    label: String,
+    range: Range<Address>,
+    colour: Color32,
+}
+
+impl MemoryEditor {
+    /// The visible highlight groups and range bookmarks which span more than one row of `address_space`.
+    fn range_brackets(&self, address_space: &Range<Address>) -> Vec<RangeBracket> {
+        let column_count = self.options.column_count;
+        let line = |address: Address| address.saturating_sub(address_space.start) / column_count;
+        let spans_rows = |range: &Range<Address>| !range.is_empty() && line(range.start) != line(range.end - 1);
+
+        let groups = self
+            .highlight_groups
+            .iter()
+            .filter(|group| !self.is_group_hidden(&group.name))
+            .flat_map(|group| {
+                group.ranges.iter().map(|range| RangeBracket {
+                    label: group.name.clone(),
+                    range: range.clone(),
+                    colour: group.colour.to_opaque(),
+                })
+            });
+        #[cfg(feature = "bookmarks")]
+        let bookmarks = self
+            .bookmarks
+            .iter()
+            .filter(|bookmark| bookmark.end.is_some())
+            .map(|bookmark| RangeBracket {
+                label: bookmark.name.clone(),
+                range: bookmark.range(),
+                colour: bookmark.colour.unwrap_or(self.options.address_text_colour),
+            });
+        #[cfg(not(feature = "bookmarks"))]
+        let bookmarks = std::iter::empty();
+
+        let mut brackets = groups
+            .chain(bookmarks)
+            .filter(|bracket| spans_rows(&bracket.range))
+            .collect::<Vec<_>>();
+        brackets.sort_by_key(|bracket| (bracket.range.start, std::cmp::Reverse(bracket.range.end)));
+        brackets
+    }
+
+    /// Paint a labelled bracket in the margin after the values for every highlight group range and range bookmark
+    /// spanning multiple rows, clipped to `clip_rect`. Overlapping brackets are nested side by side.
+    pub(crate) fn paint_range_brackets(&self, ui: &Ui, clip_rect: Rect, address_space: &Range<Address>) {
+        let Some(margin_x) = self.frame_data.range_bracket_margin else {
+            return;
+        };
+        let painter = ui.painter().with_clip_rect(clip_rect);
+        let font_id = TextStyle::Small.resolve(ui.style());
+        // The end of the last bracket drawn at every nesting depth.
+        let mut depth_ends: Vec<Address> = Vec::new();
+
+        for bracket in self.range_brackets(address_space) {
+            let depth = match depth_ends.iter().position(|&end| end <= bracket.range.start) {
+                Some(depth) => depth,
+                None => {
+                    depth_ends.push(0);
+                    depth_ends.len() - 1
+                }
+            };
+            depth_ends[depth] = bracket.range.end;
+
+            let spans = self.frame_data.cell_rects.row_spans(bracket.range.clone());
+            let (Some(first), Some(last)) = (spans.first(), spans.last()) else {
+                continue;
+            };
+            let x = margin_x + BRACKET_TICK + depth as f32 * NESTED_BRACKET_INDENT;
+            let stroke = Stroke::new(1.5, bracket.colour);
+            let top = first.top() + 1.0;
+            let bottom = last.bottom() - 1.0;
+
+            painter.vline(x, top..=bottom, stroke);
+            // Only the ends of the range which are visible get a tick.
+            if self.frame_data.cell_rects.get(bracket.range.start).is_some() {
+                painter.hline(x - BRACKET_TICK..=x, top, stroke);
+            }
+            if self.frame_data.cell_rects.get(bracket.range.end - 1).is_some() {
+                painter.hline(x - BRACKET_TICK..=x, bottom, stroke);
+            }
+
+            painter.text(
+                pos2(x, (top + bottom) / 2.0) + Vec2::new(3.0, 0.0),
+                Align2::LEFT_CENTER,
+                &bracket.label,
+                font_id.clone(),
+                bracket.colour,
+            );
+        }
+    }
+}
@@ -0,0 +1,130 @@
+use std::fmt;
+use std::ops::Range;
+
+use crate::translations::Translator;
+use crate::{Address, MemoryEditor};
+
+/// A problem with an address range, see [`MemoryEditor::try_with_address_range`].
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum AddressRangeError {
+    /// The range doesn't contain any addresses.
+    Empty { range_name: String },
+    /// The start of the range lies after its end.
+    Reversed { range_name: String },
+    /// The range overlaps with another address range.
+    Overlaps { range_name: String, other: String },
+}
+
+impl AddressRangeError {
+    /// The translated description of the problem.
+    pub(crate) fn message(&self, tr: &Translator) -> String {
+        match self {
+            AddressRangeError::Empty { range_name } => {
+                tr.format("Address range `{name}` is empty", &[("name", range_name)])
+            }
+            AddressRangeError::Reversed { range_name } => {
+                tr.format("Address range `{name}` starts after it ends", &[("name", range_name)])
+            }
+            AddressRangeError::Overlaps { range_name, other } => tr.format(
+                "Address range `{name}` overlaps with `{other}`",
+                &[("name", range_name), ("other", other)],
+            ),
+        }
+    }
+}
+
+impl fmt::Display for AddressRangeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.message(&Translator::default()))
+    }
+}
+
+impl std::error::Error for AddressRangeError {}
+
+impl MemoryEditor {
+    /// Add an address range, like [`Self::with_address_range`], but only if it is non-empty and doesn't overlap
+    /// with any of the existing address ranges.
+    ///
+    /// ```
+    /// # use egui_memory_editor::{AddressRangeError, MemoryEditor};
+    /// let memory_editor = MemoryEditor::new().with_address_range("RAM", 0..0x100);
+    /// let result = memory_editor.try_with_address_range("IO", 0x80..0x180);
+    ///
+    /// assert!(matches!(result, Err(AddressRangeError::Overlaps { .. })));
+    /// ```
+    #[inline]
+    pub fn try_with_address_range(
+        mut self,
+        range_name: impl Into<String>,
+        address_range: Range<Address>,
+    ) -> Result<Self, AddressRangeError> {
+        self.try_set_address_range(range_name, address_range)?;
+        Ok(self)
+    }
+
+    /// Add or update an address range, like [`Self::set_address_range`], but only if it is non-empty and doesn't
+    /// overlap with any of the other address ranges.
+    pub fn try_set_address_range(
+        &mut self,
+        range_name: impl Into<String>,
+        address_range: Range<Address>,
+    ) -> Result<(), AddressRangeError> {
+        let range_name = range_name.into();
+        self.validate_address_range(&range_name, &address_range)?;
+        self.set_address_range(range_name, address_range);
+        Ok(())
+    }
+
+    /// All problems with the current address ranges, such as empty or overlapping ranges.
+    ///
+    /// Overlapping ranges are allowed by [`Self::with_address_range`], as a range spanning all of memory next to
+    /// smaller regions can be useful, but they might also indicate a mistake.
+    pub fn address_range_problems(&self) -> Vec<AddressRangeError> {
+        self.address_ranges
+            .iter()
+            .enumerate()
+            .filter_map(|(i, (range_name, range))| {
+                // Only compare against earlier ranges, to report each overlap once.
+                let earlier = &self.address_ranges[..i];
+                validate(range_name, range, earlier.iter().map(|(name, range)| (name, range))).err()
+            })
+            .collect()
+    }
+
+    fn validate_address_range(
+        &self,
+        range_name: &str,
+        address_range: &Range<Address>,
+    ) -> Result<(), AddressRangeError> {
+        let others = self
+            .address_ranges
+            .iter()
+            .filter(|(name, _)| name != range_name)
+            .map(|(name, range)| (name, range));
+
+        validate(range_name, address_range, others)
+    }
+}
+
+fn validate<'a>(
+    range_name: &str,
+    address_range: &Range<Address>,
+    mut others: impl Iterator<Item = (&'a String, &'a Range<Address>)>,
+) -> Result<(), AddressRangeError> {
+    let range_name = range_name.to_string();
+
+    if address_range.start > address_range.end {
+        return Err(AddressRangeError::Reversed { range_name });
+    }
+    if address_range.is_empty() {
+        return Err(AddressRangeError::Empty { range_name });
+    }
+
+    match others.find(|(_, other)| address_range.start < other.end && other.start < address_range.end) {
+        Some((other, _)) => Err(AddressRangeError::Overlaps {
+            range_name,
+            other: other.clone(),
+        }),
+        None => Ok(()),
+    }
+}
@@ -0,0 +1,217 @@
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::ops::Range;
+
+use crate::{Address, MemoryEditor};
+
+/// A value read from memory, along with the frame in which it was read.
+#[derive(Copy, Clone, Debug)]
+struct CachedValue {
+    value: Option<u8>,
+    frame: u64,
+    /// Whether the host indicated the value changed, such values are re-read before any others.
+    invalidated: bool,
+}
+
+/// Caches the displayed values when the amount of reads per frame is limited.
+///
+/// Addresses which haven't been read yet or were invalidated are read first, the remaining reads of a frame are used
+/// to refresh the visible addresses in a round-robin fashion.
+#[derive(Clone, Debug, Default)]
+pub(crate) struct ReadCache {
+    values: HashMap<Address, CachedValue>,
+    /// The maximum amount of reads for the current frame, or `None` if reads aren't limited and the cache is unused.
+    limit: Option<usize>,
+    /// The remaining reads for the current frame.
+    budget: usize,
+    frame: u64,
+    /// The amount of frames it takes to refresh all visible addresses, values older than this are stale.
+    refresh_period: u64,
+    /// The next address to refresh.
+    refresh_cursor: Address,
+}
+
+impl ReadCache {
+    /// Prepare the cache for a new frame, in which at most `limit` reads can be done.
+    pub fn begin_frame(&mut self, limit: Option<usize>, visible_range: &Range<Address>) {
+        self.limit = limit;
+        self.frame += 1;
+
+        match limit {
+            Some(limit) => {
+                let limit = limit.max(1);
+                self.budget = limit;
+                self.refresh_period = visible_range.len().div_ceil(limit).max(1) as u64;
+            }
+            None => self.values.clear(),
+        }
+    }
+
+    /// Read the value at `address`, using the cached value if it exists and reads are limited.
+    pub fn read<T: ?Sized>(
+        &mut self,
+        mem: &mut T,
+        read_fn: &mut impl FnMut(&mut T, Address) -> Option<u8>,
+        address: Address,
+    ) -> Option<u8> {
+        if self.limit.is_none() {
+            return read_fn(mem, address);
+        }
+
+        match self.values.get(&address) {
+            Some(cached) if !cached.invalidated || self.budget == 0 => return cached.value,
+            None if self.budget == 0 => return None,
+            _ => {}
+        }
+
+        self.read_uncached(mem, read_fn, address)
+    }
+
+    /// Refresh the values in `range` with the reads which are left for this frame, and forget about values far
+    /// outside of it.
+    pub fn refresh<T: ?Sized>(
+        &mut self,
+        mem: &mut T,
+        read_fn: &mut impl FnMut(&mut T, Address) -> Option<u8>,
+        range: Range<Address>,
+    ) {
+        if self.limit.is_none() || range.is_empty() {
+            return;
+        }
+
+        if !range.contains(&self.refresh_cursor) {
+            self.refresh_cursor = range.start;
+        }
+
+        for _ in 0..range.len() {
+            if self.budget == 0 {
+                break;
+            }
+
+            let address = self.refresh_cursor;
+            if self
+                .values
+                .get(&address)
+                .is_none_or(|cached| cached.frame != self.frame)
+            {
+                self.read_uncached(mem, read_fn, address);
+            }
+
+            self.refresh_cursor = if address + 1 < range.end {
+                address + 1
+            } else {
+                range.start
+            };
+        }
+
+        // Keep some values around the visible range, so scrolling back and forth doesn't have to re-read everything.
+        let margin = range.len() * 4;
+        let retained = range.start.saturating_sub(margin)..range.end.saturating_add(margin);
+        self.values.retain(|address, _| retained.contains(address));
+    }
+
+    /// Take half of the reads left for this frame for reads made around the cache, such as scans, leaving the other
+    /// half to refresh the cached values. Returns `None` if reads aren't limited.
+    pub fn take_budget(&mut self) -> Option<usize> {
+        self.limit?;
+        let taken = self.budget.div_ceil(2);
+        self.budget -= taken;
+        Some(taken)
+    }
+
+    /// Whether the displayed value at `address` is older than a full refresh of the visible addresses.
+    pub fn is_stale(&self, address: Address) -> bool {
+        self.limit.is_some()
+            && self
+                .values
+                .get(&address)
+                .is_some_and(|cached| cached.invalidated || self.frame - cached.frame > self.refresh_period)
+    }
+
+    /// Mark the cached values in `range` as changed, so they're re-read first.
+    pub fn invalidate(&mut self, range: Range<Address>) {
+        self.values
+            .iter_mut()
+            .filter(|(address, _)| range.contains(address))
+            .for_each(|(_, cached)| cached.invalidated = true);
+    }
+
+    fn read_uncached<T: ?Sized>(
+        &mut self,
+        mem: &mut T,
+        read_fn: &mut impl FnMut(&mut T, Address) -> Option<u8>,
+        address: Address,
+    ) -> Option<u8> {
+        self.budget = self.budget.saturating_sub(1);
+        let value = read_fn(mem, address);
+        self.values.insert(
+            address,
+            CachedValue {
+                value,
+                frame: self.frame,
+                invalidated: false,
+            },
+        );
+        value
+    }
+}
+
+/// The values read from the host during a single frame, so every feature reading the same address shares one read.
+///
+/// Only addresses in and around the visible rows are kept, reads of whole regions like searches would otherwise fill
+/// the cache with values which aren't read again.
+pub(crate) struct FrameReads {
+    values: RefCell<HashMap<Address, Option<u8>>>,
+    cached_range: Range<Address>,
+}
+
+impl FrameReads {
+    /// Cache the values in the visible rows of the last frame, and a screen of rows above and below them.
+    pub fn new(visible_range: &Range<Address>) -> Self {
+        let margin = visible_range.len();
+        Self {
+            values: RefCell::new(HashMap::new()),
+            cached_range: visible_range.start.saturating_sub(margin)..visible_range.end.saturating_add(margin),
+        }
+    }
+
+    /// The value at `address` if it was already read this frame, otherwise the value returned by `read`.
+    /// Returns whether the value was cached as well.
+    pub fn read(&self, address: Address, read: impl FnOnce() -> Option<u8>) -> (Option<u8>, bool) {
+        if !self.cached_range.contains(&address) {
+            return (read(), false);
+        }
+        if let Some(&value) = self.values.borrow().get(&address) {
+            return (value, true);
+        }
+
+        let value = read();
+        self.values.borrow_mut().insert(address, value);
+        (value, false)
+    }
+
+    /// Forget the value at `address` after writing to it, so it's read again.
+    pub fn forget(&self, address: Address) {
+        self.values.borrow_mut().remove(&address);
+    }
+
+    /// Forget every value after inserting or deleting memory, which moves the values after it.
+    pub fn clear(&self) {
+        self.values.borrow_mut().clear();
+    }
+}
+
+impl MemoryEditor {
+    /// Indicate that the memory in `range` changed, for example after a DMA transfer.
+    ///
+    /// Only relevant when [`crate::option_data::MemoryEditorOptions::max_reads_per_frame`] is set, in which case the
+    /// values in the range are re-read before any other cached values.
+    pub fn invalidate_range(&mut self, range: Range<Address>) {
+        self.frame_data.read_cache.invalidate(range);
+    }
+
+    /// Indicate that all memory may have changed, see [`Self::invalidate_range`].
+    pub fn invalidate_all(&mut self) {
+        self.frame_data.read_cache.invalidate(0..Address::MAX);
+    }
+}
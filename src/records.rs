@@ -0,0 +1,218 @@
+use std::ops::Range;
+
+use egui::{Context, Key, KeyboardShortcut, Modifiers, Ui};
+
+use crate::navigation::SearchDirection;
+use crate::selection::Selection;
+use crate::{Address, MemoryEditor};
+
+/// Jump to the same field in the next record, see [`crate::option_data::MemoryEditorOptions::record_size`].
+pub(crate) const NEXT_RECORD_SHORTCUT: KeyboardShortcut = KeyboardShortcut::new(Modifiers::ALT, Key::ArrowDown);
+/// Jump to the same field in the previous record, see [`crate::option_data::MemoryEditorOptions::record_size`].
+pub(crate) const PREVIOUS_RECORD_SHORTCUT: KeyboardShortcut = KeyboardShortcut::new(Modifiers::ALT, Key::ArrowUp);
+
+impl MemoryEditor {
+    /// The record size, if record mode is enabled.
+    pub(crate) fn active_record_size(&self) -> Option<usize> {
+        self.options.record_size.filter(|&size| size > 1)
+    }
+
+    /// Handle the shortcuts for jumping between records, only active in record mode when there is a cursor.
+    pub(crate) fn handle_record_shortcuts(&mut self, ctx: &Context, address_range: &Range<Address>) {
+        if self.active_record_size().is_none()
+            || self.frame_data.cursor_address().is_none()
+            || !self.has_keyboard_focus(ctx)
+        {
+            return;
+        }
+
+        if ctx.input_mut(|i| i.consume_shortcut(&NEXT_RECORD_SHORTCUT)) {
+            self.jump_to_record(address_range, SearchDirection::Forward);
+        } else if ctx.input_mut(|i| i.consume_shortcut(&PREVIOUS_RECORD_SHORTCUT)) {
+            self.jump_to_record(address_range, SearchDirection::Backward);
+        }
+    }
+
+    /// Move the cursor to the same field in the next (or previous) record.
+    pub(crate) fn jump_to_record(&mut self, address_range: &Range<Address>, direction: SearchDirection) {
+        let (Some(size), Some(cursor)) = (self.active_record_size(), self.frame_data.cursor_address()) else {
+            return;
+        };
+        let target = match direction {
+            SearchDirection::Forward => cursor.checked_add(size),
+            SearchDirection::Backward => cursor.checked_sub(size),
+        };
+
+        if let Some(address) = target {
+            self.jump_to_address(address, address_range);
+        }
+    }
+
+    /// Select the field at the cursor in `count` consecutive records starting with the record of the cursor, or in
+    /// every following record if `count` is `0`.
+    ///
+    /// The field is the last selected run if it lies within the record of the cursor, otherwise the cursor itself.
+    pub(crate) fn select_field_across_records(&mut self, count: usize, address_range: &Range<Address>) {
+        let (Some(size), Some(cursor)) = (self.active_record_size(), self.frame_data.cursor_address()) else {
+            return;
+        };
+        if address_range.is_empty() {
+            return;
+        }
+        // A cursor left over from another address range is moved onto this one.
+        let cursor = cursor.clamp(address_range.start, address_range.end - 1);
+        let record_start = cursor - (cursor - address_range.start) % size;
+        let record = record_start..record_start + size;
+        let field = match self.frame_data.selection.ranges().last() {
+            Some(run) if run.contains(&cursor) && record.start <= run.start && run.end <= record.end => run.clone(),
+            _ => cursor..cursor + 1,
+        };
+
+        let records_end = match count {
+            0 => address_range.end,
+            count => count
+                .checked_mul(size)
+                .and_then(|len| record_start.checked_add(len))
+                .map_or(address_range.end, |end| end.min(address_range.end)),
+        };
+        let field = field.start - record_start..field.end - record_start;
+
+        self.frame_data.record_field_offset = field.start;
+        self.frame_data.record_field_width = field.len();
+        self.set_selection(Selection::strided(record_start..records_end, size, field));
+    }
+
+    /// Select the field set in the UI in every record overlapping the current selection, or in every record of the
+    /// address range if nothing is selected.
+    ///
+    /// The resulting strided selection is respected by copying, filling and the other selection operations, allowing a
+    /// single field to be edited across an entire table.
+    pub(crate) fn select_field_in_range(&mut self, address_range: &Range<Address>) {
+        let Some(size) = self.active_record_size() else {
+            return;
+        };
+        let merged = self.frame_data.selection.merged_ranges();
+        // Only the part of the selection within the address range counts, a selection left over from another address
+        // range selects every record.
+        let clamp = |address: Address| address.clamp(address_range.start, address_range.end);
+        let (start, end) = match (merged.first(), merged.last()) {
+            (Some(first), Some(last)) if clamp(first.start) < clamp(last.end) => (clamp(first.start), clamp(last.end)),
+            _ => (address_range.start, address_range.end),
+        };
+
+        // Records are aligned to the start of the address range, just like their separators.
+        let records_start = start - (start - address_range.start) % size;
+        let records_end =
+            (address_range.start + (end - address_range.start).div_ceil(size) * size).min(address_range.end);
+        let offset = self.frame_data.record_field_offset.min(size - 1);
+        let width = self.frame_data.record_field_width.clamp(1, size - offset);
+
+        self.set_selection(Selection::strided(
+            records_start..records_end,
+            size,
+            offset..offset + width,
+        ));
+    }
+
+    /// Draw the record mode options, allowing jumps between records and selecting a field across records.
+    pub(crate) fn draw_record_options(&mut self, ui: &mut Ui, current_address_range: &Range<Address>) {
+        let tr = self.translator();
+        ui.horizontal(|ui| {
+            let mut is_enabled = self.options.record_size.is_some();
+            if ui
+                .checkbox(&mut is_enabled, tr.text("Records:"))
+                .on_hover_text(
+                    tr.text("Treat the memory as an array of fixed size records, with separators between them"),
+                )
+                .changed()
+            {
+                self.options.record_size = is_enabled.then_some(self.options.column_count);
+            }
+
+            let Some(size) = &mut self.options.record_size else {
+                return;
+            };
+            ui.add(
+                egui::DragValue::new(size)
+                    .range(2..=usize::MAX)
+                    .suffix(tr.text(" bytes")),
+            )
+            .on_hover_text(tr.text("The size of a single record"));
+            let size = (*size).max(2);
+
+            let previous_shortcut = ui.ctx().format_shortcut(&PREVIOUS_RECORD_SHORTCUT);
+            if ui
+                .button(tr.text("⏶ Previous"))
+                .on_hover_text(tr.format(
+                    "Jump to the same field in the previous record ({shortcut})",
+                    &[("shortcut", &previous_shortcut)],
+                ))
+                .clicked()
+            {
+                self.jump_to_record(current_address_range, SearchDirection::Backward);
+            }
+
+            let next_shortcut = ui.ctx().format_shortcut(&NEXT_RECORD_SHORTCUT);
+            if ui
+                .button(tr.text("Next ⏷"))
+                .on_hover_text(tr.format(
+                    "Jump to the same field in the next record ({shortcut})",
+                    &[("shortcut", &next_shortcut)],
+                ))
+                .clicked()
+            {
+                self.jump_to_record(current_address_range, SearchDirection::Forward);
+            }
+
+            ui.separator();
+
+            let count = &mut self.frame_data.record_field_count;
+            ui.add(
+                egui::DragValue::new(count)
+                    .custom_formatter(|count, _| match count as usize {
+                        0 => "all".to_string(),
+                        count => count.to_string(),
+                    })
+                    .prefix(tr.text("Records: ")),
+            )
+            .on_hover_text(tr.text("The amount of records to select the field in, 0 selects every following record"));
+            let count = *count;
+
+            if ui
+                .button(tr.text("Select field"))
+                .on_hover_text(tr.text("Select the field at the cursor (or the selected field) in consecutive records"))
+                .clicked()
+            {
+                self.select_field_across_records(count, current_address_range);
+            }
+
+            ui.separator();
+
+            let frame_data = &mut self.frame_data;
+            ui.add(
+                egui::DragValue::new(&mut frame_data.record_field_offset)
+                    .range(0..=size - 1)
+                    .prefix(tr.text("Field: +")),
+            )
+            .on_hover_text(tr.text("The offset of the field within a record"));
+            frame_data.record_field_width = frame_data.record_field_width.max(1);
+            ui.add(
+                egui::DragValue::new(&mut frame_data.record_field_width)
+                    .range(1..=size - frame_data.record_field_offset)
+                    .suffix(tr.text(" bytes")),
+            )
+            .on_hover_text(tr.text("The size of the field"));
+
+            if ui
+                .button(tr.text("Select in range"))
+                .on_hover_text(tr.text(
+                    "Select the field in every record of the selected range, or of the entire address range if nothing \
+                     is selected",
+                ))
+                .clicked()
+            {
+                self.select_field_in_range(current_address_range);
+            }
+        });
+    }
+}
@@ -0,0 +1,116 @@
+//! Measuring how much of an address range is in use, see [`RegionFill`].
+use std::ops::Range;
+
+use crate::{Address, MemoryEditor};
+
+/// How many of the values in an address range are non-zero, as a quick indication of how much of a buffer is in use.
+///
+/// ```
+/// # use egui_memory_editor::MemoryEditor;
+/// let mut memory = vec![0u8; 0x100];
+/// memory[..0x40].fill(0xFF);
+/// let mut memory_editor = MemoryEditor::new().with_address_range("RAM", 0..0x100);
+///
+/// let fill = memory_editor
+///     .measure_region_fill("RAM", &mut memory, |mem, addr| mem.get(addr).copied())
+///     .unwrap();
+/// assert_eq!(fill.non_zero, 0x40);
+/// assert_eq!(fill.percentage(), 25.0);
+/// ```
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq)]
+pub struct RegionFill {
+    /// The amount of readable values which aren't zero.
+    pub non_zero: usize,
+    /// The amount of values which couldn't be read.
+    pub unreadable: usize,
+    /// The total amount of values in the address range.
+    pub len: usize,
+}
+
+impl RegionFill {
+    /// Read every value in `address_range` to count the non-zero values.
+    fn measure<T: ?Sized>(
+        mem: &mut T,
+        read_fn: &mut impl FnMut(&mut T, Address) -> Option<u8>,
+        address_range: Range<Address>,
+    ) -> Self {
+        let mut result = Self {
+            len: address_range.len(),
+            ..Self::default()
+        };
+
+        for address in address_range {
+            match read_fn(mem, address) {
+                Some(0) => {}
+                Some(_) => result.non_zero += 1,
+                None => result.unreadable += 1,
+            }
+        }
+
+        result
+    }
+
+    /// The percentage of all values which are non-zero, `0.0` for an empty address range.
+    pub fn percentage(&self) -> f32 {
+        if self.len == 0 {
+            0.0
+        } else {
+            self.non_zero as f32 / self.len as f32 * 100.0
+        }
+    }
+}
+
+impl MemoryEditor {
+    /// Count the non-zero values in the address range with the given name, which is then shown in the options header
+    /// if [`crate::option_data::MemoryEditorOptions::show_region_fill`] is enabled.
+    ///
+    /// Every value in the address range is read, so this is only done on demand rather than every frame.
+    /// Returns `None` if the address range doesn't exist.
+    pub fn measure_region_fill<T: ?Sized>(
+        &mut self,
+        range_name: &str,
+        mem: &mut T,
+        mut read_fn: impl FnMut(&mut T, Address) -> Option<u8>,
+    ) -> Option<RegionFill> {
+        let address_range = self.address_range(range_name)?.clone();
+        let fill = RegionFill::measure(mem, &mut read_fn, address_range);
+        self.frame_data.region_fill.insert(range_name.to_string(), fill);
+
+        Some(fill)
+    }
+
+    /// The last measured fill of the address range with the given name, see [`Self::measure_region_fill`].
+    pub fn region_fill(&self, range_name: &str) -> Option<RegionFill> {
+        self.frame_data.region_fill.get(range_name).copied()
+    }
+
+    /// The region fill shown in the options header, like `RAM 37% non-zero`.
+    pub(crate) fn region_fill_text(&self) -> Option<String> {
+        let range_name = &self.window.selected_address_range;
+        let fill = self.region_fill(range_name)?;
+
+        Some(self.translator().format(
+            "{region} {percentage}% non-zero",
+            &[
+                ("region", range_name),
+                ("percentage", &format!("{:.0}", fill.percentage())),
+            ],
+        ))
+    }
+
+    /// Measure the fill of the selected address range if it was requested, or if it hasn't been measured yet.
+    pub(crate) fn update_region_fill<T: ?Sized>(
+        &mut self,
+        mem: &mut T,
+        read_fn: &mut impl FnMut(&mut T, Address) -> Option<u8>,
+    ) {
+        if !self.options.show_region_fill {
+            return;
+        }
+
+        let range_name = self.window.selected_address_range.clone();
+        if std::mem::take(&mut self.frame_data.refresh_region_fill) || self.region_fill(&range_name).is_none() {
+            self.measure_region_fill(&range_name, mem, read_fn);
+        }
+    }
+}
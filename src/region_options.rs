@@ -0,0 +1,78 @@
+use crate::MemoryEditor;
+use crate::option_data::RegionOptions;
+
+impl MemoryEditor {
+    /// Override some of the options for the address range with the given name, for example to use fewer columns for a
+    /// small block of registers.
+    ///
+    /// See also [`Self::with_address_range`]
+    ///
+    /// ```
+    /// # use egui_memory_editor::MemoryEditor;
+    /// use egui_memory_editor::option_data::{RegionOptions, SidebarEncoding};
+    /// use egui_memory_editor::words::WordSize;
+    ///
+    /// let memory_editor = MemoryEditor::new()
+    ///     .with_address_range("ROM", 0..0x10_0000)
+    ///     .with_address_range("I/O", 0x10_0000..0x10_0010)
+    ///     .with_region_options(
+    ///         "I/O",
+    ///         RegionOptions {
+    ///             column_count: Some(4),
+    ///             show_ascii: Some(false),
+    ///             word_size: Some(WordSize::new(32)),
+    ///             ..Default::default()
+    ///         },
+    ///     )
+    ///     .with_region_options(
+    ///         "ROM",
+    ///         RegionOptions { sidebar_encoding: Some(SidebarEncoding::Utf16Le), ..Default::default() },
+    ///     );
+    ///
+    /// assert_eq!(memory_editor.region_options("I/O").unwrap().word_size, Some(WordSize::new(32)));
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn with_region_options(mut self, range_name: impl Into<String>, region_options: RegionOptions) -> Self {
+        self.set_region_options(range_name, region_options);
+        self
+    }
+
+    /// Set the overridden options for the address range with the given name.
+    pub fn set_region_options(&mut self, range_name: impl Into<String>, region_options: RegionOptions) {
+        self.region_options.insert(range_name.into(), region_options);
+    }
+
+    /// The overridden options for the address range with the given name, if any.
+    pub fn region_options(&self, range_name: &str) -> Option<&RegionOptions> {
+        self.region_options.get(range_name)
+    }
+
+    /// Apply the overrides of the selected address range to the options for the duration of a frame.
+    ///
+    /// Returns the name of the address range and the replaced values, which should be passed to
+    /// [`Self::restore_region_options`] at the end of the frame.
+    pub(crate) fn apply_region_options(&mut self) -> Option<(String, RegionOptions)> {
+        let range_name = self.window.selected_address_range.clone();
+        let replaced = self.region_options.get(&range_name)?.apply(&mut self.options);
+
+        Some((range_name, replaced))
+    }
+
+    /// Restore the options replaced by [`Self::apply_region_options`], storing any changes made to the overridden
+    /// options during the frame in the region's overrides.
+    pub(crate) fn restore_region_options(&mut self, applied: Option<(String, RegionOptions)>) {
+        let Some((range_name, replaced)) = applied else {
+            return;
+        };
+
+        // The selected range may have been changed during the frame, so keep that selection.
+        let selected_address_range = std::mem::take(&mut self.window.selected_address_range);
+        let changed = replaced.apply(&mut self.options);
+        self.window.selected_address_range = selected_address_range;
+
+        if let Some(region_options) = self.region_options.get_mut(&range_name) {
+            *region_options = changed;
+        }
+    }
+}
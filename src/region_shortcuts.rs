@@ -0,0 +1,112 @@
+use egui::{Context, Key, KeyboardShortcut, Modifiers};
+
+use crate::MemoryEditor;
+use crate::navigation::SearchDirection;
+
+/// Select the next address range, like switching tabs in a browser.
+///
+/// `Ctrl+Tab` can't be used, as `egui` moves the focus to the next widget on `Tab` before the editor sees it.
+pub(crate) const NEXT_REGION_SHORTCUT: KeyboardShortcut = KeyboardShortcut::new(Modifiers::COMMAND, Key::PageDown);
+/// Select the previous address range.
+pub(crate) const PREVIOUS_REGION_SHORTCUT: KeyboardShortcut = KeyboardShortcut::new(Modifiers::COMMAND, Key::PageUp);
+/// The keys which select the first nine address ranges directly, together with [`Modifiers::COMMAND`].
+const REGION_KEYS: [Key; 9] = [
+    Key::Num1,
+    Key::Num2,
+    Key::Num3,
+    Key::Num4,
+    Key::Num5,
+    Key::Num6,
+    Key::Num7,
+    Key::Num8,
+    Key::Num9,
+];
+
+impl MemoryEditor {
+    /// Select the next (or previous) address range in the order they're listed in the options, wrapping around at
+    /// the ends. Returns the name of the newly selected range, if there are any ranges.
+    ///
+    /// The cursor and selection are cleared, as they belong to the previously selected range.
+    ///
+    /// ```
+    /// # use egui_memory_editor::{MemoryEditor, SearchDirection};
+    /// let mut memory_editor = MemoryEditor::new()
+    ///     .with_address_range("WRAM", 0xC000..0xE000)
+    ///     .with_address_range("VRAM", 0x8000..0xA000)
+    ///     .with_address_range("OAM", 0xFE00..0xFEA0);
+    ///
+    /// assert_eq!(memory_editor.cycle_address_range(SearchDirection::Forward), Some("VRAM"));
+    /// assert_eq!(memory_editor.cycle_address_range(SearchDirection::Forward), Some("OAM"));
+    /// assert_eq!(memory_editor.cycle_address_range(SearchDirection::Forward), Some("WRAM"));
+    /// assert_eq!(memory_editor.cycle_address_range(SearchDirection::Backward), Some("OAM"));
+    /// ```
+    ///
+    /// While the editor has keyboard focus the ranges are cycled with `Ctrl+PageDown` and `Ctrl+PageUp`.
+    ///
+    /// ```
+    /// # use egui_memory_editor::MemoryEditor;
+    /// use egui::{Event, Key, Modifiers, RawInput};
+    /// use egui_memory_editor::selection::CursorState;
+    ///
+    /// let mut memory = vec![0u8; 0x900];
+    /// let mut memory_editor = MemoryEditor::new()
+    ///     .with_address_range("A", 0..0x100)
+    ///     .with_address_range("B", 0x800..0x900);
+    /// memory_editor.request_focus();
+    /// memory_editor.set_cursor_state(CursorState { region: "A".into(), address: Some(0x10), selection: Default::default() });
+    /// let ctx = egui::Context::default();
+    ///
+    /// let key = Event::Key { key: Key::PageDown, physical_key: None, pressed: true, repeat: false, modifiers: Modifiers::COMMAND };
+    /// let input = RawInput { events: vec![key], ..Default::default() };
+    /// let _ = ctx.run(input, |ctx| {
+    ///     egui::CentralPanel::default().show(ctx, |ui| {
+    ///         memory_editor.draw_editor_contents_read_only(ui, &mut memory, |mem, address| mem.get(address).copied());
+    ///     });
+    /// });
+    ///
+    /// assert_eq!(memory_editor.selected_address_range(), "B");
+    /// assert_eq!(memory_editor.cursor_address(), None);
+    /// ```
+    pub fn cycle_address_range(&mut self, direction: SearchDirection) -> Option<&str> {
+        let names: Vec<String> = self
+            .ordered_address_ranges()
+            .into_iter()
+            .map(|(name, _)| name.clone())
+            .collect();
+        let current = names
+            .iter()
+            .position(|name| *name == self.window.selected_address_range);
+        let index = match (current, direction) {
+            (Some(index), SearchDirection::Forward) => (index + 1) % names.len(),
+            (Some(index), SearchDirection::Backward) => (index + names.len() - 1) % names.len(),
+            (None, _) if !names.is_empty() => 0,
+            (None, _) => return None,
+        };
+
+        self.select_address_range(names[index].clone());
+        Some(&self.window.selected_address_range)
+    }
+
+    /// Handle the shortcuts for switching between the address ranges, only active if there are multiple ranges and
+    /// switching between them is allowed.
+    ///
+    /// `Ctrl+PageDown` and `Ctrl+PageUp` cycle through the ranges, and `Ctrl+1` to `Ctrl+9` select one directly.
+    pub(crate) fn handle_region_shortcuts(&mut self, ctx: &Context) {
+        if !self.frame_data.memory_range_combo_box_enabled || !self.has_keyboard_focus(ctx) {
+            return;
+        }
+
+        // Shift is ignored when matching shortcuts without it, so the shortcut with Shift has to be checked first.
+        if ctx.input_mut(|i| i.consume_shortcut(&PREVIOUS_REGION_SHORTCUT)) {
+            self.cycle_address_range(SearchDirection::Backward);
+        } else if ctx.input_mut(|i| i.consume_shortcut(&NEXT_REGION_SHORTCUT)) {
+            self.cycle_address_range(SearchDirection::Forward);
+        } else if let Some(index) = REGION_KEYS
+            .iter()
+            .position(|&key| ctx.input_mut(|i| i.consume_key(Modifiers::COMMAND, key)))
+            && let Some(name) = self.ordered_address_ranges().get(index).map(|(name, _)| name.clone())
+        {
+            self.select_address_range(name);
+        }
+    }
+}
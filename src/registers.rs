@@ -0,0 +1,165 @@
+//! A compact view of small I/O regions with one register per row, see [`RegisterWidth`].
+use std::ops::Range;
+
+use egui::{RichText, Ui};
+
+use crate::option_data::Endianness;
+use crate::{Address, MemoryEditor};
+
+/// The size of the registers in the register view, see
+/// [`crate::option_data::MemoryEditorOptions::register_view`].
+///
+/// ```
+/// # use egui_memory_editor::option_data::Endianness;
+/// # use egui_memory_editor::registers::RegisterWidth;
+/// assert_eq!(RegisterWidth::Bits16.decode(&[0x34, 0x12], Endianness::Little), 0x1234);
+/// assert_eq!(RegisterWidth::Bits16.encode(0x1234, Endianness::Big), vec![0x12, 0x34]);
+/// ```
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum RegisterWidth {
+    Bits8,
+    Bits16,
+    Bits32,
+}
+
+impl RegisterWidth {
+    pub fn iter() -> impl Iterator<Item = RegisterWidth> {
+        [RegisterWidth::Bits8, RegisterWidth::Bits16, RegisterWidth::Bits32].into_iter()
+    }
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            RegisterWidth::Bits8 => "8-bit",
+            RegisterWidth::Bits16 => "16-bit",
+            RegisterWidth::Bits32 => "32-bit",
+        }
+    }
+
+    /// The amount of bytes a register takes up.
+    pub const fn bytes(&self) -> usize {
+        match self {
+            RegisterWidth::Bits8 => 1,
+            RegisterWidth::Bits16 => 2,
+            RegisterWidth::Bits32 => 4,
+        }
+    }
+
+    /// The value of a register from its `bytes`, which should be [`Self::bytes`] long.
+    pub fn decode(&self, bytes: &[u8], endianness: Endianness) -> u32 {
+        let fold = |acc: u32, &byte: &u8| (acc << 8) | byte as u32;
+        match endianness {
+            Endianness::Big => bytes.iter().fold(0, fold),
+            Endianness::Little => bytes.iter().rev().fold(0, fold),
+        }
+    }
+
+    /// The bytes of a register holding `value`, the inverse of [`Self::decode`].
+    pub fn encode(&self, value: u32, endianness: Endianness) -> Vec<u8> {
+        let bytes = &value.to_le_bytes()[..self.bytes()];
+        match endianness {
+            Endianness::Big => bytes.iter().rev().copied().collect(),
+            Endianness::Little => bytes.to_vec(),
+        }
+    }
+}
+
+impl MemoryEditor {
+    /// Draw the registers in `rows` of the register view, one register per row with its address, symbol name,
+    /// value, and a strip of its bits. Clicking a bit toggles it, if the memory can be written.
+    #[allow(clippy::too_many_arguments)]
+    pub(crate) fn draw_register_rows<T: ?Sized>(
+        &mut self,
+        ui: &mut Ui,
+        width: RegisterWidth,
+        rows: Range<usize>,
+        mem: &mut T,
+        read: &mut impl FnMut(&mut T, Address) -> Option<u8>,
+        write: &mut Option<impl FnMut(&mut T, Address, u8)>,
+        address_space: &Range<Address>,
+    ) {
+        let tr = self.translator();
+        let endianness = self.options.data_preview.selected_endianness;
+        let text_style = self.options.memory_editor_text_style.clone();
+        let format_address = self.address_formatter(address_space);
+        let register_address = |row: usize| address_space.start + row * width.bytes();
+
+        self.visible_range = register_address(rows.start)..register_address(rows.end).min(address_space.end);
+
+        egui::Grid::new("mem_edit_registers").striped(true).show(ui, |ui| {
+            for row in rows {
+                let address = register_address(row);
+                let lanes = address..(address + width.bytes()).min(address_space.end);
+                let is_highlighted = self
+                    .frame_data
+                    .selected_highlight_address
+                    .is_some_and(|highlight| lanes.contains(&highlight));
+                let address_colour = if is_highlighted {
+                    self.options.highlight_text_colour
+                } else {
+                    self.options.address_text_colour
+                };
+                let address_text = format_address(address);
+
+                ui.label(
+                    RichText::new(format!("{address_text}:"))
+                        .color(address_colour)
+                        .text_style(self.options.memory_editor_address_text_style.clone()),
+                );
+                ui.label(self.symbols.get(&address).map(String::as_str).unwrap_or_default());
+
+                let bytes = lanes.clone().map(|lane| read(mem, lane)).collect::<Option<Vec<u8>>>();
+                let Some(bytes) = bytes.filter(|bytes| bytes.len() == width.bytes()) else {
+                    ui.label(RichText::new(&self.options.none_display_value).text_style(text_style.clone()));
+                    ui.end_row();
+                    continue;
+                };
+                let value = width.decode(&bytes, endianness);
+                let digits = 2 * width.bytes();
+                let mut new_value = value;
+
+                match write {
+                    Some(_) => {
+                        ui.add(
+                            egui::DragValue::new(&mut new_value)
+                                .hexadecimal(digits, false, true)
+                                .range(0..=u32::MAX >> (32 - 8 * width.bytes()))
+                                .prefix("0x")
+                                .speed(0.25),
+                        );
+                    }
+                    None => {
+                        ui.label(RichText::new(format!("0x{value:0digits$X}")).text_style(text_style.clone()));
+                    }
+                }
+
+                ui.horizontal(|ui| {
+                    ui.spacing_mut().item_spacing.x = 1.0;
+
+                    for bit in (0..8 * width.bytes() as u32).rev() {
+                        let is_set = value & (1 << bit) != 0;
+                        let text = RichText::new(if is_set { "1" } else { "0" }).text_style(text_style.clone());
+                        let response = ui
+                            .selectable_label(is_set, text)
+                            .on_hover_text(format!("{} {bit}", tr.text("Bit")));
+                        if response.clicked() && write.is_some() {
+                            new_value ^= 1 << bit;
+                        }
+                        if bit % 4 == 0 && bit != 0 {
+                            ui.add_space(4.0);
+                        }
+                    }
+                });
+                ui.end_row();
+
+                if new_value != value
+                    && let Some(write) = write.as_mut()
+                {
+                    for (lane, byte) in lanes.zip(width.encode(new_value, endianness)) {
+                        write(mem, lane, byte);
+                    }
+                }
+            }
+        });
+    }
+}
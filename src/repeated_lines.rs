@@ -0,0 +1,181 @@
+use std::collections::BTreeSet;
+use std::ops::Range;
+
+use crate::{Address, MemoryEditor};
+
+/// The time in seconds after which the address range is scanned for repeated lines again.
+const RESCAN_INTERVAL: f64 = 1.0;
+/// The minimum amount of repeated lines which are collapsed into a single row.
+const MIN_REPEATED_LINES: usize = 2;
+
+/// The lines of an address range which are identical to the line before them, like the `*` rows of `xxd`.
+#[derive(Clone, Debug)]
+pub(crate) struct RepeatedLines {
+    address_space: Range<Address>,
+    column_count: usize,
+    /// The time the last scan started, as reported by `egui`.
+    scanned_at: f64,
+    runs: Vec<Range<usize>>,
+    /// The first line of every run the user expanded.
+    expanded: BTreeSet<usize>,
+    /// The scan in progress, which replaces the runs once it's complete.
+    scan: Option<LineScan>,
+}
+
+/// A scan for repeated lines, which can be spread across frames when the amount of reads per frame is limited.
+#[derive(Clone, Debug, Default)]
+struct LineScan {
+    next_line: usize,
+    run_start: Option<usize>,
+    previous_line: Vec<Option<u8>>,
+    runs: Vec<Range<usize>>,
+}
+
+impl LineScan {
+    /// Read lines of `address_space` until the scan is complete or `max_reads` values were read, collecting runs of
+    /// lines identical to the line before them. Unless no reads are left, at least one line is read, so the scan
+    /// always progresses.
+    ///
+    /// Returns the runs once every line was read.
+    fn step<T: ?Sized>(
+        &mut self,
+        mem: &mut T,
+        read_fn: &mut impl FnMut(&mut T, Address) -> Option<u8>,
+        address_space: &Range<Address>,
+        column_count: usize,
+        max_reads: usize,
+    ) -> Option<Vec<Range<usize>>> {
+        let line_count = address_space.len().div_ceil(column_count);
+        if max_reads == 0 {
+            return None;
+        }
+        let mut reads = 0;
+        let mut line = Vec::with_capacity(column_count);
+
+        while self.next_line < line_count && (reads == 0 || reads + column_count <= max_reads) {
+            let index = self.next_line;
+            let start = address_space.start + index * column_count;
+            let end = (start + column_count).min(address_space.end);
+            line.clear();
+            line.extend((start..end).map(|address| read_fn(mem, address)));
+            reads += line.len();
+
+            let is_repeat = index > 0 && line == self.previous_line;
+            match (is_repeat, self.run_start) {
+                (true, None) => self.run_start = Some(index),
+                (false, Some(start)) => {
+                    self.runs.push(start..index);
+                    self.run_start = None;
+                }
+                _ => {}
+            }
+
+            std::mem::swap(&mut line, &mut self.previous_line);
+            self.next_line += 1;
+        }
+
+        if self.next_line < line_count {
+            return None;
+        }
+        if let Some(start) = self.run_start.take() {
+            self.runs.push(start..line_count);
+        }
+
+        let mut runs = std::mem::take(&mut self.runs);
+        runs.retain(|run| run.len() >= MIN_REPEATED_LINES);
+        Some(runs)
+    }
+}
+
+impl MemoryEditor {
+    /// Start scanning the given address range for repeated lines if the option is enabled, and the last scan is
+    /// outdated. Without a limit on the reads per frame the scan is completed right away, otherwise it's continued
+    /// with [`Self::continue_repeated_lines_scan`].
+    ///
+    /// Expanded runs stay expanded, as long as the address range and column count don't change.
+    pub(crate) fn update_repeated_lines<T: ?Sized>(
+        &mut self,
+        mem: &mut T,
+        read_fn: &mut impl FnMut(&mut T, Address) -> Option<u8>,
+        address_space: &Range<Address>,
+        time: f64,
+    ) {
+        if !self.options.collapse_repeated_lines {
+            self.frame_data.repeated_lines = None;
+            return;
+        }
+
+        let column_count = self.options.column_count;
+        let repeated = self
+            .frame_data
+            .repeated_lines
+            .take()
+            .filter(|previous| previous.address_space == *address_space && previous.column_count == column_count)
+            .unwrap_or_else(|| RepeatedLines {
+                address_space: address_space.clone(),
+                column_count,
+                scanned_at: f64::NEG_INFINITY,
+                runs: Vec::new(),
+                expanded: BTreeSet::new(),
+                scan: None,
+            });
+        let repeated = self.frame_data.repeated_lines.insert(repeated);
+
+        if repeated.scan.is_none() && time - repeated.scanned_at >= RESCAN_INTERVAL {
+            repeated.scanned_at = time;
+            repeated.scan = Some(LineScan::default());
+        }
+
+        if self.options.max_reads_per_frame.is_none() {
+            self.continue_repeated_lines_scan(mem, read_fn, usize::MAX);
+        }
+    }
+
+    /// Continue the scan for repeated lines in progress, if any, reading at most `max_reads` values.
+    pub(crate) fn continue_repeated_lines_scan<T: ?Sized>(
+        &mut self,
+        mem: &mut T,
+        read_fn: &mut impl FnMut(&mut T, Address) -> Option<u8>,
+        max_reads: usize,
+    ) {
+        let Some(repeated) = &mut self.frame_data.repeated_lines else {
+            return;
+        };
+        let Some(scan) = &mut repeated.scan else {
+            return;
+        };
+
+        if let Some(runs) = scan.step(mem, read_fn, &repeated.address_space, repeated.column_count, max_reads) {
+            repeated.runs = runs;
+            repeated.scan = None;
+        }
+    }
+
+    /// The runs of repeated lines which should be collapsed, excluding those the user expanded and the one containing
+    /// the cursor.
+    pub(crate) fn collapsed_repeated_lines(&self) -> Vec<Range<usize>> {
+        let Some(repeated) = &self.frame_data.repeated_lines else {
+            return Vec::new();
+        };
+        let cursor_line = self
+            .frame_data
+            .cursor_address()
+            .and_then(|address| address.checked_sub(repeated.address_space.start))
+            .map(|offset| offset / repeated.column_count);
+
+        repeated
+            .runs
+            .iter()
+            .filter(|run| !repeated.expanded.contains(&run.start))
+            .filter(|run| cursor_line.is_none_or(|line| !run.contains(&line)))
+            .cloned()
+            .collect()
+    }
+
+    /// Show the lines of the collapsed run starting at `line` again.
+    pub(crate) fn expand_repeated_lines(&mut self, line: usize) {
+        if let Some(repeated) = &mut self.frame_data.repeated_lines {
+            repeated.expanded.insert(line);
+        }
+    }
+}
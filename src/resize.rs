@@ -0,0 +1,137 @@
+use std::ops::Range;
+
+use egui::{Context, Key, Modifiers};
+
+use crate::notices::Notice;
+use crate::{Address, MemoryEditor};
+
+// This needs to exist due to the fact we want to use generics, and `Option` needs to know the size of its contents.
+pub(crate) type DummyResizeFunctions<T> = ResizeFunctions<fn(&mut T, Address, usize), fn(&mut T, Range<Address>)>;
+
+/// The functions used to grow and shrink the memory, see [`MemoryEditor::window_ui_resizable`].
+///
+/// Like the read and write functions these take the memory as an argument, so they're passed along every frame rather
+/// than stored in the editor with a builder.
+pub struct ResizeFunctions<I, D> {
+    pub(crate) insert_fn: I,
+    pub(crate) delete_fn: D,
+}
+
+impl<I, D> ResizeFunctions<I, D> {
+    /// Bundle the resize functions for a memory of type `T`.
+    ///
+    /// # Arguments
+    ///
+    /// * `insert_fn` - Any closure which inserts the given amount of zeroed bytes before the given address, shifting
+    ///   everything after it up.
+    /// * `delete_fn` - Any closure which removes the given range of addresses, shifting everything after it down.
+    ///
+    /// ```no_run
+    /// # use egui_memory_editor::{MemoryEditor, ResizeFunctions};
+    /// # let ctx = egui::Context::default();
+    /// let mut memory = vec![0xFF; 0xFF];
+    /// let mut memory_editor = MemoryEditor::new().with_address_range("Memory", 0..0xFF);
+    ///
+    /// memory_editor.window_ui_resizable(
+    ///     &ctx,
+    ///     &mut memory,
+    ///     |mem, addr| mem.get(addr).copied(),
+    ///     |mem, addr, val| mem[addr] = val,
+    ///     ResizeFunctions::new(
+    ///         |mem: &mut Vec<u8>, addr, count| drop(mem.splice(addr..addr, std::iter::repeat_n(0, count))),
+    ///         |mem: &mut Vec<u8>, range| drop(mem.drain(range)),
+    ///     ),
+    /// );
+    /// ```
+    pub fn new<T: ?Sized>(insert_fn: I, delete_fn: D) -> Self
+    where
+        I: FnMut(&mut T, Address, usize),
+        D: FnMut(&mut T, Range<Address>),
+    {
+        ResizeFunctions { insert_fn, delete_fn }
+    }
+
+    /// Borrow the functions, to use them for multiple windows in the same frame.
+    pub(crate) fn by_ref(&mut self) -> ResizeFunctions<&mut I, &mut D> {
+        ResizeFunctions {
+            insert_fn: &mut self.insert_fn,
+            delete_fn: &mut self.delete_fn,
+        }
+    }
+}
+
+impl MemoryEditor {
+    /// Insert `count` bytes before the given address.
+    pub(crate) fn insert_bytes<T: ?Sized>(
+        &mut self,
+        mem: &mut T,
+        resize_fns: &mut ResizeFunctions<impl FnMut(&mut T, Address, usize), impl FnMut(&mut T, Range<Address>)>,
+        address: Address,
+        count: usize,
+    ) {
+        (resize_fns.insert_fn)(mem, address, count);
+        self.frame_data.pending_range_resize += count as isize;
+    }
+
+    /// Remove the selected bytes within `address_range` from memory, and clear the selection.
+    ///
+    /// Runs containing an address without write permission, or which the write guard rejects writing a zero to, are
+    /// kept. Deleting bytes can't be undone.
+    pub(crate) fn delete_selection<T: ?Sized>(
+        &mut self,
+        mem: &mut T,
+        resize_fns: &mut ResizeFunctions<impl FnMut(&mut T, Address, usize), impl FnMut(&mut T, Range<Address>)>,
+        address_range: &Range<Address>,
+    ) {
+        // Delete from the back so that the addresses of the remaining runs stay valid.
+        for run in self.frame_data.selection.merged_ranges().into_iter().rev() {
+            let range = run.start.max(address_range.start)..run.end.min(address_range.end);
+            if range.is_empty() {
+                continue;
+            }
+            if let Some(address) = range.clone().find(|&address| {
+                !self.permissions_at(address).write || !Self::is_write_allowed(&self.write_guard, address, 0)
+            }) {
+                self.frame_data.notify(Notice::WriteRejected { address });
+                continue;
+            }
+
+            self.frame_data.pending_range_resize -= range.len() as isize;
+            (resize_fns.delete_fn)(mem, range);
+        }
+
+        self.frame_data.selection.clear();
+        self.frame_data.selection_anchor = None;
+    }
+
+    /// Toggle between inserting and overwriting typed values with the `Insert` key.
+    pub(crate) fn handle_insert_mode_shortcut(&mut self, ctx: &Context) {
+        if self.has_keyboard_focus(ctx) && ctx.input_mut(|i| i.consume_key(Modifiers::NONE, Key::Insert)) {
+            self.frame_data.insert_mode = !self.frame_data.insert_mode;
+        }
+    }
+
+    /// Grow or shrink the selected address range by the amount of bytes inserted/deleted this frame.
+    pub(crate) fn apply_pending_range_resize(&mut self) {
+        let resize = std::mem::take(&mut self.frame_data.pending_range_resize);
+
+        if resize == 0 {
+            return;
+        }
+
+        let selected_address_range = self.window.selected_address_range.clone();
+
+        if let Some(range) = self.address_range_mut(&selected_address_range) {
+            range.end = range.end.saturating_add_signed(resize).max(range.start);
+            let end = range.end;
+
+            // The cursor may now point past the end of the range.
+            if matches!(self.frame_data.selected_edit_address, Some(address) if address >= end) {
+                self.frame_data.selected_edit_address = None;
+            }
+            if matches!(self.frame_data.selected_highlight_address, Some(address) if address >= end) {
+                self.frame_data.selected_highlight_address = None;
+            }
+        }
+    }
+}
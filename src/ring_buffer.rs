@@ -0,0 +1,130 @@
+//! Showing an address range as a ring buffer, see [`RingBufferCursors`].
+use std::ops::Range;
+use std::sync::Arc;
+
+use egui::Ui;
+
+use crate::{Address, MemoryEditor};
+
+pub(crate) type RingBufferProvider = Arc<dyn Fn() -> RingBufferCursors + Send + Sync>;
+
+/// The positions in a ring buffer, as provided by the host every frame.
+///
+/// The data runs from the tail up to the head, wrapping around from the end of the address range to its start. A
+/// ring buffer whose head equals its tail is empty.
+///
+/// ```
+/// # use egui_memory_editor::ring_buffer::RingBufferCursors;
+/// let cursors = RingBufferCursors { head: 0x1004, tail: 0x10FC };
+/// let range = 0x1000..0x1100;
+///
+/// assert_eq!(cursors.len(&range), 8);
+/// assert!(cursors.contains(0x10FF) && cursors.contains(0x1003));
+/// assert!(!cursors.contains(0x1004) && !cursors.contains(0x10FB));
+/// ```
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq)]
+pub struct RingBufferCursors {
+    /// The address the next value is written to.
+    pub head: Address,
+    /// The address of the oldest value.
+    pub tail: Address,
+}
+
+impl RingBufferCursors {
+    /// Whether `address` holds data, rather than being free space between the head and the tail.
+    pub fn contains(&self, address: Address) -> bool {
+        if self.tail <= self.head {
+            (self.tail..self.head).contains(&address)
+        } else {
+            address >= self.tail || address < self.head
+        }
+    }
+
+    /// The amount of values held in a ring buffer spanning `range`.
+    pub fn len(&self, range: &Range<Address>) -> usize {
+        if self.tail <= self.head {
+            self.head - self.tail
+        } else {
+            (range.end.saturating_sub(self.tail)) + (self.head.saturating_sub(range.start))
+        }
+    }
+
+    /// Whether the ring buffer holds no values.
+    pub fn is_empty(&self) -> bool {
+        self.head == self.tail
+    }
+}
+
+impl MemoryEditor {
+    /// Show an address range as a ring buffer, whose head and tail are provided by the host every frame.
+    ///
+    /// The free space between the head and the tail is greyed out. With
+    /// [`crate::option_data::MemoryEditorOptions::unroll_ring_buffers`] the lines are shown in logical order,
+    /// starting at the line of the tail and wrapping around to the start of the range, while the address column
+    /// keeps showing the true addresses.
+    ///
+    /// ```
+    /// # use egui_memory_editor::MemoryEditor;
+    /// # use egui_memory_editor::ring_buffer::RingBufferCursors;
+    /// use std::sync::{Arc, Mutex};
+    ///
+    /// let cursors = Arc::new(Mutex::new(RingBufferCursors { head: 0x2010, tail: 0x2F00 }));
+    /// let shared = cursors.clone();
+    /// let memory_editor = MemoryEditor::new()
+    ///     .with_address_range("Event queue", 0x2000..0x3000)
+    ///     .with_ring_buffer("Event queue", move || *shared.lock().unwrap());
+    ///
+    /// assert!(memory_editor.is_ring_buffer("Event queue"));
+    /// ```
+    #[must_use]
+    pub fn with_ring_buffer(
+        mut self,
+        range_name: impl Into<String>,
+        cursors: impl Fn() -> RingBufferCursors + Send + Sync + 'static,
+    ) -> Self {
+        self.ring_buffers.insert(range_name.into(), Arc::new(cursors));
+        self
+    }
+
+    /// Whether the address range is shown as a ring buffer, see [`Self::with_ring_buffer`].
+    pub fn is_ring_buffer(&self, range_name: &str) -> bool {
+        self.ring_buffers.contains_key(range_name)
+    }
+
+    /// The current head and tail of the ring buffer in the given address range, if it is one.
+    pub(crate) fn ring_buffer_cursors(&self, range_name: &str) -> Option<RingBufferCursors> {
+        self.ring_buffers.get(range_name).map(|cursors| cursors())
+    }
+
+    /// The line to show first when unrolling the ring buffer of the current address range, if it should be unrolled.
+    pub(crate) fn unrolled_first_line(&self, address_space: &Range<Address>) -> Option<usize> {
+        let cursors = self
+            .frame_data
+            .ring_buffer
+            .filter(|_| self.options.unroll_ring_buffers)?;
+
+        address_space
+            .contains(&cursors.tail)
+            .then(|| (cursors.tail - address_space.start) / self.options.column_count)
+    }
+
+    /// Draw the head and tail of the ring buffer of the current address range, and the toggle for unrolling it.
+    pub(crate) fn draw_ring_buffer_options(&mut self, ui: &mut Ui, address_space: &Range<Address>) {
+        let tr = self.translator();
+        let Some(cursors) = self.frame_data.ring_buffer else {
+            return;
+        };
+
+        ui.label(tr.format(
+            "Ring buffer {tail}–{head} ({count} bytes)",
+            &[
+                ("tail", &format!("{:#X}", cursors.tail)),
+                ("head", &format!("{:#X}", cursors.head)),
+                ("count", &cursors.len(address_space)),
+            ],
+        ))
+        .on_hover_text(tr.text("The tail and head of the ring buffer, and the amount of values it holds"));
+        ui.checkbox(&mut self.options.unroll_ring_buffers, tr.text("Unroll"))
+            .on_hover_text(tr.text("Show the lines in logical order, starting at the line of the tail"));
+    }
+}
@@ -0,0 +1,218 @@
+use std::ops::Range;
+
+/// A single row in the scroll area.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub(crate) enum Row<'a> {
+    /// A labelled separator shown before a line.
+    Separator(&'a str),
+    /// A line of memory values, indexed from the start of the address range.
+    Line(usize),
+    /// Multiple lines shown as a single row, indexed from the start of the address range.
+    Collapsed(Range<usize>, CollapsedKind),
+}
+
+/// Why lines were collapsed into a single row.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub(crate) enum CollapsedKind {
+    /// The lines are in an unmapped address range.
+    Unmapped,
+    /// The lines are identical to the line before them.
+    Repeated,
+    /// The lines don't contain any tagged value, see [`crate::tags::UntaggedStyle::Hidden`].
+    Untagged,
+}
+
+/// A row which isn't simply a line of memory values.
+#[derive(Clone, Debug)]
+enum SpecialRowKind {
+    Separator(String),
+    Collapsed(Range<usize>, CollapsedKind),
+}
+
+#[derive(Clone, Debug)]
+struct SpecialRow {
+    row: usize,
+    /// The line before which the row is shown, for collapsed rows this is the first collapsed line.
+    line: usize,
+    kind: SpecialRowKind,
+}
+
+impl SpecialRow {
+    /// The first line shown after this row.
+    fn next_line(&self) -> usize {
+        match &self.kind {
+            SpecialRowKind::Separator(_) => self.line,
+            SpecialRowKind::Collapsed(lines, _) => lines.end,
+        }
+    }
+}
+
+/// Maps the rows of the scroll area to either lines of memory, the separator rows in between them, or rows which
+/// stand in for multiple collapsed lines.
+///
+/// Without separators or collapsed lines every row is simply the line with the same index, unless the layout is
+/// rotated to start at a later line, see [`RowLayout::rotated`].
+#[derive(Clone, Debug)]
+pub(crate) struct RowLayout {
+    /// All rows which aren't a single line, sorted by row (and therefore by line).
+    special_rows: Vec<SpecialRow>,
+    row_count: usize,
+    line_count: usize,
+    /// The line shown in the first row, the lines before it are shown after the last line.
+    first_line: usize,
+}
+
+impl RowLayout {
+    /// Create a layout with the given separators and collapsed lines, neither of which need to be sorted.
+    ///
+    /// Separators for the same line are combined into one, and separators in the middle of collapsed lines are dropped.
+    /// Overlapping collapsed lines are merged if they were collapsed for the same reason, otherwise the later lines are
+    /// shortened to start after the earlier ones.
+    pub fn new(
+        line_count: usize,
+        mut separators: Vec<(usize, String)>,
+        mut collapsed: Vec<(Range<usize>, CollapsedKind)>,
+    ) -> Self {
+        separators.sort_by_key(|(line, _)| *line);
+        separators.dedup_by(|(line, label), (previous_line, previous_label)| {
+            let is_same_line = line == previous_line;
+            if is_same_line {
+                previous_label.push_str(", ");
+                previous_label.push_str(label);
+            }
+            is_same_line
+        });
+
+        for (lines, _) in &mut collapsed {
+            lines.end = lines.end.min(line_count);
+        }
+        collapsed.sort_by_key(|(lines, _)| lines.start);
+        let mut merged: Vec<(Range<usize>, CollapsedKind)> = Vec::with_capacity(collapsed.len());
+        for (mut lines, kind) in collapsed {
+            match merged.last_mut() {
+                Some((previous, previous_kind)) if lines.start < previous.end && kind == *previous_kind => {
+                    previous.end = previous.end.max(lines.end)
+                }
+                Some((previous, _)) => {
+                    lines.start = lines.start.max(previous.end);
+                    if !lines.is_empty() {
+                        merged.push((lines, kind));
+                    }
+                }
+                None if !lines.is_empty() => merged.push((lines, kind)),
+                None => {}
+            }
+        }
+        separators.retain(|(line, _)| !merged.iter().any(|(lines, _)| lines.start < *line && *line < lines.end));
+
+        // Separators come before collapsed lines starting at the same line.
+        let mut special_rows: Vec<SpecialRow> = separators
+            .into_iter()
+            .map(|(line, label)| (line, SpecialRowKind::Separator(label)))
+            .chain(
+                merged
+                    .into_iter()
+                    .map(|(lines, kind)| (lines.start, SpecialRowKind::Collapsed(lines, kind))),
+            )
+            .map(|(line, kind)| SpecialRow { row: 0, line, kind })
+            .collect();
+        special_rows.sort_by_key(|special| (special.line, matches!(special.kind, SpecialRowKind::Collapsed(..))));
+
+        // Every separator adds a row, every collapsed range of lines removes all but one.
+        let mut row_count = line_count;
+        for special in &mut special_rows {
+            special.row = (special.line + row_count) - line_count;
+
+            match &special.kind {
+                SpecialRowKind::Separator(_) => row_count += 1,
+                SpecialRowKind::Collapsed(lines, _) => row_count -= lines.len() - 1,
+            }
+        }
+
+        RowLayout {
+            special_rows,
+            row_count,
+            line_count,
+            first_line: 0,
+        }
+    }
+
+    /// Create a layout without separators or collapsed lines which starts at `first_line`, and wraps around to the
+    /// first line after the last one.
+    pub fn rotated(line_count: usize, first_line: usize) -> Self {
+        RowLayout {
+            special_rows: Vec::new(),
+            row_count: line_count,
+            line_count,
+            first_line: if first_line < line_count { first_line } else { 0 },
+        }
+    }
+
+    /// The total amount of rows, including separators.
+    pub fn row_count(&self) -> usize {
+        self.row_count
+    }
+
+    /// What should be displayed in the given row.
+    pub fn row(&self, row: usize) -> Row<'_> {
+        let index = self.special_rows.partition_point(|special| special.row <= row);
+
+        match index.checked_sub(1).map(|i| &self.special_rows[i]) {
+            None if self.first_line == 0 => Row::Line(row),
+            None => Row::Line((row + self.first_line) % self.line_count),
+            Some(special) if special.row == row => match &special.kind {
+                SpecialRowKind::Separator(label) => Row::Separator(label),
+                SpecialRowKind::Collapsed(lines, kind) => Row::Collapsed(lines.clone(), *kind),
+            },
+            Some(special) => Row::Line(special.next_line() + (row - special.row - 1)),
+        }
+    }
+
+    /// The row in which the given line is displayed.
+    pub fn row_of_line(&self, line: usize) -> usize {
+        let index = self.special_rows.partition_point(|special| special.line <= line);
+
+        match index.checked_sub(1).map(|i| &self.special_rows[i]) {
+            None if self.first_line == 0 => line,
+            None => (line + self.line_count - self.first_line) % self.line_count,
+            Some(SpecialRow {
+                row,
+                kind: SpecialRowKind::Collapsed(lines, _),
+                ..
+            }) if line < lines.end => *row,
+            Some(special) => special.row + 1 + (line - special.next_line()),
+        }
+    }
+
+    /// The line displayed in the given row, or the line directly after it if the row is a separator.
+    ///
+    /// For collapsed rows this is the first collapsed line.
+    pub fn line_at_or_after(&self, row: usize) -> usize {
+        if row >= self.row_count() {
+            return self.line_count;
+        }
+
+        match self.row(row) {
+            Row::Line(line) => line,
+            Row::Collapsed(lines, _) => lines.start,
+            Row::Separator(_) => self.line_at_or_after(row + 1),
+        }
+    }
+
+    /// The lines displayed in the given rows.
+    ///
+    /// When the rows of a rotated layout wrap around to the first line, the lines aren't contiguous, so every line is
+    /// returned instead.
+    pub fn visible_lines(&self, rows: Range<usize>) -> Range<usize> {
+        let start = self.line_at_or_after(rows.start);
+        if self.first_line == 0 {
+            return start..self.line_at_or_after(rows.end);
+        }
+
+        if start + rows.len() <= self.line_count {
+            start..start + rows.len()
+        } else {
+            0..self.line_count
+        }
+    }
+}
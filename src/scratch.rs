@@ -0,0 +1,58 @@
+use std::sync::Arc;
+
+use crate::MemoryEditor;
+
+impl MemoryEditor {
+    /// Add a read-only address range holding `data` itself, rather than being read from the host's memory.
+    ///
+    /// Useful for browsing derived data, like the output of a transform (see [`Self::with_transform`]), alongside
+    /// the regular address ranges. The range starts at address `0`, and replaces any scratch region with the same name.
+    ///
+    /// ```
+    /// # use egui_memory_editor::MemoryEditor;
+    /// let mut memory_editor = MemoryEditor::new().with_address_range("ROM", 0..0x8000);
+    /// memory_editor.open_scratch_region("Decompressed @0x4000", vec![0x12; 0x200]);
+    /// assert!(memory_editor.set_selected_address_range("Decompressed @0x4000"));
+    ///
+    /// assert_eq!(memory_editor.region_len("Decompressed @0x4000"), Some(0x200));
+    /// assert_eq!(memory_editor.close_scratch_region("Decompressed @0x4000").map(|data| data.len()), Some(0x200));
+    /// assert_eq!(memory_editor.selected_address_range(), "ROM");
+    /// ```
+    pub fn open_scratch_region(&mut self, range_name: impl Into<String>, data: Vec<u8>) {
+        let range_name = range_name.into();
+
+        self.set_address_range(range_name.clone(), 0..data.len());
+        self.scratch_regions.insert(range_name, data.into());
+    }
+
+    /// Remove a scratch region added with [`Self::open_scratch_region`], returning its data.
+    ///
+    /// If the region was displayed, the first remaining address range is displayed instead.
+    pub fn close_scratch_region(&mut self, range_name: &str) -> Option<Vec<u8>> {
+        let data = self.scratch_regions.remove(range_name)?;
+
+        self.address_ranges.retain(|(name, _)| name != range_name);
+        self.region_options.remove(range_name);
+        self.frame_data.memory_range_combo_box_enabled = self.address_ranges.len() > 1;
+        if self.window.selected_address_range == range_name {
+            let first = self
+                .address_ranges
+                .first()
+                .map(|(name, _)| name.clone())
+                .unwrap_or_default();
+            self.select_address_range(first);
+        }
+
+        Some(data.to_vec())
+    }
+
+    /// The data of the scratch region with the given name, if it exists.
+    pub fn scratch_region(&self, range_name: &str) -> Option<&[u8]> {
+        self.scratch_regions.get(range_name).map(|data| &data[..])
+    }
+
+    /// The data of the displayed address range, if it's a scratch region.
+    pub(crate) fn selected_scratch_data(&self) -> Option<Arc<[u8]>> {
+        self.scratch_regions.get(&self.window.selected_address_range).cloned()
+    }
+}
@@ -0,0 +1,391 @@
+//! Running small scripts of memory commands inside the editor, such as `xor 2000..2100, 55`.
+//!
+//! A script has one command per line, followed by its arguments separated by commas. Empty lines and lines starting
+//! with `#` are skipped. Addresses and values are address expressions, see
+//! [`MemoryEditor::evaluate_address_expression`], so they can use symbols and arithmetic. A range is either
+//! `start..end`, with an exclusive end, a single address, or `selection` for the selected ranges.
+//!
+//! The scripts can be run from the console window, opened with the `⌨` button next to the goto box, or from the host
+//! with [`MemoryEditor::run_script`]. Only available with the `scripting` feature.
+use std::ops::Range;
+
+use egui::{Context, Ui, Window};
+
+use crate::navigation::SearchDirection;
+use crate::selection::Selection;
+use crate::{Address, MemoryEditor};
+
+/// The commands of the script language with their arguments, shown in the console as a reference.
+pub const SCRIPT_COMMANDS: &[(&str, &str)] = &[
+    ("print", "print EXPRESSION"),
+    ("read", "read RANGE"),
+    ("write", "write ADDRESS, VALUE, ..."),
+    ("fill", "fill RANGE, VALUE"),
+    ("xor", "xor RANGE, VALUE"),
+    ("and", "and RANGE, VALUE"),
+    ("or", "or RANGE, VALUE"),
+    ("add", "add RANGE, VALUE"),
+    ("sub", "sub RANGE, VALUE"),
+    ("copy", "copy RANGE, ADDRESS"),
+    ("find", "find VALUE, ..."),
+    ("select", "select [RANGE]"),
+    ("goto", "goto ADDRESS"),
+];
+/// The most values a single `read` command prints.
+const MAX_READ_VALUES: usize = 0x100;
+/// The amount of values per line printed by `read`.
+const READ_VALUES_PER_LINE: usize = 16;
+
+impl MemoryEditor {
+    /// Run a script of memory commands on the selected address range, returning the lines it printed.
+    ///
+    /// Writes go straight to `write_fn`, they aren't checked against the permissions or recorded in the undo history
+    /// like writes from the console are. The script stops at the first line which fails, see the
+    /// [module documentation](crate::scripting) for the syntax.
+    ///
+    /// ```
+    /// # use egui_memory_editor::MemoryEditor;
+    /// let mut memory = vec![0xFFu8; 0x10000];
+    /// let mut memory_editor = MemoryEditor::new().with_address_range("RAM", 0..0x10000);
+    /// memory_editor.set_symbol(0x2000, "tiles");
+    ///
+    /// let output = memory_editor.run_script(
+    ///     &mut memory,
+    ///     |mem, address| mem.get(address).copied(),
+    ///     |mem, address, value| mem[address] = value,
+    ///     "# Decrypt the tiles\nxor tiles..tiles+100, 55\nread tiles+FE..tiles+102",
+    /// );
+    ///
+    /// assert_eq!(output, Ok(vec!["xor: 256 value(s) written".to_string(), "0x20FE: AA AA FF FF".to_string()]));
+    /// assert_eq!(memory[0x2000], 0xAA);
+    ///
+    /// let error = memory_editor.run_script(&mut memory, |mem, address| mem.get(address).copied(), |_, _, _| {}, "fill 0..10, 100");
+    /// assert_eq!(error, Err("line 1: 0x100 doesn't fit in a byte".to_string()));
+    /// ```
+    pub fn run_script<T: ?Sized>(
+        &mut self,
+        mem: &mut T,
+        mut read_fn: impl FnMut(&mut T, Address) -> Option<u8>,
+        write_fn: impl FnMut(&mut T, Address, u8),
+        script: &str,
+    ) -> Result<Vec<String>, String> {
+        let address_range = self
+            .address_range(&self.window.selected_address_range)
+            .ok_or_else(|| self.translator().text("there is no address range").into_owned())?
+            .clone();
+        let mut output = Vec::new();
+
+        self.execute_script(
+            mem,
+            &mut read_fn,
+            &mut Some(write_fn),
+            script,
+            &address_range,
+            &mut output,
+        )?;
+
+        Ok(output)
+    }
+
+    /// Run every line of `script`, pushing the lines it prints to `output`, until a line fails.
+    fn execute_script<T: ?Sized>(
+        &mut self,
+        mem: &mut T,
+        read_fn: &mut impl FnMut(&mut T, Address) -> Option<u8>,
+        write_fn: &mut Option<impl FnMut(&mut T, Address, u8)>,
+        script: &str,
+        address_range: &Range<Address>,
+        output: &mut Vec<String>,
+    ) -> Result<(), String> {
+        let tr = self.translator();
+
+        for (index, line) in script.lines().enumerate() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+
+            self.execute_script_line(mem, read_fn, write_fn, line, address_range, output)
+                .map_err(|error| tr.format("line {line}: {error}", &[("line", &(index + 1)), ("error", &error)]))?;
+        }
+
+        Ok(())
+    }
+
+    fn execute_script_line<T: ?Sized>(
+        &mut self,
+        mem: &mut T,
+        read_fn: &mut impl FnMut(&mut T, Address) -> Option<u8>,
+        write_fn: &mut Option<impl FnMut(&mut T, Address, u8)>,
+        line: &str,
+        address_range: &Range<Address>,
+        output: &mut Vec<String>,
+    ) -> Result<(), String> {
+        let tr = self.translator();
+        let cant_write = || tr.text("the memory can't be written").into_owned();
+        let (command, arguments) = line.split_once(char::is_whitespace).unwrap_or((line, ""));
+        let command = command.to_ascii_lowercase();
+        let arguments = match arguments.trim() {
+            "" => Vec::new(),
+            arguments => arguments.split(',').map(str::trim).collect::<Vec<_>>(),
+        };
+        let usage = || {
+            let usage = SCRIPT_COMMANDS.iter().find(|(name, _)| *name == command);
+            tr.format(
+                "expected `{usage}`",
+                &[("usage", &usage.map_or("", |(_, usage)| usage))],
+            )
+        };
+
+        match (command.as_str(), arguments.as_slice()) {
+            ("print", [expression]) => {
+                let value = self.evaluate_address_expression(expression)?;
+                output.push(format!("{:#X} ({})", value, value));
+            }
+            ("read", [range]) => {
+                let selection = self.script_selection(range)?;
+
+                for chunk in selection
+                    .addresses()
+                    .take(MAX_READ_VALUES)
+                    .collect::<Vec<_>>()
+                    .chunks(READ_VALUES_PER_LINE)
+                {
+                    let values = chunk
+                        .iter()
+                        .map(|&address| match read_fn(mem, address) {
+                            Some(value) => format!("{:02X}", value),
+                            None => self.options.none_display_value.clone(),
+                        })
+                        .collect::<Vec<_>>();
+                    output.push(format!("{:#X}: {}", chunk[0], values.join(" ")));
+                }
+            }
+            ("write", [address, values @ ..]) if !values.is_empty() => {
+                let address = self.evaluate_address_expression(address)?;
+                let values = values
+                    .iter()
+                    .map(|value| self.script_value(value))
+                    .collect::<Result<Vec<_>, _>>()?;
+                let write = write_fn.as_mut().ok_or_else(cant_write)?;
+
+                for (offset, value) in values.iter().enumerate() {
+                    write(mem, address + offset, *value);
+                }
+                output.push(tr.format("write: {count} value(s) written", &[("count", &values.len())]));
+            }
+            ("fill" | "xor" | "and" | "or" | "add" | "sub", [range, value]) => {
+                let selection = self.script_selection(range)?;
+                let operand = self.script_value(value)?;
+                let write = write_fn.as_mut().ok_or_else(cant_write)?;
+                let mut written = 0;
+
+                for address in selection.addresses() {
+                    let value = match command.as_str() {
+                        "fill" => Some(operand),
+                        "xor" => read_fn(mem, address).map(|value| value ^ operand),
+                        "and" => read_fn(mem, address).map(|value| value & operand),
+                        "or" => read_fn(mem, address).map(|value| value | operand),
+                        "add" => read_fn(mem, address).map(|value| value.wrapping_add(operand)),
+                        _ => read_fn(mem, address).map(|value| value.wrapping_sub(operand)),
+                    };
+                    // Values which can't be read are left alone.
+                    if let Some(value) = value {
+                        write(mem, address, value);
+                        written += 1;
+                    }
+                }
+                output.push(tr.format(
+                    "{command}: {count} value(s) written",
+                    &[("command", &command), ("count", &written)],
+                ));
+            }
+            ("copy", [range, destination]) => {
+                let addresses = self.script_selection(range)?.addresses().collect::<Vec<_>>();
+                let destination = self.evaluate_address_expression(destination)?;
+                let Some(&start) = addresses.first() else {
+                    return Err(tr.text("the range is empty").into_owned());
+                };
+                // Read everything before writing, so overlapping ranges copy correctly.
+                let values = addresses
+                    .iter()
+                    .map(|&address| (address - start, read_fn(mem, address)))
+                    .collect::<Vec<_>>();
+                let write = write_fn.as_mut().ok_or_else(cant_write)?;
+                let mut written = 0;
+
+                for (offset, value) in values {
+                    if let Some(value) = value {
+                        write(mem, destination + offset, value);
+                        written += 1;
+                    }
+                }
+                output.push(tr.format("copy: {count} value(s) written", &[("count", &written)]));
+            }
+            ("find", values) if !values.is_empty() => {
+                let pattern = values
+                    .iter()
+                    .map(|value| self.script_value(value))
+                    .collect::<Result<Vec<_>, _>>()?;
+
+                match self.find_pattern(mem, read_fn, &pattern, address_range, SearchDirection::Forward) {
+                    Some(address) => {
+                        let address = format!("{:#X}", address);
+                        output.push(tr.format("find: found at {address}", &[("address", &address)]));
+                    }
+                    None => output.push(tr.text("find: not found").into_owned()),
+                }
+            }
+            ("select", []) => {
+                let ranges = self.selection().merged_ranges();
+                if ranges.is_empty() {
+                    output.push(tr.text("select: nothing is selected").into_owned());
+                }
+                for range in ranges {
+                    output.push(format!("{:#X}..{:#X}", range.start, range.end));
+                }
+            }
+            ("select", [range]) => {
+                let selection = self.script_selection(range)?;
+                self.set_selection(selection);
+            }
+            ("goto", [address]) => {
+                let address = self.evaluate_address_expression(address)?;
+                if !address_range.contains(&address) {
+                    let address = format!("{:#X}", address);
+                    return Err(tr.format("{address} is outside of the address range", &[("address", &address)]));
+                }
+                self.jump_to_address(address, address_range);
+            }
+            _ if SCRIPT_COMMANDS.iter().any(|(name, _)| *name == command) => return Err(usage()),
+            _ => return Err(tr.format("unknown command `{command}`", &[("command", &command)])),
+        }
+
+        Ok(())
+    }
+
+    /// The addresses of a range argument, see the [module documentation](crate::scripting).
+    fn script_selection(&self, text: &str) -> Result<Selection, String> {
+        let tr = self.translator();
+        if text == "selection" {
+            return if self.selection().is_empty() {
+                Err(tr.text("nothing is selected").into_owned())
+            } else {
+                Ok(self.selection().clone())
+            };
+        }
+
+        match text.split_once("..") {
+            Some((start, end)) => {
+                let start = self.evaluate_address_expression(start)?;
+                let end = self.evaluate_address_expression(end)?;
+                if end < start {
+                    let range = format!("{:#X}..{:#X}", start, end);
+                    return Err(tr.format("the range {range} ends before it starts", &[("range", &range)]));
+                }
+                Ok(Selection::from_range(start..end))
+            }
+            None => {
+                let address = self.evaluate_address_expression(text)?;
+                Ok(Selection::from_range(address..address + 1))
+            }
+        }
+    }
+
+    /// A value argument, which has to fit in a byte.
+    fn script_value(&self, text: &str) -> Result<u8, String> {
+        let value = self.evaluate_address_expression(text)?;
+        u8::try_from(value).map_err(|_| {
+            let value = format!("{:#X}", value);
+            self.translator()
+                .format("{value} doesn't fit in a byte", &[("value", &value)])
+        })
+    }
+
+    /// Draw the button which toggles the script console.
+    pub(crate) fn draw_console_button(&mut self, ui: &mut Ui) {
+        let tr = self.translator();
+
+        if ui
+            .selectable_label(self.frame_data.show_console, "⌨")
+            .on_hover_text(tr.text("Run scripts of memory commands"))
+            .clicked()
+        {
+            self.frame_data.show_console = !self.frame_data.show_console;
+        }
+    }
+
+    /// Draw the script console, if it's open.
+    ///
+    /// Unlike [`Self::run_script`], writes from the console go through the editor, so they respect the permissions
+    /// and can be undone.
+    pub(crate) fn draw_console_window<T: ?Sized>(
+        &mut self,
+        ctx: &Context,
+        mem: &mut T,
+        read_fn: &mut impl FnMut(&mut T, Address) -> Option<u8>,
+        write_fn: &mut Option<impl FnMut(&mut T, Address, u8)>,
+        address_range: &Range<Address>,
+    ) {
+        if !self.frame_data.show_console {
+            return;
+        }
+        let tr = self.translator();
+        let mut is_open = true;
+        let mut run = false;
+
+        Window::new(tr.text("⌨ Console"))
+            .id(egui::Id::new("mem_edit_console"))
+            .open(&mut is_open)
+            .default_width(360.0)
+            .show(ctx, |ui| {
+                ui.add(
+                    egui::TextEdit::multiline(&mut self.frame_data.console_script)
+                        .code_editor()
+                        .desired_rows(6)
+                        .desired_width(f32::INFINITY)
+                        .hint_text("xor 2000..2100, 55"),
+                );
+
+                ui.horizontal(|ui| {
+                    run = ui.button(tr.text("▶ Run")).clicked();
+                    if ui.button(tr.text("Clear output")).clicked() {
+                        self.frame_data.console_output.clear();
+                        self.frame_data.console_error = None;
+                    }
+                    ui.label("ℹ").on_hover_ui(|ui| {
+                        ui.label(tr.text("Commands, with arguments separated by commas:"));
+                        for (_, usage) in SCRIPT_COMMANDS {
+                            ui.monospace(*usage);
+                        }
+                        ui.label(tr.text("A range is `start..end`, a single address, or `selection`."));
+                    });
+                });
+
+                egui::ScrollArea::vertical()
+                    .max_height(200.0)
+                    .stick_to_bottom(true)
+                    .show(ui, |ui| {
+                        for line in &self.frame_data.console_output {
+                            ui.monospace(line);
+                        }
+                        if let Some(error) = &self.frame_data.console_error {
+                            ui.colored_label(ui.visuals().error_fg_color, error);
+                        }
+                    });
+            });
+
+        if run {
+            let script = self.frame_data.console_script.clone();
+            let mut output = std::mem::take(&mut self.frame_data.console_output);
+
+            self.frame_data.console_error = self
+                .execute_script(mem, read_fn, write_fn, &script, address_range, &mut output)
+                .err();
+            self.frame_data.console_output = output;
+        }
+        if !is_open {
+            self.frame_data.show_console = false;
+        }
+    }
+}
@@ -0,0 +1,131 @@
+use egui::{Context, Event, Id, MouseWheelUnit, Rect, Ui};
+
+use crate::{Address, MemoryEditor};
+
+/// The rows scrolled per notch of the mouse wheel when snapping without a custom scroll speed, and the initial
+/// custom scroll speed.
+pub(crate) const DEFAULT_ROWS_PER_NOTCH: usize = 3;
+
+/// The duration of the scroll animation of a jump, in seconds.
+const JUMP_ANIMATION_SECONDS: f64 = 0.25;
+/// The duration of the flash of the value jumped to, in seconds.
+const JUMP_FLASH_SECONDS: f64 = 1.0;
+
+/// The scroll position of the main UI in the last frame, for scrolling by rows and animating jumps.
+#[derive(Copy, Clone, Debug, Default)]
+pub(crate) struct ScrollState {
+    /// Whether the offset of the window state should be applied to the scroll area, after it was restored.
+    pub restore_offset: bool,
+    pub rect: Option<Rect>,
+    /// The scroll distance of the mouse wheel which didn't amount to a whole row yet, when snapping to rows.
+    pub remainder: f32,
+    /// The start offset, target offset, and start time of the current jump animation.
+    pub animation: Option<(f32, f32, f64)>,
+    /// The address jumped to, and the time it started flashing.
+    pub flash: Option<(Address, f64)>,
+}
+
+impl ScrollState {
+    /// How strongly `address` flashes, fading from `1.0` to `0.0`, if it was jumped to recently.
+    pub fn flash_strength(&self, address: Address, time: f64) -> Option<f32> {
+        let (flashed, start) = self.flash?;
+        let elapsed = time - start;
+
+        (flashed == address && elapsed < JUMP_FLASH_SECONDS).then(|| 1.0 - (elapsed / JUMP_FLASH_SECONDS) as f32)
+    }
+}
+
+impl MemoryEditor {
+    /// Scroll to `offset` with a short eased animation, flashing the value at the cursor once it's reached.
+    pub(crate) fn start_jump_animation(&mut self, offset: f32, time: f64) {
+        let flash = self.frame_data.cursor_address().map(|address| (address, time));
+        let state = &mut self.frame_data.scroll;
+
+        state.animation = Some((self.window.scroll_offset, offset, time));
+        state.flash = flash;
+    }
+
+    /// The scroll offset of the current jump animation, if one is in progress.
+    pub(crate) fn animated_scroll_offset(&mut self, ui: &Ui) -> Option<f32> {
+        let time = ui.input(|i| i.time);
+        let state = &mut self.frame_data.scroll;
+
+        if state.flash.is_some_and(|(_, start)| time - start < JUMP_FLASH_SECONDS) {
+            ui.ctx().request_repaint();
+        }
+
+        let (from, to, start) = state.animation?;
+        let progress = ((time - start) / JUMP_ANIMATION_SECONDS).min(1.0) as f32;
+        if progress >= 1.0 {
+            state.animation = None;
+        }
+
+        // Ease out, such that the view slows down as it approaches the target.
+        let eased = 1.0 - (1.0 - progress).powi(3);
+        Some(from + (to - from) * eased)
+    }
+
+    /// Whether the editor scrolls with the mouse wheel itself, rather than leaving it to egui.
+    pub(crate) fn handles_mouse_wheel(&self) -> bool {
+        self.options.scroll_rows_per_notch.is_some() || self.options.snap_scroll_to_rows
+    }
+
+    /// The new scroll offset of the main UI, if the mouse wheel moved it or it should snap to a row boundary.
+    pub(crate) fn wheel_scroll_offset(&mut self, ui: &Ui, row_height: f32) -> Option<f32> {
+        let state = &mut self.frame_data.scroll;
+        let current_offset = self.window.scroll_offset;
+        let rows_per_notch = self.options.scroll_rows_per_notch.unwrap_or(DEFAULT_ROWS_PER_NOTCH);
+        let rect = state.rect?;
+        let is_hovered = ui
+            .input(|i| i.pointer.hover_pos())
+            .is_some_and(|pos| rect.contains(pos));
+
+        // Positive wheel deltas move the content down, which scrolls up.
+        let delta = if is_hovered {
+            ui.input(|i| {
+                i.events
+                    .iter()
+                    .filter_map(|event| match event {
+                        // Scrolling with `Ctrl` held zooms instead.
+                        Event::MouseWheel { modifiers, .. } if modifiers.command => None,
+                        Event::MouseWheel { unit, delta, .. } => Some(match unit {
+                            MouseWheelUnit::Point => -delta.y,
+                            MouseWheelUnit::Line => -delta.y * rows_per_notch as f32 * row_height,
+                            MouseWheelUnit::Page => -delta.y * rect.height(),
+                        }),
+                        _ => None,
+                    })
+                    .sum::<f32>()
+            })
+        } else {
+            0.0
+        };
+
+        let offset = if self.options.snap_scroll_to_rows {
+            let snapped = (current_offset / row_height).round() * row_height;
+            // Dragging the scroll bar moves freely, the view snaps once it's released.
+            if delta == 0.0 && ui.input(|i| i.pointer.any_down()) {
+                return None;
+            }
+
+            state.remainder += delta;
+            let rows = (state.remainder / row_height).trunc();
+            state.remainder -= rows * row_height;
+            snapped + rows * row_height
+        } else {
+            current_offset + delta
+        };
+        let offset = offset.max(0.0);
+
+        ((offset - current_offset).abs() > 0.01).then_some(offset)
+    }
+}
+
+/// Whether the vertical scroll bar of the `ScrollArea` with the given id is being dragged.
+///
+/// `egui` doesn't expose this through the `ScrollAreaOutput`, so this relies on the scroll bar being interacted with
+/// using the id of the `ScrollArea` combined with its axis, as done up to at least `egui` 0.33. If that changes, no
+/// drag is detected, rather than the editor breaking.
+pub(crate) fn is_vertical_scroll_bar_dragged(ctx: &Context, scroll_area_id: Id) -> bool {
+    ctx.is_being_dragged(scroll_area_id.with(1usize))
+}
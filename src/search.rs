@@ -0,0 +1,314 @@
+use std::ops::Range;
+
+use memchr::memmem;
+
+use crate::navigation::SearchDirection;
+use crate::selection::Selection;
+use crate::{Address, MemoryEditor};
+
+/// The amount of bytes read at once when searching, larger chunks amortise the cost of each read.
+const SEARCH_CHUNK_SIZE: usize = 64 * 1024;
+/// The maximum amount of matches found by a search across all regions.
+pub(crate) const MAX_SEARCH_RESULTS: usize = 1000;
+
+/// An occurrence of a pattern found by [`MemoryEditor::find_all_in_regions`].
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct SearchMatch {
+    /// The name of the address range the match was found in.
+    pub region: String,
+    /// The address of the first byte of the match.
+    pub address: Address,
+}
+
+impl MemoryEditor {
+    /// Find the next (or previous) occurrence of `pattern` from the cursor in the selected address range, reading memory
+    /// in large chunks with `bulk_read_fn`.
+    ///
+    /// The `bulk_read_fn` should fill the given buffer with the memory starting at the given address, and return
+    /// `false` if that memory can't be read. This is much faster than searching with a per-byte read function, as
+    /// large regions can be scanned with vectorised routines.
+    ///
+    /// If an occurrence is found it is selected and scrolled into view, and its address is returned.
+    /// Only occurrences aligned to [`crate::option_data::MemoryEditorOptions::search_alignment`] are found.
+    ///
+    /// ```
+    /// # use egui_memory_editor::{MemoryEditor, SearchDirection};
+    /// let mut memory = vec![0u8; 0x10000];
+    /// memory[0x1234..0x1238].copy_from_slice(&[0xDE, 0xAD, 0xBE, 0xEF]);
+    /// let mut memory_editor = MemoryEditor::new().with_address_range("Memory", 0..0x10000);
+    ///
+    /// let found = memory_editor.find_pattern_bulk(
+    ///     &mut memory,
+    ///     |mem, address, buffer| {
+    ///         buffer.copy_from_slice(&mem[address..address + buffer.len()]);
+    ///         true
+    ///     },
+    ///     &[0xDE, 0xAD, 0xBE, 0xEF],
+    ///     SearchDirection::Forward,
+    /// );
+    ///
+    /// assert_eq!(found, Some(0x1234));
+    /// ```
+    pub fn find_pattern_bulk<T: ?Sized>(
+        &mut self,
+        mem: &mut T,
+        mut bulk_read_fn: impl FnMut(&mut T, Address, &mut [u8]) -> bool,
+        pattern: &[u8],
+        direction: SearchDirection,
+    ) -> Option<Address> {
+        let address_range = self.address_range(&self.window.selected_address_range)?.clone();
+        let found = self.search_pattern(&address_range, pattern, direction, |address, buffer, _| {
+            bulk_read_fn(mem, address, buffer)
+        });
+
+        self.select_search_result(found, pattern, &address_range)
+    }
+
+    /// Find the next (or previous) occurrence of `pattern` from the cursor using a per-byte read function.
+    ///
+    /// See [`Self::find_pattern_bulk`].
+    pub(crate) fn find_pattern<T: ?Sized>(
+        &mut self,
+        mem: &mut T,
+        read_fn: &mut impl FnMut(&mut T, Address) -> Option<u8>,
+        pattern: &[u8],
+        address_range: &Range<Address>,
+        direction: SearchDirection,
+    ) -> Option<Address> {
+        let found = self.search_pattern(address_range, pattern, direction, |address, buffer, unreadable| {
+            for (offset, byte) in buffer.iter_mut().enumerate() {
+                match read_fn(mem, address + offset) {
+                    Some(value) => *byte = value,
+                    None => unreadable.push(offset),
+                }
+            }
+            true
+        });
+
+        self.select_search_result(found, pattern, address_range)
+    }
+
+    /// Find every occurrence of `pattern` in every address range, in the order the ranges are listed, up to a
+    /// maximum of 1000 matches.
+    ///
+    /// Only occurrences aligned to [`crate::option_data::MemoryEditorOptions::search_alignment`] are found. Mirrors
+    /// are skipped, as their values are those of their primary range, and unmapped or unreadable values never match.
+    ///
+    /// ```
+    /// # use egui_memory_editor::{MemoryEditor, SearchMatch};
+    /// let mut memory = vec![0u8; 0x10000];
+    /// memory[0x0104..0x0106].copy_from_slice(&[0xCE, 0xED]);
+    /// memory[0xC010..0xC012].copy_from_slice(&[0xCE, 0xED]);
+    /// let mut memory_editor = MemoryEditor::new()
+    ///     .with_address_range("ROM", 0x0000..0x8000)
+    ///     .with_address_range("WRAM", 0xC000..0xE000);
+    ///
+    /// let matches = memory_editor.find_all_in_regions(&mut memory, |mem, address| mem.get(address).copied(), &[0xCE, 0xED]);
+    /// assert_eq!(
+    ///     matches,
+    ///     [
+    ///         SearchMatch { region: "ROM".to_string(), address: 0x0104 },
+    ///         SearchMatch { region: "WRAM".to_string(), address: 0xC010 },
+    ///     ]
+    /// );
+    /// ```
+    pub fn find_all_in_regions<T: ?Sized>(
+        &self,
+        mem: &mut T,
+        mut read_fn: impl FnMut(&mut T, Address) -> Option<u8>,
+        pattern: &[u8],
+    ) -> Vec<SearchMatch> {
+        let mut matches = Vec::new();
+
+        for (region, address_range) in self.ordered_address_ranges() {
+            if self.mirror_of(region).is_some() {
+                continue;
+            }
+
+            let mut read = |mem: &mut T, address| self.read_unselected_region(region, mem, &mut read_fn, address);
+            let limit = MAX_SEARCH_RESULTS - matches.len();
+            let found = self.search_all(address_range, pattern, limit, |address, buffer, unreadable| {
+                for (offset, byte) in buffer.iter_mut().enumerate() {
+                    match read(mem, address + offset) {
+                        Some(value) => *byte = value,
+                        None => unreadable.push(offset),
+                    }
+                }
+                true
+            });
+
+            matches.extend(found.into_iter().map(|address| SearchMatch {
+                region: region.clone(),
+                address,
+            }));
+            if matches.len() >= MAX_SEARCH_RESULTS {
+                break;
+            }
+        }
+
+        matches
+    }
+
+    /// Switch to the region of `found` and select it, see [`Self::find_all_in_regions`].
+    pub(crate) fn select_search_match(&mut self, found: &SearchMatch, pattern_len: usize) {
+        if !self.set_selected_address_range(&found.region) {
+            return;
+        }
+        let Some(address_range) = self.address_range(&found.region).cloned() else {
+            return;
+        };
+
+        self.set_selection(Selection::from_range(found.address..found.address + pattern_len));
+        self.jump_to_address(found.address, &address_range);
+    }
+
+    fn select_search_result(
+        &mut self,
+        found: Option<Address>,
+        pattern: &[u8],
+        address_range: &Range<Address>,
+    ) -> Option<Address> {
+        let address = found?;
+
+        self.set_selection(Selection::from_range(address..address + pattern.len()));
+        self.jump_to_address(address, address_range);
+
+        Some(address)
+    }
+
+    /// Search for `pattern` after (or before) the cursor, reading `address_range` in chunks.
+    /// Matches which don't start at a multiple of the search alignment are skipped.
+    ///
+    /// The `read_chunk` function fills the buffer with the memory starting at the given address, pushing the offsets of
+    /// any unreadable bytes to the given `Vec`. It returns `false` if the entire chunk is unreadable.
+    fn search_pattern(
+        &self,
+        address_range: &Range<Address>,
+        pattern: &[u8],
+        direction: SearchDirection,
+        mut read_chunk: impl FnMut(Address, &mut [u8], &mut Vec<usize>) -> bool,
+    ) -> Option<Address> {
+        if pattern.is_empty() || pattern.len() > address_range.len() {
+            return None;
+        }
+
+        let cursor = self.frame_data.cursor_address();
+        let alignment = self.options.search_alignment.max(1);
+        // Consecutive chunks overlap, so matches spanning the boundary between them are found as well.
+        let overlap = pattern.len() - 1;
+        let chunk_size = SEARCH_CHUNK_SIZE.max(pattern.len() * 2);
+        let mut buffer = vec![0; chunk_size];
+        let mut unreadable = Vec::new();
+        let is_valid = |unreadable: &[usize], start: Address, offset: usize| {
+            is_valid_match(unreadable, start + offset, offset, pattern.len(), alignment)
+        };
+
+        match direction {
+            SearchDirection::Forward => {
+                let finder = memmem::Finder::new(pattern);
+                let mut start = cursor.map_or(address_range.start, |address| (address + 1).max(address_range.start));
+
+                while start + pattern.len() <= address_range.end {
+                    let end = (start + chunk_size).min(address_range.end);
+                    let chunk = &mut buffer[..end - start];
+                    unreadable.clear();
+
+                    if read_chunk(start, chunk, &mut unreadable)
+                        && let Some(offset) = finder
+                            .find_iter(chunk)
+                            .find(|&offset| is_valid(&unreadable, start, offset))
+                    {
+                        return Some(start + offset);
+                    }
+
+                    if end == address_range.end {
+                        break;
+                    }
+                    start = end - overlap;
+                }
+            }
+            SearchDirection::Backward => {
+                let finder = memmem::FinderRev::new(pattern);
+                // Only matches starting before the cursor count.
+                let mut end = cursor.map_or(address_range.end, |address| (address + overlap).min(address_range.end));
+
+                while end >= address_range.start + pattern.len() {
+                    let start = end.saturating_sub(chunk_size).max(address_range.start);
+                    let chunk = &mut buffer[..end - start];
+                    unreadable.clear();
+
+                    if read_chunk(start, chunk, &mut unreadable)
+                        && let Some(offset) = finder
+                            .rfind_iter(chunk)
+                            .find(|&offset| is_valid(&unreadable, start, offset))
+                    {
+                        return Some(start + offset);
+                    }
+
+                    if start == address_range.start {
+                        break;
+                    }
+                    end = start + overlap;
+                }
+            }
+        }
+
+        None
+    }
+
+    /// Find up to `limit` occurrences of `pattern` in `address_range`, reading it in chunks like
+    /// [`Self::search_pattern`].
+    fn search_all(
+        &self,
+        address_range: &Range<Address>,
+        pattern: &[u8],
+        limit: usize,
+        mut read_chunk: impl FnMut(Address, &mut [u8], &mut Vec<usize>) -> bool,
+    ) -> Vec<Address> {
+        let mut found = Vec::new();
+        if pattern.is_empty() || pattern.len() > address_range.len() {
+            return found;
+        }
+
+        let alignment = self.options.search_alignment.max(1);
+        let overlap = pattern.len() - 1;
+        let chunk_size = SEARCH_CHUNK_SIZE.max(pattern.len() * 2);
+        let finder = memmem::Finder::new(pattern);
+        let mut buffer = vec![0; chunk_size];
+        let mut unreadable = Vec::new();
+        let mut start = address_range.start;
+
+        while start + pattern.len() <= address_range.end && found.len() < limit {
+            let end = (start + chunk_size).min(address_range.end);
+            let chunk = &mut buffer[..end - start];
+            unreadable.clear();
+
+            if read_chunk(start, chunk, &mut unreadable) {
+                let last = found.last().copied();
+                let matches = finder
+                    .find_iter(chunk)
+                    .filter(|&offset| is_valid_match(&unreadable, start + offset, offset, pattern.len(), alignment))
+                    .map(|offset| start + offset)
+                    // Matches in the overlap were already found in the previous chunk.
+                    .filter(|&address| last.is_none_or(|last| address > last));
+                found.extend(matches.take(limit - found.len()));
+            }
+
+            if end == address_range.end {
+                break;
+            }
+            start = end - overlap;
+        }
+
+        found
+    }
+}
+
+/// Whether the match at `address`, which is `offset` into the chunk, is aligned and none of its bytes were unreadable.
+fn is_valid_match(unreadable: &[usize], address: Address, offset: usize, len: usize, alignment: usize) -> bool {
+    let first_unreadable = unreadable.partition_point(|&unreadable| unreadable < offset);
+    address.is_multiple_of(alignment)
+        && unreadable
+            .get(first_unreadable)
+            .is_none_or(|&unreadable| unreadable >= offset + len)
+}
@@ -0,0 +1,466 @@
+//! The selection model of the editor, see [`Selection`].
+use std::ops::Range;
+
+use egui::{Context, Event, Key, Modifiers, Ui};
+
+use crate::ascii_sidebar::decode_cell;
+use crate::{Address, MemoryEditor};
+
+/// A set of selected address ranges, which don't have to be contiguous.
+///
+/// In the UI a range can be selected by dragging across values, or by `Shift` clicking a value after
+/// selecting/highlighting another. Additional disjoint runs can be added by `Ctrl` clicking or `Ctrl` dragging.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Selection {
+    /// The selected runs in the order they were added, the last run is the one being extended by `Shift` clicks.
+    ranges: Vec<Range<Address>>,
+}
+
+impl Selection {
+    /// Create an empty selection.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Create a selection consisting of a single contiguous range.
+    pub fn from_range(range: Range<Address>) -> Self {
+        let mut result = Self::new();
+        result.add_range(range);
+        result
+    }
+
+    /// Create a strided selection of the same `field` in every record of `record_size` bytes, with the first record
+    /// starting at `records.start`.
+    ///
+    /// The `field` is given as offsets within a record, fields which don't end within `records` aren't selected.
+    /// Every field is a separate run, so copying the selection puts every field on its own line.
+    ///
+    /// ```
+    /// # use egui_memory_editor::selection::Selection;
+    /// // The two byte field at offset 4 of every 12 byte record.
+    /// let selection = Selection::strided(0x100..0x130, 12, 4..6);
+    ///
+    /// assert_eq!(selection.ranges(), &[0x104..0x106, 0x110..0x112, 0x11C..0x11E, 0x128..0x12A]);
+    /// ```
+    pub fn strided(records: Range<Address>, record_size: usize, field: Range<usize>) -> Self {
+        let mut result = Self::new();
+
+        for record_start in records.clone().step_by(record_size.max(1)) {
+            let run = record_start + field.start..record_start + field.end;
+
+            if run.end > records.end {
+                break;
+            }
+            result.add_range(run);
+        }
+
+        result
+    }
+
+    /// The selected runs, in the order in which they were added.
+    ///
+    /// Runs may overlap, see [`Self::merged_ranges`] for a normalised view.
+    pub fn ranges(&self) -> &[Range<Address>] {
+        &self.ranges
+    }
+
+    /// The selected runs sorted by address, with overlapping and adjacent runs merged together.
+    pub fn merged_ranges(&self) -> Vec<Range<Address>> {
+        let mut sorted = self.ranges.clone();
+        sorted.sort_by_key(|range| range.start);
+
+        let mut result: Vec<Range<Address>> = Vec::with_capacity(sorted.len());
+        for range in sorted {
+            match result.last_mut() {
+                Some(last) if range.start <= last.end => last.end = last.end.max(range.end),
+                _ => result.push(range),
+            }
+        }
+
+        result
+    }
+
+    /// Iterate over every selected address exactly once, in ascending order.
+    pub fn addresses(&self) -> impl Iterator<Item = Address> {
+        self.merged_ranges().into_iter().flatten()
+    }
+
+    /// The total amount of selected bytes.
+    pub fn len(&self) -> usize {
+        self.merged_ranges().iter().map(|range| range.len()).sum()
+    }
+
+    /// Whether nothing is selected.
+    pub fn is_empty(&self) -> bool {
+        self.ranges.is_empty()
+    }
+
+    /// Whether the given address is part of any selected run.
+    #[inline]
+    pub fn contains(&self, address: Address) -> bool {
+        self.ranges.iter().any(|range| range.contains(&address))
+    }
+
+    /// Add a new run to the selection, empty ranges are ignored.
+    pub fn add_range(&mut self, range: Range<Address>) {
+        if !range.is_empty() {
+            self.ranges.push(range);
+        }
+    }
+
+    /// Replace the last added run, or add it if the selection is empty.
+    pub(crate) fn replace_last_range(&mut self, range: Range<Address>) {
+        self.ranges.pop();
+        self.add_range(range);
+    }
+
+    /// Deselect everything.
+    pub fn clear(&mut self) {
+        self.ranges.clear();
+    }
+}
+
+/// Where the focus of the editor lies: the selected address range, the cursor, and the selection.
+///
+/// Can be saved and restored by the host with [`MemoryEditor::cursor_state`] and
+/// [`MemoryEditor::set_cursor_state`], for example as part of its own workspace persistence.
+///
+/// ```
+/// # use egui_memory_editor::MemoryEditor;
+/// # use egui_memory_editor::selection::{CursorState, Selection};
+/// let mut memory_editor = MemoryEditor::new()
+///     .with_address_range("RAM", 0..0x100)
+///     .with_address_range("ROM", 0x100..0x200);
+///
+/// memory_editor.set_cursor_state(CursorState {
+///     region: "ROM".to_string(),
+///     address: Some(0x120),
+///     selection: Selection::from_range(0x120..0x124),
+/// });
+///
+/// let state = memory_editor.cursor_state();
+/// assert_eq!(state.region, "ROM");
+/// assert_eq!(state.address, Some(0x120));
+/// assert_eq!(state.selection.len(), 4);
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+pub struct CursorState {
+    /// The name of the selected address range.
+    pub region: String,
+    /// The address of the cursor, if any.
+    pub address: Option<Address>,
+    pub selection: Selection,
+}
+
+impl MemoryEditor {
+    /// The selected address range, cursor and selection, see [`CursorState`].
+    pub fn cursor_state(&self) -> CursorState {
+        CursorState {
+            region: self.window.selected_address_range.clone(),
+            address: self.frame_data.cursor_address(),
+            selection: self.frame_data.selection.clone(),
+        }
+    }
+
+    /// Restore the selected address range, cursor and selection, scrolling the cursor into view.
+    ///
+    /// A region which doesn't exist leaves the selected address range unchanged, and a cursor outside of the address
+    /// range is ignored.
+    pub fn set_cursor_state(&mut self, state: CursorState) {
+        if self.address_range(&state.region).is_some() {
+            self.select_address_range(state.region);
+        }
+
+        match state.address {
+            Some(address) => {
+                if let Some(address_range) = self.address_range(&self.window.selected_address_range).cloned() {
+                    self.jump_to_address(address, &address_range);
+                }
+            }
+            None => {
+                self.frame_data.selected_highlight_address = None;
+                self.frame_data.selected_edit_address = None;
+            }
+        }
+
+        self.set_selection(state.selection);
+    }
+
+    /// The current selection in the UI.
+    pub fn selection(&self) -> &Selection {
+        &self.frame_data.selection
+    }
+
+    /// Replace the current selection.
+    pub fn set_selection(&mut self, selection: Selection) {
+        self.frame_data.selection = selection;
+        self.frame_data.selection_anchor = None;
+        self.frame_data.selected_from_ascii = false;
+    }
+
+    /// The range of the selection, from the start of the first run to the end of the last.
+    pub(crate) fn selection_span(&self) -> Option<Range<Address>> {
+        let merged = self.frame_data.selection.merged_ranges();
+        Some(merged.first()?.start..merged.last()?.end)
+    }
+
+    /// While drag selecting, show the length of the run being selected next to the pointer, and stop once the
+    /// pointer is released.
+    pub(crate) fn draw_drag_selection_badge(&mut self, ui: &mut Ui) {
+        if !self.frame_data.drag_selecting {
+            return;
+        }
+
+        if !ui.input(|i| i.pointer.primary_down()) {
+            self.frame_data.drag_selecting = false;
+            return;
+        }
+
+        let Some(run) = self.frame_data.selection.ranges().last() else {
+            return;
+        };
+        let tr = self.translator();
+        let word_bytes = self.options.word_size.bytes();
+        let text = if word_bytes > 1 {
+            let words = run.len().div_ceil(word_bytes);
+            tr.format(
+                "{count} bytes ({words} words)",
+                &[("count", &run.len()), ("words", &words)],
+            )
+        } else {
+            tr.format("{count} bytes", &[("count", &run.len())])
+        };
+
+        egui::Tooltip::always_open(
+            ui.ctx().clone(),
+            ui.layer_id(),
+            ui.id().with("drag_selection_badge"),
+            egui::PopupAnchor::Pointer,
+        )
+        .gap(12.0)
+        .show(|ui| ui.label(text));
+    }
+
+    /// Handle the selection shortcuts while the editor has keyboard focus.
+    ///
+    /// * `Ctrl+A` selects the entire address range.
+    /// * `Ctrl+C` copies the current selection, as text if it was selected in the ASCII sidebar.
+    pub(crate) fn handle_selection_shortcuts<T: ?Sized>(
+        &mut self,
+        ctx: &Context,
+        mem: &mut T,
+        read_fn: &mut impl FnMut(&mut T, Address) -> Option<u8>,
+        address_range: &Range<Address>,
+    ) {
+        if !self.has_keyboard_focus(ctx) {
+            return;
+        }
+
+        if ctx.input_mut(|i| i.consume_key(Modifiers::COMMAND, Key::A)) {
+            self.set_selection(Selection::from_range(address_range.clone()));
+        }
+
+        if !self.frame_data.selection.is_empty() && ctx.input(|i| i.events.iter().any(|e| matches!(e, Event::Copy))) {
+            self.copy_selection_from_pane(ctx, mem, read_fn);
+        }
+    }
+
+    /// Handle the shortcuts which modify the selected values, only active while the editor has keyboard focus.
+    ///
+    /// * `+` increments every selected value by one.
+    /// * `=` increments every selected value by one, or by `0x10` while holding `Shift`.
+    /// * `-` decrements every selected value by one, or by `0x10` while holding `Shift`.
+    ///
+    /// If nothing is selected the highlighted value is modified instead.
+    pub(crate) fn handle_selection_edit_shortcuts<T: ?Sized>(
+        &mut self,
+        ctx: &Context,
+        mem: &mut T,
+        read_fn: &mut impl FnMut(&mut T, Address) -> Option<u8>,
+        write_fn: &mut Option<impl FnMut(&mut T, Address, u8)>,
+    ) {
+        let Some(write_fn) = write_fn.as_mut() else {
+            return;
+        };
+        if !self.has_keyboard_focus(ctx) {
+            return;
+        }
+
+        let delta: i8 = ctx.input(|i| {
+            let step = if i.modifiers.shift { 0x10 } else { 1 };
+
+            // `Ctrl` + `+`/`-` is used for zooming by `egui`.
+            if i.modifiers.command {
+                0
+            } else if i.key_pressed(Key::Plus) {
+                // On many layouts `+` is typed with `Shift`, so it never uses the larger step.
+                1
+            } else if i.key_pressed(Key::Equals) {
+                step
+            } else if i.key_pressed(Key::Minus) {
+                -step
+            } else {
+                0
+            }
+        });
+
+        if delta != 0 {
+            self.add_to_selection(mem, read_fn, write_fn, delta);
+        }
+    }
+
+    /// Add the (wrapping) `delta` to every selected value, or to the highlighted value if nothing is selected.
+    ///
+    /// Values which can't be read are skipped.
+    pub(crate) fn add_to_selection<T: ?Sized>(
+        &self,
+        mem: &mut T,
+        read_fn: &mut impl FnMut(&mut T, Address) -> Option<u8>,
+        write_fn: &mut impl FnMut(&mut T, Address, u8),
+        delta: i8,
+    ) {
+        let addresses: Vec<Address> = if self.frame_data.selection.is_empty() {
+            self.frame_data.selected_highlight_address.into_iter().collect()
+        } else {
+            self.frame_data.selection.addresses().collect()
+        };
+
+        for address in addresses {
+            if let Some(value) = read_fn(mem, address) {
+                write_fn(mem, address, value.wrapping_add_signed(delta));
+            }
+        }
+    }
+
+    /// Select the row starting at `row_start`, clamped to the `address_range`.
+    ///
+    /// If `add` is `true` the row is added to the current selection instead of replacing it.
+    pub(crate) fn select_row(&mut self, row_start: Address, address_range: &Range<Address>, add: bool) {
+        let row_end = (row_start + self.options.column_count).min(address_range.end);
+
+        if !add {
+            self.frame_data.selection.clear();
+        }
+
+        self.frame_data.selection.add_range(row_start..row_end);
+        self.frame_data.selection_anchor = Some(row_start);
+    }
+
+    /// Select the given column for every row that was visible in the last frame.
+    pub(crate) fn select_column(&mut self, column: usize, address_range: &Range<Address>) {
+        let column_count = self.options.column_count;
+        let selection = &mut self.frame_data.selection;
+        selection.clear();
+
+        for row_start in self.visible_range.clone().step_by(column_count) {
+            let address = row_start + column;
+
+            if address_range.contains(&address) {
+                selection.add_range(address..address + 1);
+            }
+        }
+
+        self.frame_data.selection_anchor = None;
+    }
+
+    /// Copy the selected bytes as hex to the clipboard, each disjoint run is placed on its own line.
+    pub(crate) fn copy_selection<T: ?Sized>(
+        &self,
+        ctx: &Context,
+        mem: &mut T,
+        read_fn: &mut impl FnMut(&mut T, Address) -> Option<u8>,
+    ) {
+        let text = self
+            .frame_data
+            .selection
+            .merged_ranges()
+            .into_iter()
+            .map(|range| self.export_hex(mem, &mut *read_fn, range))
+            .collect::<Vec<_>>()
+            .join("\n");
+
+        ctx.copy_text(text);
+    }
+
+    /// Copy the selection in the format of the pane it was made in, as escaped text if it was selected in the ASCII
+    /// sidebar and as hex otherwise.
+    pub(crate) fn copy_selection_from_pane<T: ?Sized>(
+        &self,
+        ctx: &Context,
+        mem: &mut T,
+        read_fn: &mut impl FnMut(&mut T, Address) -> Option<u8>,
+    ) {
+        if self.frame_data.selected_from_ascii {
+            self.copy_selection_as_text(ctx, mem, read_fn, true);
+        } else {
+            self.copy_selection(ctx, mem, read_fn);
+        }
+    }
+
+    /// Copy the selected values to the clipboard decoded as text in the sidebar's encoding, with a line per run.
+    ///
+    /// Non-printable and unreadable characters are either escaped (`\n`, `\t`, `\xNN`, `\uNNNN`) or skipped.
+    pub(crate) fn copy_selection_as_text<T: ?Sized>(
+        &self,
+        ctx: &Context,
+        mem: &mut T,
+        read_fn: &mut impl FnMut(&mut T, Address) -> Option<u8>,
+        escape_non_printable: bool,
+    ) {
+        let encoding = self.options.sidebar_encoding;
+        let bytes_per_character = self.bytes_per_character();
+        let address_space = self.selected_address_space();
+        let text = self
+            .frame_data
+            .selection
+            .merged_ranges()
+            .into_iter()
+            .map(|range| {
+                let mut text = String::new();
+                // Characters are decoded from the same cells as in the sidebar, which are aligned to the region.
+                let first_cell = range.start - range.start.saturating_sub(address_space.start) % bytes_per_character;
+
+                for cell_start in (first_cell..range.end).step_by(bytes_per_character) {
+                    let cell = cell_start..cell_start + bytes_per_character;
+
+                    match decode_cell(encoding, cell, |address| read_fn(mem, address)) {
+                        Some(Ok(character)) => {
+                            // Escape the backslash itself, so the escaped text can be decoded unambiguously.
+                            if escape_non_printable && character == '\\' {
+                                text.push('\\');
+                            }
+                            text.push(character);
+                        }
+                        _ if !escape_non_printable => {}
+                        Some(Err(0x0A)) => text.push_str("\\n"),
+                        Some(Err(0x09)) => text.push_str("\\t"),
+                        Some(Err(0x0D)) => text.push_str("\\r"),
+                        Some(Err(value)) if value <= 0xFF => text.push_str(&format!("\\x{:02X}", value)),
+                        Some(Err(value)) => text.push_str(&format!("\\u{:04X}", value)),
+                        None => text.push_str("\\x??"),
+                    }
+                }
+
+                text
+            })
+            .collect::<Vec<_>>()
+            .join("\n");
+
+        ctx.copy_text(text);
+    }
+
+    /// Write the given value to every selected address.
+    pub(crate) fn fill_selection<T: ?Sized>(
+        &self,
+        mem: &mut T,
+        write_fn: &mut impl FnMut(&mut T, Address, u8),
+        value: u8,
+    ) {
+        for address in self.frame_data.selection.addresses() {
+            write_fn(mem, address, value);
+        }
+    }
+}
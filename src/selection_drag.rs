@@ -0,0 +1,236 @@
+use std::ops::Range;
+
+use egui::{Context, Rect, Stroke, Ui, Window};
+
+use crate::option_data::BetweenFrameData;
+use crate::selection::Selection;
+use crate::{Address, MemoryEditor};
+
+/// A selected run being dragged to another address.
+#[derive(Clone, Debug)]
+pub(crate) struct SelectionDrag {
+    source: Range<Address>,
+    /// The distance from the start of the run to the value the drag started on.
+    grab_offset: usize,
+    /// The address the run would be dropped at, if the pointer is above a value.
+    target: Option<Address>,
+}
+
+/// A copy or move of a run onto an overlapping range, waiting for confirmation.
+#[derive(Clone, Debug)]
+pub(crate) struct PendingSelectionMove {
+    source: Range<Address>,
+    destination: Address,
+    is_move: bool,
+}
+
+impl BetweenFrameData {
+    /// Start dragging the selection if it's a single run containing `address`, returns `false` otherwise.
+    pub fn start_selection_drag(&mut self, address: Address) -> bool {
+        let merged = self.selection.merged_ranges();
+        let [source] = merged.as_slice() else {
+            return false;
+        };
+        if !source.contains(&address) {
+            return false;
+        }
+
+        self.selection_drag = Some(SelectionDrag {
+            source: source.clone(),
+            grab_offset: address - source.start,
+            target: None,
+        });
+        true
+    }
+
+    /// Drop the dragged run such that the value it was grabbed by ends up at `address`.
+    pub fn update_selection_drag(&mut self, address: Address) {
+        if let Some(drag) = &mut self.selection_drag {
+            drag.target = address.checked_sub(drag.grab_offset);
+        }
+    }
+}
+
+impl MemoryEditor {
+    /// Paint where the dragged selection would be dropped, clipped to `clip_rect`.
+    pub(crate) fn paint_selection_drag_preview(&self, ui: &Ui, clip_rect: Rect) {
+        let Some(SelectionDrag {
+            source,
+            target: Some(target),
+            ..
+        }) = &self.frame_data.selection_drag
+        else {
+            return;
+        };
+        let painter = ui.painter().with_clip_rect(clip_rect);
+        let selection = ui.visuals().selection;
+
+        for span in self
+            .frame_data
+            .cell_rects
+            .row_spans(*target..target.saturating_add(source.len()))
+        {
+            painter.rect_filled(span, 2.0, selection.bg_fill.gamma_multiply(0.4));
+            painter.rect_stroke(
+                span,
+                2.0,
+                Stroke::new(1.0, selection.stroke.color),
+                egui::StrokeKind::Outside,
+            );
+        }
+    }
+
+    /// While dragging the selection show what it would do next to the pointer, and copy or move the run once the
+    /// pointer is released. Holding Shift on release moves the run, filling the values it leaves behind with
+    /// [`crate::option_data::MemoryEditorOptions::navigation_fill_value`].
+    pub(crate) fn finish_selection_drag<T: ?Sized>(
+        &mut self,
+        ui: &mut Ui,
+        mem: &mut T,
+        read_fn: &mut impl FnMut(&mut T, Address) -> Option<u8>,
+        write_fn: &mut Option<impl FnMut(&mut T, Address, u8)>,
+        address_range: &Range<Address>,
+    ) {
+        let Some(drag) = self.frame_data.selection_drag.clone() else {
+            return;
+        };
+        let tr = self.translator();
+        let is_move = ui.input(|i| i.modifiers.shift);
+        let destination = drag
+            .target
+            .filter(|target| *target != drag.source.start)
+            .filter(|target| address_range.contains(target) && target + drag.source.len() <= address_range.end);
+
+        if ui.input(|i| i.pointer.primary_down()) {
+            let text = match destination {
+                Some(destination) => tr.format(
+                    if is_move {
+                        "Move {count} → {destination}"
+                    } else {
+                        "Copy {count} → {destination}"
+                    },
+                    &[
+                        ("count", &drag.source.len()),
+                        ("destination", &format!("{:#X}", destination)),
+                    ],
+                ),
+                None => tr.text("Hold Shift to move instead of copy").into_owned(),
+            };
+            egui::Tooltip::always_open(
+                ui.ctx().clone(),
+                ui.layer_id(),
+                ui.id().with("selection_drag_badge"),
+                egui::PopupAnchor::Pointer,
+            )
+            .gap(12.0)
+            .show(|ui| ui.label(text));
+            return;
+        }
+
+        self.frame_data.selection_drag = None;
+        let Some(destination) = destination else {
+            return;
+        };
+        let destination_range = destination..destination + drag.source.len();
+
+        if drag.source.start < destination_range.end && destination < drag.source.end {
+            self.frame_data.pending_selection_move = Some(PendingSelectionMove {
+                source: drag.source,
+                destination,
+                is_move,
+            });
+        } else if let Some(write_fn) = write_fn.as_mut() {
+            self.move_run(mem, read_fn, write_fn, drag.source, destination, is_move);
+        }
+    }
+
+    /// Draw the confirmation for copying or moving a run onto an overlapping range, if any.
+    pub(crate) fn draw_selection_move_dialog<T: ?Sized>(
+        &mut self,
+        ctx: &Context,
+        mem: &mut T,
+        read_fn: &mut impl FnMut(&mut T, Address) -> Option<u8>,
+        write_fn: &mut Option<impl FnMut(&mut T, Address, u8)>,
+    ) {
+        let tr = self.translator();
+        let Some(pending) = self.frame_data.pending_selection_move.clone() else {
+            return;
+        };
+        let mut is_open = true;
+        let mut confirmed = false;
+
+        Window::new(tr.text("Overlapping ranges"))
+            .id(egui::Id::new("mem_edit_selection_move_dialog"))
+            .open(&mut is_open)
+            .collapsible(false)
+            .resizable(false)
+            .show(ctx, |ui| {
+                ui.label(tr.format(
+                    if pending.is_move {
+                        "Move {source} → {destination}"
+                    } else {
+                        "Copy {source} → {destination}"
+                    },
+                    &[
+                        (
+                            "source",
+                            &format!("0x{:X}–0x{:X}", pending.source.start, pending.source.end - 1),
+                        ),
+                        ("destination", &format!("0x{:X}", pending.destination)),
+                    ],
+                ));
+                ui.label(tr.text("The destination overlaps with the values being copied, part of them is overwritten"));
+                ui.horizontal(|ui| {
+                    confirmed = ui.button(tr.text("Continue")).clicked();
+                    if ui.button(tr.text("Cancel")).clicked() {
+                        self.frame_data.pending_selection_move = None;
+                    }
+                });
+            });
+
+        if confirmed && let Some(write_fn) = write_fn.as_mut() {
+            self.move_run(
+                mem,
+                read_fn,
+                write_fn,
+                pending.source,
+                pending.destination,
+                pending.is_move,
+            );
+            is_open = false;
+        }
+        if !is_open {
+            self.frame_data.pending_selection_move = None;
+        }
+    }
+
+    /// Copy the values of `source` to the addresses starting at `destination`, clearing the values it leaves behind if
+    /// `is_move` is set, and select the destination. Values which can't be read are skipped.
+    fn move_run<T: ?Sized>(
+        &mut self,
+        mem: &mut T,
+        read_fn: &mut impl FnMut(&mut T, Address) -> Option<u8>,
+        write_fn: &mut impl FnMut(&mut T, Address, u8),
+        source: Range<Address>,
+        destination: Address,
+        is_move: bool,
+    ) {
+        // Read everything first, so overlapping ranges copy the original values.
+        let values = source.clone().map(|address| read_fn(mem, address)).collect::<Vec<_>>();
+        let destination_range = destination..destination + source.len();
+
+        if is_move {
+            let fill_value = self.options.navigation_fill_value;
+            for address in source.filter(|address| !destination_range.contains(address)) {
+                write_fn(mem, address, fill_value);
+            }
+        }
+        for (address, value) in destination_range.clone().zip(values) {
+            if let Some(value) = value {
+                write_fn(mem, address, value);
+            }
+        }
+
+        self.set_selection(Selection::from_range(destination_range));
+    }
+}
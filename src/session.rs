@@ -0,0 +1,102 @@
+//! Recording the edits made in the UI to replay them later, see [`EditSession`].
+use crate::{Address, MemoryEditor};
+
+/// A single write made from the UI.
+#[derive(Copy, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct RecordedEdit {
+    /// The seconds since the recording started.
+    pub time: f64,
+    /// The address passed to the write function.
+    pub address: Address,
+    pub value: u8,
+}
+
+/// The writes made from the UI while recording, in order, see [`MemoryEditor::start_recording`].
+///
+/// Sessions can be replayed on fresh memory to reproduce a sequence of manual patches, for example as a test fixture.
+///
+/// ```
+/// # use egui_memory_editor::session::{EditSession, RecordedEdit};
+/// let session = EditSession {
+///     edits: vec![
+///         RecordedEdit { time: 0.5, address: 0x10, value: 0xAA },
+///         RecordedEdit { time: 1.5, address: 0x11, value: 0xBB },
+///         RecordedEdit { time: 2.0, address: 0x10, value: 0xCC },
+///     ],
+/// };
+///
+/// let mut memory = vec![0u8; 0x20];
+/// session.replay_until(&mut memory, |mem, address, value| mem[address] = value, 1.5);
+/// assert_eq!(memory[0x10..0x12], [0xAA, 0xBB]);
+///
+/// session.replay(&mut memory, |mem, address, value| mem[address] = value);
+/// assert_eq!(memory[0x10..0x12], [0xCC, 0xBB]);
+/// ```
+#[derive(Clone, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct EditSession {
+    pub edits: Vec<RecordedEdit>,
+}
+
+impl EditSession {
+    /// Apply every edit of the session, in order.
+    pub fn replay<T: ?Sized>(&self, mem: &mut T, write_fn: impl FnMut(&mut T, Address, u8)) {
+        self.replay_until(mem, write_fn, f64::INFINITY);
+    }
+
+    /// Apply the edits made up to and including `time` seconds into the recording, in order.
+    pub fn replay_until<T: ?Sized>(&self, mem: &mut T, mut write_fn: impl FnMut(&mut T, Address, u8), time: f64) {
+        for edit in self.edits.iter().take_while(|edit| edit.time <= time) {
+            write_fn(mem, edit.address, edit.value);
+        }
+    }
+
+    /// The length of the session in seconds, up to its last edit.
+    pub fn duration(&self) -> f64 {
+        self.edits.last().map_or(0.0, |edit| edit.time)
+    }
+}
+
+/// An [`EditSession`] being recorded.
+#[derive(Clone, Debug, Default)]
+pub(crate) struct Recorder {
+    session: EditSession,
+    /// The `egui` time of the first frame of the recording.
+    started_at: Option<f64>,
+}
+
+impl Recorder {
+    /// Add the `writes` made in the frame at `time`.
+    pub fn record(&mut self, writes: Vec<(Address, u8)>, time: f64) {
+        let started_at = *self.started_at.get_or_insert(time);
+        let edits = writes.into_iter().map(|(address, value)| RecordedEdit {
+            time: time - started_at,
+            address,
+            value,
+        });
+
+        self.session.edits.extend(edits);
+    }
+}
+
+impl MemoryEditor {
+    /// Start recording every write made from the UI, including undoing and redoing, replacing any unfinished recording.
+    pub fn start_recording(&mut self) {
+        self.frame_data.recorder = Some(Recorder::default());
+    }
+
+    /// Stop recording, returning the recorded session if a recording was in progress.
+    pub fn stop_recording(&mut self) -> Option<EditSession> {
+        self.frame_data.recorder.take().map(|recorder| recorder.session)
+    }
+
+    pub fn is_recording(&self) -> bool {
+        self.frame_data.recorder.is_some()
+    }
+
+    /// The session recorded so far, if a recording is in progress.
+    pub fn recording(&self) -> Option<&EditSession> {
+        self.frame_data.recorder.as_ref().map(|recorder| &recorder.session)
+    }
+}
@@ -0,0 +1,178 @@
+//! Detecting embedded files by their magic numbers, useful when exploring ROM dumps and save files.
+//!
+//! ```
+//! # use egui_memory_editor::signatures;
+//! let mut memory = vec![0u8; 0x400];
+//! memory[0x40..0x48].copy_from_slice(b"\x89PNG\r\n\x1a\n");
+//! memory[0x200..0x204].copy_from_slice(b"PK\x03\x04");
+//!
+//! let found = signatures::find_signatures(&mut memory, |mem, addr| mem.get(addr).copied(), 0..0x400);
+//! let found = found.iter().map(|found| (found.address, found.signature.name)).collect::<Vec<_>>();
+//! assert_eq!(found, vec![(0x40, "PNG image"), (0x200, "ZIP archive")]);
+//! ```
+use std::ops::Range;
+
+use egui::Ui;
+
+use crate::instrumentation::{AccessReason, with_access_reason};
+use crate::{Address, MemoryEditor};
+
+/// The maximum amount of results listed in the UI.
+const MAX_LISTED_RESULTS: usize = 1000;
+
+/// A file format recognised by a magic number.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub struct Signature {
+    pub name: &'static str,
+    pub magic: &'static [u8],
+    /// The offset of the magic number from the start of the file.
+    pub offset: usize,
+}
+
+impl Signature {
+    pub const fn new(name: &'static str, magic: &'static [u8], offset: usize) -> Self {
+        Signature { name, magic, offset }
+    }
+}
+
+/// The signatures detected by [`find_signatures`].
+pub const SIGNATURES: &[Signature] = &[
+    Signature::new("PNG image", b"\x89PNG\r\n\x1a\n", 0),
+    Signature::new("JPEG image", b"\xFF\xD8\xFF", 0),
+    Signature::new("GIF image", b"GIF8", 0),
+    Signature::new("ZIP archive", b"PK\x03\x04", 0),
+    Signature::new("gzip data", b"\x1F\x8B\x08", 0),
+    Signature::new("RIFF container", b"RIFF", 0),
+    Signature::new("ELF executable", b"\x7FELF", 0),
+    Signature::new("PDF document", b"%PDF-", 0),
+    Signature::new("NES ROM", b"NES\x1A", 0),
+    // The start of the Nintendo logo in the cartridge header.
+    Signature::new("Game Boy ROM", b"\xCE\xED\x66\x66\xCC\x0D\x00\x0B", 0x104),
+    Signature::new("Yaz0 compressed data", b"Yaz0", 0),
+    Signature::new("LZ4 frame", b"\x04\x22\x4D\x18", 0),
+];
+
+/// A signature found in memory.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub struct SignatureMatch {
+    /// The address of the start of the file, which precedes the magic number by [`Signature::offset`].
+    pub address: Address,
+    pub signature: &'static Signature,
+}
+
+/// The state of the signature scan in the UI.
+#[derive(Clone, Debug, Default)]
+pub(crate) struct SignatureScanState {
+    /// Whether to only scan the selection, rather than the whole address range.
+    pub selection_only: bool,
+    /// The signatures found by the last scan.
+    pub results: Option<Vec<SignatureMatch>>,
+}
+
+/// Find every file in `range` with one of the [`SIGNATURES`], ordered by address.
+///
+/// Files whose start would precede the range, or whose magic number contains an unreadable byte, are never matched.
+pub fn find_signatures<T: ?Sized>(
+    mem: &mut T,
+    mut read_fn: impl FnMut(&mut T, Address) -> Option<u8>,
+    range: Range<Address>,
+) -> Vec<SignatureMatch> {
+    let bytes = range.clone().map(|address| read_fn(mem, address)).collect::<Vec<_>>();
+    let mut found = Vec::new();
+
+    for (i, value) in bytes.iter().enumerate() {
+        // Skip unreadable bytes and bytes which don't start any magic number quickly.
+        let Some(first) = value else {
+            continue;
+        };
+
+        for signature in SIGNATURES.iter().filter(|signature| signature.magic[0] == *first) {
+            let is_match = bytes.get(i..i + signature.magic.len()).is_some_and(|candidate| {
+                candidate
+                    .iter()
+                    .zip(signature.magic)
+                    .all(|(value, magic)| *value == Some(*magic))
+            });
+
+            if is_match && let Some(start) = i.checked_sub(signature.offset) {
+                found.push(SignatureMatch {
+                    address: range.start + start,
+                    signature,
+                });
+            }
+        }
+    }
+
+    found.sort_by_key(|found| found.address);
+    found
+}
+
+impl MemoryEditor {
+    /// Draw the signature scan, which lists the embedded files found in the current address range.
+    pub(crate) fn draw_signature_options<T: ?Sized>(
+        &mut self,
+        ui: &mut Ui,
+        current_address_range: &Range<Address>,
+        mem: &mut T,
+        read: &mut impl FnMut(&mut T, Address) -> Option<u8>,
+    ) {
+        let tr = self.translator();
+        let mut to_jump = None;
+
+        ui.collapsing(tr.text("Signatures"), |ui| {
+            let state = &mut self.frame_data.signature_scan;
+
+            ui.horizontal(|ui| {
+                if ui
+                    .button(tr.text("🔍 Scan"))
+                    .on_hover_text(tr.text("List the files embedded in memory, such as images, archives, and ROMs"))
+                    .clicked()
+                {
+                    let ranges = if state.selection_only {
+                        self.frame_data.selection.merged_ranges()
+                    } else {
+                        vec![current_address_range.clone()]
+                    };
+                    let read = &mut with_access_reason(self.access_reason.clone(), AccessReason::Search, read);
+                    let found = ranges
+                        .into_iter()
+                        .flat_map(|range| find_signatures(mem, &mut *read, range))
+                        .collect();
+
+                    state.results = Some(found);
+                }
+
+                ui.checkbox(&mut state.selection_only, tr.text("Selection only"));
+
+                if let Some(results) = &state.results {
+                    ui.label(tr.format("{count} signature(s)", &[("count", &results.len())]));
+                }
+            });
+
+            if let Some(results) = &state.results {
+                egui::ScrollArea::vertical()
+                    .id_salt("SignatureScanResults")
+                    .max_height(150.0)
+                    .show(ui, |ui| {
+                        for found in results.iter().take(MAX_LISTED_RESULTS) {
+                            if ui
+                                .link(format!("{:#X} {}", found.address, found.signature.name))
+                                .clicked()
+                            {
+                                to_jump = Some(found.address);
+                            }
+                        }
+
+                        if results.len() > MAX_LISTED_RESULTS {
+                            let count = results.len() - MAX_LISTED_RESULTS;
+                            ui.label(tr.format("… and {count} more", &[("count", &count)]));
+                        }
+                    });
+            }
+        });
+
+        if let Some(address) = to_jump {
+            self.jump_to_address(address, current_address_range);
+        }
+    }
+}
@@ -0,0 +1,97 @@
+use std::ops::Range;
+
+use crate::Address;
+
+/// A copy of an address range at a certain point in time, used to highlight changes made to the memory since.
+#[derive(Clone, Debug)]
+pub(crate) struct Snapshot {
+    /// The first address of the captured range.
+    pub start: Address,
+    /// The captured values, `None` if the `read_fn` couldn't provide a value at the time.
+    pub values: Vec<Option<u8>>,
+}
+
+/// A run of consecutive addresses whose values changed since the snapshot was taken.
+#[cfg(any(feature = "export", feature = "ips"))]
+#[derive(Clone, Debug)]
+pub(crate) struct ChangedRun {
+    pub start: Address,
+    pub old: Vec<Option<u8>>,
+    pub new: Vec<Option<u8>>,
+}
+
+impl Snapshot {
+    /// Capture all values in the provided `address_range`.
+    pub fn capture<T: ?Sized>(
+        mem: &mut T,
+        read_fn: &mut impl FnMut(&mut T, Address) -> Option<u8>,
+        address_range: &Range<Address>,
+    ) -> Self {
+        Snapshot {
+            start: address_range.start,
+            values: address_range.clone().map(|address| read_fn(mem, address)).collect(),
+        }
+    }
+
+    /// A snapshot of previously saved `bytes`, such as a dump from an earlier session, starting at `start`.
+    pub fn from_bytes(start: Address, bytes: &[u8]) -> Self {
+        Snapshot {
+            start,
+            values: bytes.iter().copied().map(Some).collect(),
+        }
+    }
+
+    /// Returns the captured value at the given address, or `None` if the address is not part of the snapshot.
+    #[inline]
+    pub fn value(&self, address: Address) -> Option<Option<u8>> {
+        address
+            .checked_sub(self.start)
+            .and_then(|index| self.values.get(index).copied())
+    }
+
+    /// The address range which was captured.
+    #[cfg(any(feature = "export", feature = "ips"))]
+    pub fn range(&self) -> Range<Address> {
+        self.start..self.start + self.values.len()
+    }
+
+    /// Collect every run of consecutive addresses whose value differs from the captured one.
+    #[cfg(any(feature = "export", feature = "ips"))]
+    pub fn changed_runs<T: ?Sized>(
+        &self,
+        mem: &mut T,
+        read_fn: &mut impl FnMut(&mut T, Address) -> Option<u8>,
+    ) -> Vec<ChangedRun> {
+        let mut runs: Vec<ChangedRun> = Vec::new();
+
+        for (address, &old) in self.range().zip(&self.values) {
+            let new = read_fn(mem, address);
+
+            if old == new {
+                continue;
+            }
+
+            match runs.last_mut() {
+                Some(run) if run.start + run.old.len() == address => {
+                    run.old.push(old);
+                    run.new.push(new);
+                }
+                _ => runs.push(ChangedRun {
+                    start: address,
+                    old: vec![old],
+                    new: vec![new],
+                }),
+            }
+        }
+
+        runs
+    }
+
+    /// Whether the `current` value differs from the captured one.
+    ///
+    /// Addresses outside the snapshot are never considered changed.
+    #[inline]
+    pub fn is_changed(&self, address: Address, current: Option<u8>) -> bool {
+        matches!(self.value(address), Some(old) if old != current)
+    }
+}
@@ -0,0 +1,91 @@
+use egui::{RichText, Ui};
+
+use crate::MemoryEditor;
+
+/// The space taken up by the separator above the status bar.
+const SEPARATOR_SPACING: f32 = 6.0;
+
+impl MemoryEditor {
+    /// The height the status bar takes up below the memory values, including spacing.
+    pub(crate) fn status_bar_height(&self, ui: &Ui) -> f32 {
+        if self.options.show_status_bar {
+            // A separator, followed by a single row of widgets.
+            SEPARATOR_SPACING + ui.spacing().item_spacing.y * 2.0 + ui.spacing().interact_size.y
+        } else {
+            0.0
+        }
+    }
+
+    /// Draw the status bar, showing the selected region, the cursor, the selection, the coverage, and the typing mode
+    /// if the memory can be resized.
+    pub(crate) fn draw_status_bar(&mut self, ui: &mut Ui, is_resizable: bool) {
+        let tr = self.translator();
+        ui.add(egui::Separator::default().spacing(SEPARATOR_SPACING));
+
+        ui.horizontal(|ui| {
+            let text_style = self.options.memory_editor_address_text_style.clone();
+
+            if self.frame_data.memory_range_combo_box_enabled {
+                ui.strong(&self.window.selected_address_range)
+                    .on_hover_text(tr.text("The selected region, switch with Ctrl+PageDown or Ctrl+1 to Ctrl+9"));
+                ui.separator();
+            }
+
+            match self.frame_data.cursor_address() {
+                Some(address) => {
+                    let address = self.address_formatter(&self.selected_address_space())(address);
+                    ui.label(
+                        RichText::new(tr.format("Cursor: {address}", &[("address", &address)])).text_style(text_style),
+                    )
+                }
+                None => ui.weak(tr.text("No cursor")),
+            };
+
+            if self.frame_data.cursors.len() > 1 {
+                ui.separator();
+                ui.label(tr.format("{count} cursors", &[("count", &self.frame_data.cursors.len())]))
+                    .on_hover_text(tr.text("Typed values are written at every cursor, press Escape to remove them"));
+            }
+
+            if !self.frame_data.selection.is_empty() {
+                ui.separator();
+                ui.label(tr.format(
+                    "Selected: {count} bytes",
+                    &[("count", &self.frame_data.selection.len())],
+                ));
+            }
+
+            if let Some(coverage) = self.visible_coverage() {
+                ui.separator();
+                ui.checkbox(
+                    &mut self.options.show_coverage,
+                    format!("{} {:.0}%", tr.text("Coverage:"), coverage * 100.0),
+                )
+                .on_hover_text(
+                    tr.text("The share of the visible values which are covered, click to toggle the shading"),
+                );
+            }
+
+            if is_resizable {
+                ui.with_layout(egui::Layout::right_to_left(egui::Align::Center), |ui| {
+                    let (text, hover) = if self.frame_data.insert_mode {
+                        ("INS", "Typed values are inserted, press Insert to overwrite instead")
+                    } else {
+                        (
+                            "OVR",
+                            "Typed values overwrite existing ones, press Insert to insert instead",
+                        )
+                    };
+
+                    if ui
+                        .selectable_label(self.frame_data.insert_mode, tr.text(text))
+                        .on_hover_text(tr.text(hover))
+                        .clicked()
+                    {
+                        self.frame_data.insert_mode = !self.frame_data.insert_mode;
+                    }
+                });
+            }
+        });
+    }
+}
@@ -0,0 +1,30 @@
+use std::sync::Arc;
+
+use egui::Style;
+
+use crate::MemoryEditor;
+
+pub(crate) type StyleOverride = Arc<dyn Fn(&mut Style) + Send + Sync>;
+
+impl MemoryEditor {
+    /// Adjust the `egui` style used while drawing the editor, such as tighter spacing or another monospace font,
+    /// without changing the style of the rest of the host's UI.
+    ///
+    /// The callback receives a copy of the style of the surrounding `Ui` every frame, the changes only apply to the
+    /// contents of the editor. Windows opened by the editor, like the script console, keep the global style.
+    ///
+    /// ```
+    /// # use egui_memory_editor::MemoryEditor;
+    /// let memory_editor = MemoryEditor::new()
+    ///     .with_address_range("RAM", 0..0x10000)
+    ///     .with_style_override(|style| {
+    ///         style.spacing.item_spacing.y = 0.0;
+    ///         style.text_styles.insert(egui::TextStyle::Monospace, egui::FontId::monospace(11.0));
+    ///     });
+    /// ```
+    #[must_use]
+    pub fn with_style_override(mut self, style_override: impl Fn(&mut Style) + Send + Sync + 'static) -> Self {
+        self.style_override = Some(Arc::new(style_override));
+        self
+    }
+}
@@ -0,0 +1,62 @@
+use std::collections::BTreeMap;
+use std::ops::Range;
+
+use crate::row_layout::{CollapsedKind, RowLayout};
+use crate::{Address, MemoryEditor};
+
+impl MemoryEditor {
+    /// All registered symbols, indexed by address.
+    pub fn symbols(&self) -> &BTreeMap<Address, String> {
+        &self.symbols
+    }
+
+    /// Register a named address, such as a variable or table from a linker map.
+    pub fn set_symbol(&mut self, address: Address, name: impl Into<String>) {
+        self.symbols.insert(address, name.into());
+    }
+
+    /// Replace all symbols.
+    pub fn set_symbols(&mut self, symbols: impl IntoIterator<Item = (Address, String)>) {
+        self.symbols = symbols.into_iter().collect();
+    }
+
+    /// Remove the symbol at the given address, returning its name if there was one.
+    pub fn remove_symbol(&mut self, address: Address) -> Option<String> {
+        self.symbols.remove(&address)
+    }
+
+    /// Determine which rows to show in the given address range.
+    ///
+    /// If enabled, a separator row is added before every line containing a symbol or annotation, and long stretches of
+    /// unmapped, repeated, or hidden untagged lines are collapsed into a single row. An unrolled ring buffer shows every
+    /// line, starting at the line of its tail.
+    pub(crate) fn row_layout(&self, address_space: &Range<Address>) -> RowLayout {
+        let column_count = self.options.column_count;
+        let line_count = address_space.len().div_ceil(column_count);
+        if let Some(first_line) = self.unrolled_first_line(address_space) {
+            return RowLayout::rotated(line_count, first_line);
+        }
+
+        let separators = if self.options.show_symbol_separators {
+            self.symbols
+                .range(address_space.clone())
+                .chain(self.annotations.range(address_space.clone()))
+                .map(|(address, label)| ((address - address_space.start) / column_count, label.clone()))
+                .collect()
+        } else {
+            Vec::new()
+        };
+
+        let mut collapsed = Vec::new();
+        if self.options.collapse_unmapped_lines {
+            let unmapped = self.collapsed_lines(address_space).into_iter();
+            collapsed.extend(unmapped.map(|lines| (lines, CollapsedKind::Unmapped)));
+        }
+        let repeated = self.collapsed_repeated_lines().into_iter();
+        collapsed.extend(repeated.map(|lines| (lines, CollapsedKind::Repeated)));
+        let untagged = self.untagged_lines(address_space).into_iter();
+        collapsed.extend(untagged.map(|lines| (lines, CollapsedKind::Untagged)));
+
+        RowLayout::new(line_count, separators, collapsed)
+    }
+}
@@ -0,0 +1,241 @@
+//! User-defined tags on address ranges, building a navigable memory map over time, see [`Tag`].
+use std::ops::Range;
+
+use egui::Ui;
+
+use crate::{Address, MemoryEditor};
+
+/// A range of memory tagged with a user-defined name, like `sprite data` or `music`.
+///
+/// A range can have multiple tags, and a tag can be given to multiple ranges.
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Tag {
+    /// The name of the tag, shared by every range with the same tag.
+    pub name: String,
+    /// The tagged addresses.
+    pub range: Range<Address>,
+}
+
+/// How the addresses without a tag are displayed, see
+/// [`crate::option_data::MemoryEditorOptions::untagged_style`].
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum UntaggedStyle {
+    /// Display untagged values like any other value.
+    #[default]
+    Normal,
+    /// Display untagged values with a weaker text colour.
+    Dimmed,
+    /// Collapse the lines without any tagged value into a single row.
+    Hidden,
+}
+
+impl UntaggedStyle {
+    pub fn iter() -> impl Iterator<Item = UntaggedStyle> {
+        [UntaggedStyle::Normal, UntaggedStyle::Dimmed, UntaggedStyle::Hidden].into_iter()
+    }
+
+    pub const fn name(&self) -> &'static str {
+        match self {
+            UntaggedStyle::Normal => "Normal",
+            UntaggedStyle::Dimmed => "Dimmed",
+            UntaggedStyle::Hidden => "Hidden",
+        }
+    }
+}
+
+impl MemoryEditor {
+    /// Tag a range of memory, see [`Tag`].
+    ///
+    /// With [`crate::option_data::MemoryEditorOptions::untagged_style`] the untagged space can be dimmed or hidden,
+    /// and [`crate::option_data::MemoryEditorOptions::tag_filter`] restricts that to a single tag.
+    ///
+    /// ```
+    /// # use egui_memory_editor::MemoryEditor;
+    /// let memory_editor = MemoryEditor::new()
+    ///     .with_address_range("ROM", 0x0000..0x8000)
+    ///     .with_tag("music", 0x4000..0x4800)
+    ///     .with_tag("sprite data", 0x1000..0x1200);
+    ///
+    /// assert_eq!(memory_editor.tag_names(), ["music", "sprite data"]);
+    /// assert_eq!(memory_editor.tags_at(0x4010).map(|tag| tag.name.as_str()).collect::<Vec<_>>(), ["music"]);
+    /// ```
+    #[must_use]
+    pub fn with_tag(mut self, name: impl Into<String>, range: Range<Address>) -> Self {
+        self.add_tag(name, range);
+        self
+    }
+
+    /// Tag a range of memory, see [`Self::with_tag`].
+    pub fn add_tag(&mut self, name: impl Into<String>, range: Range<Address>) {
+        self.tags.push(Tag {
+            name: name.into(),
+            range,
+        });
+    }
+
+    /// Remove the tag at the given index, returning it if it existed.
+    pub fn remove_tag(&mut self, index: usize) -> Option<Tag> {
+        (index < self.tags.len()).then(|| self.tags.remove(index))
+    }
+
+    /// Replace all tags.
+    pub fn set_tags(&mut self, tags: Vec<Tag>) {
+        self.tags = tags;
+    }
+
+    /// All tags, in the order they were added.
+    pub fn tags(&self) -> &[Tag] {
+        &self.tags
+    }
+
+    /// The distinct names of the tags, sorted alphabetically.
+    pub fn tag_names(&self) -> Vec<&str> {
+        let mut names: Vec<_> = self.tags.iter().map(|tag| tag.name.as_str()).collect();
+        names.sort_unstable();
+        names.dedup();
+        names
+    }
+
+    /// The tags whose range contains `address`.
+    pub fn tags_at(&self, address: Address) -> impl Iterator<Item = &Tag> {
+        self.tags.iter().filter(move |tag| tag.range.contains(&address))
+    }
+
+    /// The tags which pass the [`crate::option_data::MemoryEditorOptions::tag_filter`].
+    fn filtered_tags(&self) -> impl Iterator<Item = &Tag> {
+        let filter = self.options.tag_filter.as_deref();
+        self.tags
+            .iter()
+            .filter(move |tag| filter.is_none_or(|name| tag.name == name))
+    }
+
+    /// Whether `address` should be dimmed because it has no tag passing the filter.
+    pub(crate) fn is_dimmed_untagged(&self, address: Address) -> bool {
+        self.options.untagged_style == UntaggedStyle::Dimmed
+            && !self.filtered_tags().any(|tag| tag.range.contains(&address))
+    }
+
+    /// The stretches of lines without any tag passing the filter, which are collapsed if untagged space is hidden.
+    pub(crate) fn untagged_lines(&self, address_space: &Range<Address>) -> Vec<Range<usize>> {
+        if self.options.untagged_style != UntaggedStyle::Hidden {
+            return Vec::new();
+        }
+        let column_count = self.options.column_count;
+        let line_count = address_space.len().div_ceil(column_count);
+
+        let mut tagged: Vec<Range<usize>> = self
+            .filtered_tags()
+            .filter(|tag| tag.range.start < address_space.end && address_space.start < tag.range.end)
+            .map(|tag| {
+                let start = tag.range.start.max(address_space.start) - address_space.start;
+                let end = tag.range.end.min(address_space.end) - address_space.start;
+                start / column_count..end.div_ceil(column_count)
+            })
+            .collect();
+        tagged.sort_by_key(|lines| lines.start);
+
+        let mut untagged = Vec::new();
+        let mut next_line = 0;
+        for lines in tagged {
+            if lines.start > next_line {
+                untagged.push(next_line..lines.start);
+            }
+            next_line = next_line.max(lines.end);
+        }
+        if next_line < line_count {
+            untagged.push(next_line..line_count);
+        }
+
+        untagged
+    }
+
+    /// Draw the controls for tagging the selection, the filter, and the list of tagged ranges with jump links.
+    pub(crate) fn draw_tag_options(&mut self, ui: &mut Ui, current_address_range: &Range<Address>) {
+        let tr = self.translator();
+
+        egui::CollapsingHeader::new(tr.text("🏷 Tags"))
+            .id_salt("mem_edit_tags")
+            .show(ui, |ui| {
+                ui.horizontal(|ui| {
+                    ui.add(
+                        egui::TextEdit::singleline(&mut self.frame_data.new_tag_name)
+                            .hint_text(tr.text("Tag name"))
+                            .desired_width(120.0),
+                    );
+
+                    let span = self.selection_span();
+                    let can_tag = span.is_some() && !self.frame_data.new_tag_name.trim().is_empty();
+                    if ui
+                        .add_enabled(can_tag, egui::Button::new(tr.text("🏷 Tag selection")))
+                        .on_disabled_hover_text(tr.text("Select the values and enter a name first"))
+                        .clicked()
+                        && let Some(span) = span
+                    {
+                        let name = self.frame_data.new_tag_name.trim().to_string();
+                        self.add_tag(name, span);
+                    }
+                });
+
+                ui.horizontal(|ui| {
+                    let filter_text = self
+                        .options
+                        .tag_filter
+                        .clone()
+                        .unwrap_or_else(|| tr.text("All").into_owned());
+                    let names: Vec<String> = self.tag_names().into_iter().map(str::to_string).collect();
+                    egui::ComboBox::from_label(tr.text("Filter"))
+                        .selected_text(filter_text)
+                        .show_ui(ui, |ui| {
+                            ui.selectable_value(&mut self.options.tag_filter, None, tr.text("All"));
+                            for name in names {
+                                ui.selectable_value(&mut self.options.tag_filter, Some(name.clone()), name);
+                            }
+                        });
+
+                    egui::ComboBox::from_label(tr.text("Untagged"))
+                        .selected_text(tr.text(self.options.untagged_style.name()))
+                        .show_ui(ui, |ui| {
+                            for style in UntaggedStyle::iter() {
+                                ui.selectable_value(&mut self.options.untagged_style, style, tr.text(style.name()));
+                            }
+                        })
+                        .response
+                        .on_hover_text(tr.text("How the values without a tag passing the filter are displayed"));
+                });
+
+                let mut to_remove = None;
+                let mut to_jump = None;
+                let filter = self.options.tag_filter.as_deref();
+                egui::Grid::new("mem_edit_tag_list").striped(true).show(ui, |ui| {
+                    for (index, tag) in self.tags.iter().enumerate() {
+                        if filter.is_some_and(|name| tag.name != name) {
+                            continue;
+                        }
+
+                        ui.label(&tag.name);
+                        let range_text = format!("{:#X}–{:#X}", tag.range.start, tag.range.end.saturating_sub(1));
+                        if ui
+                            .link(range_text)
+                            .on_hover_text(tr.text("Jump to the start of the range"))
+                            .clicked()
+                        {
+                            to_jump = Some(tag.range.start);
+                        }
+                        if ui.small_button("✖").on_hover_text(tr.text("Remove the tag")).clicked() {
+                            to_remove = Some(index);
+                        }
+                        ui.end_row();
+                    }
+                });
+
+                if let Some(address) = to_jump {
+                    self.jump_to_address(address, current_address_range);
+                }
+                if let Some(index) = to_remove {
+                    self.remove_tag(index);
+                }
+            });
+    }
+}
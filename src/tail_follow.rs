@@ -0,0 +1,95 @@
+use std::ops::Range;
+use std::sync::Arc;
+
+use egui::Ui;
+
+use crate::{Address, MemoryEditor};
+
+pub(crate) type WriteCursorProvider = Arc<dyn Fn() -> Option<Address> + Send + Sync>;
+
+impl MemoryEditor {
+    /// Provide the most recently written address of a streaming buffer, such as the write cursor of a ring buffer or
+    /// serial log.
+    ///
+    /// The provider is called every frame. While following the tail, like `tail -f`, the view stays pinned with the
+    /// written address on its bottom line. Following can be toggled from the options, see also
+    /// [`Self::set_following_tail`]. Returning `None` leaves the view where it is.
+    ///
+    /// ```
+    /// # use egui_memory_editor::MemoryEditor;
+    /// use std::sync::Arc;
+    /// use std::sync::atomic::{AtomicUsize, Ordering};
+    ///
+    /// let write_cursor = Arc::new(AtomicUsize::new(0x2000));
+    /// let cursor = write_cursor.clone();
+    /// let mut memory_editor = MemoryEditor::new()
+    ///     .with_address_range("UART log", 0x2000..0x3000)
+    ///     .with_write_cursor(move || Some(cursor.load(Ordering::Relaxed)));
+    ///
+    /// assert!(!memory_editor.is_following_tail());
+    /// memory_editor.set_following_tail(true);
+    /// assert!(memory_editor.is_following_tail());
+    /// ```
+    #[must_use]
+    pub fn with_write_cursor(mut self, provider: impl Fn() -> Option<Address> + Send + Sync + 'static) -> Self {
+        self.write_cursor_provider = Some(Arc::new(provider));
+        self
+    }
+
+    /// Whether the view stays pinned to the write cursor of [`Self::with_write_cursor`].
+    pub fn is_following_tail(&self) -> bool {
+        self.write_cursor_provider.is_some() && self.frame_data.is_following_tail
+    }
+
+    /// Start or stop following the write cursor of [`Self::with_write_cursor`].
+    pub fn set_following_tail(&mut self, is_following_tail: bool) {
+        self.frame_data.is_following_tail = is_following_tail;
+    }
+
+    /// Keep the line of the write cursor at the bottom of the view whenever the cursor moves, or when it was
+    /// scrolled out of view.
+    pub(crate) fn follow_write_cursor(&mut self, address_range: &Range<Address>) {
+        let Some(provider) = &self.write_cursor_provider else {
+            return;
+        };
+        let address = provider();
+        let has_moved = address != self.frame_data.write_cursor;
+        self.frame_data.write_cursor = address;
+
+        let Some(address) = address.filter(|address| address_range.contains(address)) else {
+            return;
+        };
+        if !self.frame_data.is_following_tail || (!has_moved && self.visible_range.contains(&address)) {
+            return;
+        }
+
+        let column_count = self.options.column_count;
+        let line = (address - address_range.start) / column_count;
+        // The last visible line is usually only partially visible, so we leave one extra line of room.
+        let visible_lines = self.visible_range.len() / column_count;
+        self.frame_data.goto_address_line = Some((line + 2).saturating_sub(visible_lines));
+    }
+
+    /// Draw the toggle for following the host's write cursor, if there is one.
+    pub(crate) fn draw_tail_follow_toggle(&mut self, ui: &mut Ui) {
+        let tr = self.translator();
+        if self.write_cursor_provider.is_none() {
+            return;
+        }
+
+        let text = match self.frame_data.write_cursor {
+            Some(address) => format!("{} {:#X}", tr.text("⏬ Follow tail"), address),
+            None => tr.text("⏬ Follow tail").into_owned(),
+        };
+        let response = ui
+            .selectable_label(self.frame_data.is_following_tail, text)
+            .on_hover_text(tr.text(
+                "Keep the most recently written address at the bottom of the view, click to stop or resume following",
+            ));
+        if response.clicked() {
+            self.frame_data.is_following_tail = !self.frame_data.is_following_tail;
+            // Resuming should move back to the tail right away.
+            self.frame_data.write_cursor = None;
+        }
+    }
+}
@@ -0,0 +1,149 @@
+//! Decoding common timestamp encodings in the data preview, see [`TimestampFormat`].
+use crate::option_data::Endianness;
+
+/// The seconds between the FILETIME epoch, 1601-01-01, and the Unix epoch, 1970-01-01.
+const FILETIME_UNIX_OFFSET: i64 = 11_644_473_600;
+/// The FILETIME intervals per second, as it counts in units of 100 nanoseconds.
+const FILETIME_TICKS_PER_SECOND: u64 = 10_000_000;
+
+/// A way of encoding a date and time.
+///
+/// ```
+/// # use egui_memory_editor::option_data::Endianness;
+/// # use egui_memory_editor::timestamps::TimestampFormat;
+/// let unix = 1_700_000_000u32.to_le_bytes();
+/// assert_eq!(
+///     TimestampFormat::Unix32.decode(&unix, Endianness::Little).as_deref(),
+///     Some("2023-11-14 22:13:20 UTC")
+/// );
+///
+/// // 2021-07-04 12:30:10, with the time in the low word.
+/// let dos = [0xC5, 0x63, 0xE4, 0x52];
+/// assert_eq!(
+///     TimestampFormat::DosDateTime.decode(&dos, Endianness::Little).as_deref(),
+///     Some("2021-07-04 12:30:10")
+/// );
+/// assert_eq!(TimestampFormat::DosDateTime.decode(&[0xFF; 4], Endianness::Little), None);
+/// ```
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum TimestampFormat {
+    /// Signed seconds since 1970-01-01 UTC, as a 32-bit `time_t`.
+    Unix32,
+    /// Signed seconds since 1970-01-01 UTC, as a 64-bit `time_t`.
+    Unix64,
+    /// The packed date and time of FAT file systems, with the time in the low 16 bits, in local time.
+    DosDateTime,
+    /// The Windows `FILETIME`, 100 nanosecond intervals since 1601-01-01 UTC.
+    FileTime,
+}
+
+impl TimestampFormat {
+    pub fn iter() -> impl Iterator<Item = TimestampFormat> {
+        use TimestampFormat::*;
+        [Unix32, Unix64, DosDateTime, FileTime].into_iter()
+    }
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            TimestampFormat::Unix32 => "Unix time (32-bit)",
+            TimestampFormat::Unix64 => "Unix time (64-bit)",
+            TimestampFormat::DosDateTime => "MS-DOS date/time",
+            TimestampFormat::FileTime => "FILETIME",
+        }
+    }
+
+    /// The amount of bytes a timestamp takes up.
+    pub const fn bytes(&self) -> usize {
+        match self {
+            TimestampFormat::Unix32 | TimestampFormat::DosDateTime => 4,
+            TimestampFormat::Unix64 | TimestampFormat::FileTime => 8,
+        }
+    }
+
+    /// Decode `bytes`, which should be [`Self::bytes`] long, as a date and time like `2023-11-14 22:13:20 UTC`.
+    ///
+    /// Returns `None` for values which aren't a valid date, or which lie outside of the years 0 to 9999.
+    pub fn decode(&self, bytes: &[u8], endianness: Endianness) -> Option<String> {
+        let raw = match endianness {
+            Endianness::Big => bytes.iter().fold(0u64, |acc, &byte| (acc << 8) | byte as u64),
+            Endianness::Little => bytes.iter().rev().fold(0u64, |acc, &byte| (acc << 8) | byte as u64),
+        };
+
+        match self {
+            TimestampFormat::Unix32 => format_unix_time(raw as u32 as i32 as i64),
+            TimestampFormat::Unix64 => format_unix_time(raw as i64),
+            TimestampFormat::FileTime => {
+                format_unix_time((raw / FILETIME_TICKS_PER_SECOND) as i64 - FILETIME_UNIX_OFFSET)
+            }
+            TimestampFormat::DosDateTime => {
+                let (date, time) = ((raw >> 16) as u32, raw as u32 & 0xFFFF);
+                let (year, month, day) = (1980 + (date >> 9), (date >> 5) & 0xF, date & 0x1F);
+                let (hour, minute, second) = (time >> 11, (time >> 5) & 0x3F, 2 * (time & 0x1F));
+                let is_valid = (1..=12).contains(&month)
+                    && (1..=days_in_month(year as i64, month)).contains(&day)
+                    && hour < 24
+                    && minute < 60
+                    && second < 60;
+
+                is_valid.then(|| {
+                    format!(
+                        "{:04}-{:02}-{:02} {:02}:{:02}:{:02}",
+                        year, month, day, hour, minute, second
+                    )
+                })
+            }
+        }
+    }
+}
+
+/// Format seconds since the Unix epoch as a UTC date and time.
+fn format_unix_time(seconds: i64) -> Option<String> {
+    let days = seconds.div_euclid(86_400);
+    let second_of_day = seconds.rem_euclid(86_400);
+    let (year, month, day) = civil_from_days(days)?;
+
+    Some(format!(
+        "{:04}-{:02}-{:02} {:02}:{:02}:{:02} UTC",
+        year,
+        month,
+        day,
+        second_of_day / 3600,
+        (second_of_day / 60) % 60,
+        second_of_day % 60
+    ))
+}
+
+/// The proleptic Gregorian date of the given days since 1970-01-01, limited to the years 0 to 9999.
+fn civil_from_days(days: i64) -> Option<(i64, u32, u32)> {
+    // The day count of 0000-03-01 and 10000-03-01, outside of which dates aren't displayed.
+    if !(-719_468..2_932_897).contains(&days) {
+        return None;
+    }
+
+    // See http://howardhinnant.github.io/date_algorithms.html#civil_from_days
+    let days = days + 719_468;
+    let era = days.div_euclid(146_097);
+    let day_of_era = days.rem_euclid(146_097);
+    let year_of_era = (day_of_era - day_of_era / 1460 + day_of_era / 36_524 - day_of_era / 146_096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let shifted_month = (5 * day_of_year + 2) / 153;
+    let day = (day_of_year - (153 * shifted_month + 2) / 5 + 1) as u32;
+    let month = if shifted_month < 10 {
+        shifted_month + 3
+    } else {
+        shifted_month - 9
+    } as u32;
+    let year = year_of_era + era * 400 + i64::from(month <= 2);
+
+    (0..=9999).contains(&year).then_some((year, month, day))
+}
+
+fn days_in_month(year: i64, month: u32) -> u32 {
+    match month {
+        2 if year % 4 == 0 && (year % 100 != 0 || year % 400 == 0) => 29,
+        2 => 28,
+        4 | 6 | 9 | 11 => 30,
+        _ => 31,
+    }
+}
@@ -0,0 +1,125 @@
+//! Copying memory between two editors, such as those of two emulator instances, see [`Transfer`].
+use std::ops::Range;
+
+use egui::{Response, Ui};
+
+use crate::{Address, MemoryEditor};
+
+/// A copy of a range of addresses from the memory of one editor to the memory of another, performed in steps so large
+/// copies can show their progress rather than stall a frame.
+///
+/// The values are read with the source's read function and written with the destination's write function, values
+/// which can't be read are skipped.
+///
+/// ```
+/// # use egui_memory_editor::MemoryEditor;
+/// # use egui_memory_editor::selection::Selection;
+/// let mut source = vec![0xAB; 0x1000];
+/// let mut destination = vec![0u8; 0x1000];
+/// let mut source_editor = MemoryEditor::new().with_address_range("Instance 1", 0..0x1000);
+/// source_editor.set_selection(Selection::from_range(0x100..0x900));
+///
+/// let mut transfer = source_editor.transfer_selection_to(0x200).unwrap();
+/// while !transfer.is_finished() {
+///     // A host would do a single step every frame, drawing the progress with `Transfer::draw_progress`.
+///     transfer.step(
+///         &mut source,
+///         |mem, address| mem.get(address).copied(),
+///         &mut destination,
+///         |mem, address, value| mem[address] = value,
+///         0x400,
+///     );
+/// }
+///
+/// assert_eq!(transfer.copied(), 0x800);
+/// assert_eq!(destination[0x200..0xA00], [0xAB; 0x800]);
+/// assert_eq!(destination[0xA00], 0);
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Transfer {
+    source: Range<Address>,
+    destination: Address,
+    /// The amount of addresses handled so far, from the start of the source.
+    position: usize,
+    copied: usize,
+}
+
+impl Transfer {
+    /// A copy of the `source` range to the addresses starting at `destination`.
+    pub fn new(source: Range<Address>, destination: Address) -> Self {
+        Transfer {
+            source,
+            destination,
+            position: 0,
+            copied: 0,
+        }
+    }
+
+    /// The addresses which are copied.
+    pub fn source(&self) -> Range<Address> {
+        self.source.clone()
+    }
+
+    /// The addresses which are written.
+    pub fn destination(&self) -> Range<Address> {
+        self.destination..self.destination + self.source.len()
+    }
+
+    /// The amount of values written so far, which excludes the values which couldn't be read.
+    pub fn copied(&self) -> usize {
+        self.copied
+    }
+
+    /// The fraction of the source which was handled, from `0.0` to `1.0`.
+    pub fn progress(&self) -> f32 {
+        if self.source.is_empty() {
+            1.0
+        } else {
+            self.position as f32 / self.source.len() as f32
+        }
+    }
+
+    pub fn is_finished(&self) -> bool {
+        self.position >= self.source.len()
+    }
+
+    /// Copy up to `max_bytes` more values, returning whether the transfer is finished.
+    pub fn step<S: ?Sized, D: ?Sized>(
+        &mut self,
+        source_mem: &mut S,
+        mut read_fn: impl FnMut(&mut S, Address) -> Option<u8>,
+        destination_mem: &mut D,
+        mut write_fn: impl FnMut(&mut D, Address, u8),
+        max_bytes: usize,
+    ) -> bool {
+        let end = (self.position + max_bytes.max(1)).min(self.source.len());
+
+        for offset in self.position..end {
+            if let Some(value) = read_fn(source_mem, self.source.start + offset) {
+                write_fn(destination_mem, self.destination + offset, value);
+                self.copied += 1;
+            }
+        }
+
+        self.position = end;
+        self.is_finished()
+    }
+
+    /// Draw a progress bar of the transfer.
+    pub fn draw_progress(&self, ui: &mut Ui) -> Response {
+        ui.add(egui::ProgressBar::new(self.progress()).show_percentage().text(format!(
+            "{:#X}–{:#X} ➜ {:#X}",
+            self.source.start,
+            self.source.end.saturating_sub(1),
+            self.destination
+        )))
+    }
+}
+
+impl MemoryEditor {
+    /// Start a copy of the selection, from its first to its last selected address, to the addresses starting at
+    /// `destination` in another editor's memory. Returns `None` if nothing is selected.
+    pub fn transfer_selection_to(&self, destination: Address) -> Option<Transfer> {
+        self.selection_span().map(|source| Transfer::new(source, destination))
+    }
+}
@@ -0,0 +1,178 @@
+use std::sync::Arc;
+
+use egui::{Context, Ui, Window};
+
+use crate::{Address, MemoryEditor};
+
+pub(crate) type Transform = Arc<dyn Fn(&[u8]) -> Result<Vec<u8>, String> + Send + Sync>;
+
+/// The output of a transform, shown in a read-only editor of its own.
+#[derive(Clone)]
+pub(crate) struct TransformView {
+    pub title: String,
+    pub data: Vec<u8>,
+    pub editor: Box<MemoryEditor>,
+}
+
+impl std::fmt::Debug for TransformView {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("TransformView")
+            .field("title", &self.title)
+            .field("len", &self.data.len())
+            .finish_non_exhaustive()
+    }
+}
+
+impl MemoryEditor {
+    /// Add a named transform, which can be applied to the selected values from the selection options, showing the
+    /// output in a read-only window. Useful for decompressing or decrypting data in place.
+    ///
+    /// Transforms are listed in the order they were added. An error is shown to the user instead of the output.
+    ///
+    /// ```no_run
+    /// # use egui_memory_editor::MemoryEditor;
+    /// // Decode data compressed as pairs of a count and a value.
+    /// let memory_editor = MemoryEditor::new()
+    ///     .with_address_range("ROM", 0..0x8000)
+    ///     .with_transform("Decompress RLE", |bytes| {
+    ///         if bytes.len() % 2 != 0 {
+    ///             return Err("Expected pairs of a count and a value");
+    ///         }
+    ///
+    ///         Ok(bytes
+    ///             .chunks_exact(2)
+    ///             .flat_map(|pair| std::iter::repeat_n(pair[1], pair[0] as usize))
+    ///             .collect())
+    ///     });
+    /// ```
+    #[must_use]
+    pub fn with_transform<E: std::fmt::Display>(
+        mut self,
+        name: impl Into<String>,
+        transform: impl Fn(&[u8]) -> Result<Vec<u8>, E> + Send + Sync + 'static,
+    ) -> Self {
+        self.transforms.push((
+            name.into(),
+            Arc::new(move |bytes| transform(bytes).map_err(|error| error.to_string())),
+        ));
+        self
+    }
+
+    /// Draw the menu of transforms to apply to the selection, if any were added.
+    pub(crate) fn draw_transform_menu<T: ?Sized>(
+        &mut self,
+        ui: &mut Ui,
+        mem: &mut T,
+        read: &mut impl FnMut(&mut T, Address) -> Option<u8>,
+    ) {
+        let tr = self.translator();
+        if self.transforms.is_empty() {
+            return;
+        }
+
+        let mut to_apply = None;
+        ui.menu_button(tr.text("Transform"), |ui| {
+            for (i, (name, _)) in self.transforms.iter().enumerate() {
+                if ui.button(name).clicked() {
+                    to_apply = Some(i);
+                    ui.close();
+                }
+            }
+        })
+        .response
+        .on_hover_text(tr.text("Decode the selected values, showing the output in a read-only window"));
+
+        if let Some(index) = to_apply {
+            self.apply_transform(index, mem, read);
+        }
+    }
+
+    /// Apply the transform at `index` to the selected values, opening a view of the output.
+    fn apply_transform<T: ?Sized>(
+        &mut self,
+        index: usize,
+        mem: &mut T,
+        read: &mut impl FnMut(&mut T, Address) -> Option<u8>,
+    ) {
+        let (name, transform) = self.transforms[index].clone();
+        let Some(source) = self.selection_span() else {
+            return;
+        };
+
+        let Some(bytes) = self
+            .frame_data
+            .selection
+            .merged_ranges()
+            .into_iter()
+            .flatten()
+            .map(|address| read(mem, address))
+            .collect::<Option<Vec<u8>>>()
+        else {
+            let error = self.translator().text("The selection contains unreadable values");
+            self.frame_data.transform_error = Some(format!("{name}: {error}"));
+            return;
+        };
+
+        match transform(&bytes) {
+            Ok(data) => {
+                self.frame_data.transform_error = None;
+                self.open_transform_view(format!("{name} @ {:#X}", source.start), data);
+            }
+            Err(error) => self.frame_data.transform_error = Some(format!("{name}: {error}")),
+        }
+    }
+
+    /// Open a read-only view of `data`, replacing any earlier view with the same title.
+    fn open_transform_view(&mut self, title: String, data: Vec<u8>) {
+        let mut editor = MemoryEditor::new().with_address_range(title.clone(), 0..data.len());
+        editor.options = self.options.clone();
+        editor.translator = self.translator();
+        editor.window.selected_address_range = title.clone();
+        editor.options.record_size = None;
+
+        self.frame_data.transform_views.retain(|view| view.title != title);
+        self.frame_data.transform_views.push(TransformView {
+            title,
+            data,
+            editor: Box::new(editor),
+        });
+    }
+
+    /// Draw a window for every view of a transform's output, closed views are discarded.
+    pub(crate) fn draw_transform_views(&mut self, ctx: &Context) {
+        let tr = self.translator();
+        let mut to_open = None;
+
+        self.frame_data.transform_views.retain_mut(|view| {
+            let mut is_open = true;
+
+            Window::new(&view.title)
+                .id(egui::Id::new(("mem_edit_transform_view", &view.title)))
+                .open(&mut is_open)
+                .hscroll(false)
+                .vscroll(false)
+                .show(ctx, |ui| {
+                    ui.horizontal(|ui| {
+                        ui.label(tr.format("{count} bytes, read-only", &[("count", &view.data.len())]));
+
+                        if ui
+                            .button(tr.text("📂 Open as region"))
+                            .on_hover_text(tr.text("Browse the output as an extra region of the main editor"))
+                            .clicked()
+                        {
+                            to_open = Some((view.title.clone(), std::mem::take(&mut view.data)));
+                        }
+                    });
+                    view.editor
+                        .draw_editor_contents_read_only(ui, &mut view.data, |data, address| data.get(address).copied());
+                });
+
+            is_open && to_open.as_ref().is_none_or(|(title, _)| *title != view.title)
+        });
+
+        if let Some((title, data)) = to_open {
+            self.open_scratch_region(title.clone(), data);
+            self.select_address_range(title);
+        }
+    }
+}
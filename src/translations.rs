@@ -0,0 +1,67 @@
+use std::borrow::Cow;
+use std::fmt::Display;
+use std::sync::Arc;
+
+use crate::MemoryEditor;
+
+type Translate = Arc<dyn Fn(&str) -> Option<String> + Send + Sync>;
+
+/// Translates the built-in text of the UI, see [`MemoryEditor::with_translations`].
+#[derive(Clone, Default)]
+pub(crate) struct Translator(Option<Translate>);
+
+impl Translator {
+    /// The translation of `text`, or `text` itself if there is no translation.
+    pub fn text<'a>(&self, text: &'a str) -> Cow<'a, str> {
+        match self.0.as_ref().and_then(|translate| translate(text)) {
+            Some(translation) => Cow::Owned(translation),
+            None => Cow::Borrowed(text),
+        }
+    }
+
+    /// The translation of the message `text`, with every `{name}` placeholder replaced by the value of the same name.
+    pub fn format(&self, text: &str, values: &[(&str, &dyn Display)]) -> String {
+        values
+            .iter()
+            .fold(self.text(text).into_owned(), |message, (name, value)| {
+                message.replace(&format!("{{{name}}}"), &value.to_string())
+            })
+    }
+}
+
+impl MemoryEditor {
+    /// Set a translation for the built-in text of the UI, such as the options, tooltips, and error messages, to
+    /// localize the editor for non-English applications.
+    ///
+    /// The callback receives the English text of a label, button or tooltip, and returns its translation, or `None`
+    /// to keep the English text. Icons are part of the text, so a translation should keep them. Messages which include
+    /// values, such as addresses and counts, are passed with named placeholders like `{address}`, which the translation
+    /// should keep as well.
+    ///
+    /// ```
+    /// # use egui_memory_editor::MemoryEditor;
+    /// let memory_editor = MemoryEditor::new()
+    ///     .with_address_range("Speicher", 0..0x100)
+    ///     .with_translations(|text| match text {
+    ///         "🛠 Options" => Some("🛠 Optionen".to_string()),
+    ///         "Show ASCII" => Some("ASCII anzeigen".to_string()),
+    ///         "Unknown variable `${name}`" => Some("Unbekannte Variable `${name}`".to_string()),
+    ///         _ => None,
+    ///     });
+    ///
+    /// assert_eq!(
+    ///     memory_editor.evaluate_address_expression("$sp"),
+    ///     Err("Unbekannte Variable `$sp`".to_string())
+    /// );
+    /// ```
+    #[must_use]
+    pub fn with_translations(mut self, translate: impl Fn(&str) -> Option<String> + Send + Sync + 'static) -> Self {
+        self.translator = Translator(Some(Arc::new(translate)));
+        self
+    }
+
+    /// A cheap handle to the translations, which doesn't borrow the editor.
+    pub(crate) fn translator(&self) -> Translator {
+        self.translator.clone()
+    }
+}
@@ -0,0 +1,264 @@
+//! Capturing memory when the value at an address changes, see [`Trigger`].
+use std::ops::Range;
+
+use egui::Ui;
+
+use crate::snapshot::Snapshot;
+use crate::{Address, MemoryEditor};
+
+/// The most captures kept, older captures are dropped first.
+const MAX_TRIGGER_CAPTURES: usize = 100;
+
+/// What a [`Trigger`] does when the value at its address changes.
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum TriggerAction {
+    /// Only record the old and new value.
+    Log,
+    /// Record the old and new value, and take a snapshot of the range which can be compared against later.
+    Snapshot(Range<Address>),
+}
+
+/// Watches the value at an address, and captures memory whenever it changes between two frames.
+///
+/// ```
+/// # use egui_memory_editor::MemoryEditor;
+/// # use egui_memory_editor::triggers::{Trigger, TriggerAction};
+/// let mut memory = vec![0u8; 0x10000];
+/// let read = |mem: &mut Vec<u8>, address: usize| mem.get(address).copied();
+/// let mut memory_editor = MemoryEditor::new().with_address_range("RAM", 0..0x10000);
+/// memory_editor.add_trigger(Trigger::new(0xFF34, TriggerAction::Snapshot(0xC000..0xE000)));
+///
+/// memory_editor.check_triggers(&mut memory, read, 0.0);
+/// memory[0xFF34] = 1;
+/// memory_editor.check_triggers(&mut memory, read, 0.5);
+///
+/// let capture = &memory_editor.trigger_captures()[0];
+/// assert_eq!((capture.address, capture.old, capture.new), (0xFF34, Some(0), Some(1)));
+/// assert!(capture.has_snapshot());
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Trigger {
+    pub address: Address,
+    pub action: TriggerAction,
+}
+
+impl Trigger {
+    pub fn new(address: Address, action: TriggerAction) -> Self {
+        Trigger { address, action }
+    }
+}
+
+/// A change caught by a [`Trigger`].
+#[derive(Clone, Debug)]
+pub struct TriggerCapture {
+    /// The `egui` time, or the time passed to [`MemoryEditor::check_triggers`], at which the change was seen.
+    pub time: f64,
+    pub address: Address,
+    pub old: Option<u8>,
+    pub new: Option<u8>,
+    pub(crate) snapshot: Option<Snapshot>,
+}
+
+impl TriggerCapture {
+    /// Whether a snapshot was taken, see [`MemoryEditor::compare_with_capture`].
+    pub fn has_snapshot(&self) -> bool {
+        self.snapshot.is_some()
+    }
+}
+
+impl MemoryEditor {
+    /// All triggers, in the order they were added.
+    pub fn triggers(&self) -> &[Trigger] {
+        &self.triggers
+    }
+
+    pub fn add_trigger(&mut self, trigger: Trigger) {
+        self.triggers.push(trigger);
+    }
+
+    pub fn remove_trigger(&mut self, index: usize) -> Option<Trigger> {
+        (index < self.triggers.len()).then(|| self.triggers.remove(index))
+    }
+
+    /// The changes caught by the triggers, oldest first.
+    pub fn trigger_captures(&self) -> &[TriggerCapture] {
+        &self.frame_data.trigger_captures
+    }
+
+    pub fn clear_trigger_captures(&mut self) {
+        self.frame_data.trigger_captures.clear();
+    }
+
+    /// Compare the memory against the snapshot of a capture, highlighting the values changed since, like
+    /// [`Self::take_snapshot`]. Returns `false` if the capture has no snapshot.
+    pub fn compare_with_capture(&mut self, index: usize) -> bool {
+        let snapshot = self
+            .frame_data
+            .trigger_captures
+            .get(index)
+            .and_then(|capture| capture.snapshot.clone());
+        let has_snapshot = snapshot.is_some();
+
+        if has_snapshot {
+            self.frame_data.snapshot = snapshot;
+        }
+        has_snapshot
+    }
+
+    /// Read the value of every trigger, and fire the triggers whose value differs from the previous check.
+    ///
+    /// This is done every frame the editor is drawn, hosts only need to call it to catch changes while the editor
+    /// isn't drawn, such as between frames of an emulator running ahead.
+    pub fn check_triggers<T: ?Sized>(
+        &mut self,
+        mem: &mut T,
+        mut read_fn: impl FnMut(&mut T, Address) -> Option<u8>,
+        time: f64,
+    ) {
+        let frame_data = &mut self.frame_data;
+        // Forget the values of removed triggers, so re-adding one doesn't fire right away.
+        frame_data
+            .trigger_values
+            .retain(|address, _| self.triggers.iter().any(|trigger| trigger.address == *address));
+
+        for trigger in &self.triggers {
+            let new = read_fn(mem, trigger.address);
+            let Some(old) = frame_data.trigger_values.insert(trigger.address, new) else {
+                continue;
+            };
+            if old == new {
+                continue;
+            }
+
+            let snapshot = match &trigger.action {
+                TriggerAction::Log => None,
+                TriggerAction::Snapshot(range) => Some(Snapshot::capture(mem, &mut read_fn, range)),
+            };
+            frame_data.trigger_captures.push(TriggerCapture {
+                time,
+                address: trigger.address,
+                old,
+                new,
+                snapshot,
+            });
+        }
+
+        let excess = frame_data.trigger_captures.len().saturating_sub(MAX_TRIGGER_CAPTURES);
+        frame_data.trigger_captures.drain(..excess);
+    }
+
+    /// Draw the list of triggers and their captures underneath a collapsing header.
+    pub(crate) fn draw_triggers(&mut self, ui: &mut Ui, current_address_range: &Range<Address>) {
+        let tr = self.translator();
+        egui::CollapsingHeader::new(tr.text("⚡ Triggers"))
+            .default_open(false)
+            .show(ui, |ui| {
+                let mut to_remove = None;
+
+                egui::Grid::new("triggers_grid").show(ui, |ui| {
+                    for (i, trigger) in self.triggers.iter().enumerate() {
+                        ui.monospace(format!("{:#X}", trigger.address));
+                        match &trigger.action {
+                            TriggerAction::Log => ui.label(tr.text("Log")),
+                            TriggerAction::Snapshot(range) => ui.label(format!(
+                                "{} {:#X}–{:#X}",
+                                tr.text("Snapshot"),
+                                range.start,
+                                range.end.saturating_sub(1)
+                            )),
+                        };
+                        if ui
+                            .small_button("🗑")
+                            .on_hover_text(tr.text("Remove the trigger"))
+                            .clicked()
+                        {
+                            to_remove = Some(i);
+                        }
+                        ui.end_row();
+                    }
+                });
+                if let Some(index) = to_remove {
+                    self.remove_trigger(index);
+                }
+
+                ui.horizontal(|ui| {
+                    let response = ui
+                        .add(
+                            egui::TextEdit::singleline(&mut self.frame_data.new_trigger_text)
+                                .hint_text(tr.text("Address"))
+                                .desired_width(120.0),
+                        )
+                        .on_hover_text(tr.text("The address expression of the value to watch for changes"));
+                    let submitted = response.lost_focus() && ui.input(|i| i.key_pressed(egui::Key::Enter));
+                    ui.checkbox(&mut self.frame_data.new_trigger_snapshot, tr.text("Snapshot"))
+                        .on_hover_text(tr.text("Take a snapshot of the current address range when the value changes"));
+
+                    if ui.button(tr.text("Add trigger")).clicked() || submitted {
+                        match self.evaluate_address_expression(&self.frame_data.new_trigger_text) {
+                            Ok(address) => {
+                                let action = if self.frame_data.new_trigger_snapshot {
+                                    TriggerAction::Snapshot(current_address_range.clone())
+                                } else {
+                                    TriggerAction::Log
+                                };
+                                self.add_trigger(Trigger::new(address, action));
+                                self.frame_data.new_trigger_text.clear();
+                                self.frame_data.new_trigger_error = None;
+                            }
+                            Err(error) => self.frame_data.new_trigger_error = Some(error),
+                        }
+                    }
+                });
+                if let Some(error) = &self.frame_data.new_trigger_error {
+                    ui.colored_label(ui.visuals().error_fg_color, error);
+                }
+
+                if self.frame_data.trigger_captures.is_empty() {
+                    return;
+                }
+                ui.separator();
+
+                let mut to_compare = None;
+                egui::ScrollArea::vertical()
+                    .id_salt("trigger_captures")
+                    .max_height(150.0)
+                    .stick_to_bottom(true)
+                    .show(ui, |ui| {
+                        let format = |value: Option<u8>| {
+                            value.map_or(self.options.none_display_value.clone(), |value| {
+                                format!("{:02X}", value)
+                            })
+                        };
+
+                        for (i, capture) in self.frame_data.trigger_captures.iter().enumerate() {
+                            ui.horizontal(|ui| {
+                                ui.monospace(format!(
+                                    "{:.2}s {:#X}: {} → {}",
+                                    capture.time,
+                                    capture.address,
+                                    format(capture.old),
+                                    format(capture.new)
+                                ));
+                                if capture.has_snapshot()
+                                    && ui
+                                        .small_button(tr.text("Compare"))
+                                        .on_hover_text(tr.text("Highlight the values changed since this capture"))
+                                        .clicked()
+                                {
+                                    to_compare = Some(i);
+                                }
+                            });
+                        }
+                    });
+                if let Some(index) = to_compare {
+                    self.compare_with_capture(index);
+                }
+
+                if ui.button(tr.text("🗑 Clear captures")).clicked() {
+                    self.clear_trigger_captures();
+                }
+            });
+    }
+}
@@ -0,0 +1,230 @@
+use egui::{Context, Key, KeyboardShortcut, Modifiers};
+
+use crate::{Address, MemoryEditor};
+
+/// Undo the last group of edits.
+pub(crate) const UNDO_SHORTCUT: KeyboardShortcut = KeyboardShortcut::new(Modifiers::COMMAND, Key::Z);
+/// Redo the last undone group of edits.
+pub(crate) const REDO_SHORTCUT: KeyboardShortcut =
+    KeyboardShortcut::new(Modifiers::COMMAND.plus(Modifiers::SHIFT), Key::Z);
+/// Redo the last undone group of edits, as on Windows.
+const ALTERNATIVE_REDO_SHORTCUT: KeyboardShortcut = KeyboardShortcut::new(Modifiers::COMMAND, Key::Y);
+
+/// Single value edits less than this many seconds apart are undone together, so typing across several values is a
+/// single step.
+const MERGE_SECONDS: f64 = 1.0;
+
+/// A single write, along with the value it replaced.
+#[derive(Copy, Clone, Debug)]
+pub(crate) struct UndoWrite {
+    pub address: Address,
+    /// The value before the write, `None` if it couldn't be read, in which case the write can't be undone.
+    pub previous: Option<u8>,
+    pub value: u8,
+}
+
+/// The edits which can be undone and redone, in groups which are undone as a single step.
+#[derive(Clone, Debug, Default)]
+pub(crate) struct UndoHistory {
+    undo: Vec<Vec<UndoWrite>>,
+    redo: Vec<Vec<UndoWrite>>,
+    /// The amount of unfinished [`MemoryEditor::begin_undo_group`] calls.
+    open_groups: usize,
+    /// Whether the next write starts a new group, even if it's part of an explicit group or closely follows another.
+    start_new_group: bool,
+    /// The time of the last recorded write, if it was a single write like typing a value.
+    last_single_write: Option<f64>,
+}
+
+impl UndoHistory {
+    /// Record the `writes` of a single frame at `time`, keeping at most `max_groups` groups.
+    ///
+    /// All writes of a frame, like a fill or a paste, are a single group.
+    pub fn record(&mut self, writes: Vec<UndoWrite>, time: f64, max_groups: usize) {
+        if writes.is_empty() || max_groups == 0 {
+            return;
+        }
+
+        let is_single = writes.len() == 1;
+        let is_merged = self.open_groups > 0
+            || (is_single && self.last_single_write.is_some_and(|last| time - last < MERGE_SECONDS));
+        match self.undo.last_mut() {
+            Some(group) if is_merged && !self.start_new_group => group.extend(writes),
+            _ => self.undo.push(writes),
+        }
+
+        if self.undo.len() > max_groups {
+            self.undo.remove(0);
+        }
+        self.start_new_group = false;
+        self.last_single_write = is_single.then_some(time);
+        self.redo.clear();
+    }
+}
+
+impl MemoryEditor {
+    /// Start a group of edits which is undone as a single step, until the matching [`Self::end_undo_group`].
+    ///
+    /// All edits made in the UI and all writes reported with [`Self::record_write`] in between are part of the group.
+    /// Groups can be nested, in which case the outermost group is used.
+    ///
+    /// ```
+    /// # use egui_memory_editor::MemoryEditor;
+    /// let mut memory = vec![0u8; 0x100];
+    /// let mut memory_editor = MemoryEditor::new().with_address_range("Memory", 0..0x100);
+    ///
+    /// // A bulk write by the host, undone in one step.
+    /// memory_editor.begin_undo_group();
+    /// for address in 0x10..0x20 {
+    ///     memory_editor.record_write(address, Some(memory[address]), 0xFF);
+    ///     memory[address] = 0xFF;
+    /// }
+    /// memory_editor.end_undo_group();
+    ///
+    /// assert!(memory_editor.undo(&mut memory, |mem, addr, value| mem[addr] = value));
+    /// assert!(memory.iter().all(|value| *value == 0));
+    /// assert!(memory_editor.can_redo());
+    /// ```
+    pub fn begin_undo_group(&mut self) {
+        let history = &mut self.frame_data.undo_history;
+        if history.open_groups == 0 {
+            history.start_new_group = true;
+        }
+        history.open_groups += 1;
+    }
+
+    /// Finish a group of edits started with [`Self::begin_undo_group`].
+    pub fn end_undo_group(&mut self) {
+        let history = &mut self.frame_data.undo_history;
+        history.open_groups = history.open_groups.saturating_sub(1);
+        if history.open_groups == 0 {
+            history.start_new_group = true;
+        }
+    }
+
+    /// Report a write made by the host, so that it can be undone from the editor.
+    ///
+    /// `previous` is the value before the write, or `None` if it's unknown, in which case the write can't be undone.
+    pub fn record_write(&mut self, address: Address, previous: Option<u8>, value: u8) {
+        let write = UndoWrite {
+            address,
+            previous,
+            value,
+        };
+        // Host writes are only merged with other writes when they're part of an explicit group.
+        let history = &mut self.frame_data.undo_history;
+        history.start_new_group |= history.open_groups == 0;
+        history.record(vec![write], f64::NEG_INFINITY, self.options.undo_history_size);
+        history.start_new_group |= history.open_groups == 0;
+    }
+
+    /// Whether there are edits which can be undone.
+    pub fn can_undo(&self) -> bool {
+        !self.frame_data.undo_history.undo.is_empty()
+    }
+
+    /// Whether there are undone edits which can be redone.
+    pub fn can_redo(&self) -> bool {
+        !self.frame_data.undo_history.redo.is_empty()
+    }
+
+    /// Undo the last group of edits by writing back the previous values, returns whether anything was undone.
+    ///
+    /// Edits made in the UI, such as typing a nibble, are undone the same way, or by pressing `Ctrl+Z`.
+    ///
+    /// ```
+    /// # use egui_memory_editor::MemoryEditor;
+    /// use egui::{Event, Key, Modifiers, PointerButton, RawInput, pos2};
+    ///
+    /// fn run(ctx: &egui::Context, memory_editor: &mut MemoryEditor, memory: &mut Vec<u8>, events: Vec<Event>) {
+    ///     let input = RawInput { events, ..Default::default() };
+    ///     let _ = ctx.run(input, |ctx| {
+    ///         egui::CentralPanel::default().show(ctx, |ui| {
+    ///             memory_editor.draw_editor_contents(
+    ///                 ui,
+    ///                 memory,
+    ///                 |mem, address| mem.get(address).copied(),
+    ///                 |mem, address, value| mem[address] = value,
+    ///             );
+    ///         });
+    ///     });
+    /// }
+    ///
+    /// let mut memory = vec![0x12u8; 0x100];
+    /// let mut memory_editor = MemoryEditor::new().with_address_range("Memory", 0..0x100).with_locked_options();
+    /// memory_editor.options.nibble_editing = true;
+    /// memory_editor.options.show_address_column = false;
+    /// memory_editor.options.show_gutter_markers = false;
+    /// let ctx = egui::Context::default();
+    ///
+    /// // Click the first value to edit it, and type its high nibble.
+    /// let pos = pos2(65.0, 32.0);
+    /// let click = |pressed| Event::PointerButton { pos, button: PointerButton::Primary, pressed, modifiers: Modifiers::NONE };
+    /// run(&ctx, &mut memory_editor, &mut memory, vec![Event::PointerMoved(pos)]);
+    /// run(&ctx, &mut memory_editor, &mut memory, vec![click(true)]);
+    /// run(&ctx, &mut memory_editor, &mut memory, vec![click(false)]);
+    /// run(&ctx, &mut memory_editor, &mut memory, vec![Event::Text("A".to_string())]);
+    /// assert_eq!(memory[0], 0xA2);
+    ///
+    /// let undo = Event::Key { key: Key::Z, physical_key: None, pressed: true, repeat: false, modifiers: Modifiers::COMMAND };
+    /// run(&ctx, &mut memory_editor, &mut memory, vec![undo]);
+    /// assert_eq!(memory[0], 0x12);
+    /// ```
+    pub fn undo<T: ?Sized>(&mut self, mem: &mut T, mut write_fn: impl FnMut(&mut T, Address, u8)) -> bool {
+        let history = &mut self.frame_data.undo_history;
+        let Some(group) = history.undo.pop() else {
+            return false;
+        };
+
+        for write in group.iter().rev() {
+            if let Some(previous) = write.previous {
+                write_fn(mem, write.address, previous);
+            }
+        }
+
+        history.redo.push(group);
+        history.start_new_group = true;
+        true
+    }
+
+    /// Redo the last undone group of edits, returns whether anything was redone.
+    pub fn redo<T: ?Sized>(&mut self, mem: &mut T, mut write_fn: impl FnMut(&mut T, Address, u8)) -> bool {
+        let history = &mut self.frame_data.undo_history;
+        let Some(group) = history.redo.pop() else {
+            return false;
+        };
+
+        for write in &group {
+            write_fn(mem, write.address, write.value);
+        }
+
+        history.undo.push(group);
+        history.start_new_group = true;
+        true
+    }
+
+    /// Handle the undo and redo shortcuts while the editor has keyboard focus.
+    ///
+    /// * `Ctrl+Z` undoes the last group of edits.
+    /// * `Ctrl+Shift+Z` or `Ctrl+Y` redoes the last undone group of edits.
+    pub(crate) fn handle_undo_shortcuts<T: ?Sized>(
+        &mut self,
+        ctx: &Context,
+        mem: &mut T,
+        write_fn: &mut Option<impl FnMut(&mut T, Address, u8)>,
+    ) {
+        let Some(write_fn) = write_fn.as_mut() else {
+            return;
+        };
+        if !self.has_keyboard_focus(ctx) {
+            return;
+        }
+
+        // The redo shortcut has to be checked first, as it includes the modifiers of the undo shortcut.
+        if ctx.input_mut(|i| i.consume_shortcut(&REDO_SHORTCUT) || i.consume_shortcut(&ALTERNATIVE_REDO_SHORTCUT)) {
+            self.redo(mem, write_fn);
+        } else if ctx.input_mut(|i| i.consume_shortcut(&UNDO_SHORTCUT)) {
+            self.undo(mem, write_fn);
+        }
+    }
+}
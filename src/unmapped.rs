@@ -0,0 +1,136 @@
+use std::ops::Range;
+
+use egui::{Color32, Rect, Stroke, Ui};
+
+use crate::option_data::UnreadableStyle;
+use crate::utilities::format_byte_size;
+use crate::{Address, MemoryEditor};
+
+/// The minimum amount of unmapped lines which are collapsed into a single row.
+const MIN_COLLAPSED_LINES: usize = 4;
+/// The horizontal distance between the lines of a hatched background.
+const HATCH_SPACING: f32 = 4.0;
+
+impl MemoryEditor {
+    /// Declare an address range as unmapped, such as a hole in a memory map.
+    ///
+    /// Values in unmapped ranges are never read, and are displayed according to
+    /// [`crate::option_data::MemoryEditorOptions::unreadable_style`].
+    #[inline]
+    #[must_use]
+    pub fn with_unmapped_address_range(mut self, address_range: Range<Address>) -> Self {
+        self.unmapped_address_ranges.push(address_range);
+        self
+    }
+
+    /// Replace all unmapped address ranges, see [`Self::with_unmapped_address_range`].
+    pub fn set_unmapped_address_ranges(&mut self, address_ranges: impl IntoIterator<Item = Range<Address>>) {
+        self.unmapped_address_ranges = address_ranges.into_iter().collect();
+    }
+
+    /// All address ranges which were declared as unmapped.
+    pub fn unmapped_address_ranges(&self) -> &[Range<Address>] {
+        &self.unmapped_address_ranges
+    }
+
+    /// The lines of the given address range which lie entirely in an unmapped address range, for stretches which are
+    /// long enough to collapse.
+    pub(crate) fn collapsed_lines(&self, address_space: &Range<Address>) -> Vec<Range<usize>> {
+        let column_count = self.options.column_count;
+        let line_count = address_space.len().div_ceil(column_count);
+
+        self.unmapped_address_ranges
+            .iter()
+            .filter(|unmapped| unmapped.start < address_space.end && address_space.start < unmapped.end)
+            .map(|unmapped| {
+                let start = unmapped
+                    .start
+                    .saturating_sub(address_space.start)
+                    .div_ceil(column_count);
+                // The last line may be partial, in which case it's entirely unmapped if the rest of the range is.
+                let end = if unmapped.end >= address_space.end {
+                    line_count
+                } else {
+                    (unmapped.end - address_space.start) / column_count
+                };
+
+                start..end
+            })
+            .filter(|lines| lines.end.saturating_sub(lines.start) >= MIN_COLLAPSED_LINES)
+            .collect()
+    }
+
+    /// The label of a row which stands in for the given collapsed lines.
+    pub(crate) fn collapsed_row_label(&self, lines: &Range<usize>, address_space: &Range<Address>) -> String {
+        let column_count = self.options.column_count;
+        let start = address_space.start + lines.start * column_count;
+        let end = (address_space.start + lines.end * column_count).min(address_space.end);
+        let word_size = self.options.word_size;
+        let range = format!(
+            "0x{:X}–0x{:X}",
+            word_size.word_address(start),
+            word_size.word_address(end - 1)
+        );
+
+        self.translator().format(
+            "{size} unmapped ({range})",
+            &[("size", &format_byte_size(end - start)), ("range", &range)],
+        )
+    }
+
+    /// Draw the options for displaying unreadable values.
+    pub(crate) fn draw_unreadable_options(&mut self, ui: &mut Ui) {
+        let tr = self.translator();
+        let options = &mut self.options;
+        let selected_text = match options.unreadable_style {
+            UnreadableStyle::Text => options.none_display_value.clone(),
+            UnreadableStyle::Blank => "Blank".to_string(),
+            UnreadableStyle::Hatched => "Hatched".to_string(),
+        };
+
+        ui.horizontal(|ui| {
+            egui::ComboBox::from_label(tr.text("Unreadable"))
+                .selected_text(selected_text)
+                .show_ui(ui, |ui| {
+                    for text in ["--", "??"] {
+                        let is_selected =
+                            options.unreadable_style == UnreadableStyle::Text && options.none_display_value == text;
+
+                        if ui.selectable_label(is_selected, text).clicked() {
+                            options.unreadable_style = UnreadableStyle::Text;
+                            options.none_display_value = text.to_string();
+                        }
+                    }
+
+                    ui.selectable_value(&mut options.unreadable_style, UnreadableStyle::Blank, tr.text("Blank"));
+                    ui.selectable_value(
+                        &mut options.unreadable_style,
+                        UnreadableStyle::Hatched,
+                        tr.text("Hatched"),
+                    );
+                })
+                .response
+                .on_hover_text(tr.text("How to display values which can't be read"));
+
+            if !self.unmapped_address_ranges.is_empty() {
+                ui.checkbox(&mut options.collapse_unmapped_lines, tr.text("Collapse unmapped"))
+                    .on_hover_text(tr.text("Show long stretches of unmapped lines as a single row"));
+            }
+        });
+    }
+}
+
+/// Paint diagonal lines across `rect`, marking it as unreadable.
+pub(crate) fn paint_hatching(ui: &Ui, rect: Rect, colour: Color32) {
+    let painter = ui.painter_at(rect);
+    let stroke = Stroke::new(1.0, colour);
+    let mut x = rect.left() - rect.height();
+
+    while x < rect.right() {
+        painter.line_segment(
+            [egui::pos2(x, rect.bottom()), egui::pos2(x + rect.height(), rect.top())],
+            stroke,
+        );
+        x += HATCH_SPACING;
+    }
+}
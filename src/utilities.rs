@@ -1,4 +1,13 @@
+#[cfg(any(feature = "search", feature = "tools"))]
+use egui::Ui;
+
 use crate::option_data::{DataFormatType, DataPreviewOptions, Endianness};
+#[cfg(any(feature = "search", feature = "tools"))]
+use crate::translations::Translator;
+
+/// The alignments which can be selected in the UI.
+#[cfg(any(feature = "search", feature = "tools"))]
+const ALIGNMENTS: [usize; 5] = [1, 2, 4, 8, 16];
 
 /// Turn a provided slice into a decimal [`String`] representing it's value, interpretation is based on the provided
 /// [`crate::option_data::DataPreviewOptions`].
@@ -32,3 +41,158 @@ pub fn slice_to_decimal_string(data_preview: DataPreviewOptions, bytes: &[u8]) -
         },
     }
 }
+
+/// Interpret `bytes` as a number according to the data preview options.
+pub fn slice_to_f64(data_preview: DataPreviewOptions, bytes: &[u8]) -> f64 {
+    macro_rules! decode {
+        ($ty:ty) => {{
+            let bytes = bytes.try_into().unwrap();
+            (match data_preview.selected_endianness {
+                Endianness::Big => <$ty>::from_be_bytes(bytes),
+                Endianness::Little => <$ty>::from_le_bytes(bytes),
+            }) as f64
+        }};
+    }
+
+    match data_preview.selected_data_format {
+        DataFormatType::U8 => decode!(u8),
+        DataFormatType::U16 => decode!(u16),
+        DataFormatType::U32 => decode!(u32),
+        DataFormatType::U64 => decode!(u64),
+        DataFormatType::I8 => decode!(i8),
+        DataFormatType::I16 => decode!(i16),
+        DataFormatType::I32 => decode!(i32),
+        DataFormatType::I64 => decode!(i64),
+        DataFormatType::F32 => decode!(f32),
+        DataFormatType::F64 => decode!(f64),
+    }
+}
+
+/// Decode `bytes` as packed BCD, two decimal digits per byte, with the most significant digits first according to
+/// the endianness. Returns `None` if any nibble isn't a decimal digit.
+#[cfg(feature = "inspector")]
+pub fn slice_to_bcd_string(endianness: Endianness, bytes: &[u8]) -> Option<String> {
+    let digit = |nibble: u8| (nibble < 10).then(|| char::from(b'0' + nibble));
+    let mut text = String::with_capacity(2 * bytes.len());
+    let mut push_byte = |byte: &u8| {
+        text.push(digit(byte >> 4)?);
+        text.push(digit(byte & 0xF)?);
+        Some(())
+    };
+
+    match endianness {
+        Endianness::Big => bytes.iter().try_for_each(&mut push_byte)?,
+        Endianness::Little => bytes.iter().rev().try_for_each(&mut push_byte)?,
+    }
+
+    Some(text)
+}
+
+/// Parse a decimal (or `0x` prefixed hexadecimal, for integers) string into the bytes representing its value,
+/// interpretation is based on the provided [`crate::option_data::DataPreviewOptions`].
+///
+/// Returns `None` if the string is not a valid value for the selected data format.
+pub fn decimal_string_to_bytes(data_preview: DataPreviewOptions, text: &str) -> Option<Vec<u8>> {
+    let text = text.trim();
+    let hex = text.strip_prefix("0x").or_else(|| text.strip_prefix("0X"));
+
+    macro_rules! parse_int {
+        ($ty:ty) => {{
+            let value = match hex {
+                Some(hex) => <$ty>::from_str_radix(hex, 16).ok()?,
+                None => text.parse::<$ty>().ok()?,
+            };
+            match data_preview.selected_endianness {
+                Endianness::Big => value.to_be_bytes().to_vec(),
+                Endianness::Little => value.to_le_bytes().to_vec(),
+            }
+        }};
+    }
+
+    macro_rules! parse_float {
+        ($ty:ty) => {{
+            let value = text.parse::<$ty>().ok()?;
+            match data_preview.selected_endianness {
+                Endianness::Big => value.to_be_bytes().to_vec(),
+                Endianness::Little => value.to_le_bytes().to_vec(),
+            }
+        }};
+    }
+
+    let bytes = match data_preview.selected_data_format {
+        DataFormatType::U8 => parse_int!(u8),
+        DataFormatType::U16 => parse_int!(u16),
+        DataFormatType::U32 => parse_int!(u32),
+        DataFormatType::U64 => parse_int!(u64),
+        DataFormatType::I8 => parse_int!(i8),
+        DataFormatType::I16 => parse_int!(i16),
+        DataFormatType::I32 => parse_int!(i32),
+        DataFormatType::I64 => parse_int!(i64),
+        DataFormatType::F32 => parse_float!(f32),
+        DataFormatType::F64 => parse_float!(f64),
+    };
+
+    Some(bytes)
+}
+
+/// Format a size in bytes in a human readable way, using the largest binary unit which fits, e.g. `8 KiB`.
+pub fn format_byte_size(size: usize) -> String {
+    const UNITS: [&str; 5] = ["B", "KiB", "MiB", "GiB", "TiB"];
+
+    let mut value = size as f64;
+    let mut unit = 0;
+
+    while value >= 1024.0 && unit < UNITS.len() - 1 {
+        value /= 1024.0;
+        unit += 1;
+    }
+
+    if value.fract() == 0.0 {
+        format!("{} {}", value, UNITS[unit])
+    } else {
+        format!("{:.1} {}", value, UNITS[unit])
+    }
+}
+
+/// Parse a search pattern, either hexadecimal bytes like `DE AD BE EF` or text in quotes like `"Hello"`.
+///
+/// Returns `None` if the pattern is empty or not valid.
+pub fn parse_search_pattern(text: &str) -> Option<Vec<u8>> {
+    let text = text.trim();
+
+    if let Some(quoted) = text.strip_prefix('"') {
+        let quoted = quoted.strip_suffix('"').unwrap_or(quoted);
+        return (!quoted.is_empty()).then(|| quoted.as_bytes().to_vec());
+    }
+
+    let digits: String = text.chars().filter(|c| !c.is_whitespace()).collect();
+    let digits = digits.strip_prefix("0x").unwrap_or(&digits);
+
+    if digits.is_empty() || !digits.len().is_multiple_of(2) {
+        return None;
+    }
+
+    (0..digits.len())
+        .step_by(2)
+        .map(|i| u8::from_str_radix(digits.get(i..i + 2)?, 16).ok())
+        .collect()
+}
+
+/// Draw a combo box to select the alignment of search results.
+#[cfg(any(feature = "search", feature = "tools"))]
+pub(crate) fn draw_alignment_combo(ui: &mut Ui, tr: &Translator, id_salt: &str, alignment: &mut usize) {
+    let text = |alignment: usize| match alignment {
+        0 | 1 => tr.text("Any alignment").into_owned(),
+        alignment => tr.format("Aligned to {alignment}", &[("alignment", &alignment)]),
+    };
+
+    egui::ComboBox::from_id_salt(id_salt)
+        .selected_text(text(*alignment))
+        .show_ui(ui, |ui| {
+            for option in ALIGNMENTS {
+                ui.selectable_value(alignment, option, text(option));
+            }
+        })
+        .response
+        .on_hover_text(tr.text("Only find matches at addresses which are a multiple of this many bytes"));
+}
@@ -0,0 +1,123 @@
+use std::ops::Range;
+
+use egui::{Context, Window};
+
+use crate::{Address, MemoryEditor};
+
+/// The state of the dialog for editing a typed value, opened by double-clicking a value while editing.
+#[derive(Clone, Debug)]
+pub(crate) struct ValueEditDialog {
+    /// The address at which the value starts.
+    pub address: Address,
+    /// The value being edited, either as a number (interpreted as the data preview format) or as text.
+    pub text: String,
+    /// Whether the `text` should be written as-is, as an ASCII string.
+    pub as_text: bool,
+}
+
+impl MemoryEditor {
+    /// Open the typed value dialog at the given address, pre-filled with the current value.
+    pub(crate) fn open_value_edit_dialog<T: ?Sized>(
+        &mut self,
+        mem: &mut T,
+        read_fn: &mut impl FnMut(&mut T, Address) -> Option<u8>,
+        address: Address,
+        address_range: &Range<Address>,
+    ) {
+        let text = Self::read_mem_value(mem, read_fn, address, self.options.data_preview, address_range);
+
+        self.frame_data.value_edit_dialog = Some(ValueEditDialog {
+            address,
+            text,
+            as_text: false,
+        });
+    }
+
+    /// Draw the typed value dialog, if it is open.
+    pub(crate) fn draw_value_edit_dialog<T: ?Sized>(
+        &mut self,
+        ctx: &Context,
+        mem: &mut T,
+        write_fn: &mut Option<impl FnMut(&mut T, Address, u8)>,
+        address_range: &Range<Address>,
+    ) {
+        let tr = self.translator();
+        let Some(dialog) = &mut self.frame_data.value_edit_dialog else {
+            return;
+        };
+        let data_preview = self.options.data_preview;
+        let mut is_open = true;
+        let mut to_write = None;
+
+        Window::new(tr.text("Edit value"))
+            .id(egui::Id::new("mem_edit_value_dialog"))
+            .open(&mut is_open)
+            .collapsible(false)
+            .resizable(false)
+            .show(ctx, |ui| {
+                let kind = if dialog.as_text {
+                    tr.text("ASCII text").into_owned()
+                } else {
+                    format!(
+                        "{:?} ({:?} endian)",
+                        data_preview.selected_data_format, data_preview.selected_endianness
+                    )
+                };
+                let address = format!("{:#X}", dialog.address);
+                ui.label(tr.format("{kind} at {address}", &[("kind", &kind), ("address", &address)]));
+
+                let response = ui.text_edit_singleline(&mut dialog.text);
+                ui.checkbox(&mut dialog.as_text, tr.text("Write as text"))
+                    .on_hover_text(
+                        tr.text("Write the characters of the text, rather than interpreting it as a number"),
+                    );
+
+                let bytes = if dialog.as_text {
+                    dialog.text.is_ascii().then(|| dialog.text.as_bytes().to_vec())
+                } else {
+                    crate::utilities::decimal_string_to_bytes(data_preview, &dialog.text)
+                };
+
+                match &bytes {
+                    Some(bytes) if dialog.address + bytes.len() > address_range.end => {
+                        ui.colored_label(
+                            ui.visuals().warn_fg_color,
+                            tr.text("Value extends past the end of the range"),
+                        );
+                    }
+                    Some(_) => {}
+                    None => {
+                        ui.colored_label(ui.visuals().error_fg_color, tr.text("Invalid value"));
+                    }
+                }
+
+                let enter_pressed = response.lost_focus() && ui.input(|i| i.key_pressed(egui::Key::Enter));
+                if (ui
+                    .add_enabled(bytes.is_some(), egui::Button::new(tr.text("Apply")))
+                    .clicked()
+                    || enter_pressed)
+                    && let Some(bytes) = bytes
+                {
+                    to_write = Some((dialog.address, bytes));
+                }
+            });
+
+        if let Some((address, bytes)) = to_write {
+            if let Some(write_fn) = write_fn.as_mut() {
+                for (i, value) in bytes.into_iter().enumerate() {
+                    let address = address + i;
+
+                    if address_range.contains(&address) {
+                        write_fn(mem, address, value);
+                    }
+                }
+            }
+
+            is_open = false;
+        }
+
+        if !is_open {
+            self.frame_data.value_edit_dialog = None;
+        }
+    }
+}
@@ -0,0 +1,74 @@
+use std::collections::{BTreeMap, HashMap};
+
+use crate::{Address, MemoryEditor};
+
+/// The legend name of values which changed between frames.
+pub(crate) const RECENT_CHANGES_GROUP: &str = "Recent changes";
+
+/// The values displayed in the last two frames, used to detect values changing between frames without the host
+/// having to keep its own copy of memory.
+#[derive(Clone, Debug, Default)]
+pub(crate) struct ValueHistory {
+    /// The values displayed in the current frame, or in the last frame in between frames.
+    current: BTreeMap<Address, Option<u8>>,
+    /// The values displayed in the frame before `current`.
+    previous: BTreeMap<Address, Option<u8>>,
+    /// The time at which recently changed addresses last changed.
+    changed_at: HashMap<Address, f64>,
+    time: f64,
+}
+
+impl ValueHistory {
+    /// Swap the buffers for a new frame at `time`, forgetting changes older than `fade_seconds`.
+    pub fn begin_frame(&mut self, time: f64, fade_seconds: f32) {
+        std::mem::swap(&mut self.current, &mut self.previous);
+        self.current.clear();
+        self.time = time;
+        self.changed_at
+            .retain(|_, changed_at| time - *changed_at < fade_seconds as f64);
+    }
+
+    /// Record the value displayed at `address` this frame.
+    pub fn record(&mut self, address: Address, value: Option<u8>) {
+        if self.previous.get(&address).is_some_and(|previous| *previous != value) {
+            self.changed_at.insert(address, self.time);
+        }
+
+        self.current.insert(address, value);
+    }
+
+    /// How recently the value at `address` changed, from `1.0` when it just changed to `0.0` after `fade_seconds`.
+    pub fn change_strength(&self, address: Address, fade_seconds: f32) -> Option<f32> {
+        let elapsed = (self.time - self.changed_at.get(&address)?) as f32;
+        Some((1.0 - elapsed / fade_seconds.max(f32::EPSILON)).clamp(0.0, 1.0))
+    }
+
+    /// Whether any change is still fading out.
+    pub fn is_fading(&self) -> bool {
+        !self.changed_at.is_empty()
+    }
+}
+
+impl MemoryEditor {
+    /// The values displayed in the last frame, by address.
+    ///
+    /// Only the addresses which were visible are included, unreadable values are `None`.
+    pub fn last_frame_values(&self) -> &BTreeMap<Address, Option<u8>> {
+        &self.frame_data.value_history.current
+    }
+
+    /// The values displayed in the frame before the last frame, by address, see [`Self::last_frame_values`].
+    pub fn previous_frame_values(&self) -> &BTreeMap<Address, Option<u8>> {
+        &self.frame_data.value_history.previous
+    }
+
+    /// The addresses visible in both of the last two frames, whose value differed between them.
+    pub fn changed_since_previous_frame(&self) -> impl Iterator<Item = Address> + '_ {
+        let history = &self.frame_data.value_history;
+        history
+            .current
+            .iter()
+            .filter(|(address, value)| history.previous.get(address).is_some_and(|previous| previous != *value))
+            .map(|(address, _)| *address)
+    }
+}
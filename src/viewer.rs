@@ -0,0 +1,128 @@
+//! A read-only hex viewer, for hosts which only display data, see [`MemoryViewer`].
+use std::marker::PhantomData;
+use std::ops::Range;
+
+use egui::{Context, Style, Ui};
+
+use crate::option_data::MemoryEditorOptions;
+use crate::{Address, MemoryEditor};
+
+/// A convenience wrapper around a read-only [`MemoryEditor`], for displaying data such as logs or network packets.
+///
+/// The viewer keeps its read function, so drawing it only needs the data. It never receives a write function, so
+/// nothing can be written, and the options start out collapsed without the status bar. The options are those of the
+/// wrapped editor, the ones for editing simply have no effect.
+///
+/// ```no_run
+/// # use egui_memory_editor::viewer::MemoryViewer;
+/// # let ctx = egui::Context::default();
+/// let packet = vec![0x45, 0x00, 0x00, 0x54];
+/// let mut viewer = MemoryViewer::new(|packet: &Vec<u8>, address| packet.get(address).copied())
+///     .with_address_range("Packet", 0..packet.len());
+///
+/// viewer.window_ui(&ctx, &packet);
+/// ```
+pub struct MemoryViewer<T: ?Sized, F> {
+    editor: MemoryEditor,
+    read_fn: F,
+    data: PhantomData<fn(&T)>,
+}
+
+impl<T: ?Sized, F: Clone> Clone for MemoryViewer<T, F> {
+    fn clone(&self) -> Self {
+        MemoryViewer {
+            editor: self.editor.clone(),
+            read_fn: self.read_fn.clone(),
+            data: PhantomData,
+        }
+    }
+}
+
+impl<T: ?Sized, F: Fn(&T, Address) -> Option<u8>> MemoryViewer<T, F> {
+    /// Create the viewer, which should be kept in memory between frames.
+    ///
+    /// The `read_fn` returns the value at an address of the data passed to [`Self::window_ui`] or [`Self::draw`], or
+    /// `None` if it isn't available.
+    pub fn new(read_fn: F) -> Self {
+        let mut editor = MemoryEditor::new().with_window_title("Memory Viewer");
+        editor.options.is_read_only = true;
+        editor.options.is_options_collapsed = true;
+        editor.options.show_status_bar = false;
+
+        MemoryViewer {
+            editor,
+            read_fn,
+            data: PhantomData,
+        }
+    }
+
+    /// Set the title of the window, see [`MemoryEditor::with_window_title`].
+    #[must_use]
+    pub fn with_window_title(mut self, title: impl Into<String>) -> Self {
+        self.editor = self.editor.with_window_title(title);
+        self
+    }
+
+    /// Add an address range to view, see [`MemoryEditor::with_address_range`].
+    #[must_use]
+    pub fn with_address_range(mut self, range_name: impl Into<String>, address_range: Range<Address>) -> Self {
+        self.editor = self.editor.with_address_range(range_name, address_range);
+        self
+    }
+
+    /// Set the options, see [`MemoryEditor::with_options`].
+    #[must_use]
+    pub fn with_options(mut self, options: MemoryEditorOptions) -> Self {
+        self.editor = self.editor.with_options(options);
+        self
+    }
+
+    /// Adjust the style used while drawing the viewer, see [`MemoryEditor::with_style_override`].
+    #[must_use]
+    pub fn with_style_override(mut self, style_override: impl Fn(&mut Style) + Send + Sync + 'static) -> Self {
+        self.editor = self.editor.with_style_override(style_override);
+        self
+    }
+
+    /// Add or replace an address range, for example when the length of the data changed.
+    pub fn set_address_range(&mut self, range_name: impl Into<String>, address_range: Range<Address>) {
+        self.editor.set_address_range(range_name, address_range);
+    }
+
+    pub fn options(&self) -> &MemoryEditorOptions {
+        &self.editor.options
+    }
+
+    pub fn options_mut(&mut self) -> &mut MemoryEditorOptions {
+        &mut self.editor.options
+    }
+
+    /// Returns the visible range of the last frame.
+    pub fn visible_range(&self) -> &Range<Address> {
+        self.editor.visible_range()
+    }
+
+    /// Whether the window is shown, see [`MemoryEditor::is_open`].
+    pub fn is_open(&self) -> bool {
+        self.editor.is_open()
+    }
+
+    /// Show or hide the window, see [`MemoryEditor::set_open`].
+    pub fn set_open(&mut self, is_open: bool) {
+        self.editor.set_open(is_open);
+    }
+
+    /// Create a window and render the viewer within, if it's open.
+    pub fn window_ui(&mut self, ctx: &Context, data: &T) {
+        let read_fn = &self.read_fn;
+        self.editor
+            .window_ui_read_only(ctx, &mut &*data, |data, address| read_fn(data, address));
+    }
+
+    /// Draw the viewer in whatever container you want, use [`Self::window_ui`] for a window instead.
+    pub fn draw(&mut self, ui: &mut Ui, data: &T) {
+        let read_fn = &self.read_fn;
+        self.editor
+            .draw_editor_contents_read_only(ui, &mut &*data, |data, address| read_fn(data, address));
+    }
+}
@@ -0,0 +1,158 @@
+//! Additional windows showing the same memory as the editor, see [`MemoryEditor::spawn_view`].
+use std::ops::Range;
+
+use egui::Context;
+
+use crate::option_data::{BetweenFrameData, MemoryEditorOptions};
+use crate::scrolling::ScrollState;
+use crate::window_state::{ViewLayout, WindowState};
+use crate::{Address, MemoryEditor, ResizeFunctions};
+
+/// An additional window of the editor, with its own title, options, and window state.
+#[derive(Clone)]
+pub(crate) struct View {
+    title: String,
+    window: WindowState,
+    options: MemoryEditorOptions,
+    frame_data: BetweenFrameData,
+    visible_range: Range<Address>,
+}
+
+impl View {
+    /// The state of the view, to restore it later.
+    pub(crate) fn layout(&self) -> ViewLayout {
+        ViewLayout {
+            title: self.title.clone(),
+            window: self.window.clone(),
+            options: self.options.clone(),
+        }
+    }
+
+    /// Recreate a view saved with [`Self::layout`], its position is applied the next time it's drawn.
+    pub(crate) fn restore(layout: ViewLayout) -> Self {
+        View {
+            title: layout.title,
+            window: layout.window,
+            options: layout.options,
+            frame_data: BetweenFrameData {
+                restore_window_position: true,
+                scroll: ScrollState {
+                    restore_offset: true,
+                    ..Default::default()
+                },
+                ..Default::default()
+            },
+            visible_range: Default::default(),
+        }
+    }
+}
+
+impl MemoryEditor {
+    /// Open an additional window showing the address range `range_name`, which is drawn along with the editor's own
+    /// window by [`Self::window_ui`] and its variants, using the same memory and functions.
+    ///
+    /// Every view has its own scroll position, cursor, selection, and options, which start out as a copy of the
+    /// editor's options and can be overridden through the returned reference. The address ranges, bookmarks, symbols,
+    /// and the rest of the configuration are shared. Views are discarded when their window is closed, and spawning a
+    /// view with the title of an existing one replaces it. The title has to differ from the editor's own title, see
+    /// [`Self::with_window_title`].
+    ///
+    /// ```
+    /// # use egui_memory_editor::MemoryEditor;
+    /// let mut memory_editor = MemoryEditor::new()
+    ///     .with_address_range("WRAM", 0xC000..0xE000)
+    ///     .with_address_range("VRAM", 0x8000..0xA000);
+    ///
+    /// memory_editor.spawn_view("VRAM view", "VRAM").column_count = 32;
+    ///
+    /// assert!(memory_editor.has_view("VRAM view"));
+    /// assert_eq!(memory_editor.options.column_count, 16);
+    /// ```
+    pub fn spawn_view(&mut self, title: impl Into<String>, range_name: &str) -> &mut MemoryEditorOptions {
+        let title = title.into();
+        let selected_address_range = match self.address_range(range_name) {
+            Some(_) => range_name.to_string(),
+            None => self.window.selected_address_range.clone(),
+        };
+
+        self.views.retain(|view| view.title != title);
+        let index = self.views.len();
+        self.views.push(View {
+            title,
+            window: WindowState {
+                selected_address_range,
+                ..Default::default()
+            },
+            options: self.options.clone(),
+            frame_data: Default::default(),
+            visible_range: Default::default(),
+        });
+
+        &mut self.views[index].options
+    }
+
+    /// Whether a view with the given title is open, see [`Self::spawn_view`].
+    pub fn has_view(&self, title: &str) -> bool {
+        self.views.iter().any(|view| view.title == title)
+    }
+
+    /// The titles of the open views, in the order they were spawned.
+    pub fn view_titles(&self) -> impl Iterator<Item = &str> {
+        self.views.iter().map(|view| view.title.as_str())
+    }
+
+    /// The options of the view with the given title, to change them after it was spawned.
+    pub fn view_options_mut(&mut self, title: &str) -> Option<&mut MemoryEditorOptions> {
+        self.views
+            .iter_mut()
+            .find(|view| view.title == title)
+            .map(|view| &mut view.options)
+    }
+
+    /// Close the view with the given title, returns `false` if no such view exists.
+    pub fn close_view(&mut self, title: &str) -> bool {
+        let count = self.views.len();
+        self.views.retain(|view| view.title != title);
+
+        self.views.len() != count
+    }
+
+    /// Draw the window of every view, closed views are discarded.
+    pub(crate) fn draw_views<T: ?Sized>(
+        &mut self,
+        ctx: &Context,
+        mem: &mut T,
+        read_fn: &mut impl FnMut(&mut T, Address) -> Option<u8>,
+        write_fn: &mut Option<impl FnMut(&mut T, Address, u8)>,
+        resize_fns: &mut Option<
+            ResizeFunctions<impl FnMut(&mut T, Address, usize), impl FnMut(&mut T, Range<Address>)>,
+        >,
+    ) {
+        let mut views = std::mem::take(&mut self.views);
+
+        for view in &mut views {
+            self.swap_view(view);
+            self.frame_data.memory_range_combo_box_enabled = self.address_ranges.len() > 1;
+            self.draw_window(
+                ctx,
+                mem,
+                &mut *read_fn,
+                write_fn.as_mut(),
+                resize_fns.as_mut().map(ResizeFunctions::by_ref),
+            );
+            self.swap_view(view);
+        }
+
+        views.retain(|view| view.window.is_open);
+        self.views = views;
+    }
+
+    /// Exchange the state of the editor's own window with that of `view`.
+    fn swap_view(&mut self, view: &mut View) {
+        std::mem::swap(&mut self.window_name, &mut view.title);
+        std::mem::swap(&mut self.window, &mut view.window);
+        std::mem::swap(&mut self.options, &mut view.options);
+        std::mem::swap(&mut self.frame_data, &mut view.frame_data);
+        std::mem::swap(&mut self.visible_range, &mut view.visible_range);
+    }
+}
@@ -0,0 +1,322 @@
+//! Expressions whose values are read and displayed every frame, see [`Watch`].
+use std::collections::{HashMap, VecDeque};
+use std::ops::Range;
+
+use egui::{Pos2, RichText, Sense, Stroke, Ui, Vec2};
+
+use crate::option_data::{DataFormatType, DataPreviewOptions};
+use crate::translations::Translator;
+use crate::utilities::{slice_to_decimal_string, slice_to_f64};
+use crate::{Address, MemoryEditor};
+
+/// A value to display every frame, the address is an expression which is evaluated every frame as well.
+///
+/// ```
+/// # use egui_memory_editor::option_data::DataFormatType;
+/// # use egui_memory_editor::watches::Watch;
+/// let watch = Watch::parse("u16 @ player_hp").unwrap();
+/// assert_eq!(watch, Watch::new("player_hp", DataFormatType::U16));
+///
+/// // Without a type the watch displays a single byte.
+/// assert_eq!(Watch::parse("sp + 2").unwrap().format, DataFormatType::U8);
+/// assert!(Watch::parse("u12 @ player_hp").is_err());
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Watch {
+    /// The address expression, see [`MemoryEditor::evaluate_address_expression`].
+    pub expression: String,
+    /// How the value at the address is interpreted, using the endianness of the data preview.
+    pub format: DataFormatType,
+}
+
+impl Watch {
+    pub fn new(expression: impl Into<String>, format: DataFormatType) -> Self {
+        Watch {
+            expression: expression.into(),
+            format,
+        }
+    }
+
+    /// Parse a watch written as `type @ expression`, like `u16 @ player_hp`, or as just the expression for a `u8`.
+    pub fn parse(text: &str) -> Result<Self, String> {
+        Self::parse_translated(text, &Translator::default())
+    }
+
+    /// Parse a watch like [`Self::parse`], translating why it couldn't be parsed.
+    fn parse_translated(text: &str, tr: &Translator) -> Result<Self, String> {
+        let (format, expression) = match text.split_once('@') {
+            Some((format, expression)) => {
+                let format = format.trim();
+                let format = DataFormatType::iter()
+                    .find(|option| format!("{option:?}").eq_ignore_ascii_case(format))
+                    .ok_or_else(|| tr.format("Unknown type `{format}`", &[("format", &format)]))?;
+                (format, expression.trim())
+            }
+            None => (DataFormatType::U8, text.trim()),
+        };
+
+        if expression.is_empty() {
+            return Err(tr.text("Enter an address expression to watch").into_owned());
+        }
+
+        Ok(Self::new(expression, format))
+    }
+}
+
+impl std::fmt::Display for Watch {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{} @ {}",
+            format!("{:?}", self.format).to_lowercase(),
+            self.expression
+        )
+    }
+}
+
+/// The width of the sparkline of a watch.
+const SPARKLINE_WIDTH: f32 = 64.0;
+/// The amount of frames kept when the sparklines are enabled from the UI.
+const DEFAULT_SPARKLINE_LENGTH: usize = 120;
+
+/// The last value of a watch, to detect when it changes.
+#[derive(Clone, Debug, Default)]
+pub(crate) struct WatchHistory {
+    value: Option<Vec<u8>>,
+    changed_at: Option<f64>,
+    /// The values of the last frames for the sparkline, oldest first.
+    samples: VecDeque<f64>,
+}
+
+impl MemoryEditor {
+    /// All watches, in the order they were added.
+    pub fn watches(&self) -> &[Watch] {
+        &self.watches
+    }
+
+    /// Add a watch, which is displayed in the watch list in the UI.
+    pub fn add_watch(&mut self, watch: Watch) {
+        self.watches.push(watch);
+    }
+
+    /// Remove the watch at the given index, see [`Self::watches`].
+    pub fn remove_watch(&mut self, index: usize) -> Option<Watch> {
+        (index < self.watches.len()).then(|| self.watches.remove(index))
+    }
+
+    /// Replace all watches, useful for restoring previously persisted watches.
+    pub fn set_watches(&mut self, watches: Vec<Watch>) {
+        self.watches = watches;
+    }
+
+    /// Draw the watch list, evaluating and reading every watch.
+    pub(crate) fn draw_watches<T: ?Sized>(
+        &mut self,
+        ui: &mut Ui,
+        current_address_range: &Range<Address>,
+        mem: &mut T,
+        read: &mut impl FnMut(&mut T, Address) -> Option<u8>,
+    ) {
+        let tr = self.translator();
+        egui::CollapsingHeader::new(tr.text("👁 Watches"))
+            .default_open(false)
+            .show(ui, |ui| {
+                let time = ui.input(|i| i.time);
+                let fade_seconds = self.options.recent_change_fade_seconds;
+                let mut history = std::mem::take(&mut self.frame_data.watch_history);
+                let mut is_fading = false;
+                let mut to_remove = None;
+                let mut to_jump = None;
+
+                egui::Grid::new("watches_grid").show(ui, |ui| {
+                    for (i, watch) in self.watches.iter().enumerate() {
+                        ui.label(watch.to_string());
+
+                        match self.evaluate_address_expression(&watch.expression) {
+                            Ok(address) => {
+                                let entry = history.entry(watch.clone()).or_default();
+                                to_jump = self
+                                    .draw_watch_value(ui, mem, read, watch, address, entry, current_address_range)
+                                    .or(to_jump);
+                                is_fading |= entry
+                                    .changed_at
+                                    .is_some_and(|changed_at| ((time - changed_at) as f32) < fade_seconds);
+                            }
+                            Err(error) => {
+                                ui.colored_label(ui.visuals().error_fg_color, error);
+                                ui.label("");
+                                if self.options.watch_sparkline_length.is_some() {
+                                    ui.label("");
+                                }
+                            }
+                        }
+
+                        if ui
+                            .small_button("🗑")
+                            .on_hover_text(tr.text("Remove the watch"))
+                            .clicked()
+                        {
+                            to_remove = Some(i);
+                        }
+
+                        ui.end_row();
+                    }
+                });
+
+                // Forget the history of removed watches.
+                history.retain(|watch, _| self.watches.contains(watch));
+                self.frame_data.watch_history = history;
+                if is_fading && self.options.highlight_watch_changes {
+                    ui.ctx().request_repaint();
+                }
+
+                if let Some(address) = to_jump {
+                    self.jump_to_address(address, current_address_range);
+                }
+                if let Some(index) = to_remove {
+                    self.remove_watch(index);
+                }
+
+                ui.horizontal(|ui| {
+                    let response = ui
+                        .add(
+                            egui::TextEdit::singleline(&mut self.frame_data.new_watch_text)
+                                .hint_text(tr.text("u16 @ player_hp"))
+                                .desired_width(160.0),
+                        )
+                        .on_hover_text(tr.text(
+                            "A type (u8, i16, f32, ...) and an address expression separated by `@`, without a type \
+                             the watch displays a single byte",
+                        ));
+                    let submitted = response.lost_focus() && ui.input(|i| i.key_pressed(egui::Key::Enter));
+
+                    if ui.button(tr.text("Add watch")).clicked() || submitted {
+                        match Watch::parse_translated(&self.frame_data.new_watch_text, &tr) {
+                            Ok(watch) => {
+                                self.add_watch(watch);
+                                self.frame_data.new_watch_text.clear();
+                                self.frame_data.new_watch_error = None;
+                            }
+                            Err(error) => self.frame_data.new_watch_error = Some(error),
+                        }
+                    }
+
+                    ui.checkbox(&mut self.options.highlight_watch_changes, tr.text("Highlight changes"))
+                        .on_hover_text(tr.text("Highlight values which changed, the highlight fades out over time"));
+
+                    let mut show_sparklines = self.options.watch_sparkline_length.is_some();
+                    if ui
+                        .checkbox(&mut show_sparklines, tr.text("Sparklines"))
+                        .on_hover_text(tr.text("Show a small graph of the values of the last frames of every watch"))
+                        .changed()
+                    {
+                        self.options.watch_sparkline_length = show_sparklines.then_some(DEFAULT_SPARKLINE_LENGTH);
+                    }
+                });
+
+                if let Some(error) = &self.frame_data.new_watch_error {
+                    ui.colored_label(ui.visuals().error_fg_color, error);
+                }
+            });
+    }
+
+    /// Draw the address and value of a single watch, updating its history.
+    /// Returns the address if the user wants to jump to it.
+    #[allow(clippy::too_many_arguments)]
+    fn draw_watch_value<T: ?Sized>(
+        &self,
+        ui: &mut Ui,
+        mem: &mut T,
+        read: &mut impl FnMut(&mut T, Address) -> Option<u8>,
+        watch: &Watch,
+        address: Address,
+        history: &mut WatchHistory,
+        current_address_range: &Range<Address>,
+    ) -> Option<Address> {
+        let tr = self.translator();
+        let jump = ui
+            .add_enabled(
+                current_address_range.contains(&address),
+                egui::Button::new(format!("{:#X}", address)),
+            )
+            .on_hover_text(tr.text("Jump to the watched address"))
+            .on_disabled_hover_text(tr.text("The address is not part of the selected address range"))
+            .clicked();
+
+        let bytes = (0..watch.format.bytes_to_read())
+            .map(|offset| read(mem, address.checked_add(offset)?))
+            .collect::<Option<Vec<u8>>>();
+        let time = ui.input(|i| i.time);
+        if history.value.is_some() && history.value != bytes {
+            history.changed_at = Some(time);
+        }
+
+        let data_preview = DataPreviewOptions {
+            selected_data_format: watch.format,
+            ..self.options.data_preview
+        };
+        let mut text = RichText::new(match &bytes {
+            Some(bytes) => slice_to_decimal_string(data_preview, bytes),
+            None => self.options.none_display_value.clone(),
+        })
+        .text_style(self.options.memory_editor_text_style.clone());
+        if let Some(length) = self.options.watch_sparkline_length {
+            if let Some(bytes) = &bytes {
+                history.samples.push_back(slice_to_f64(data_preview, bytes));
+            }
+            while history.samples.len() > length {
+                history.samples.pop_front();
+            }
+        }
+        history.value = bytes;
+
+        let fade_seconds = self.options.recent_change_fade_seconds.max(f32::EPSILON);
+        let elapsed = history.changed_at.map(|changed_at| (time - changed_at) as f32);
+        if self.options.highlight_watch_changes
+            && let Some(elapsed) = elapsed.filter(|elapsed| *elapsed < fade_seconds)
+        {
+            let strength = 1.0 - elapsed / fade_seconds;
+            text = text.background_color(self.options.recent_change_colour.gamma_multiply(strength));
+        }
+        ui.label(text);
+
+        if self.options.watch_sparkline_length.is_some() {
+            draw_sparkline(ui, &history.samples);
+        }
+
+        jump.then_some(address)
+    }
+}
+
+/// Draw the samples as a line scaled to fit between their lowest and highest value, hovering shows the range.
+fn draw_sparkline(ui: &mut Ui, samples: &VecDeque<f64>) {
+    let height = ui.text_style_height(&egui::TextStyle::Body);
+    let (rect, response) = ui.allocate_exact_size(Vec2::new(SPARKLINE_WIDTH, height), Sense::hover());
+    let finite = samples.iter().copied().filter(|sample| sample.is_finite());
+    let (min, max) = finite.fold((f64::INFINITY, f64::NEG_INFINITY), |(min, max), sample| {
+        (min.min(sample), max.max(sample))
+    });
+    if samples.len() < 2 || min > max {
+        return;
+    }
+
+    let span = if max > min { max - min } else { 1.0 };
+    let step = rect.width() / (samples.len() - 1) as f32;
+    let points = samples
+        .iter()
+        .enumerate()
+        .filter(|(_, sample)| sample.is_finite())
+        .map(|(i, sample)| {
+            let y = rect.bottom() - ((sample - min) / span) as f32 * rect.height();
+            Pos2::new(rect.left() + i as f32 * step, y)
+        })
+        .collect::<Vec<_>>();
+    ui.painter()
+        .add(egui::Shape::line(points, Stroke::new(1.0, ui.visuals().text_color())));
+
+    response.on_hover_text(format!("{min} – {max}"));
+}
+
+/// The history of every watch, by watch.
+pub(crate) type WatchHistories = HashMap<Watch, WatchHistory>;
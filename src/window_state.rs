@@ -0,0 +1,162 @@
+use egui::{Pos2, Vec2, Window};
+
+use crate::MemoryEditor;
+use crate::option_data::MemoryEditorOptions;
+use crate::views::View;
+
+/// The state of the editor's window, rather than how the memory is displayed.
+///
+/// Where [`crate::option_data::MemoryEditorOptions`] describes the presentation, this describes what the user is
+/// looking at: whether the window is open, where it is, the selected address range, and how far it's scrolled. It can
+/// optionally be serialized/deserialized with `serde` to restore a session, see [`MemoryEditor::set_window_state`].
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+pub struct WindowState {
+    /// Whether the window is shown by [`MemoryEditor::window_ui`].
+    pub(crate) is_open: bool,
+    /// The name of the selected address range.
+    pub(crate) selected_address_range: String,
+    /// The vertical scroll offset of the main UI, in points.
+    pub(crate) scroll_offset: f32,
+    /// The position of the top left corner of the window in the last frame it was shown, if it was.
+    pub(crate) position: Option<Pos2>,
+    /// The size of the contents of the window in the last frame it was shown, if it was.
+    pub(crate) size: Option<Vec2>,
+}
+
+/// The state of the editor's window along with the additional views and their options, to restore a whole workspace.
+///
+/// Can optionally be serialized/deserialized with `serde`, see [`MemoryEditor::layout`].
+#[derive(Clone, Debug, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+pub struct Layout {
+    /// The state of the editor's own window.
+    pub window: WindowState,
+    /// The additional views, in the order they were spawned, see [`MemoryEditor::spawn_view`].
+    pub views: Vec<ViewLayout>,
+}
+
+/// The state of an additional view, see [`Layout`].
+#[derive(Clone, Debug, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+pub struct ViewLayout {
+    /// The title of the view's window.
+    pub title: String,
+    /// The state of the view's window.
+    pub window: WindowState,
+    /// The options of the view.
+    pub options: MemoryEditorOptions,
+}
+
+impl Default for WindowState {
+    fn default() -> Self {
+        WindowState {
+            is_open: true,
+            selected_address_range: String::new(),
+            scroll_offset: 0.0,
+            position: None,
+            size: None,
+        }
+    }
+}
+
+impl MemoryEditor {
+    /// Whether the window is shown by [`Self::window_ui`], it's open by default.
+    ///
+    /// The window can be closed by the user with its close button, or by the host with [`Self::set_open`].
+    ///
+    /// ```
+    /// # use egui_memory_editor::MemoryEditor;
+    /// let mut memory_editor = MemoryEditor::new().with_address_range("Memory", 0..0x100);
+    /// assert!(memory_editor.is_open());
+    ///
+    /// memory_editor.toggle();
+    /// assert!(!memory_editor.is_open());
+    ///
+    /// memory_editor.open();
+    /// assert!(memory_editor.is_open());
+    /// ```
+    pub fn is_open(&self) -> bool {
+        self.window.is_open
+    }
+
+    /// Show the window, see [`Self::is_open`].
+    pub fn open(&mut self) {
+        self.window.is_open = true;
+    }
+
+    /// Show or hide the window, see [`Self::is_open`].
+    pub fn set_open(&mut self, is_open: bool) {
+        self.window.is_open = is_open;
+    }
+
+    /// Hide the window if it's shown, or show it if it's hidden, see [`Self::is_open`].
+    pub fn toggle(&mut self) {
+        self.window.is_open = !self.window.is_open;
+    }
+
+    /// The current state of the window, to persist the session separately from the options.
+    pub fn window_state(&self) -> &WindowState {
+        &self.window
+    }
+
+    /// Restore the state of the window, such as one saved with [`Self::window_state`].
+    ///
+    /// The scroll offset and the position of the window are applied the next time the editor is drawn. The size is
+    /// only used when `egui` doesn't remember the window yet, such as when the application was restarted.
+    pub fn set_window_state(&mut self, window_state: WindowState) {
+        self.window = window_state;
+        self.frame_data.scroll.restore_offset = true;
+        self.frame_data.restore_window_position = true;
+    }
+
+    /// The state of the window and every view, to restore the arrangement later with [`Self::restore_layout`].
+    ///
+    /// ```
+    /// # use egui_memory_editor::MemoryEditor;
+    /// let mut memory_editor = MemoryEditor::new()
+    ///     .with_address_range("WRAM", 0xC000..0xE000)
+    ///     .with_address_range("VRAM", 0x8000..0xA000);
+    /// memory_editor.spawn_view("VRAM view", "VRAM").column_count = 32;
+    ///
+    /// let layout = memory_editor.layout();
+    /// let mut restored = MemoryEditor::new()
+    ///     .with_address_range("WRAM", 0xC000..0xE000)
+    ///     .with_address_range("VRAM", 0x8000..0xA000);
+    /// restored.restore_layout(layout);
+    ///
+    /// assert_eq!(restored.view_options_mut("VRAM view").map(|options| options.column_count), Some(32));
+    /// ```
+    pub fn layout(&self) -> Layout {
+        Layout {
+            window: self.window.clone(),
+            views: self.views.iter().map(View::layout).collect(),
+        }
+    }
+
+    /// Restore the window and the views saved with [`Self::layout`], replacing any open views.
+    pub fn restore_layout(&mut self, layout: Layout) {
+        self.set_window_state(layout.window);
+        self.views = layout.views.into_iter().map(View::restore).collect();
+    }
+
+    /// Place the window where it was in the last frame it was shown, if it was.
+    pub(crate) fn apply_window_geometry<'open>(&mut self, mut window: Window<'open>) -> Window<'open> {
+        if let Some(position) = self.window.position {
+            window = window.default_pos(position);
+
+            if std::mem::take(&mut self.frame_data.restore_window_position) {
+                window = window.current_pos(position);
+            }
+        }
+
+        if let Some(size) = self.window.size {
+            window = window.default_size(size);
+        }
+
+        window
+    }
+}
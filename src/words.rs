@@ -0,0 +1,131 @@
+//! Support for platforms whose addressable unit is larger than a byte, such as DSPs with 16-bit words.
+//!
+//! The editor itself works with bytes, so every word is split into [`WordSize::bytes`] byte lanes, stored big-endian
+//! at consecutive addresses. Word `n` therefore corresponds to the addresses
+//! `n * word_size.bytes()..(n + 1) * word_size.bytes()`, see [`WordSize::lane_range`].
+//!
+//! The lanes of a word are displayed as a single group, and the ASCII sidebar decodes the whole word. Addresses in the
+//! UI, such as the address column, the goto box, search results, bookmarks, and exports, are word addresses, while
+//! the API of the editor, including the read and write functions it's given, uses byte lane addresses.
+//!
+//! ```no_run
+//! # use egui_memory_editor::MemoryEditor;
+//! # use egui_memory_editor::words::{self, WordSize};
+//! # let ctx = egui::Context::default();
+//! let mut memory = vec![0u16; 0x100];
+//! let word_size = WordSize::new(16);
+//! let mut memory_editor = MemoryEditor::new().with_address_range("Memory", word_size.lane_range(0..0x100));
+//! memory_editor.options.word_size = word_size;
+//!
+//! let read_word = |mem: &mut Vec<u16>, address: usize| mem.get(address).map(|&word| word as u32);
+//! memory_editor.window_ui(
+//!     &ctx,
+//!     &mut memory,
+//!     words::read_word_lanes(word_size, read_word),
+//!     words::write_word_lanes(word_size, read_word, |mem, address, word| mem[address] = word as u16),
+//! );
+//! ```
+use std::ops::Range;
+
+use crate::Address;
+
+/// The amount of bits in a single addressable unit of memory.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct WordSize {
+    bits: u32,
+}
+
+impl WordSize {
+    /// Ordinary byte addressable memory.
+    pub const BYTE: WordSize = WordSize { bits: 8 };
+
+    /// Create a word size with the given amount of bits, clamped to `8..=32`.
+    pub const fn new(bits: u32) -> Self {
+        let bits = if bits < 8 {
+            8
+        } else if bits > 32 {
+            32
+        } else {
+            bits
+        };
+
+        WordSize { bits }
+    }
+
+    /// The amount of bits in a word.
+    pub const fn bits(&self) -> u32 {
+        self.bits
+    }
+
+    /// The amount of byte lanes needed to display a word.
+    pub const fn bytes(&self) -> usize {
+        self.bits.div_ceil(8) as usize
+    }
+
+    /// The bits of a `u32` which are part of a word.
+    pub const fn mask(&self) -> u32 {
+        if self.bits == 32 {
+            u32::MAX
+        } else {
+            (1 << self.bits) - 1
+        }
+    }
+
+    /// The byte lane addresses used by the given range of word addresses.
+    pub const fn lane_range(&self, words: Range<Address>) -> Range<Address> {
+        words.start * self.bytes()..words.end * self.bytes()
+    }
+
+    /// The words of which the given range of byte lane addresses is part.
+    pub const fn word_range(&self, lanes: Range<Address>) -> Range<Address> {
+        self.word_address(lanes.start)..lanes.end.div_ceil(self.bytes())
+    }
+
+    /// The word containing the given byte lane address.
+    pub const fn word_address(&self, lane_address: Address) -> Address {
+        lane_address / self.bytes()
+    }
+}
+
+impl Default for WordSize {
+    fn default() -> Self {
+        WordSize::BYTE
+    }
+}
+
+/// Turn a function reading words into a read function for the editor, which reads the byte lanes of each word.
+pub fn read_word_lanes<T: ?Sized>(
+    word_size: WordSize,
+    mut read_word: impl FnMut(&mut T, Address) -> Option<u32>,
+) -> impl FnMut(&mut T, Address) -> Option<u8> {
+    move |mem, address| {
+        let word = read_word(mem, word_size.word_address(address))? & word_size.mask();
+        let shift = 8 * (word_size.bytes() - 1 - address % word_size.bytes());
+
+        Some((word >> shift) as u8)
+    }
+}
+
+/// Turn functions reading and writing words into a write function for the editor, which replaces a single byte lane
+/// of a word.
+///
+/// The other lanes are kept, so if a word wider than a byte can't be read the write is skipped.
+pub fn write_word_lanes<T: ?Sized>(
+    word_size: WordSize,
+    mut read_word: impl FnMut(&mut T, Address) -> Option<u32>,
+    mut write_word: impl FnMut(&mut T, Address, u32),
+) -> impl FnMut(&mut T, Address, u8) {
+    move |mem, address, value| {
+        let word_address = word_size.word_address(address);
+        let shift = 8 * (word_size.bytes() - 1 - address % word_size.bytes());
+        let word = match read_word(mem, word_address) {
+            Some(word) => word,
+            None if word_size.bytes() == 1 => 0,
+            None => return,
+        };
+        let word = (word & !(0xFF << shift)) | ((value as u32) << shift);
+
+        write_word(mem, word_address, word & word_size.mask());
+    }
+}
@@ -0,0 +1,72 @@
+use std::sync::Arc;
+
+use egui::Ui;
+
+use crate::notices::Notice;
+use crate::{Address, MemoryEditor};
+
+pub(crate) type WriteGuard = Arc<dyn Fn(Address, u8) -> bool + Send + Sync>;
+
+/// How long the tooltip explaining a rejected edit stays visible.
+const WRITE_NOTICE_SECONDS: f64 = 2.0;
+
+impl MemoryEditor {
+    /// Set a callback which is consulted before every write initiated from the UI, with the address and the value to
+    /// write. Returning `false` blocks the write, and a tooltip tells the user the edit was rejected.
+    ///
+    /// Useful to enforce rules of the host, such as not letting users corrupt memory mapped registers.
+    /// For mirrored address ranges the address is the one in the mirror, as displayed in the editor. Blocked writes are
+    /// still reported to the [`Self::with_access_hook`] callback, as the guard is consulted last.
+    ///
+    /// ```no_run
+    /// # use egui_memory_editor::MemoryEditor;
+    /// // Don't allow edits to the I/O registers.
+    /// let memory_editor = MemoryEditor::new()
+    ///     .with_address_range("Memory", 0..0x10000)
+    ///     .with_write_guard(|address, _value| !(0xFF00..0xFF80).contains(&address));
+    /// ```
+    #[must_use]
+    pub fn with_write_guard(mut self, allow_write: impl Fn(Address, u8) -> bool + Send + Sync + 'static) -> Self {
+        self.write_guard = Some(Arc::new(allow_write));
+        self
+    }
+
+    /// Whether the write guard allows writing `value` at `address`, see [`Self::with_write_guard`].
+    pub(crate) fn is_write_allowed(write_guard: &Option<WriteGuard>, address: Address, value: u8) -> bool {
+        write_guard
+            .as_ref()
+            .is_none_or(|allow_write| allow_write(address, value))
+    }
+
+    /// Show a tooltip at the pointer for a while after an edit was rejected, such as a write blocked by the write
+    /// guard.
+    pub(crate) fn draw_write_notice(&mut self, ui: &mut Ui, blocked_write: Option<Address>) {
+        let time = ui.input(|i| i.time);
+        if let Some(address) = blocked_write {
+            self.frame_data.notify(Notice::WriteRejected { address });
+            let address = format!("{:#X}", address);
+            let notice = self
+                .translator()
+                .format("🚫 Writing to {address} isn't allowed", &[("address", &address)]);
+            self.frame_data.write_notice = Some((notice, time));
+        }
+
+        let Some((notice, rejected_at)) = &self.frame_data.write_notice else {
+            return;
+        };
+        if time - rejected_at > WRITE_NOTICE_SECONDS {
+            self.frame_data.write_notice = None;
+            return;
+        }
+
+        egui::Tooltip::always_open(
+            ui.ctx().clone(),
+            ui.layer_id(),
+            ui.id().with("write_notice_tooltip"),
+            egui::PopupAnchor::Pointer,
+        )
+        .gap(12.0)
+        .show(|ui| ui.colored_label(ui.visuals().warn_fg_color, notice));
+        ui.ctx().request_repaint();
+    }
+}